@@ -20,14 +20,22 @@
 use arrow_array::cast::*;
 use arrow_array::iterator::ArrayIter;
 use arrow_array::*;
-use arrow_buffer::NullBuffer;
+use arrow_buffer::{ArrowNativeType, NullBuffer};
 use arrow_data::bit_iterator::try_for_each_valid_idx;
 use arrow_schema::*;
+use num_traits::ToPrimitive;
 use std::borrow::BorrowMut;
 use std::cmp::{self, Ordering};
+use std::collections::HashSet;
 use std::ops::{BitAnd, BitOr, BitXor};
 use types::ByteViewType;
 
+mod hyperloglog;
+mod tdigest;
+
+pub use hyperloglog::HyperLogLog;
+pub use tdigest::TDigest;
+
 /// An accumulator for primitive numeric values.
 trait NumericAccumulator<T: ArrowNativeTypeOp>: Copy + Default {
     /// Accumulate a non-null value.
@@ -1028,6 +1036,438 @@ pub fn max<T: ArrowNumericType>(array: &PrimitiveArray<T>) -> Option<T::Native>
     aggregate::<T::Native, T, MaxAccumulator<T::Native>>(array)
 }
 
+/// Returns the scale of a decimal `data_type`, or `0` for any other type
+fn decimal_scale(data_type: &DataType) -> i8 {
+    match data_type {
+        DataType::Decimal32(_, s)
+        | DataType::Decimal64(_, s)
+        | DataType::Decimal128(_, s)
+        | DataType::Decimal256(_, s) => *s,
+        _ => 0,
+    }
+}
+
+/// Invokes `f` with each non-null value of `array` converted to `f64`,
+/// taking into account the scale of decimal arrays
+fn for_each_valid_f64<T, F>(array: &PrimitiveArray<T>, mut f: F)
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+    F: FnMut(f64),
+{
+    let div = 10_f64.powi(decimal_scale(array.data_type()) as i32);
+    let mut visit = |v: &T::Native| f(v.to_f64().unwrap_or(f64::NAN) / div);
+    match array.nulls() {
+        None => array.values().iter().for_each(visit),
+        Some(nulls) => nulls
+            .valid_indices()
+            .for_each(|idx| visit(&array.values()[idx])),
+    }
+}
+
+/// A mergeable accumulator of the count, mean and variance of a set of values
+///
+/// Values are accumulated using Welford's online algorithm, which is numerically
+/// stable, and partial accumulators can be combined with [`Self::merge`]
+///
+/// # Example
+/// ```
+/// # use arrow_array::Float64Array;
+/// # use arrow_arith::aggregate::VarianceAccumulator;
+/// let mut a = VarianceAccumulator::new();
+/// a.update_array(&Float64Array::from(vec![2.0, 4.0, 4.0, 4.0]));
+///
+/// let mut b = VarianceAccumulator::new();
+/// b.update_array(&Float64Array::from(vec![5.0, 5.0, 7.0, 9.0]));
+///
+/// a.merge(&b);
+/// assert_eq!(a.count(), 8);
+/// assert_eq!(a.mean(), Some(5.0));
+/// assert_eq!(a.variance_pop(), Some(4.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VarianceAccumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl VarianceAccumulator {
+    /// Create a new, empty [`VarianceAccumulator`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `value` to this accumulator
+    pub fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Add the non-null values of `array` to this accumulator
+    ///
+    /// Decimal values are scaled according to the scale of the array
+    pub fn update_array<T>(&mut self, array: &PrimitiveArray<T>)
+    where
+        T: ArrowNumericType,
+        T::Native: ToPrimitive,
+    {
+        for_each_valid_f64(array, |v| self.update(v))
+    }
+
+    /// Combine the values of `other` into this accumulator
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let other_weight = other.count as f64 / count as f64;
+        self.mean += delta * other_weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * other_weight;
+        self.count = count;
+    }
+
+    /// Returns the number of values in this accumulator
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the arithmetic mean, or `None` if there are no values
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the population variance, or `None` if there are no values
+    pub fn variance_pop(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Returns the sample variance, or `None` if there are fewer than two values
+    pub fn variance_sample(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+}
+
+fn variance_accumulator<T>(array: &PrimitiveArray<T>) -> VarianceAccumulator
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    let mut acc = VarianceAccumulator::new();
+    acc.update_array(array);
+    acc
+}
+
+/// Returns the arithmetic mean of the values in the array as a `f64`
+///
+/// Returns `None` if the array is empty or only contains null values.
+/// Decimal values are scaled according to the scale of the array, for an
+/// exact result consider using [`mean_decimal`]
+pub fn mean<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    variance_accumulator(array).mean()
+}
+
+/// Returns the arithmetic mean of the values in the decimal array, with the
+/// same precision and scale as the array
+///
+/// The mean is rounded half away from zero to the scale of the array.
+///
+/// Returns `Ok(None)` if the array is empty or only contains null values.
+///
+/// This detects overflow of the intermediate sum and returns an `Err` for that.
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::Decimal128Array;
+/// # use arrow_arith::aggregate::mean_decimal;
+/// // 1.00, 2.00 and 2.00
+/// let array = Decimal128Array::from(vec![100, 200, 200])
+///     .with_precision_and_scale(5, 2)
+///     .unwrap();
+/// // 1.67
+/// assert_eq!(mean_decimal(&array).unwrap(), Some(167));
+/// ```
+pub fn mean_decimal<T: types::DecimalType>(
+    array: &PrimitiveArray<T>,
+) -> Result<Option<T::Native>, ArrowError> {
+    let Some(sum) = sum_checked(array)? else {
+        return Ok(None);
+    };
+    let count = array.len() - array.null_count();
+    let count = T::Native::from_usize(count).ok_or_else(|| {
+        ArrowError::ArithmeticOverflow(format!(
+            "Overflow happened on: mean of {count} values of {}",
+            array.data_type()
+        ))
+    })?;
+
+    let mean = sum.div_wrapping(count);
+    let rem = sum.mod_wrapping(count);
+    let abs_rem = match rem.is_lt(T::Native::ZERO) {
+        true => rem.neg_wrapping(),
+        false => rem,
+    };
+    Ok(Some(match abs_rem.is_ge(count.sub_wrapping(abs_rem)) {
+        true if sum.is_lt(T::Native::ZERO) => mean.sub_wrapping(T::Native::ONE),
+        true => mean.add_wrapping(T::Native::ONE),
+        false => mean,
+    }))
+}
+
+/// Returns the population variance of the values in the array
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn variance_pop<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    variance_accumulator(array).variance_pop()
+}
+
+/// Returns the sample variance of the values in the array
+///
+/// Returns `None` if the array contains fewer than two non-null values.
+pub fn variance_sample<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    variance_accumulator(array).variance_sample()
+}
+
+/// Returns the population standard deviation of the values in the array
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn stddev_pop<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    variance_pop(array).map(f64::sqrt)
+}
+
+/// Returns the sample standard deviation of the values in the array
+///
+/// Returns `None` if the array contains fewer than two non-null values.
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::Int32Array;
+/// # use arrow_arith::aggregate::stddev_sample;
+/// let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+/// assert_eq!(stddev_sample(&array), Some(2_f64.sqrt()));
+/// ```
+pub fn stddev_sample<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    variance_sample(array).map(f64::sqrt)
+}
+
+/// How [`quantile`] computes a quantile that lies between two values
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuantileInterpolation {
+    /// Linearly interpolate between the two values
+    #[default]
+    Linear,
+    /// Use the lower of the two values
+    Lower,
+    /// Use the higher of the two values
+    Higher,
+    /// Use the nearest of the two values, choosing the higher on a tie
+    Nearest,
+    /// Use the average of the two values
+    Midpoint,
+}
+
+/// Returns the exact quantile `q` of the values in the array as a `f64`
+///
+/// `q` must be between `0` and `1`, and quantiles that lie between two values
+/// are computed according to `interpolation`. Decimal values are scaled according
+/// to the scale of the array, and floating point `NaN` values are ordered after
+/// all other values.
+///
+/// Returns `Ok(None)` if the array is empty or only contains null values.
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::Int32Array;
+/// # use arrow_arith::aggregate::{quantile, QuantileInterpolation};
+/// let array = Int32Array::from(vec![4, 1, 3, 2]);
+/// let q = quantile(&array, 0.5, QuantileInterpolation::Linear).unwrap();
+/// assert_eq!(q, Some(2.5));
+/// let q = quantile(&array, 0.5, QuantileInterpolation::Lower).unwrap();
+/// assert_eq!(q, Some(2.0));
+/// ```
+pub fn quantile<T>(
+    array: &PrimitiveArray<T>,
+    q: f64,
+    interpolation: QuantileInterpolation,
+) -> Result<Option<f64>, ArrowError>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    if !(0.0..=1.0).contains(&q) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Quantile must be between 0 and 1, got {q}"
+        )));
+    }
+
+    let mut values: Vec<T::Native> = match array.nulls() {
+        None => array.values().to_vec(),
+        Some(nulls) => nulls
+            .valid_indices()
+            .map(|idx| array.values()[idx])
+            .collect(),
+    };
+    if values.is_empty() {
+        return Ok(None);
+    }
+    values.sort_unstable_by(|a, b| a.compare(*b));
+
+    let div = 10_f64.powi(decimal_scale(array.data_type()) as i32);
+    let value = |idx: usize| values[idx].to_f64().unwrap_or(f64::NAN) / div;
+
+    let position = q * (values.len() - 1) as f64;
+    let (lower, higher) = (position.floor() as usize, position.ceil() as usize);
+    Ok(Some(match interpolation {
+        QuantileInterpolation::Lower => value(lower),
+        QuantileInterpolation::Higher => value(higher),
+        QuantileInterpolation::Nearest => value(position.round() as usize),
+        QuantileInterpolation::Midpoint if lower == higher => value(lower),
+        QuantileInterpolation::Midpoint => f64::midpoint(value(lower), value(higher)),
+        QuantileInterpolation::Linear if lower == higher => value(lower),
+        QuantileInterpolation::Linear => {
+            let (l, h) = (value(lower), value(higher));
+            l + (h - l) * (position - lower as f64)
+        }
+    }))
+}
+
+/// Returns the exact median of the values in the array as a `f64`
+///
+/// The median of an even number of values is the average of the two middle values.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn median<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    quantile(array, 0.5, QuantileInterpolation::Linear).unwrap()
+}
+
+type ValueBytes<'a> = Box<dyn Fn(usize) -> Option<&'a [u8]> + 'a>;
+
+/// Returns a function returning the byte representation of the value at an index of
+/// `array`, or `None` if it is null
+///
+/// Equal values have equal byte representations, floating point values are compared
+/// by their bit pattern
+fn value_bytes<'a>(array: &'a dyn Array) -> Result<ValueBytes<'a>, ArrowError> {
+    use arrow_buffer::ToByteSlice;
+
+    if let Some(dict) = array.as_any_dictionary_opt() {
+        let keys = dict.keys();
+        let normalized = dict.normalized_keys();
+        let values = value_bytes(dict.values().as_ref())?;
+        return Ok(Box::new(move |idx| {
+            keys.is_valid(idx).then(|| values(normalized[idx]))?
+        }));
+    }
+
+    macro_rules! primitive_helper {
+        ($t:ty, $a:ident) => {{
+            let array = $a.as_primitive::<$t>();
+            Ok(Box::new(move |idx| {
+                array
+                    .is_valid(idx)
+                    .then(|| array.values()[idx].to_byte_slice())
+            }))
+        }};
+    }
+
+    downcast_primitive! {
+        array.data_type() => (primitive_helper, array),
+        DataType::Boolean => {
+            let array = array.as_boolean();
+            Ok(Box::new(move |idx| {
+                array
+                    .is_valid(idx)
+                    .then(|| match array.value(idx) {
+                        true => [1_u8].as_slice(),
+                        false => [0_u8].as_slice(),
+                    })
+            }))
+        }
+        DataType::Utf8 => {
+            let array = array.as_bytes::<types::Utf8Type>();
+            Ok(Box::new(move |idx| array.is_valid(idx).then(|| array.value(idx).as_bytes())))
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_bytes::<types::LargeUtf8Type>();
+            Ok(Box::new(move |idx| array.is_valid(idx).then(|| array.value(idx).as_bytes())))
+        }
+        DataType::Binary => {
+            let array = array.as_bytes::<types::BinaryType>();
+            Ok(Box::new(move |idx| array.is_valid(idx).then(|| array.value(idx))))
+        }
+        DataType::LargeBinary => {
+            let array = array.as_bytes::<types::LargeBinaryType>();
+            Ok(Box::new(move |idx| array.is_valid(idx).then(|| array.value(idx))))
+        }
+        DataType::Utf8View => {
+            let array = array.as_byte_view::<types::StringViewType>();
+            Ok(Box::new(move |idx| array.is_valid(idx).then(|| array.value(idx).as_bytes())))
+        }
+        DataType::BinaryView => {
+            let array = array.as_byte_view::<types::BinaryViewType>();
+            Ok(Box::new(move |idx| array.is_valid(idx).then(|| array.value(idx))))
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array.as_fixed_size_binary();
+            Ok(Box::new(move |idx| array.is_valid(idx).then(|| array.value(idx))))
+        }
+        t => Err(ArrowError::NotYetImplemented(format!(
+            "Distinct values of {t} are not supported"
+        ))),
+    }
+}
+
+/// Returns the number of distinct non-null values in the array
+///
+/// Floating point values are compared by their bit pattern, and so `0.0` and `-0.0`
+/// are distinct. Dictionary arrays count the distinct values referenced by their keys.
+///
+/// For an approximate count that can be combined across arrays see [`HyperLogLog`]
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::StringArray;
+/// # use arrow_arith::aggregate::count_distinct;
+/// let array = StringArray::from(vec![Some("a"), None, Some("b"), Some("a")]);
+/// assert_eq!(count_distinct(&array).unwrap(), 2);
+/// ```
+pub fn count_distinct(array: &dyn Array) -> Result<usize, ArrowError> {
+    let value = value_bytes(array)?;
+    let distinct: HashSet<&[u8]> = (0..array.len()).filter_map(value).collect();
+    Ok(distinct.len())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2162,4 +2602,117 @@ mod tests {
         let result = max_array::<Int32Type, _>(typed_array);
         assert_eq!(result, Some(100));
     }
+
+    #[test]
+    fn test_variance_stddev() {
+        let a = Int32Array::from(vec![Some(2), Some(4), None, Some(4), Some(4)]);
+        let b = Int32Array::from(vec![Some(5), Some(5), Some(7), Some(9)]);
+        assert_eq!(mean(&a), Some(3.5));
+        assert_eq!(variance_pop(&a), Some(0.75));
+        assert_eq!(variance_sample(&a), Some(1.0));
+        assert_eq!(stddev_sample(&a), Some(1.0));
+
+        let mut acc = VarianceAccumulator::new();
+        acc.update_array(&a);
+        let mut other = VarianceAccumulator::new();
+        other.update_array(&b);
+        acc.merge(&other);
+        acc.merge(&VarianceAccumulator::new());
+        assert_eq!(acc.count(), 8);
+        assert_eq!(acc.mean(), Some(5.0));
+        assert_eq!(acc.variance_pop(), Some(4.0));
+
+        let single = Float64Array::from(vec![Some(1.0), None]);
+        assert_eq!(variance_pop(&single), Some(0.0));
+        assert_eq!(variance_sample(&single), None);
+        assert_eq!(stddev_pop(&Float64Array::new_null(2)), None);
+
+        // Welford's algorithm is stable for values with a large offset
+        let offset = Float64Array::from_iter_values([4.0, 7.0, 13.0, 16.0].map(|v| v + 1e9));
+        assert_eq!(variance_sample(&offset), Some(30.0));
+
+        let decimal = Decimal128Array::from(vec![150, 250])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        assert_eq!(mean(&decimal), Some(2.0));
+        assert_eq!(variance_pop(&decimal), Some(0.25));
+    }
+
+    #[test]
+    fn test_mean_decimal() {
+        let array = Decimal128Array::from(vec![Some(-100), Some(-200), None, Some(-200)])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        assert_eq!(mean_decimal(&array).unwrap(), Some(-167));
+
+        let array = Decimal32Array::from(vec![1, 2])
+            .with_precision_and_scale(9, 0)
+            .unwrap();
+        assert_eq!(mean_decimal(&array).unwrap(), Some(2));
+
+        let array = Decimal256Array::new_null(1);
+        assert_eq!(mean_decimal(&array).unwrap(), None);
+
+        let array = Decimal32Array::from(vec![i32::MAX, 1]);
+        let err = mean_decimal(&array).unwrap_err().to_string();
+        assert!(err.contains("Overflow happened on"), "{err}");
+    }
+
+    #[test]
+    fn test_quantile() {
+        let array = Int32Array::from(vec![Some(40), None, Some(10), Some(30), Some(20)]);
+        let quantile = |q, i| quantile(&array, q, i).unwrap().unwrap();
+        assert_eq!(quantile(0.0, QuantileInterpolation::Linear), 10.0);
+        assert_eq!(quantile(1.0, QuantileInterpolation::Linear), 40.0);
+        assert_eq!(quantile(0.4, QuantileInterpolation::Linear), 22.0);
+        assert_eq!(quantile(0.4, QuantileInterpolation::Lower), 20.0);
+        assert_eq!(quantile(0.4, QuantileInterpolation::Higher), 30.0);
+        assert_eq!(quantile(0.4, QuantileInterpolation::Nearest), 20.0);
+        assert_eq!(quantile(0.5, QuantileInterpolation::Nearest), 30.0);
+        assert_eq!(quantile(0.4, QuantileInterpolation::Midpoint), 25.0);
+        assert_eq!(median(&array), Some(25.0));
+
+        let array = Float64Array::from(vec![f64::NAN, 3.0, 1.0]);
+        assert_eq!(median(&array), Some(3.0));
+        assert_eq!(median(&Float64Array::new_null(3)), None);
+
+        let array = Decimal128Array::from(vec![125, 175])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        assert_eq!(median(&array), Some(1.5));
+
+        let err = super::quantile(&array, -0.1, QuantileInterpolation::Linear).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Quantile must be between 0 and 1, got -0.1"
+        );
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let array = Int32Array::from(vec![Some(1), None, Some(2), Some(1), None]);
+        assert_eq!(count_distinct(&array).unwrap(), 2);
+        assert_eq!(count_distinct(&array.slice(2, 3)).unwrap(), 2);
+
+        let array = BooleanArray::from(vec![Some(true), None, Some(true)]);
+        assert_eq!(count_distinct(&array).unwrap(), 1);
+
+        let array = StringViewArray::from(vec!["a", "a long string value", "a"]);
+        assert_eq!(count_distinct(&array).unwrap(), 2);
+
+        let array = Float64Array::from(vec![0.0, -0.0, 0.0]);
+        assert_eq!(count_distinct(&array).unwrap(), 2);
+
+        let values = StringArray::from(vec![Some("a"), None, Some("b"), Some("a")]);
+        let keys = Int8Array::from(vec![Some(0), Some(1), None, Some(3), Some(2)]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+        assert_eq!(count_distinct(&array).unwrap(), 2);
+
+        let array = new_null_array(&DataType::Null, 3);
+        let err = count_distinct(&array).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Not yet implemented: Distinct values of Null are not supported"
+        );
+    }
 }
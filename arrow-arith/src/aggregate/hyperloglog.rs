@@ -0,0 +1,286 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use super::value_bytes;
+use arrow_array::Array;
+use arrow_schema::ArrowError;
+
+/// A [HyperLogLog] sketch estimating the number of distinct values
+///
+/// The sketch uses `2 ^ precision` single byte registers, and has a relative
+/// standard error of approximately `1.04 / sqrt(2 ^ precision)`.
+///
+/// Values are hashed with a fixed hash function, and so sketches built from different
+/// arrays, processes or machines can be combined with [`Self::merge`], or exchanged
+/// using [`Self::to_bytes`] and [`Self::try_from_bytes`].
+///
+/// # Example
+/// ```
+/// # use arrow_array::Int64Array;
+/// # use arrow_arith::aggregate::HyperLogLog;
+/// let mut a = HyperLogLog::new();
+/// a.update(&Int64Array::from_iter_values(0..6000)).unwrap();
+///
+/// let mut b = HyperLogLog::new();
+/// b.update(&Int64Array::from_iter_values(4000..10000)).unwrap();
+///
+/// let b = HyperLogLog::try_from_bytes(&b.to_bytes()).unwrap();
+/// a.merge(&b).unwrap();
+///
+/// let estimate = a.estimate();
+/// assert!((9800..10200).contains(&estimate));
+/// ```
+///
+/// [HyperLogLog]: https://en.wikipedia.org/wiki/HyperLogLog
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HyperLogLog {
+    /// The default precision, resulting in a relative standard error of `0.81%`
+    pub const DEFAULT_PRECISION: u8 = 14;
+
+    /// The minimum supported precision
+    pub const MIN_PRECISION: u8 = 4;
+
+    /// The maximum supported precision
+    pub const MAX_PRECISION: u8 = 18;
+
+    /// Create a new, empty [`HyperLogLog`] with [`Self::DEFAULT_PRECISION`]
+    pub fn new() -> Self {
+        Self::try_new(Self::DEFAULT_PRECISION).unwrap()
+    }
+
+    /// Create a new, empty [`HyperLogLog`] with `2 ^ precision` registers
+    ///
+    /// Returns an error if `precision` is not between [`Self::MIN_PRECISION`] and
+    /// [`Self::MAX_PRECISION`]
+    pub fn try_new(precision: u8) -> Result<Self, ArrowError> {
+        if !(Self::MIN_PRECISION..=Self::MAX_PRECISION).contains(&precision) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "HyperLogLog precision must be between {} and {}, got {precision}",
+                Self::MIN_PRECISION,
+                Self::MAX_PRECISION
+            )));
+        }
+        Ok(Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    /// Returns the precision of this sketch
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Add the non-null values of `array` to this sketch
+    ///
+    /// Values are identified by their byte representation, and so equal values of
+    /// different types, e.g. `1_i32` and `1_u32`, are not distinct. Dictionary
+    /// arrays add the values referenced by their keys.
+    pub fn update(&mut self, array: &dyn Array) -> Result<(), ArrowError> {
+        let value = value_bytes(array)?;
+        (0..array.len())
+            .filter_map(value)
+            .for_each(|v| self.update_bytes(v));
+        Ok(())
+    }
+
+    /// Add a value with the byte representation `bytes` to this sketch
+    pub fn update_bytes(&mut self, bytes: &[u8]) {
+        let hash = hash_bytes(bytes);
+        let idx = (hash >> (64 - self.precision)) as usize;
+        // The remaining bits, with a sentinel bit bounding the number of leading zeros
+        let rest = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.registers[idx] = self.registers[idx].max(rank);
+    }
+
+    /// Combine the values of `other` into this sketch
+    ///
+    /// Returns an error if the sketches have different precisions
+    pub fn merge(&mut self, other: &Self) -> Result<(), ArrowError> {
+        if self.precision != other.precision {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot merge HyperLogLog of precision {} with precision {}",
+                other.precision, self.precision
+            )));
+        }
+        self.registers
+            .iter_mut()
+            .zip(&other.registers)
+            .for_each(|(a, b)| *a = (*a).max(*b));
+        Ok(())
+    }
+
+    /// Returns the estimated number of distinct values added to this sketch
+    pub fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1. + 1.079 / m),
+        };
+        let (sum, zeros) = self.registers.iter().fold((0_f64, 0_usize), |(s, z), r| {
+            (s + 2_f64.powi(-(*r as i32)), z + (*r == 0) as usize)
+        });
+
+        let estimate = alpha * m * m / sum;
+        let estimate = match estimate <= 2.5 * m && zeros != 0 {
+            // Use linear counting for small cardinalities
+            true => m * (m / zeros as f64).ln(),
+            false => estimate,
+        };
+        estimate.round() as u64
+    }
+
+    /// Serialize this sketch to bytes, that can be read by [`Self::try_from_bytes`]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.registers.len() + 1);
+        out.push(self.precision);
+        out.extend_from_slice(&self.registers);
+        out
+    }
+
+    /// Deserialize a sketch written by [`Self::to_bytes`]
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, ArrowError> {
+        let (precision, registers) = bytes.split_first().ok_or_else(|| {
+            ArrowError::ParseError("Invalid HyperLogLog: empty buffer".to_string())
+        })?;
+        let mut sketch = Self::try_new(*precision).map_err(|_| {
+            ArrowError::ParseError(format!(
+                "Invalid HyperLogLog: precision must be between {} and {}, got {precision}",
+                Self::MIN_PRECISION,
+                Self::MAX_PRECISION
+            ))
+        })?;
+        if registers.len() != sketch.registers.len() {
+            return Err(ArrowError::ParseError(format!(
+                "Invalid HyperLogLog: expected {} registers for precision {precision}, got {}",
+                sketch.registers.len(),
+                registers.len()
+            )));
+        }
+        let max_rank = 65 - precision;
+        if let Some(r) = registers.iter().find(|r| **r > max_rank) {
+            return Err(ArrowError::ParseError(format!(
+                "Invalid HyperLogLog: register value {r} exceeds {max_rank}"
+            )));
+        }
+        sketch.registers.copy_from_slice(registers);
+        Ok(sketch)
+    }
+}
+
+/// A stable 64-bit hash, FNV-1a followed by the MurmurHash3 finalizer
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut h = bytes.iter().fold(0xcbf29ce484222325_u64, |h, b| {
+        (h ^ *b as u64).wrapping_mul(0x100000001b3)
+    });
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51afd7ed558ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+    h ^ (h >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{DictionaryArray, Int32Array, StringArray};
+
+    fn assert_close(estimate: u64, expected: u64, error: f64) {
+        let actual = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(actual < error, "{estimate} vs {expected}");
+    }
+
+    #[test]
+    fn test_estimate() {
+        let mut hll = HyperLogLog::new();
+        assert_eq!(hll.estimate(), 0);
+
+        let array = StringArray::from(vec![Some("a"), None, Some("b"), Some("a")]);
+        hll.update(&array).unwrap();
+        assert_eq!(hll.estimate(), 2);
+
+        for precision in [4, 10, 14, 18] {
+            let mut hll = HyperLogLog::try_new(precision).unwrap();
+            let array = Int32Array::from_iter_values(0..100_000);
+            hll.update(&array).unwrap();
+            hll.update(&array).unwrap();
+            let error = 4. * 1.04 / 2_f64.powi(precision as i32).sqrt();
+            assert_close(hll.estimate(), 100_000, error);
+        }
+    }
+
+    #[test]
+    fn test_dictionary() {
+        let values = StringArray::from(vec!["a", "b", "c"]);
+        let keys = Int32Array::from(vec![Some(0), Some(0), None, Some(2)]);
+        let array =
+            DictionaryArray::<Int32Type>::try_new(keys, std::sync::Arc::new(values)).unwrap();
+        let mut hll = HyperLogLog::new();
+        hll.update(&array).unwrap();
+        assert_eq!(hll.estimate(), 2);
+    }
+
+    #[test]
+    fn test_merge_and_serialize() {
+        let mut a = HyperLogLog::new();
+        a.update(&Int32Array::from_iter_values(0..50_000)).unwrap();
+        let mut b = HyperLogLog::new();
+        b.update(&Int32Array::from_iter_values(25_000..75_000))
+            .unwrap();
+
+        let bytes = b.to_bytes();
+        assert_eq!(bytes.len(), (1 << 14) + 1);
+        let b = HyperLogLog::try_from_bytes(&bytes).unwrap();
+        a.merge(&b).unwrap();
+        assert_close(a.estimate(), 75_000, 0.03);
+
+        let c = HyperLogLog::try_new(10).unwrap();
+        let err = a.merge(&c).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Cannot merge HyperLogLog of precision 10 with precision 14"
+        );
+
+        let err = HyperLogLog::try_from_bytes(&bytes[..100])
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Parser error: Invalid HyperLogLog: expected 16384 registers for precision 14, got 99"
+        );
+        let err = HyperLogLog::try_from_bytes(&[3]).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Parser error: Invalid HyperLogLog: precision must be between 4 and 18, got 3"
+        );
+    }
+}
@@ -0,0 +1,440 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use super::for_each_valid_f64;
+use arrow_array::{ArrowNumericType, PrimitiveArray};
+use arrow_schema::ArrowError;
+use num_traits::ToPrimitive;
+use std::f64::consts::PI;
+
+/// A cluster of values summarised by their mean and count
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A merging [t-digest] sketch estimating the quantiles of a set of values
+///
+/// The sketch summarises values as at most `O(compression)` centroids, with smaller
+/// centroids, and therefore more accurate estimates, towards the extreme quantiles.
+///
+/// Sketches built from different arrays, processes or machines can be combined with
+/// [`Self::merge`], or exchanged using [`Self::to_bytes`] and [`Self::try_from_bytes`].
+///
+/// # Example
+/// ```
+/// # use arrow_array::Float64Array;
+/// # use arrow_arith::aggregate::TDigest;
+/// let mut a = TDigest::new();
+/// a.update(&Float64Array::from_iter_values((0..500).map(|x| x as f64)));
+///
+/// let mut b = TDigest::new();
+/// b.update(&Float64Array::from_iter_values((500..1000).map(|x| x as f64)));
+///
+/// let b = TDigest::try_from_bytes(&b.to_bytes()).unwrap();
+/// a.merge(&b);
+///
+/// assert_eq!(a.count(), 1000);
+/// let median = a.quantile(0.5).unwrap().unwrap();
+/// assert!((median - 499.5).abs() < 5.);
+/// ```
+///
+/// [t-digest]: https://arxiv.org/abs/1902.04023
+#[derive(Debug, Clone, PartialEq)]
+pub struct TDigest {
+    compression: f64,
+    /// Compressed centroids, ordered by mean
+    centroids: Vec<Centroid>,
+    /// Centroids yet to be compressed
+    buffer: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TDigest {
+    /// The default compression
+    pub const DEFAULT_COMPRESSION: f64 = 100.;
+
+    /// Create a new, empty [`TDigest`] with [`Self::DEFAULT_COMPRESSION`]
+    pub fn new() -> Self {
+        Self::try_new(Self::DEFAULT_COMPRESSION).unwrap()
+    }
+
+    /// Create a new, empty [`TDigest`] with the provided `compression`
+    ///
+    /// Higher compressions result in more accurate estimates, at the cost of more
+    /// centroids. Returns an error if `compression` is not finite or less than `1`
+    pub fn try_new(compression: f64) -> Result<Self, ArrowError> {
+        if !compression.is_finite() || compression < 1. {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "TDigest compression must be a finite value of at least 1, got {compression}"
+            )));
+        }
+        Ok(Self {
+            compression,
+            centroids: vec![],
+            buffer: vec![],
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        })
+    }
+
+    /// Returns the compression of this sketch
+    pub fn compression(&self) -> f64 {
+        self.compression
+    }
+
+    /// Add the non-null values of `array` to this sketch
+    ///
+    /// Decimal values are scaled according to the scale of the array, and
+    /// `NaN` and infinite values are ignored
+    pub fn update<T>(&mut self, array: &PrimitiveArray<T>)
+    where
+        T: ArrowNumericType,
+        T::Native: ToPrimitive,
+    {
+        for_each_valid_f64(array, |v| self.update_value(v))
+    }
+
+    /// Add `value` to this sketch, ignoring `NaN` and infinite values
+    pub fn update_value(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.push(Centroid {
+            mean: value,
+            weight: 1.,
+        });
+    }
+
+    fn push(&mut self, centroid: Centroid) {
+        self.buffer.push(centroid);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.compress();
+        }
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        (self.compression as usize).saturating_mul(5).max(32)
+    }
+
+    /// Combine the values of `other` into this sketch
+    pub fn merge(&mut self, other: &Self) {
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        for c in other.centroids.iter().chain(&other.buffer) {
+            self.push(*c);
+        }
+    }
+
+    /// Returns the number of values added to this sketch
+    pub fn count(&self) -> u64 {
+        let weight: f64 = self
+            .centroids
+            .iter()
+            .chain(&self.buffer)
+            .map(|c| c.weight)
+            .sum();
+        weight as u64
+    }
+
+    /// Returns the minimum value added to this sketch, if any
+    pub fn min(&self) -> Option<f64> {
+        (self.min <= self.max).then_some(self.min)
+    }
+
+    /// Returns the maximum value added to this sketch, if any
+    pub fn max(&self) -> Option<f64> {
+        (self.min <= self.max).then_some(self.max)
+    }
+
+    /// Merge the buffered values into the centroids
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut all = std::mem::take(&mut self.centroids);
+        all.append(&mut self.buffer);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = all.iter().map(|c| c.weight).sum();
+        let mut iter = all.into_iter();
+        let mut current = iter.next().unwrap();
+        let mut weight_so_far = 0.;
+        let mut limit = self.q_limit(0.);
+        for c in iter {
+            let q = (weight_so_far + current.weight + c.weight) / total;
+            if q <= limit {
+                current.weight += c.weight;
+                current.mean += (c.mean - current.mean) * c.weight / current.weight;
+            } else {
+                weight_so_far += current.weight;
+                self.centroids.push(current);
+                limit = self.q_limit(weight_so_far / total);
+                current = c;
+            }
+        }
+        self.centroids.push(current);
+    }
+
+    /// Returns the largest quantile that a centroid starting at quantile `q` may reach,
+    /// using the `k1` scale function `k(q) = compression / 2π * asin(2q - 1)`
+    fn q_limit(&self, q: f64) -> f64 {
+        let scale = self.compression / (2. * PI);
+        let k = scale * (2. * q - 1.).asin() + 1.;
+        match k >= scale * PI / 2. {
+            true => 1.,
+            false => f64::midpoint((k / scale).sin(), 1.),
+        }
+    }
+
+    /// Returns the estimated quantile `q` of the values added to this sketch
+    ///
+    /// `q` must be between `0` and `1`. Returns `Ok(None)` if no values have been added
+    pub fn quantile(&mut self, q: f64) -> Result<Option<f64>, ArrowError> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Quantile must be between 0 and 1, got {q}"
+            )));
+        }
+        self.compress();
+        if self.centroids.is_empty() {
+            return Ok(None);
+        }
+
+        let total: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let rank = q * total;
+
+        // Interpolate between the centres of adjacent centroids, and the extreme values
+        let mut prev = (0., self.min);
+        let mut cumulative = 0.;
+        for c in &self.centroids {
+            let center = (cumulative + c.weight / 2., c.mean);
+            if rank <= center.0 {
+                return Ok(Some(interpolate(prev, center, rank)));
+            }
+            cumulative += c.weight;
+            prev = center;
+        }
+        Ok(Some(interpolate(prev, (total, self.max), rank)))
+    }
+
+    /// Serialize this sketch to bytes, that can be read by [`Self::try_from_bytes`]
+    ///
+    /// The format is the little-endian `f64` compression, minimum and maximum, and `u64`
+    /// number of centroids, followed by the `f64` mean and weight of each centroid
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut digest = self.clone();
+        digest.compress();
+        let mut out = Vec::with_capacity(32 + digest.centroids.len() * 16);
+        out.extend_from_slice(&digest.compression.to_le_bytes());
+        out.extend_from_slice(&digest.min.to_le_bytes());
+        out.extend_from_slice(&digest.max.to_le_bytes());
+        out.extend_from_slice(&(digest.centroids.len() as u64).to_le_bytes());
+        for c in &digest.centroids {
+            out.extend_from_slice(&c.mean.to_le_bytes());
+            out.extend_from_slice(&c.weight.to_le_bytes());
+        }
+        out
+    }
+
+    /// Deserialize a sketch written by [`Self::to_bytes`]
+    ///
+    /// Returns an error if the centroids are not ordered by mean, have non-finite
+    /// means or weights, or lie outside the minimum and maximum
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, ArrowError> {
+        let err = |msg: &str| ArrowError::ParseError(format!("Invalid TDigest: {msg}"));
+        if bytes.len() < 32 || !bytes.len().is_multiple_of(16) {
+            return Err(err(&format!("unexpected length {}", bytes.len())));
+        }
+        let (header, centroids) = bytes.split_at(32);
+        let header: Vec<[u8; 8]> = header
+            .chunks_exact(8)
+            .map(|b| b.try_into().unwrap())
+            .collect();
+
+        let compression = f64::from_le_bytes(header[0]);
+        let mut digest =
+            Self::try_new(compression).map_err(|_| err(&format!("compression {compression}")))?;
+        digest.min = f64::from_le_bytes(header[1]);
+        digest.max = f64::from_le_bytes(header[2]);
+        let len = u64::from_le_bytes(header[3]);
+        if len != (centroids.len() / 16) as u64 {
+            return Err(err(&format!("expected {len} centroids")));
+        }
+        let mut values = centroids
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()));
+        while let (Some(mean), Some(weight)) = (values.next(), values.next()) {
+            let ordered = digest.centroids.last().is_none_or(|c| c.mean <= mean);
+            if !mean.is_finite() || !weight.is_finite() || weight <= 0. || !ordered {
+                return Err(err(&format!("invalid centroid ({mean}, {weight})")));
+            }
+            digest.centroids.push(Centroid { mean, weight });
+        }
+
+        // quantile only sorts buffered centroids, so the bounds must enclose the
+        // ordered centroids, or be those of an empty sketch
+        let (min, max) = (digest.min, digest.max);
+        let valid_bounds = match (digest.centroids.first(), digest.centroids.last()) {
+            (Some(first), Some(last)) => min <= first.mean && last.mean <= max,
+            _ => min == f64::INFINITY && max == f64::NEG_INFINITY,
+        };
+        if !valid_bounds {
+            return Err(err(&format!("invalid minimum {min} and maximum {max}")));
+        }
+        Ok(digest)
+    }
+}
+
+/// Linearly interpolate the value at `rank` between the points `a` and `b`
+fn interpolate(a: (f64, f64), b: (f64, f64), rank: f64) -> f64 {
+    match b.0 > a.0 {
+        true => a.1 + (b.1 - a.1) * ((rank - a.0) / (b.0 - a.0)).clamp(0., 1.),
+        false => b.1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Decimal128Array, Float64Array, Int32Array};
+
+    #[test]
+    fn test_quantile() {
+        let mut digest = TDigest::new();
+        assert_eq!(digest.quantile(0.5).unwrap(), None);
+        assert_eq!(digest.min(), None);
+
+        let values: Vec<i32> = (0..100_000).map(|x| (x * 7919) % 100_000).collect();
+        digest.update(&Int32Array::from(values));
+        assert_eq!(digest.count(), 100_000);
+        assert_eq!(digest.min(), Some(0.));
+        assert_eq!(digest.max(), Some(99_999.));
+        assert!(digest.centroids.len() < 200, "{}", digest.centroids.len());
+
+        assert_eq!(digest.quantile(0.).unwrap(), Some(0.));
+        assert_eq!(digest.quantile(1.).unwrap(), Some(99_999.));
+        for q in [0.001, 0.01, 0.25, 0.5, 0.75, 0.99, 0.999] {
+            let estimate = digest.quantile(q).unwrap().unwrap();
+            let expected = q * 99_999.;
+            assert!((estimate - expected).abs() < 500., "{q}: {estimate}");
+        }
+
+        let err = digest.quantile(1.5).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Quantile must be between 0 and 1, got 1.5"
+        );
+    }
+
+    #[test]
+    fn test_small() {
+        let mut digest = TDigest::new();
+        digest.update(&Float64Array::from(vec![
+            Some(1.),
+            None,
+            Some(f64::NAN),
+            Some(3.),
+        ]));
+        assert_eq!(digest.count(), 2);
+        assert_eq!(digest.quantile(0.5).unwrap(), Some(2.));
+
+        let mut digest = TDigest::new();
+        let array = Decimal128Array::from(vec![150])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        digest.update(&array);
+        assert_eq!(digest.quantile(0.3).unwrap(), Some(1.5));
+    }
+
+    #[test]
+    fn test_merge_and_serialize() {
+        let mut a = TDigest::new();
+        let mut b = TDigest::new();
+        for v in 0..10_000 {
+            match v % 2 {
+                0 => a.update_value(v as f64),
+                _ => b.update_value(v as f64),
+            }
+        }
+        let b = TDigest::try_from_bytes(&b.to_bytes()).unwrap();
+        assert_eq!(b.count(), 5_000);
+        a.merge(&b);
+        assert_eq!(a.count(), 10_000);
+        let median = a.quantile(0.5).unwrap().unwrap();
+        assert!((median - 5_000.).abs() < 50., "{median}");
+
+        let err = TDigest::try_from_bytes(&[0; 40]).unwrap_err().to_string();
+        assert_eq!(err, "Parser error: Invalid TDigest: unexpected length 40");
+        let err = TDigest::try_new(0.5).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: TDigest compression must be a finite value of at least 1, got 0.5"
+        );
+    }
+
+    #[test]
+    fn test_try_from_bytes_validation() {
+        let mut digest = TDigest::new();
+        digest.update(&Float64Array::from(vec![1., 2., f64::INFINITY]));
+        assert_eq!(digest.max(), Some(2.));
+        let bytes = digest.to_bytes();
+        assert_eq!(TDigest::try_from_bytes(&bytes).unwrap().count(), 2);
+        assert!(TDigest::try_from_bytes(&TDigest::new().to_bytes()).is_ok());
+
+        let with = |offset: usize, value: f64| {
+            let mut bytes = bytes.clone();
+            bytes[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+            TDigest::try_from_bytes(&bytes).unwrap_err().to_string()
+        };
+        assert_eq!(
+            with(0, 0.5),
+            "Parser error: Invalid TDigest: compression 0.5"
+        );
+        assert_eq!(
+            with(8, 1.5),
+            "Parser error: Invalid TDigest: invalid minimum 1.5 and maximum 2"
+        );
+        assert_eq!(
+            with(16, f64::NAN),
+            "Parser error: Invalid TDigest: invalid minimum 1 and maximum NaN"
+        );
+        // centroids out of order
+        assert_eq!(
+            with(32, 3.),
+            "Parser error: Invalid TDigest: invalid centroid (2, 1)"
+        );
+        assert_eq!(
+            with(40, f64::INFINITY),
+            "Parser error: Invalid TDigest: invalid centroid (1, inf)"
+        );
+        assert_eq!(
+            with(48, f64::NEG_INFINITY),
+            "Parser error: Invalid TDigest: invalid centroid (-inf, 1)"
+        );
+    }
+}
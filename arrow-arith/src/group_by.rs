@@ -0,0 +1,1113 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines grouped aggregation of unsorted data
+//!
+//! [`GroupIds`] assigns dense group ids to the distinct values of a set of key columns,
+//! and [`GroupBy`] uses these to compute [`AggregateFunction`]s of value columns for
+//! each group, incrementally across [`RecordBatch`]es.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_arith::group_by::{AggregateFunction, GroupBy};
+//! # use arrow_array::{Int64Array, RecordBatch, StringArray};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::Int64Type;
+//! # use arrow_schema::{DataType, Field, Schema};
+//! let schema = Arc::new(Schema::new(vec![
+//!     Field::new("k", DataType::Utf8, true),
+//!     Field::new("v", DataType::Int64, true),
+//! ]));
+//! let mut group_by =
+//!     GroupBy::try_new(&schema, &[0], &[(AggregateFunction::Sum, 1)]).unwrap();
+//!
+//! let batch = RecordBatch::try_new(schema.clone(), vec![
+//!     Arc::new(StringArray::from(vec!["a", "b", "a"])),
+//!     Arc::new(Int64Array::from(vec![1, 2, 3])),
+//! ]).unwrap();
+//! group_by.update(&batch).unwrap();
+//! group_by.update(&batch).unwrap();
+//!
+//! let result = group_by.finish().unwrap();
+//! assert_eq!(result.schema().field(1).name(), "sum(v)");
+//! assert_eq!(result.column(0).as_string::<i32>().value(0), "a");
+//! assert_eq!(result.column(1).as_primitive::<Int64Type>().values(), &[8, 4]);
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use ahash::RandomState;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer, ToByteSlice};
+use arrow_row::{RowConverter, Rows, SortField};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+use hashbrown::HashTable;
+use num_traits::ToPrimitive;
+
+/// Maps the distinct values of a set of key columns to dense group ids
+///
+/// Group ids are assigned in the order keys are first seen, starting at `0`, and null
+/// values are grouped together. A single primitive, string or binary key is interned
+/// directly, other keys are interned using the [`arrow_row`] format.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_arith::group_by::GroupIds;
+/// # use arrow_array::{ArrayRef, Int32Array};
+/// # use arrow_schema::DataType;
+/// let mut ids = GroupIds::try_new(&[DataType::Int32]).unwrap();
+/// let keys: ArrayRef = Arc::new(Int32Array::from(vec![Some(5), None, Some(5), Some(1)]));
+/// let mut groups = vec![];
+/// ids.intern(&[keys], &mut groups).unwrap();
+/// assert_eq!(groups, &[0, 1, 0, 2]);
+/// assert_eq!(ids.num_groups(), 3);
+/// ```
+pub struct GroupIds {
+    data_types: Vec<DataType>,
+    keys: Box<dyn GroupKeys>,
+}
+
+impl std::fmt::Debug for GroupIds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GroupIds")
+            .field("data_types", &self.data_types)
+            .field("num_groups", &self.num_groups())
+            .finish()
+    }
+}
+
+impl GroupIds {
+    /// Create a new [`GroupIds`] for key columns of the provided types
+    ///
+    /// Returns an error if there are no key columns, or they cannot be grouped
+    pub fn try_new(data_types: &[DataType]) -> Result<Self, ArrowError> {
+        macro_rules! primitive_helper {
+            ($t:ty, $d:expr) => {
+                Box::new(PrimitiveGroupKeys::<$t>::new($d.clone())) as Box<dyn GroupKeys>
+            };
+        }
+
+        let keys = match data_types {
+            [] => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "GroupIds requires at least one key column".to_string(),
+                ));
+            }
+            [d] => downcast_primitive! {
+                d => (primitive_helper, d),
+                DataType::Utf8 => Box::new(BytesGroupKeys::<Utf8Type>::new()),
+                DataType::LargeUtf8 => Box::new(BytesGroupKeys::<LargeUtf8Type>::new()),
+                DataType::Binary => Box::new(BytesGroupKeys::<BinaryType>::new()),
+                DataType::LargeBinary => Box::new(BytesGroupKeys::<LargeBinaryType>::new()),
+                _ => Box::new(RowGroupKeys::try_new(data_types)?),
+            },
+            _ => Box::new(RowGroupKeys::try_new(data_types)?),
+        };
+        Ok(Self {
+            data_types: data_types.to_vec(),
+            keys,
+        })
+    }
+
+    /// Assigns a group id to each row of `keys`, writing them to `groups`
+    ///
+    /// `groups` is cleared before writing. Returns an error if `keys` do not match the
+    /// types provided to [`Self::try_new`]
+    pub fn intern(&mut self, keys: &[ArrayRef], groups: &mut Vec<usize>) -> Result<(), ArrowError> {
+        if keys.len() != self.data_types.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "GroupIds expected {} key columns, got {}",
+                self.data_types.len(),
+                keys.len()
+            )));
+        }
+        for (key, data_type) in keys.iter().zip(&self.data_types) {
+            if key.data_type() != data_type {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "GroupIds expected key column of type {data_type}, got {}",
+                    key.data_type()
+                )));
+            }
+        }
+        if let Some(len) = keys.iter().map(|k| k.len()).find(|l| *l != keys[0].len()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "GroupIds expected key columns of equal length, got {} and {len}",
+                keys[0].len()
+            )));
+        }
+        groups.clear();
+        groups.reserve(keys[0].len());
+        self.keys.intern(keys, groups)
+    }
+
+    /// Returns the number of distinct groups seen so far
+    pub fn num_groups(&self) -> usize {
+        self.keys.num_groups()
+    }
+
+    /// Returns the key columns of each group, in group id order
+    pub fn finish(self) -> Result<Vec<ArrayRef>, ArrowError> {
+        self.keys.finish()
+    }
+}
+
+/// Interns key columns as group ids
+trait GroupKeys: Send {
+    fn intern(&mut self, keys: &[ArrayRef], groups: &mut Vec<usize>) -> Result<(), ArrowError>;
+
+    fn num_groups(&self) -> usize;
+
+    fn finish(self: Box<Self>) -> Result<Vec<ArrayRef>, ArrowError>;
+}
+
+/// Returns the group of the key with `hash`, as determined by `eq`, or appends a new
+/// group to `keys` with `insert` if there is no such group
+fn find_or_insert<K>(
+    table: &mut HashTable<usize>,
+    hashes: &mut Vec<u64>,
+    keys: &mut K,
+    hash: u64,
+    eq: impl Fn(&K, usize) -> bool,
+    insert: impl FnOnce(&mut K),
+) -> usize {
+    match table.find(hash, |g| eq(keys, *g)) {
+        Some(g) => *g,
+        None => {
+            let group = hashes.len();
+            hashes.push(hash);
+            table.insert_unique(hash, group, |g| hashes[*g]);
+            insert(keys);
+            group
+        }
+    }
+}
+
+/// Returns a [`NullBuffer`] of `len` with a single null at `null_group`
+fn null_group_buffer(len: usize, null_group: usize) -> NullBuffer {
+    (0..len).map(|g| g != null_group).collect()
+}
+
+/// [`GroupKeys`] for a single primitive key column
+struct PrimitiveGroupKeys<T: ArrowPrimitiveType> {
+    data_type: DataType,
+    state: RandomState,
+    table: HashTable<usize>,
+    hashes: Vec<u64>,
+    values: Vec<T::Native>,
+    null_group: Option<usize>,
+}
+
+impl<T: ArrowPrimitiveType> PrimitiveGroupKeys<T> {
+    fn new(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            state: RandomState::new(),
+            table: HashTable::new(),
+            hashes: vec![],
+            values: vec![],
+            null_group: None,
+        }
+    }
+}
+
+impl<T: ArrowPrimitiveType> GroupKeys for PrimitiveGroupKeys<T> {
+    fn intern(&mut self, keys: &[ArrayRef], groups: &mut Vec<usize>) -> Result<(), ArrowError> {
+        let array = keys[0].as_primitive::<T>();
+        for (idx, value) in array.values().iter().enumerate() {
+            if array.is_null(idx) {
+                let group = *self.null_group.get_or_insert_with(|| {
+                    self.hashes.push(0);
+                    self.values.push(T::Native::default());
+                    self.values.len() - 1
+                });
+                groups.push(group);
+                continue;
+            }
+
+            let bytes = value.to_byte_slice();
+            let hash = self.state.hash_one(bytes);
+            let group = find_or_insert(
+                &mut self.table,
+                &mut self.hashes,
+                &mut self.values,
+                hash,
+                |values, g| values[g].to_byte_slice() == bytes,
+                |values| values.push(*value),
+            );
+            groups.push(group);
+        }
+        Ok(())
+    }
+
+    fn num_groups(&self) -> usize {
+        self.values.len()
+    }
+
+    fn finish(self: Box<Self>) -> Result<Vec<ArrayRef>, ArrowError> {
+        let len = self.values.len();
+        let nulls = self.null_group.map(|n| null_group_buffer(len, n));
+        let array = PrimitiveArray::<T>::new(self.values.into(), nulls);
+        Ok(vec![Arc::new(array.with_data_type(self.data_type))])
+    }
+}
+
+/// [`GroupKeys`] for a single string or binary key column
+struct BytesGroupKeys<T: ByteArrayType> {
+    state: RandomState,
+    table: HashTable<usize>,
+    hashes: Vec<u64>,
+    values: Vec<u8>,
+    offsets: Vec<usize>,
+    null_group: Option<usize>,
+    phantom: std::marker::PhantomData<fn(T)>,
+}
+
+impl<T: ByteArrayType> BytesGroupKeys<T> {
+    fn new() -> Self {
+        Self {
+            state: RandomState::new(),
+            table: HashTable::new(),
+            hashes: vec![],
+            values: vec![],
+            offsets: vec![0],
+            null_group: None,
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T: ByteArrayType> GroupKeys for BytesGroupKeys<T> {
+    fn intern(&mut self, keys: &[ArrayRef], groups: &mut Vec<usize>) -> Result<(), ArrowError> {
+        let array = keys[0].as_bytes::<T>();
+        for value in array.iter() {
+            let Some(value) = value else {
+                let group = *self.null_group.get_or_insert_with(|| {
+                    self.hashes.push(0);
+                    self.offsets.push(self.values.len());
+                    self.offsets.len() - 2
+                });
+                groups.push(group);
+                continue;
+            };
+
+            let bytes: &[u8] = value.as_ref();
+            let hash = self.state.hash_one(bytes);
+            let group = find_or_insert(
+                &mut self.table,
+                &mut self.hashes,
+                &mut (&mut self.values, &mut self.offsets),
+                hash,
+                |(values, offsets), g| &values[offsets[g]..offsets[g + 1]] == bytes,
+                |(values, offsets)| {
+                    values.extend_from_slice(bytes);
+                    offsets.push(values.len());
+                },
+            );
+            groups.push(group);
+        }
+        Ok(())
+    }
+
+    fn num_groups(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn finish(self: Box<Self>) -> Result<Vec<ArrayRef>, ArrowError> {
+        let offsets = self
+            .offsets
+            .iter()
+            .map(|o| T::Offset::from_usize(*o).ok_or_else(|| ArrowError::OffsetOverflowError(*o)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let len = offsets.len() - 1;
+        let nulls = self.null_group.map(|n| null_group_buffer(len, n));
+        let offsets = OffsetBuffer::new(ScalarBuffer::from(offsets));
+        let array = GenericByteArray::<T>::try_new(offsets, self.values.into(), nulls)?;
+        Ok(vec![Arc::new(array)])
+    }
+}
+
+/// [`GroupKeys`] for any key columns supported by [`RowConverter`]
+struct RowGroupKeys {
+    converter: RowConverter,
+    state: RandomState,
+    table: HashTable<usize>,
+    hashes: Vec<u64>,
+    rows: Rows,
+}
+
+impl RowGroupKeys {
+    fn try_new(data_types: &[DataType]) -> Result<Self, ArrowError> {
+        let fields = data_types.iter().cloned().map(SortField::new).collect();
+        let converter = RowConverter::new(fields)?;
+        let rows = converter.empty_rows(0, 0);
+        Ok(Self {
+            converter,
+            state: RandomState::new(),
+            table: HashTable::new(),
+            hashes: vec![],
+            rows,
+        })
+    }
+}
+
+impl GroupKeys for RowGroupKeys {
+    fn intern(&mut self, keys: &[ArrayRef], groups: &mut Vec<usize>) -> Result<(), ArrowError> {
+        let batch_rows = self.converter.convert_columns(keys)?;
+        for row in batch_rows.iter() {
+            let hash = self.state.hash_one(row.as_ref());
+            let group = find_or_insert(
+                &mut self.table,
+                &mut self.hashes,
+                &mut self.rows,
+                hash,
+                |rows, g| rows.row(g) == row,
+                |rows| rows.push(row),
+            );
+            groups.push(group);
+        }
+        Ok(())
+    }
+
+    fn num_groups(&self) -> usize {
+        self.rows.num_rows()
+    }
+
+    fn finish(self: Box<Self>) -> Result<Vec<ArrayRef>, ArrowError> {
+        self.converter.convert_rows(&self.rows)
+    }
+}
+
+/// An aggregate function computed for each group by [`GroupBy`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AggregateFunction {
+    /// The number of non-null values, as an [`Int64Array`]
+    Count,
+    /// The sum of the non-null values, returning an error on overflow
+    ///
+    /// Signed and unsigned integers are summed as `Int64` and `UInt64`, floats as
+    /// `Float64`, and decimals as `Decimal128` or `Decimal256` of the maximum precision
+    Sum,
+    /// The minimum non-null value, with floating point `NaN` greater than any other value
+    Min,
+    /// The maximum non-null value, with floating point `NaN` greater than any other value
+    Max,
+    /// The arithmetic mean of the non-null values, as a [`Float64Array`]
+    Avg,
+}
+
+impl Display for AggregateFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Count => write!(f, "count"),
+            Self::Sum => write!(f, "sum"),
+            Self::Min => write!(f, "min"),
+            Self::Max => write!(f, "max"),
+            Self::Avg => write!(f, "avg"),
+        }
+    }
+}
+
+/// Computes an [`AggregateFunction`] for each group
+trait GroupAccumulator: Send {
+    /// Update the accumulator with `values`, where `groups[i]` is the group of `values[i]`
+    fn update(
+        &mut self,
+        values: &dyn Array,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError>;
+
+    /// Returns the aggregate for each of `num_groups` groups
+    fn finish(self: Box<Self>, num_groups: usize) -> Result<ArrayRef, ArrowError>;
+}
+
+/// Invokes `f` with the group and value of each non-null value of `array`
+fn for_each_valid<T, F>(
+    array: &PrimitiveArray<T>,
+    groups: &[usize],
+    mut f: F,
+) -> Result<(), ArrowError>
+where
+    T: ArrowPrimitiveType,
+    F: FnMut(usize, T::Native) -> Result<(), ArrowError>,
+{
+    let values = array.values();
+    match array.nulls() {
+        None => groups.iter().zip(values).try_for_each(|(g, v)| f(*g, *v)),
+        Some(nulls) => nulls
+            .valid_indices()
+            .try_for_each(|idx| f(groups[idx], values[idx])),
+    }
+}
+
+struct CountAccumulator {
+    counts: Vec<i64>,
+}
+
+impl GroupAccumulator for CountAccumulator {
+    fn update(
+        &mut self,
+        values: &dyn Array,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError> {
+        self.counts.resize(num_groups, 0);
+        match values.logical_nulls() {
+            None => groups.iter().for_each(|g| self.counts[*g] += 1),
+            Some(nulls) => nulls
+                .valid_indices()
+                .for_each(|idx| self.counts[groups[idx]] += 1),
+        }
+        Ok(())
+    }
+
+    fn finish(mut self: Box<Self>, num_groups: usize) -> Result<ArrayRef, ArrowError> {
+        self.counts.resize(num_groups, 0);
+        Ok(Arc::new(Int64Array::from(self.counts)))
+    }
+}
+
+/// Accumulates values of type `I` as values of type `O`
+struct SumAccumulator<I: ArrowPrimitiveType, O: ArrowPrimitiveType> {
+    data_type: DataType,
+    sums: Vec<O::Native>,
+    seen: Vec<bool>,
+    phantom: std::marker::PhantomData<fn(I)>,
+}
+
+impl<I: ArrowPrimitiveType, O: ArrowPrimitiveType> SumAccumulator<I, O> {
+    fn new(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            sums: vec![],
+            seen: vec![],
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<I, O> GroupAccumulator for SumAccumulator<I, O>
+where
+    I: ArrowPrimitiveType,
+    O: ArrowPrimitiveType,
+    I::Native: Into<O::Native>,
+{
+    fn update(
+        &mut self,
+        values: &dyn Array,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError> {
+        self.sums.resize(num_groups, O::Native::ZERO);
+        self.seen.resize(num_groups, false);
+        for_each_valid(values.as_primitive::<I>(), groups, |g, v| {
+            self.sums[g] = self.sums[g].add_checked(v.into())?;
+            self.seen[g] = true;
+            Ok(())
+        })
+    }
+
+    fn finish(mut self: Box<Self>, num_groups: usize) -> Result<ArrayRef, ArrowError> {
+        self.sums.resize(num_groups, O::Native::ZERO);
+        self.seen.resize(num_groups, false);
+        let nulls = NullBuffer::from(self.seen);
+        let array = PrimitiveArray::<O>::new(self.sums.into(), Some(nulls));
+        let array: ArrayRef = Arc::new(array.with_data_type(self.data_type));
+
+        // Decimal sums are only checked for overflow of the native type in update
+        match array.data_type() {
+            DataType::Decimal128(p, _) => array
+                .as_primitive::<Decimal128Type>()
+                .validate_decimal_precision(*p)?,
+            DataType::Decimal256(p, _) => array
+                .as_primitive::<Decimal256Type>()
+                .validate_decimal_precision(*p)?,
+            _ => {}
+        }
+        Ok(array)
+    }
+}
+
+struct MinMaxAccumulator<T: ArrowPrimitiveType> {
+    max: bool,
+    data_type: DataType,
+    values: Vec<T::Native>,
+    seen: Vec<bool>,
+}
+
+impl<T: ArrowPrimitiveType> MinMaxAccumulator<T> {
+    fn new(max: bool, data_type: DataType) -> Self {
+        Self {
+            max,
+            data_type,
+            values: vec![],
+            seen: vec![],
+        }
+    }
+}
+
+impl<T: ArrowPrimitiveType> GroupAccumulator for MinMaxAccumulator<T> {
+    fn update(
+        &mut self,
+        values: &dyn Array,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError> {
+        self.values.resize(num_groups, T::Native::default());
+        self.seen.resize(num_groups, false);
+        for_each_valid(values.as_primitive::<T>(), groups, |g, v| {
+            let replace = match self.max {
+                true => v.is_gt(self.values[g]),
+                false => v.is_lt(self.values[g]),
+            };
+            if !self.seen[g] || replace {
+                self.values[g] = v;
+                self.seen[g] = true;
+            }
+            Ok(())
+        })
+    }
+
+    fn finish(mut self: Box<Self>, num_groups: usize) -> Result<ArrayRef, ArrowError> {
+        self.values.resize(num_groups, T::Native::default());
+        self.seen.resize(num_groups, false);
+        let nulls = NullBuffer::from(self.seen);
+        let array = PrimitiveArray::<T>::new(self.values.into(), Some(nulls));
+        Ok(Arc::new(array.with_data_type(self.data_type)))
+    }
+}
+
+struct AvgAccumulator<T: ArrowPrimitiveType> {
+    /// The divisor converting native values to `f64`, to account for decimal scales
+    div: f64,
+    sums: Vec<f64>,
+    counts: Vec<u64>,
+    phantom: std::marker::PhantomData<fn(T)>,
+}
+
+impl<T: ArrowPrimitiveType> AvgAccumulator<T> {
+    fn new(scale: i8) -> Self {
+        Self {
+            div: 10_f64.powi(scale as i32),
+            sums: vec![],
+            counts: vec![],
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<T> GroupAccumulator for AvgAccumulator<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: ToPrimitive,
+{
+    fn update(
+        &mut self,
+        values: &dyn Array,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError> {
+        self.sums.resize(num_groups, 0.);
+        self.counts.resize(num_groups, 0);
+        for_each_valid(values.as_primitive::<T>(), groups, |g, v| {
+            self.sums[g] += v.to_f64().unwrap_or(f64::NAN) / self.div;
+            self.counts[g] += 1;
+            Ok(())
+        })
+    }
+
+    fn finish(mut self: Box<Self>, num_groups: usize) -> Result<ArrayRef, ArrowError> {
+        self.sums.resize(num_groups, 0.);
+        self.counts.resize(num_groups, 0);
+        let array: Float64Array = self
+            .sums
+            .iter()
+            .zip(&self.counts)
+            .map(|(s, c)| (*c > 0).then(|| s / *c as f64))
+            .collect();
+        Ok(Arc::new(array))
+    }
+}
+
+/// Returns the [`GroupAccumulator`] and output type computing `function` of `data_type`
+fn new_accumulator(
+    function: AggregateFunction,
+    data_type: &DataType,
+) -> Result<(Box<dyn GroupAccumulator>, DataType), ArrowError> {
+    use DataType::*;
+
+    macro_rules! min_max_helper {
+        ($t:ty, $max:expr, $d:expr) => {
+            Box::new(MinMaxAccumulator::<$t>::new($max, $d.clone())) as Box<dyn GroupAccumulator>
+        };
+    }
+
+    macro_rules! sum {
+        ($i:ty, $o:ty, $d:expr) => {
+            (
+                Box::new(SumAccumulator::<$i, $o>::new($d.clone())) as Box<dyn GroupAccumulator>,
+                $d,
+            )
+        };
+    }
+
+    macro_rules! avg {
+        ($t:ty, $s:expr) => {
+            (
+                Box::new(AvgAccumulator::<$t>::new($s)) as Box<dyn GroupAccumulator>,
+                Float64,
+            )
+        };
+    }
+
+    let unsupported = || {
+        ArrowError::InvalidArgumentError(format!(
+            "Unsupported aggregate function {function} for {data_type}"
+        ))
+    };
+
+    Ok(match function {
+        AggregateFunction::Count => (Box::new(CountAccumulator { counts: vec![] }), Int64),
+        AggregateFunction::Min | AggregateFunction::Max => {
+            let max = function == AggregateFunction::Max;
+            let accumulator = downcast_primitive! {
+                data_type => (min_max_helper, max, data_type),
+                _ => return Err(unsupported()),
+            };
+            (accumulator, data_type.clone())
+        }
+        AggregateFunction::Sum => match data_type {
+            Int8 => sum!(Int8Type, Int64Type, Int64),
+            Int16 => sum!(Int16Type, Int64Type, Int64),
+            Int32 => sum!(Int32Type, Int64Type, Int64),
+            Int64 => sum!(Int64Type, Int64Type, Int64),
+            UInt8 => sum!(UInt8Type, UInt64Type, UInt64),
+            UInt16 => sum!(UInt16Type, UInt64Type, UInt64),
+            UInt32 => sum!(UInt32Type, UInt64Type, UInt64),
+            UInt64 => sum!(UInt64Type, UInt64Type, UInt64),
+            Float16 => sum!(Float16Type, Float64Type, Float64),
+            Float32 => sum!(Float32Type, Float64Type, Float64),
+            Float64 => sum!(Float64Type, Float64Type, Float64),
+            Decimal32(_, s) => sum!(Decimal32Type, Decimal128Type, Decimal128(38, *s)),
+            Decimal64(_, s) => sum!(Decimal64Type, Decimal128Type, Decimal128(38, *s)),
+            Decimal128(_, s) => sum!(Decimal128Type, Decimal128Type, Decimal128(38, *s)),
+            Decimal256(_, s) => sum!(Decimal256Type, Decimal256Type, Decimal256(76, *s)),
+            _ => return Err(unsupported()),
+        },
+        AggregateFunction::Avg => match data_type {
+            Int8 => avg!(Int8Type, 0),
+            Int16 => avg!(Int16Type, 0),
+            Int32 => avg!(Int32Type, 0),
+            Int64 => avg!(Int64Type, 0),
+            UInt8 => avg!(UInt8Type, 0),
+            UInt16 => avg!(UInt16Type, 0),
+            UInt32 => avg!(UInt32Type, 0),
+            UInt64 => avg!(UInt64Type, 0),
+            Float16 => avg!(Float16Type, 0),
+            Float32 => avg!(Float32Type, 0),
+            Float64 => avg!(Float64Type, 0),
+            Decimal32(_, s) => avg!(Decimal32Type, *s),
+            Decimal64(_, s) => avg!(Decimal64Type, *s),
+            Decimal128(_, s) => avg!(Decimal128Type, *s),
+            Decimal256(_, s) => avg!(Decimal256Type, *s),
+            _ => return Err(unsupported()),
+        },
+    })
+}
+
+/// Computes [`AggregateFunction`]s of value columns, grouped by the distinct values of
+/// key columns, incrementally across [`RecordBatch`]es
+///
+/// The output of [`Self::finish`] contains a row for each group, in the order the groups
+/// were first seen, with the key columns followed by a column for each aggregate named
+/// `function(column)`, e.g. `sum(v)`.
+///
+/// See the [module docs](self) for an example
+pub struct GroupBy {
+    schema: SchemaRef,
+    keys: Vec<usize>,
+    group_ids: GroupIds,
+    accumulators: Vec<(usize, Box<dyn GroupAccumulator>)>,
+    output_fields: Vec<Field>,
+    groups: Vec<usize>,
+}
+
+impl std::fmt::Debug for GroupBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GroupBy")
+            .field("schema", &self.schema)
+            .field("keys", &self.keys)
+            .field("output_fields", &self.output_fields)
+            .field("group_ids", &self.group_ids)
+            .finish()
+    }
+}
+
+impl GroupBy {
+    /// Create a new [`GroupBy`] for batches of `schema`, grouping by the columns at
+    /// indices `keys`, and computing each `(function, column index)` of `aggregates`
+    ///
+    /// Returns an error if an index is out of bounds, or the types of the columns are
+    /// not supported
+    pub fn try_new(
+        schema: &SchemaRef,
+        keys: &[usize],
+        aggregates: &[(AggregateFunction, usize)],
+    ) -> Result<Self, ArrowError> {
+        let field = |idx: usize| {
+            schema.fields().get(idx).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Column index {idx} out of bounds for schema with {} fields",
+                    schema.fields().len()
+                ))
+            })
+        };
+
+        let mut output_fields = Vec::with_capacity(keys.len() + aggregates.len());
+        let mut key_types = Vec::with_capacity(keys.len());
+        for idx in keys {
+            let field = field(*idx)?;
+            key_types.push(field.data_type().clone());
+            output_fields.push(field.as_ref().clone().with_nullable(true));
+        }
+        let group_ids = GroupIds::try_new(&key_types)?;
+
+        let mut accumulators = Vec::with_capacity(aggregates.len());
+        for (function, idx) in aggregates {
+            let field = field(*idx)?;
+            let (accumulator, data_type) = new_accumulator(*function, field.data_type())?;
+            let name = format!("{function}({})", field.name());
+            let nullable = *function != AggregateFunction::Count;
+            output_fields.push(Field::new(name, data_type, nullable));
+            accumulators.push((*idx, accumulator));
+        }
+
+        Ok(Self {
+            schema: Arc::clone(schema),
+            keys: keys.to_vec(),
+            group_ids,
+            accumulators,
+            output_fields,
+            groups: vec![],
+        })
+    }
+
+    /// Update the aggregates with the rows of `batch`
+    ///
+    /// Returns an error if `batch` does not have the schema provided to [`Self::try_new`],
+    /// or an aggregate overflows. Updates are not atomic: after an error, the groups and
+    /// aggregates may include some of the rows of `batch`, so this [`GroupBy`] should be
+    /// discarded
+    pub fn update(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        if batch.schema().fields() != self.schema.fields() {
+            return Err(ArrowError::SchemaError(format!(
+                "GroupBy expected batch with schema {}, got {}",
+                self.schema,
+                batch.schema()
+            )));
+        }
+        let keys: Vec<_> = self
+            .keys
+            .iter()
+            .map(|k| Arc::clone(batch.column(*k)))
+            .collect();
+        self.group_ids.intern(&keys, &mut self.groups)?;
+
+        let num_groups = self.group_ids.num_groups();
+        for (idx, accumulator) in &mut self.accumulators {
+            accumulator.update(batch.column(*idx).as_ref(), &self.groups, num_groups)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of distinct groups seen so far
+    pub fn num_groups(&self) -> usize {
+        self.group_ids.num_groups()
+    }
+
+    /// Returns a [`RecordBatch`] of the key columns and aggregates of each group
+    ///
+    /// Returns an error if the sum of a decimal column exceeds the maximum precision
+    /// of the output type
+    pub fn finish(self) -> Result<RecordBatch, ArrowError> {
+        let num_groups = self.group_ids.num_groups();
+        let mut columns = self.group_ids.finish()?;
+        for (_, accumulator) in self.accumulators {
+            columns.push(accumulator.finish(num_groups)?);
+        }
+
+        // Keys decoded from the row format may have a different type, e.g. dictionaries
+        let fields = self
+            .output_fields
+            .into_iter()
+            .zip(&columns)
+            .map(|(f, c)| f.with_data_type(c.data_type().clone()));
+        let schema = Schema::new(fields.collect::<Vec<_>>());
+        RecordBatch::try_new(Arc::new(schema), columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+
+    fn schema(fields: Vec<(&str, DataType)>) -> SchemaRef {
+        let fields: Vec<_> = fields
+            .into_iter()
+            .map(|(n, d)| Field::new(n, d, true))
+            .collect();
+        Arc::new(Schema::new(fields))
+    }
+
+    #[test]
+    fn test_group_ids() {
+        let mut ids = GroupIds::try_new(&[DataType::Float64]).unwrap();
+        let keys: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(f64::NAN),
+            Some(0.0),
+            None,
+            Some(f64::NAN),
+            Some(-0.0),
+            None,
+        ]));
+        let mut groups = vec![];
+        ids.intern(&[keys], &mut groups).unwrap();
+        assert_eq!(groups, &[0, 1, 2, 0, 3, 2]);
+
+        let keys = ids.finish().unwrap();
+        let keys = keys[0].as_primitive::<Float64Type>();
+        assert_eq!(keys.len(), 4);
+        assert!(keys.value(0).is_nan());
+        assert!(keys.is_null(2));
+
+        let mut ids = GroupIds::try_new(&[DataType::LargeBinary]).unwrap();
+        let keys: ArrayRef = Arc::new(LargeBinaryArray::from_opt_vec(vec![
+            Some(b"a"),
+            None,
+            Some(b"bc"),
+            Some(b"a"),
+        ]));
+        ids.intern(&[Arc::clone(&keys)], &mut groups).unwrap();
+        assert_eq!(groups, &[0, 1, 2, 0]);
+        ids.intern(&[keys.slice(1, 3)], &mut groups).unwrap();
+        assert_eq!(groups, &[1, 2, 0]);
+        let keys = ids.finish().unwrap();
+        let expected = LargeBinaryArray::from_opt_vec(vec![Some(b"a"), None, Some(b"bc")]);
+        assert_eq!(keys[0].as_binary::<i64>(), &expected);
+    }
+
+    #[test]
+    fn test_group_ids_errors() {
+        let err = GroupIds::try_new(&[]).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: GroupIds requires at least one key column"
+        );
+
+        let mut ids = GroupIds::try_new(&[DataType::Int32, DataType::Utf8]).unwrap();
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a"]));
+        let mut groups = vec![];
+        let err = ids.intern(&[Arc::clone(&a)], &mut groups).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: GroupIds expected 2 key columns, got 1"
+        );
+        let err = ids
+            .intern(&[Arc::clone(&b), a.clone()], &mut groups)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: GroupIds expected key column of type Int32, got Utf8"
+        );
+        let err = ids.intern(&[a, b], &mut groups).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: GroupIds expected key columns of equal length, got 2 and 1"
+        );
+    }
+
+    #[test]
+    fn test_group_by_primitive() {
+        let schema = schema(vec![("k", DataType::Int32), ("v", DataType::Int32)]);
+        let aggregates = [
+            (AggregateFunction::Count, 1),
+            (AggregateFunction::Sum, 1),
+            (AggregateFunction::Min, 1),
+            (AggregateFunction::Max, 1),
+            (AggregateFunction::Avg, 1),
+        ];
+        let mut group_by = GroupBy::try_new(&schema, &[0], &aggregates).unwrap();
+
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(2), Some(1)])),
+                Arc::new(Int32Array::from(vec![
+                    Some(10),
+                    Some(5),
+                    None,
+                    Some(i32::MAX),
+                ])),
+            ],
+        )
+        .unwrap();
+        group_by.update(&batch).unwrap();
+        group_by.update(&batch.slice(0, 2)).unwrap();
+        assert_eq!(group_by.num_groups(), 3);
+
+        let result = group_by.finish().unwrap();
+        let names: Vec<_> = result
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(
+            names,
+            ["k", "count(v)", "sum(v)", "min(v)", "max(v)", "avg(v)"]
+        );
+        assert!(!result.schema().field(1).is_nullable());
+
+        let keys = result.column(0).as_primitive::<Int32Type>();
+        assert_eq!(keys, &Int32Array::from(vec![Some(1), None, Some(2)]));
+        let counts = result.column(1).as_primitive::<Int64Type>();
+        assert_eq!(counts.values(), &[3, 2, 0]);
+        let sums = result.column(2).as_primitive::<Int64Type>();
+        let expected = Int64Array::from(vec![Some(20 + i32::MAX as i64), Some(10), None]);
+        assert_eq!(sums, &expected);
+        let mins = result.column(3).as_primitive::<Int32Type>();
+        assert_eq!(mins, &Int32Array::from(vec![Some(10), Some(5), None]));
+        let maxes = result.column(4).as_primitive::<Int32Type>();
+        assert_eq!(
+            maxes,
+            &Int32Array::from(vec![Some(i32::MAX), Some(5), None])
+        );
+        let avgs = result.column(5).as_primitive::<Float64Type>();
+        let expected = Float64Array::from(vec![Some((20. + i32::MAX as f64) / 3.), Some(5.), None]);
+        assert_eq!(avgs, &expected);
+    }
+
+    #[test]
+    fn test_group_by_multiple_keys() {
+        let schema = schema(vec![
+            ("a", DataType::Utf8),
+            ("b", DataType::Boolean),
+            ("v", DataType::Decimal128(10, 2)),
+        ]);
+        let aggregates = [(AggregateFunction::Sum, 2), (AggregateFunction::Avg, 2)];
+        let mut group_by = GroupBy::try_new(&schema, &[0, 1], &aggregates).unwrap();
+
+        let v = Decimal128Array::from(vec![100, 250, 300, 50])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(StringArray::from(vec!["x", "x", "y", "x"])),
+                Arc::new(BooleanArray::from(vec![
+                    Some(true),
+                    None,
+                    Some(true),
+                    Some(true),
+                ])),
+                Arc::new(v),
+            ],
+        )
+        .unwrap();
+        group_by.update(&batch).unwrap();
+        let result = group_by.finish().unwrap();
+
+        assert_eq!(result.num_rows(), 3);
+        let a = result.column(0).as_string::<i32>();
+        assert_eq!(a, &StringArray::from(vec!["x", "x", "y"]));
+        let b = result.column(1).as_boolean();
+        assert_eq!(b, &BooleanArray::from(vec![Some(true), None, Some(true)]));
+        assert_eq!(result.column(2).data_type(), &DataType::Decimal128(38, 2));
+        let sums = result.column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(sums.values(), &[150, 250, 300]);
+        let avgs = result.column(3).as_primitive::<Float64Type>();
+        assert_eq!(avgs.values(), &[0.75, 2.5, 3.0]);
+    }
+
+    #[test]
+    fn test_group_by_errors() {
+        let schema = schema(vec![("k", DataType::Int64), ("v", DataType::Utf8)]);
+        let err = GroupBy::try_new(&schema, &[0], &[(AggregateFunction::Sum, 1)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Unsupported aggregate function sum for Utf8"
+        );
+        let err = GroupBy::try_new(&schema, &[2], &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Column index 2 out of bounds for schema with 2 fields"
+        );
+
+        let mut group_by = GroupBy::try_new(&schema, &[1], &[(AggregateFunction::Sum, 0)]).unwrap();
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(Int64Array::from(vec![i64::MAX, 1])),
+                Arc::new(StringArray::from(vec!["a", "a"])),
+            ],
+        )
+        .unwrap();
+        let err = group_by.update(&batch).unwrap_err().to_string();
+        assert!(err.contains("Overflow happened on"), "{err}");
+
+        let other = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("k", DataType::Int64, true)])),
+            vec![Arc::new(Int64Array::from(vec![1]))],
+        )
+        .unwrap();
+        let err = group_by.update(&other).unwrap_err().to_string();
+        assert!(
+            err.starts_with("Schema error: GroupBy expected batch with schema"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_group_by_decimal_sum_precision() {
+        let schema = schema(vec![
+            ("k", DataType::Int64),
+            ("v", DataType::Decimal128(38, 0)),
+        ]);
+        let mut group_by = GroupBy::try_new(&schema, &[0], &[(AggregateFunction::Sum, 1)]).unwrap();
+
+        // the sum fits in an i128 but exceeds the maximum precision of 38
+        let values = Decimal128Array::from(vec![6 * 10_i128.pow(37); 2])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(Int64Array::from(vec![1, 1])), Arc::new(values)],
+        )
+        .unwrap();
+        group_by.update(&batch).unwrap();
+        let err = group_by.finish().unwrap_err().to_string();
+        assert!(
+            err.contains("is too large to store in a Decimal128 of precision 38"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,820 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to hash the values of arrays, such as for partitioning or joins
+//!
+//! Hashes are stable across processes and platforms, and are compatible with the
+//! `xxhash64` and `hash` (Murmur3) functions of Apache Spark, and therefore its
+//! bucketing:
+//!
+//! * Values are hashed in sequence, with the hash of each value used as the seed of
+//!   the next. Null values leave the hash unchanged.
+//! * 8, 16 and 32-bit signed integers, 8 and 16-bit unsigned integers, dates, booleans
+//!   and 32-bit floats are hashed as 4 bytes, other primitive values, including 32-bit
+//!   unsigned integers, as 8 bytes. `-0.0` is hashed as `0.0`, and all `NaN` as the
+//!   canonical `NaN`.
+//! * Decimals of precision up to 18 are hashed as their 8 byte unscaled value, larger
+//!   decimals as the minimal big-endian two's complement bytes of their unscaled value.
+//! * Lists hash each of their elements, structs each of their fields, and maps the key
+//!   and value of each entry.
+//! * Dictionary, run-end encoded and union arrays hash their logical values, and so hash
+//!   identically to the equivalent plain arrays.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_arith::hash::{hash_columns, hash_columns_with_algorithm, HashAlgorithm};
+//! # use arrow_array::{ArrayRef, Int32Array, ListArray, StringArray};
+//! # use arrow_array::types::Int32Type;
+//! let columns: Vec<ArrayRef> = vec![
+//!     Arc::new(StringArray::from(vec!["Spark"])),
+//!     Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>([Some([Some(123)])])),
+//!     Arc::new(Int32Array::from(vec![2])),
+//! ];
+//! // SELECT xxhash64('Spark', array(123), 2)
+//! let hashes = hash_columns(&columns, 42).unwrap();
+//! assert_eq!(hashes.value(0) as i64, 5602566077635097486);
+//!
+//! // SELECT hash('Spark', array(123), 2)
+//! let hashes = hash_columns_with_algorithm(&columns, 42, HashAlgorithm::Murmur3).unwrap();
+//! assert_eq!(hashes.value(0) as u32 as i32, -1321691492);
+//! ```
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, IntervalDayTime, IntervalMonthDayNano, i256};
+use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
+
+/// The hash function used by [`hash_array_with_algorithm`] and
+/// [`hash_columns_with_algorithm`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// The 64-bit [xxHash] hash function, compatible with Spark's `xxhash64`
+    ///
+    /// [xxHash]: https://github.com/Cyan4973/xxHash
+    #[default]
+    XxHash64,
+    /// The 32-bit x86 [MurmurHash3] hash function, compatible with Spark's `hash`
+    ///
+    /// Seeds are truncated to 32 bits, and hashes are returned zero-extended to 64 bits
+    ///
+    /// [MurmurHash3]: https://github.com/aappleby/smhasher
+    Murmur3,
+}
+
+/// Returns the [`HashAlgorithm::XxHash64`] hash of each value of `array`, starting from `seed`
+///
+/// See the [module docs](self) for how values are hashed
+pub fn hash_array(array: &dyn Array, seed: u64) -> Result<UInt64Array, ArrowError> {
+    hash_array_with_algorithm(array, seed, HashAlgorithm::XxHash64)
+}
+
+/// Returns the hash of each value of `array`, starting from `seed`, using `algorithm`
+///
+/// See the [module docs](self) for how values are hashed
+pub fn hash_array_with_algorithm(
+    array: &dyn Array,
+    seed: u64,
+    algorithm: HashAlgorithm,
+) -> Result<UInt64Array, ArrowError> {
+    hash_arrays(&[array], seed, algorithm)
+}
+
+/// Returns the [`HashAlgorithm::XxHash64`] hash of each row of `columns`, starting
+/// from `seed`
+///
+/// See [`hash_columns_with_algorithm`]
+pub fn hash_columns(columns: &[ArrayRef], seed: u64) -> Result<UInt64Array, ArrowError> {
+    hash_columns_with_algorithm(columns, seed, HashAlgorithm::XxHash64)
+}
+
+/// Returns the hash of each row of `columns`, starting from `seed`, using `algorithm`
+///
+/// The value of each column is hashed in order, using the hash of the previous
+/// column as the seed. Returns an error if `columns` is empty or the columns have
+/// different lengths.
+pub fn hash_columns_with_algorithm(
+    columns: &[ArrayRef],
+    seed: u64,
+    algorithm: HashAlgorithm,
+) -> Result<UInt64Array, ArrowError> {
+    let columns: Vec<_> = columns.iter().map(|c| c.as_ref()).collect();
+    hash_arrays(&columns, seed, algorithm)
+}
+
+fn hash_arrays(
+    columns: &[&dyn Array],
+    seed: u64,
+    algorithm: HashAlgorithm,
+) -> Result<UInt64Array, ArrowError> {
+    let Some(first) = columns.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "Hashing requires at least one column".to_string(),
+        ));
+    };
+    let len = first.len();
+    if let Some(c) = columns.iter().find(|c| c.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Hashed columns must have the same length, got {len} and {}",
+            c.len()
+        )));
+    }
+
+    let seed = match algorithm {
+        HashAlgorithm::XxHash64 => seed,
+        HashAlgorithm::Murmur3 => seed as u32 as u64,
+    };
+    let indices: Vec<usize> = (0..len).collect();
+    let mut hashes = vec![seed; len];
+    for column in columns {
+        hash_at(*column, &indices, &mut hashes, algorithm)?;
+    }
+    Ok(UInt64Array::from(hashes))
+}
+
+impl HashAlgorithm {
+    fn hash_i32(self, value: i32, seed: u64) -> u64 {
+        match self {
+            Self::XxHash64 => xxhash64(&value.to_le_bytes(), seed),
+            Self::Murmur3 => murmur3::hash_int(value, seed as u32) as u64,
+        }
+    }
+
+    fn hash_i64(self, value: i64, seed: u64) -> u64 {
+        match self {
+            Self::XxHash64 => xxhash64(&value.to_le_bytes(), seed),
+            Self::Murmur3 => murmur3::hash_long(value, seed as u32) as u64,
+        }
+    }
+
+    fn hash_bytes(self, value: &[u8], seed: u64) -> u64 {
+        match self {
+            Self::XxHash64 => xxhash64(value, seed),
+            Self::Murmur3 => murmur3::hash_bytes(value, seed as u32) as u64,
+        }
+    }
+
+    fn hash_f32(self, value: f32, seed: u64) -> u64 {
+        let bits = match value {
+            0. => 0,
+            v if v.is_nan() => 0x7fc00000,
+            v => v.to_bits() as i32,
+        };
+        self.hash_i32(bits, seed)
+    }
+
+    fn hash_f64(self, value: f64, seed: u64) -> u64 {
+        let bits = match value {
+            0. => 0,
+            v if v.is_nan() => 0x7ff8000000000000,
+            v => v.to_bits() as i64,
+        };
+        self.hash_i64(bits, seed)
+    }
+
+    fn hash_i128(self, value: i128, precision: u8, seed: u64) -> u64 {
+        match precision <= 18 {
+            true => self.hash_i64(value as i64, seed),
+            false => self.hash_bytes(minimal_be_bytes(&value.to_be_bytes()), seed),
+        }
+    }
+
+    fn hash_i256(self, value: i256, precision: u8, seed: u64) -> u64 {
+        match precision <= 18 {
+            true => self.hash_i64(value.as_i128() as i64, seed),
+            false => self.hash_bytes(minimal_be_bytes(&value.to_be_bytes()), seed),
+        }
+    }
+}
+
+/// Strips the redundant leading sign bytes of big-endian two's complement `bytes`
+fn minimal_be_bytes(bytes: &[u8]) -> &[u8] {
+    let redundant = bytes
+        .windows(2)
+        .take_while(|w| (w[0] == 0 && w[1] & 0x80 == 0) || (w[0] == 0xff && w[1] & 0x80 != 0))
+        .count();
+    &bytes[redundant..]
+}
+
+/// Updates `hashes[j]` with the hash of the value at `indices[j]`, for each valid value
+fn hash_valid<F>(array: &dyn Array, indices: &[usize], hashes: &mut [u64], f: F)
+where
+    F: Fn(usize, u64) -> u64,
+{
+    let nulls = array.logical_nulls();
+    for (idx, hash) in indices.iter().zip(hashes.iter_mut()) {
+        if nulls.as_ref().is_none_or(|n| n.is_valid(*idx)) {
+            *hash = f(*idx, *hash);
+        }
+    }
+}
+
+/// Updates `hashes[positions[j]]` with the hash of the value of `array` at `indices[j]`
+fn hash_gathered(
+    array: &dyn Array,
+    indices: &[usize],
+    positions: &[usize],
+    hashes: &mut [u64],
+    algorithm: HashAlgorithm,
+) -> Result<(), ArrowError> {
+    let mut gathered: Vec<u64> = positions.iter().map(|p| hashes[*p]).collect();
+    hash_at(array, indices, &mut gathered, algorithm)?;
+    for (p, h) in positions.iter().zip(gathered) {
+        hashes[*p] = h;
+    }
+    Ok(())
+}
+
+/// Updates `hashes[j]` with the hash of each element of the list at `indices[j]`,
+/// where `ranges[j]` is the range of elements of the list in `values`
+fn hash_lists(
+    values: &dyn Array,
+    ranges: &[Option<(usize, usize)>],
+    hashes: &mut [u64],
+    algorithm: HashAlgorithm,
+) -> Result<(), ArrowError> {
+    // The position and remaining range of each list with elements left to hash, so
+    // that each round only visits the lists that are at least that long
+    let mut active: Vec<(usize, usize, usize)> = ranges
+        .iter()
+        .enumerate()
+        .filter_map(|(position, range)| {
+            range
+                .filter(|(start, end)| start < end)
+                .map(|(start, end)| (position, start, end))
+        })
+        .collect();
+    let mut positions = Vec::with_capacity(active.len());
+    let mut indices = Vec::with_capacity(active.len());
+    while !active.is_empty() {
+        positions.clear();
+        indices.clear();
+        for (position, start, _) in &active {
+            positions.push(*position);
+            indices.push(*start);
+        }
+        hash_gathered(values, &indices, &positions, hashes, algorithm)?;
+        active.retain_mut(|(_, start, end)| {
+            *start += 1;
+            start < end
+        });
+    }
+    Ok(())
+}
+
+/// Returns the element range of each valid list at `indices`
+fn list_ranges(
+    array: &dyn Array,
+    indices: &[usize],
+    range: impl Fn(usize) -> (usize, usize),
+) -> Vec<Option<(usize, usize)>> {
+    let nulls = array.logical_nulls();
+    indices
+        .iter()
+        .map(|idx| {
+            nulls
+                .as_ref()
+                .is_none_or(|n| n.is_valid(*idx))
+                .then(|| range(*idx))
+        })
+        .collect()
+}
+
+/// Updates `hashes[j]` with the hash of the value of `array` at `indices[j]`
+fn hash_at(
+    array: &dyn Array,
+    indices: &[usize],
+    hashes: &mut [u64],
+    algorithm: HashAlgorithm,
+) -> Result<(), ArrowError> {
+    use DataType::*;
+
+    macro_rules! hash_primitive {
+        ($t:ty, $f:ident, $conv:expr) => {{
+            let values = array.as_primitive::<$t>().values();
+            hash_valid(array, indices, hashes, |i, seed| {
+                algorithm.$f($conv(values[i]), seed)
+            })
+        }};
+    }
+
+    macro_rules! hash_bytes {
+        ($a:expr) => {{
+            let a = $a;
+            hash_valid(array, indices, hashes, |i, seed| {
+                algorithm.hash_bytes(a.value(i), seed)
+            })
+        }};
+        ($a:expr, $conv:ident) => {{
+            let a = $a;
+            hash_valid(array, indices, hashes, |i, seed| {
+                algorithm.hash_bytes(a.value(i).$conv(), seed)
+            })
+        }};
+    }
+
+    match array.data_type() {
+        Null => {}
+        Boolean => {
+            let a = array.as_boolean();
+            hash_valid(array, indices, hashes, |i, seed| {
+                algorithm.hash_i32(a.value(i) as i32, seed)
+            })
+        }
+        Int8 => hash_primitive!(Int8Type, hash_i32, i32::from),
+        Int16 => hash_primitive!(Int16Type, hash_i32, i32::from),
+        Int32 => hash_primitive!(Int32Type, hash_i32, |v| v),
+        Int64 => hash_primitive!(Int64Type, hash_i64, |v| v),
+        UInt8 => hash_primitive!(UInt8Type, hash_i32, i32::from),
+        UInt16 => hash_primitive!(UInt16Type, hash_i32, i32::from),
+        UInt32 => hash_primitive!(UInt32Type, hash_i64, i64::from),
+        UInt64 => hash_primitive!(UInt64Type, hash_i64, |v: u64| v as i64),
+        Float16 => {
+            let values = array.as_primitive::<Float16Type>().values();
+            hash_valid(array, indices, hashes, |i, seed| {
+                algorithm.hash_f32(values[i].to_f32(), seed)
+            })
+        }
+        Float32 => hash_primitive!(Float32Type, hash_f32, |v| v),
+        Float64 => hash_primitive!(Float64Type, hash_f64, |v| v),
+        Date32 => hash_primitive!(Date32Type, hash_i32, |v| v),
+        Date64 => hash_primitive!(Date64Type, hash_i64, |v| v),
+        Time32(TimeUnit::Second) => hash_primitive!(Time32SecondType, hash_i32, |v| v),
+        Time32(TimeUnit::Millisecond) => {
+            hash_primitive!(Time32MillisecondType, hash_i32, |v| v)
+        }
+        Time64(TimeUnit::Microsecond) => {
+            hash_primitive!(Time64MicrosecondType, hash_i64, |v| v)
+        }
+        Time64(TimeUnit::Nanosecond) => hash_primitive!(Time64NanosecondType, hash_i64, |v| v),
+        Timestamp(TimeUnit::Second, _) => {
+            hash_primitive!(TimestampSecondType, hash_i64, |v| v)
+        }
+        Timestamp(TimeUnit::Millisecond, _) => {
+            hash_primitive!(TimestampMillisecondType, hash_i64, |v| v)
+        }
+        Timestamp(TimeUnit::Microsecond, _) => {
+            hash_primitive!(TimestampMicrosecondType, hash_i64, |v| v)
+        }
+        Timestamp(TimeUnit::Nanosecond, _) => {
+            hash_primitive!(TimestampNanosecondType, hash_i64, |v| v)
+        }
+        Duration(TimeUnit::Second) => hash_primitive!(DurationSecondType, hash_i64, |v| v),
+        Duration(TimeUnit::Millisecond) => {
+            hash_primitive!(DurationMillisecondType, hash_i64, |v| v)
+        }
+        Duration(TimeUnit::Microsecond) => {
+            hash_primitive!(DurationMicrosecondType, hash_i64, |v| v)
+        }
+        Duration(TimeUnit::Nanosecond) => {
+            hash_primitive!(DurationNanosecondType, hash_i64, |v| v)
+        }
+        Interval(IntervalUnit::YearMonth) => {
+            hash_primitive!(IntervalYearMonthType, hash_i32, |v| v)
+        }
+        Interval(IntervalUnit::DayTime) => {
+            let values = array.as_primitive::<IntervalDayTimeType>().values();
+            hash_valid(array, indices, hashes, |i, seed| {
+                let IntervalDayTime { days, milliseconds } = values[i];
+                algorithm.hash_i32(milliseconds, algorithm.hash_i32(days, seed))
+            })
+        }
+        Interval(IntervalUnit::MonthDayNano) => {
+            let values = array.as_primitive::<IntervalMonthDayNanoType>().values();
+            hash_valid(array, indices, hashes, |i, seed| {
+                let IntervalMonthDayNano {
+                    months,
+                    days,
+                    nanoseconds,
+                } = values[i];
+                let seed = algorithm.hash_i32(days, algorithm.hash_i32(months, seed));
+                algorithm.hash_i64(nanoseconds, seed)
+            })
+        }
+        Decimal32(_, _) => hash_primitive!(Decimal32Type, hash_i64, i64::from),
+        Decimal64(_, _) => hash_primitive!(Decimal64Type, hash_i64, |v| v),
+        Decimal128(p, _) => {
+            let values = array.as_primitive::<Decimal128Type>().values();
+            hash_valid(array, indices, hashes, |i, seed| {
+                algorithm.hash_i128(values[i], *p, seed)
+            })
+        }
+        Decimal256(p, _) => {
+            let values = array.as_primitive::<Decimal256Type>().values();
+            hash_valid(array, indices, hashes, |i, seed| {
+                algorithm.hash_i256(values[i], *p, seed)
+            })
+        }
+        Utf8 => hash_bytes!(array.as_string::<i32>(), as_bytes),
+        LargeUtf8 => hash_bytes!(array.as_string::<i64>(), as_bytes),
+        Utf8View => hash_bytes!(array.as_string_view(), as_bytes),
+        Binary => hash_bytes!(array.as_binary::<i32>()),
+        LargeBinary => hash_bytes!(array.as_binary::<i64>()),
+        BinaryView => hash_bytes!(array.as_binary_view()),
+        FixedSizeBinary(_) => hash_bytes!(array.as_fixed_size_binary()),
+        List(_) => {
+            let a = array.as_list::<i32>();
+            let offsets = a.value_offsets();
+            let ranges = list_ranges(array, indices, |i| {
+                (offsets[i].as_usize(), offsets[i + 1].as_usize())
+            });
+            hash_lists(a.values().as_ref(), &ranges, hashes, algorithm)?
+        }
+        LargeList(_) => {
+            let a = array.as_list::<i64>();
+            let offsets = a.value_offsets();
+            let ranges = list_ranges(array, indices, |i| {
+                (offsets[i].as_usize(), offsets[i + 1].as_usize())
+            });
+            hash_lists(a.values().as_ref(), &ranges, hashes, algorithm)?
+        }
+        ListView(_) => {
+            let a = array.as_list_view::<i32>();
+            let (offsets, sizes) = (a.offsets(), a.sizes());
+            let ranges = list_ranges(array, indices, |i| {
+                let offset = offsets[i].as_usize();
+                (offset, offset + sizes[i].as_usize())
+            });
+            hash_lists(a.values().as_ref(), &ranges, hashes, algorithm)?
+        }
+        LargeListView(_) => {
+            let a = array.as_list_view::<i64>();
+            let (offsets, sizes) = (a.offsets(), a.sizes());
+            let ranges = list_ranges(array, indices, |i| {
+                let offset = offsets[i].as_usize();
+                (offset, offset + sizes[i].as_usize())
+            });
+            hash_lists(a.values().as_ref(), &ranges, hashes, algorithm)?
+        }
+        FixedSizeList(_, _) => {
+            let a = array.as_fixed_size_list();
+            let len = a.value_length() as usize;
+            let ranges = list_ranges(array, indices, |i| {
+                let offset = a.value_offset(i) as usize;
+                (offset, offset + len)
+            });
+            hash_lists(a.values().as_ref(), &ranges, hashes, algorithm)?
+        }
+        Map(_, _) => {
+            let a = array.as_map();
+            let offsets = a.value_offsets();
+            let ranges = list_ranges(array, indices, |i| {
+                (offsets[i].as_usize(), offsets[i + 1].as_usize())
+            });
+            hash_lists(a.entries(), &ranges, hashes, algorithm)?
+        }
+        Struct(_) => {
+            let a = array.as_struct();
+            match a.nulls() {
+                None => {
+                    for column in a.columns() {
+                        hash_at(column.as_ref(), indices, hashes, algorithm)?;
+                    }
+                }
+                Some(nulls) => {
+                    let (positions, valid): (Vec<_>, Vec<_>) = indices
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, idx)| nulls.is_valid(**idx))
+                        .unzip();
+                    for column in a.columns() {
+                        hash_gathered(column.as_ref(), &valid, &positions, hashes, algorithm)?;
+                    }
+                }
+            }
+        }
+        Dictionary(_, _) => {
+            let a = array.as_any_dictionary();
+            let keys = a.keys();
+            let normalized = a.normalized_keys();
+            let (positions, values): (Vec<_>, Vec<_>) = indices
+                .iter()
+                .enumerate()
+                .filter(|(_, idx)| keys.is_valid(**idx))
+                .map(|(p, idx)| (p, normalized[*idx]))
+                .unzip();
+            hash_gathered(a.values().as_ref(), &values, &positions, hashes, algorithm)?
+        }
+        RunEndEncoded(run_ends, _) => {
+            let (physical, values): (Vec<usize>, _) = match run_ends.data_type() {
+                Int16 => {
+                    let a = array.as_run::<Int16Type>();
+                    (
+                        indices.iter().map(|i| a.get_physical_index(*i)).collect(),
+                        a.values(),
+                    )
+                }
+                Int32 => {
+                    let a = array.as_run::<Int32Type>();
+                    (
+                        indices.iter().map(|i| a.get_physical_index(*i)).collect(),
+                        a.values(),
+                    )
+                }
+                Int64 => {
+                    let a = array.as_run::<Int64Type>();
+                    (
+                        indices.iter().map(|i| a.get_physical_index(*i)).collect(),
+                        a.values(),
+                    )
+                }
+                t => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Invalid run end type {t}"
+                    )));
+                }
+            };
+            hash_at(values.as_ref(), &physical, hashes, algorithm)?
+        }
+        Union(fields, _) => {
+            let a = array.as_union();
+            for (type_id, _) in fields.iter() {
+                let (positions, child): (Vec<_>, Vec<_>) = indices
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, idx)| a.type_id(**idx) == type_id)
+                    .map(|(p, idx)| (p, a.value_offset(*idx)))
+                    .unzip();
+                if !positions.is_empty() {
+                    hash_gathered(
+                        a.child(type_id).as_ref(),
+                        &child,
+                        &positions,
+                        hashes,
+                        algorithm,
+                    )?;
+                }
+            }
+        }
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Hashing of {t} is not supported"
+            )));
+        }
+    }
+    Ok(())
+}
+
+const PRIME64_1: u64 = 0x9E3779B185EBCA87;
+const PRIME64_2: u64 = 0xC2B2AE3D27D4EB4F;
+const PRIME64_3: u64 = 0x165667B19E3779F9;
+const PRIME64_4: u64 = 0x85EBCA77C2B2AE63;
+const PRIME64_5: u64 = 0x27D4EB2F165667C5;
+
+/// Returns the 64-bit xxHash of `bytes`
+fn xxhash64(bytes: &[u8], seed: u64) -> u64 {
+    fn read_u64(b: &[u8]) -> u64 {
+        u64::from_le_bytes(b[..8].try_into().unwrap())
+    }
+
+    fn round(acc: u64, input: u64) -> u64 {
+        acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+            .rotate_left(31)
+            .wrapping_mul(PRIME64_1)
+    }
+
+    fn merge_round(acc: u64, val: u64) -> u64 {
+        (acc ^ round(0, val))
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4)
+    }
+
+    let mut remaining = bytes;
+    let mut hash = if bytes.len() >= 32 {
+        let mut v = [
+            seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2),
+            seed.wrapping_add(PRIME64_2),
+            seed,
+            seed.wrapping_sub(PRIME64_1),
+        ];
+        while remaining.len() >= 32 {
+            for (i, v) in v.iter_mut().enumerate() {
+                *v = round(*v, read_u64(&remaining[i * 8..]));
+            }
+            remaining = &remaining[32..];
+        }
+        let hash = v[0]
+            .rotate_left(1)
+            .wrapping_add(v[1].rotate_left(7))
+            .wrapping_add(v[2].rotate_left(12))
+            .wrapping_add(v[3].rotate_left(18));
+        v.iter().fold(hash, |h, v| merge_round(h, *v))
+    } else {
+        seed.wrapping_add(PRIME64_5)
+    };
+    hash = hash.wrapping_add(bytes.len() as u64);
+
+    while remaining.len() >= 8 {
+        hash ^= round(0, read_u64(remaining));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(PRIME64_1)
+            .wrapping_add(PRIME64_4);
+        remaining = &remaining[8..];
+    }
+    if remaining.len() >= 4 {
+        let v = u32::from_le_bytes(remaining[..4].try_into().unwrap()) as u64;
+        hash ^= v.wrapping_mul(PRIME64_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(PRIME64_2)
+            .wrapping_add(PRIME64_3);
+        remaining = &remaining[4..];
+    }
+    for b in remaining {
+        hash ^= (*b as u64).wrapping_mul(PRIME64_5);
+        hash = hash.rotate_left(11).wrapping_mul(PRIME64_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(PRIME64_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(PRIME64_3);
+    hash ^ (hash >> 32)
+}
+
+/// The 32-bit x86 MurmurHash3, as implemented by Apache Spark
+mod murmur3 {
+    const C1: u32 = 0xcc9e2d51;
+    const C2: u32 = 0x1b873593;
+
+    fn mix_k1(k1: u32) -> u32 {
+        k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2)
+    }
+
+    fn mix_h1(h1: u32, k1: u32) -> u32 {
+        (h1 ^ k1)
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe6546b64)
+    }
+
+    fn fmix(mut h1: u32, len: u32) -> u32 {
+        h1 ^= len;
+        h1 ^= h1 >> 16;
+        h1 = h1.wrapping_mul(0x85ebca6b);
+        h1 ^= h1 >> 13;
+        h1 = h1.wrapping_mul(0xc2b2ae35);
+        h1 ^ (h1 >> 16)
+    }
+
+    pub(super) fn hash_int(value: i32, seed: u32) -> u32 {
+        fmix(mix_h1(seed, mix_k1(value as u32)), 4)
+    }
+
+    pub(super) fn hash_long(value: i64, seed: u32) -> u32 {
+        let h1 = mix_h1(seed, mix_k1(value as u32));
+        fmix(mix_h1(h1, mix_k1((value >> 32) as u32)), 8)
+    }
+
+    /// Hashes `bytes` in 4 byte words, followed by each remaining byte sign-extended
+    /// to a word, matching Spark's `hashUnsafeBytes`
+    pub(super) fn hash_bytes(bytes: &[u8], seed: u32) -> u32 {
+        let chunks = bytes.chunks_exact(4);
+        let tail = chunks.remainder();
+        let h1 = chunks.fold(seed, |h1, c| {
+            mix_h1(h1, mix_k1(u32::from_le_bytes(c.try_into().unwrap())))
+        });
+        let h1 = tail
+            .iter()
+            .fold(h1, |h1, b| mix_h1(h1, mix_k1(*b as i8 as i32 as u32)));
+        fmix(h1, bytes.len() as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::NullBuffer;
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_xxhash64_vectors() {
+        assert_eq!(xxhash64(b"", 0), 0xEF46DB3751D8E999);
+        assert_eq!(xxhash64(b"a", 0), 0xD24EC4F1A98C6E5B);
+        assert_ne!(xxhash64(b"a", 0), xxhash64(b"a", 1));
+    }
+
+    #[test]
+    fn test_spark_murmur3() {
+        // SELECT hash(1), hash(null), hash(0.0), hash(-0.0)
+        let a = Int32Array::from(vec![Some(1), None]);
+        let hashes = hash_array_with_algorithm(&a, 42, HashAlgorithm::Murmur3).unwrap();
+        assert_eq!(hashes.value(0) as u32 as i32, -559580957);
+        assert_eq!(hashes.value(1), 42);
+        assert!(hashes.nulls().is_none());
+
+        let a = Float64Array::from(vec![0.0, -0.0, f64::NAN, -f64::NAN]);
+        let hashes = hash_array_with_algorithm(&a, 42, HashAlgorithm::Murmur3).unwrap();
+        assert_eq!(hashes.value(0), hashes.value(1));
+        assert_eq!(hashes.value(2), hashes.value(3));
+        let long = Int64Array::from(vec![0]);
+        let long = hash_array_with_algorithm(&long, 42, HashAlgorithm::Murmur3).unwrap();
+        assert_eq!(hashes.value(0), long.value(0));
+    }
+
+    #[test]
+    fn test_logical_values() {
+        let plain = StringArray::from(vec![Some("a"), None, Some("bc"), Some("a")]);
+        let expected = hash_array(&plain, 42).unwrap();
+
+        let dict: DictionaryArray<Int8Type> = vec![Some("a"), None, Some("bc"), Some("a")]
+            .into_iter()
+            .collect();
+        assert_eq!(hash_array(&dict, 42).unwrap(), expected);
+
+        let views = StringViewArray::from(vec![Some("a"), None, Some("bc"), Some("a")]);
+        assert_eq!(hash_array(&views, 42).unwrap(), expected);
+
+        let run_ends = Int32Array::from(vec![1, 2, 3, 4]);
+        let ree = RunArray::try_new(&run_ends, &plain).unwrap();
+        assert_eq!(hash_array(&ree, 42).unwrap(), expected);
+
+        let sliced = hash_array(&plain.slice(1, 3), 42).unwrap();
+        assert_eq!(sliced.values(), &expected.values()[1..]);
+    }
+
+    #[test]
+    fn test_nested() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>([
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(1), None, Some(2)]),
+        ]);
+        let hashes = hash_array(&list, 42).unwrap();
+        assert_eq!(hashes.value(1), 42);
+        assert_eq!(hashes.value(2), 42);
+        // Null elements leave the hash unchanged
+        assert_eq!(hashes.value(0), hashes.value(3));
+
+        // Lists of different lengths hash each of their elements in sequence
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>([
+            Some(vec![Some(1), Some(2), Some(3)]),
+            Some(vec![Some(4)]),
+        ]);
+        let hashes = hash_array(&list, 42).unwrap();
+        let expected = [1, 2, 3].iter().fold(42, |seed, v| {
+            hash_array(&Int32Array::from(vec![*v]), seed)
+                .unwrap()
+                .value(0)
+        });
+        assert_eq!(hashes.value(0), expected);
+        let expected = hash_array(&Int32Array::from(vec![4]), 42).unwrap();
+        assert_eq!(hashes.value(1), expected.value(0));
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec!["x", "y"])),
+        ];
+        let strukt = StructArray::new(
+            vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Utf8, false),
+            ]
+            .into(),
+            columns.clone(),
+            Some(NullBuffer::from(vec![true, false])),
+        );
+        let hashes = hash_array(&strukt, 42).unwrap();
+        assert_eq!(
+            hashes.value(0),
+            hash_columns(&columns, 42).unwrap().value(0)
+        );
+        assert_eq!(hashes.value(1), 42);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = hash_columns(&[], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Hashing requires at least one column"
+        );
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(Int32Array::from(vec![1])),
+        ];
+        let err = hash_columns(&columns, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Hashed columns must have the same length, got 2 and 1"
+        );
+    }
+}
@@ -29,5 +29,8 @@ pub mod arithmetic;
 pub mod arity;
 pub mod bitwise;
 pub mod boolean;
+pub mod group_by;
+pub mod hash;
+pub mod math;
 pub mod numeric;
 pub mod temporal;
@@ -0,0 +1,1151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines math kernels on [`PrimitiveArray`], such as [`sqrt`], [`round`] and [`pow`]
+//!
+//! Kernels accept integer, floating point and decimal arrays:
+//!
+//! * Transcendental functions, such as [`sqrt`], [`ln`] or [`sin`], return the same
+//!   floating point type for floating point inputs, and [`Float64Array`] for integer
+//!   and decimal inputs. Values outside their domain result in `NaN`, as per IEEE 754.
+//! * Other kernels, such as [`abs`], [`round`] or [`gcd`], preserve the input type and
+//!   return an error on overflow, with `_wrapping` variants where overflow can be wrapped.
+//! * Decimal rounding is scale-aware, and preserves the precision and scale of the input.
+//!
+//! ```
+//! # use arrow_arith::math::{round, sqrt};
+//! # use arrow_array::{Decimal128Array, Float64Array, Int32Array};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::{Decimal128Type, Float64Type};
+//! let a = Int32Array::from(vec![4, 9, 16]);
+//! let r = sqrt(&a).unwrap();
+//! assert_eq!(r.as_primitive::<Float64Type>().values(), &[2.0, 3.0, 4.0]);
+//!
+//! // 1.235 and -1.235 rounded to 2 decimal places
+//! let a = Decimal128Array::from(vec![1235, -1235])
+//!     .with_precision_and_scale(6, 3)
+//!     .unwrap();
+//! let r = round(&a, 2).unwrap();
+//! assert_eq!(r.as_primitive::<Decimal128Type>().values(), &[1240, -1240]);
+//! ```
+
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType};
+use num_traits::{AsPrimitive, Float, NumCast, ToPrimitive};
+
+use crate::arity::{binary, try_binary};
+
+/// Computes the square root of each element of `array`
+pub fn sqrt(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Sqrt, array)
+}
+
+/// Computes the cube root of each element of `array`
+pub fn cbrt(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Cbrt, array)
+}
+
+/// Computes `e ^ x` for each element `x` of `array`
+pub fn exp(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Exp, array)
+}
+
+/// Computes the natural logarithm of each element of `array`
+pub fn ln(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Ln, array)
+}
+
+/// Computes the base 2 logarithm of each element of `array`
+pub fn log2(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Log2, array)
+}
+
+/// Computes the base 10 logarithm of each element of `array`
+pub fn log10(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Log10, array)
+}
+
+/// Computes the sine of each element of `array`, in radians
+pub fn sin(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Sin, array)
+}
+
+/// Computes the cosine of each element of `array`, in radians
+pub fn cos(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Cos, array)
+}
+
+/// Computes the tangent of each element of `array`, in radians
+pub fn tan(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Tan, array)
+}
+
+/// Computes the arcsine of each element of `array`, in radians
+pub fn asin(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Asin, array)
+}
+
+/// Computes the arccosine of each element of `array`, in radians
+pub fn acos(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Acos, array)
+}
+
+/// Computes the arctangent of each element of `array`, in radians
+pub fn atan(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Atan, array)
+}
+
+/// Computes the hyperbolic sine of each element of `array`
+pub fn sinh(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Sinh, array)
+}
+
+/// Computes the hyperbolic cosine of each element of `array`
+pub fn cosh(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Cosh, array)
+}
+
+/// Computes the hyperbolic tangent of each element of `array`
+pub fn tanh(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    float_math_op(FloatOp::Tanh, array)
+}
+
+/// Computes the absolute value of each element of `array`, returning an error on overflow
+///
+/// For wrapping signed integer overflow consider using [`abs_wrapping`]
+pub fn abs(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    macro_rules! abs_checked {
+        ($t:ty, $a:ident) => {{
+            let array = $a.as_primitive::<$t>().try_unary::<_, $t, _>(|x| {
+                match x.is_lt(<$t as ArrowPrimitiveType>::Native::ZERO) {
+                    true => x.neg_checked(),
+                    false => Ok(x),
+                }
+            })?;
+            Ok(Arc::new(array))
+        }};
+    }
+
+    use DataType::*;
+    downcast_integer! {
+        array.data_type() => (abs_checked, array),
+        Float16 => Ok(Arc::new(float_unary::<Float16Type, _>(array, |x| x.abs()))),
+        Float32 => Ok(Arc::new(float_unary::<Float32Type, _>(array, |x| x.abs()))),
+        Float64 => Ok(Arc::new(float_unary::<Float64Type, _>(array, |x| x.abs()))),
+        Decimal32(p, s) => decimal_abs::<Decimal32Type>(array, *p, *s),
+        Decimal64(p, s) => decimal_abs::<Decimal64Type>(array, *p, *s),
+        Decimal128(p, s) => decimal_abs::<Decimal128Type>(array, *p, *s),
+        Decimal256(p, s) => decimal_abs::<Decimal256Type>(array, *p, *s),
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid math operation: abs({t})"
+        ))),
+    }
+}
+
+/// Computes the absolute value of each element of `array`, wrapping on overflow for
+/// [`DataType::is_integer`]
+pub fn abs_wrapping(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    macro_rules! abs_wrapping {
+        ($t:ty, $a:ident) => {{
+            let array = $a.as_primitive::<$t>().unary::<_, $t>(|x| {
+                match x.is_lt(<$t as ArrowPrimitiveType>::Native::ZERO) {
+                    true => x.neg_wrapping(),
+                    false => x,
+                }
+            });
+            Ok(Arc::new(array))
+        }};
+    }
+
+    downcast_integer! {
+        array.data_type() => (abs_wrapping, array),
+        _ => abs(array),
+    }
+}
+
+/// Computes the sign of each element of `array`, as `-1`, `0` or `1`
+///
+/// Floating point `NaN` and signed zeros are returned unchanged. Decimal arrays of
+/// precision `p` return decimals of precision `p` and scale `0`.
+pub fn sign(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    macro_rules! integer_sign {
+        ($t:ty, $a:ident) => {
+            Ok(Arc::new(
+                $a.as_primitive::<$t>().unary::<_, $t>(native_sign),
+            ))
+        };
+    }
+
+    use DataType::*;
+    downcast_integer! {
+        array.data_type() => (integer_sign, array),
+        Float16 => Ok(Arc::new(float_unary::<Float16Type, _>(array, float_sign))),
+        Float32 => Ok(Arc::new(float_unary::<Float32Type, _>(array, float_sign))),
+        Float64 => Ok(Arc::new(float_unary::<Float64Type, _>(array, float_sign))),
+        Decimal32(p, _) => decimal_sign::<Decimal32Type>(array, *p),
+        Decimal64(p, _) => decimal_sign::<Decimal64Type>(array, *p),
+        Decimal128(p, _) => decimal_sign::<Decimal128Type>(array, *p),
+        Decimal256(p, _) => decimal_sign::<Decimal256Type>(array, *p),
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid math operation: sign({t})"
+        ))),
+    }
+}
+
+/// Rounds each element of `array` to `decimal_places`, rounding half away from zero
+///
+/// A negative `decimal_places` rounds to the left of the decimal point, e.g. rounding
+/// `1250` to `-2` decimal places results in `1300`. Decimal arrays are rounded according
+/// to their scale, and return an error if a rounded value exceeds their precision.
+pub fn round(array: &dyn Array, decimal_places: i32) -> Result<ArrayRef, ArrowError> {
+    round_op(Rounding::HalfAwayFromZero, array, decimal_places)
+}
+
+/// Truncates each element of `array` to `decimal_places`, rounding towards zero
+///
+/// See [`round`] for the handling of negative `decimal_places` and decimal arrays
+pub fn trunc(array: &dyn Array, decimal_places: i32) -> Result<ArrayRef, ArrowError> {
+    round_op(Rounding::TowardZero, array, decimal_places)
+}
+
+/// Rounds each element of `array` up to the nearest integer
+///
+/// Integer arrays are returned unchanged, decimal arrays keep their precision and scale
+/// and return an error if a rounded value exceeds their precision
+pub fn ceil(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    round_op(Rounding::Ceil, array, 0)
+}
+
+/// Rounds each element of `array` down to the nearest integer
+///
+/// Integer arrays are returned unchanged, decimal arrays keep their precision and scale
+/// and return an error if a rounded value exceeds their precision
+pub fn floor(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    round_op(Rounding::Floor, array, 0)
+}
+
+/// Perform `base ^ exponent`, returning an error on overflow
+///
+/// Both inputs must have the same type. Integer exponents must be non-negative and
+/// fit in a `u32`, decimal inputs return a [`Float64Array`]
+pub fn pow(base: &dyn Datum, exponent: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    math_binary_op(BinaryOp::Pow, base, exponent)
+}
+
+/// Perform `base ^ exponent`, wrapping on overflow for [`DataType::is_integer`]
+///
+/// See [`pow`] for the supported inputs
+pub fn pow_wrapping(base: &dyn Datum, exponent: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    math_binary_op(BinaryOp::PowWrapping, base, exponent)
+}
+
+/// Computes the four quadrant arctangent of `y / x`, in radians
+///
+/// Both inputs must have the same type, see the [module docs](self) for the return type
+pub fn atan2(y: &dyn Datum, x: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    math_binary_op(BinaryOp::Atan2, y, x)
+}
+
+/// Computes the greatest common divisor of `lhs` and `rhs`, returning an error on overflow
+///
+/// Both inputs must have the same integer type. The result is always non-negative,
+/// and `gcd(0, 0)` is `0`.
+pub fn gcd(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    math_binary_op(BinaryOp::Gcd, lhs, rhs)
+}
+
+/// Computes the least common multiple of `lhs` and `rhs`, returning an error on overflow
+///
+/// Both inputs must have the same integer type. The result is always non-negative,
+/// and is `0` if either input is `0`.
+pub fn lcm(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    math_binary_op(BinaryOp::Lcm, lhs, rhs)
+}
+
+/// Computes the least common multiple of `lhs` and `rhs`, wrapping on overflow
+///
+/// See [`lcm`] for the supported inputs
+pub fn lcm_wrapping(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    math_binary_op(BinaryOp::LcmWrapping, lhs, rhs)
+}
+
+/// An enumeration of floating point functions
+///
+/// This allows sharing the type dispatch logic across the various kernels
+#[derive(Debug, Copy, Clone)]
+enum FloatOp {
+    Sqrt,
+    Cbrt,
+    Exp,
+    Ln,
+    Log2,
+    Log10,
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Sinh,
+    Cosh,
+    Tanh,
+}
+
+impl std::fmt::Display for FloatOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Sqrt => "sqrt",
+            Self::Cbrt => "cbrt",
+            Self::Exp => "exp",
+            Self::Ln => "ln",
+            Self::Log2 => "log2",
+            Self::Log10 => "log10",
+            Self::Sin => "sin",
+            Self::Cos => "cos",
+            Self::Tan => "tan",
+            Self::Asin => "asin",
+            Self::Acos => "acos",
+            Self::Atan => "atan",
+            Self::Sinh => "sinh",
+            Self::Cosh => "cosh",
+            Self::Tanh => "tanh",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FloatOp {
+    fn apply<F: Float>(self, x: F) -> F {
+        match self {
+            Self::Sqrt => x.sqrt(),
+            Self::Cbrt => x.cbrt(),
+            Self::Exp => x.exp(),
+            Self::Ln => x.ln(),
+            Self::Log2 => x.log2(),
+            Self::Log10 => x.log10(),
+            Self::Sin => x.sin(),
+            Self::Cos => x.cos(),
+            Self::Tan => x.tan(),
+            Self::Asin => x.asin(),
+            Self::Acos => x.acos(),
+            Self::Atan => x.atan(),
+            Self::Sinh => x.sinh(),
+            Self::Cosh => x.cosh(),
+            Self::Tanh => x.tanh(),
+        }
+    }
+}
+
+/// Dispatch the given floating point `op` to the appropriate specialized kernel
+fn float_math_op(op: FloatOp, array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Float16 => Ok(Arc::new(float_unary::<Float16Type, _>(array, |x| {
+            op.apply(x)
+        }))),
+        DataType::Float32 => Ok(Arc::new(float_unary::<Float32Type, _>(array, |x| {
+            op.apply(x)
+        }))),
+        DataType::Float64 => Ok(Arc::new(float_unary::<Float64Type, _>(array, |x| {
+            op.apply(x)
+        }))),
+        t => match to_float64(array) {
+            Some(a) => Ok(Arc::new(a.unary::<_, Float64Type>(|x| op.apply(x)))),
+            None => Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid math operation: {op}({t})"
+            ))),
+        },
+    }
+}
+
+fn float_unary<T, F>(array: &dyn Array, op: F) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> T::Native,
+{
+    array.as_primitive::<T>().unary(op)
+}
+
+/// Converts integer and decimal arrays to [`Float64Array`], returning `None` for other types
+fn to_float64(array: &dyn Array) -> Option<Float64Array> {
+    macro_rules! integer_to_f64 {
+        ($t:ty, $a:ident) => {
+            Some($a.as_primitive::<$t>().unary(|x| x as f64))
+        };
+    }
+
+    fn decimal_to_f64<T: DecimalType>(array: &dyn Array, scale: i8) -> Float64Array
+    where
+        T::Native: ToPrimitive,
+    {
+        let div = 10_f64.powi(scale as i32);
+        array
+            .as_primitive::<T>()
+            .unary(|x| x.to_f64().unwrap_or(f64::NAN) / div)
+    }
+
+    use DataType::*;
+    downcast_integer! {
+        array.data_type() => (integer_to_f64, array),
+        Decimal32(_, s) => Some(decimal_to_f64::<Decimal32Type>(array, *s)),
+        Decimal64(_, s) => Some(decimal_to_f64::<Decimal64Type>(array, *s)),
+        Decimal128(_, s) => Some(decimal_to_f64::<Decimal128Type>(array, *s)),
+        Decimal256(_, s) => Some(decimal_to_f64::<Decimal256Type>(array, *s)),
+        _ => None,
+    }
+}
+
+fn decimal_abs<T: DecimalType>(
+    array: &dyn Array,
+    precision: u8,
+    scale: i8,
+) -> Result<ArrayRef, ArrowError> {
+    let array =
+        array
+            .as_primitive::<T>()
+            .try_unary::<_, T, _>(|x| match x.is_lt(T::Native::ZERO) {
+                true => x.neg_checked(),
+                false => Ok(x),
+            })?;
+    Ok(Arc::new(array.with_precision_and_scale(precision, scale)?))
+}
+
+fn native_sign<N: ArrowNativeTypeOp>(x: N) -> N {
+    match x.compare(N::ZERO) {
+        std::cmp::Ordering::Less => N::ONE.neg_wrapping(),
+        std::cmp::Ordering::Equal => N::ZERO,
+        std::cmp::Ordering::Greater => N::ONE,
+    }
+}
+
+fn float_sign<F: Float>(x: F) -> F {
+    match x.is_nan() || x.is_zero() {
+        true => x,
+        false => x.signum(),
+    }
+}
+
+fn decimal_sign<T: DecimalType>(array: &dyn Array, precision: u8) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<T>().unary::<_, T>(native_sign);
+    Ok(Arc::new(array.with_precision_and_scale(precision, 0)?))
+}
+
+/// The rounding mode of [`round_op`]
+#[derive(Debug, Copy, Clone)]
+enum Rounding {
+    HalfAwayFromZero,
+    TowardZero,
+    Ceil,
+    Floor,
+}
+
+impl std::fmt::Display for Rounding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::HalfAwayFromZero => write!(f, "round"),
+            Self::TowardZero => write!(f, "trunc"),
+            Self::Ceil => write!(f, "ceil"),
+            Self::Floor => write!(f, "floor"),
+        }
+    }
+}
+
+impl Rounding {
+    fn apply_float<F: Float>(self, x: F) -> F {
+        match self {
+            Self::HalfAwayFromZero => x.round(),
+            Self::TowardZero => x.trunc(),
+            Self::Ceil => x.ceil(),
+            Self::Floor => x.floor(),
+        }
+    }
+
+    /// Rounds `x` to a multiple of `factor`
+    fn apply_native<N: ArrowNativeTypeOp>(self, x: N, factor: N) -> Result<N, ArrowError> {
+        let rem = x.mod_wrapping(factor);
+        let base = x.sub_wrapping(rem);
+        match self {
+            Self::HalfAwayFromZero => {
+                let abs_rem = match rem.is_lt(N::ZERO) {
+                    true => rem.neg_wrapping(),
+                    false => rem,
+                };
+                match abs_rem.is_ge(factor.sub_wrapping(abs_rem)) {
+                    true if x.is_lt(N::ZERO) => base.sub_checked(factor),
+                    true => base.add_checked(factor),
+                    false => Ok(base),
+                }
+            }
+            Self::TowardZero => Ok(base),
+            Self::Ceil if rem.is_gt(N::ZERO) => base.add_checked(factor),
+            Self::Floor if rem.is_lt(N::ZERO) => base.sub_checked(factor),
+            Self::Ceil | Self::Floor => Ok(base),
+        }
+    }
+
+    /// Rounds `x` to a multiple of `10 ^ exponent`, where `10 ^ exponent` does not fit in `N`
+    fn apply_native_overflow<N: ArrowNativeTypeOp>(
+        self,
+        x: N,
+        exponent: u32,
+    ) -> Result<N, ArrowError> {
+        let overflow = || {
+            ArrowError::ArithmeticOverflow(format!(
+                "Overflow happened on: {self}({x:?}) to a multiple of 10 ^ {exponent}"
+            ))
+        };
+        match self {
+            Self::TowardZero => Ok(N::ZERO),
+            Self::Ceil if x.is_gt(N::ZERO) => Err(overflow()),
+            Self::Floor if x.is_lt(N::ZERO) => Err(overflow()),
+            Self::Ceil | Self::Floor => Ok(N::ZERO),
+            Self::HalfAwayFromZero => {
+                // values at least half of 10 ^ exponent round away from zero and overflow
+                let half = N::usize_as(10)
+                    .pow_checked(exponent - 1)
+                    .and_then(|x| x.mul_checked(N::usize_as(5)));
+                match half {
+                    Ok(half) if x.is_ge(half) || x.is_le(half.neg_wrapping()) => Err(overflow()),
+                    _ => Ok(N::ZERO),
+                }
+            }
+        }
+    }
+}
+
+/// Dispatch the given rounding `op` to the appropriate specialized kernel
+fn round_op(op: Rounding, array: &dyn Array, digits: i32) -> Result<ArrayRef, ArrowError> {
+    macro_rules! integer_helper {
+        ($t:ty, $op:ident, $a:ident, $d:ident) => {
+            integer_round::<$t>($op, $a, $d)
+        };
+    }
+
+    use DataType::*;
+    downcast_integer! {
+        array.data_type() => (integer_helper, op, array, digits),
+        Float16 => Ok(Arc::new(float_round::<Float16Type>(op, array, digits))),
+        Float32 => Ok(Arc::new(float_round::<Float32Type>(op, array, digits))),
+        Float64 => Ok(Arc::new(float_round::<Float64Type>(op, array, digits))),
+        Decimal32(p, s) => decimal_round::<Decimal32Type>(op, array, digits, *p, *s),
+        Decimal64(p, s) => decimal_round::<Decimal64Type>(op, array, digits, *p, *s),
+        Decimal128(p, s) => decimal_round::<Decimal128Type>(op, array, digits, *p, *s),
+        Decimal256(p, s) => decimal_round::<Decimal256Type>(op, array, digits, *p, *s),
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid math operation: {op}({t})"
+        ))),
+    }
+}
+
+fn float_round<T>(op: Rounding, array: &dyn Array, digits: i32) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Float,
+{
+    let array = array.as_primitive::<T>();
+    if digits == 0 {
+        return array.unary(|x| op.apply_float(x));
+    }
+
+    let factor = <T::Native as NumCast>::from(10).unwrap().powi(digits);
+    array.unary(|x| {
+        let scaled = x * factor;
+        // the factor or the scaled value is too large to be represented, in which
+        // case `x` has no digits beyond those requested
+        if digits > 0 && !scaled.is_finite() {
+            return x;
+        }
+        let rounded = op.apply_float(scaled) / factor;
+        match rounded.is_nan() && !x.is_nan() {
+            // the factor is too small to be represented
+            true => T::Native::neg_zero().copysign(x),
+            false => rounded,
+        }
+    })
+}
+
+/// Rounds the elements of `array` to a multiple of `10 ^ exponent`
+fn round_native<T: ArrowPrimitiveType>(
+    op: Rounding,
+    array: &PrimitiveArray<T>,
+    exponent: u32,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    match T::Native::usize_as(10).pow_checked(exponent) {
+        Ok(factor) => array.try_unary(|x| op.apply_native(x, factor)),
+        Err(_) => array.try_unary(|x| op.apply_native_overflow(x, exponent)),
+    }
+}
+
+fn integer_round<T: ArrowPrimitiveType>(
+    op: Rounding,
+    array: &dyn Array,
+    digits: i32,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<T>();
+    match digits >= 0 {
+        true => Ok(Arc::new(array.clone())),
+        false => Ok(Arc::new(round_native(op, array, digits.unsigned_abs())?)),
+    }
+}
+
+fn decimal_round<T: DecimalType>(
+    op: Rounding,
+    array: &dyn Array,
+    digits: i32,
+    precision: u8,
+    scale: i8,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<T>();
+    let exponent = scale as i64 - digits as i64;
+    if exponent <= 0 {
+        return Ok(Arc::new(array.clone()));
+    }
+
+    let exponent = u32::try_from(exponent).unwrap_or(u32::MAX);
+    let rounded = round_native(op, array, exponent)?.try_unary::<_, T, ArrowError>(|v| {
+        T::validate_decimal_precision(v, precision, scale)?;
+        Ok(v)
+    })?;
+    Ok(Arc::new(
+        rounded.with_precision_and_scale(precision, scale)?,
+    ))
+}
+
+/// An enumeration of binary math operations
+///
+/// This allows sharing the type dispatch logic across the various kernels
+#[derive(Debug, Copy, Clone)]
+enum BinaryOp {
+    Pow,
+    PowWrapping,
+    Atan2,
+    Gcd,
+    Lcm,
+    LcmWrapping,
+}
+
+impl std::fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pow | Self::PowWrapping => write!(f, "pow"),
+            Self::Atan2 => write!(f, "atan2"),
+            Self::Gcd => write!(f, "gcd"),
+            Self::Lcm | Self::LcmWrapping => write!(f, "lcm"),
+        }
+    }
+}
+
+impl BinaryOp {
+    /// Returns true if this operation is computed on floating point values
+    fn is_float(&self) -> bool {
+        matches!(self, Self::Pow | Self::PowWrapping | Self::Atan2)
+    }
+}
+
+/// Dispatch the given binary `op` to the appropriate specialized kernel
+fn math_binary_op(op: BinaryOp, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    use DataType::*;
+
+    macro_rules! integer_helper {
+        ($t:ty, $op:ident, $l:ident, $l_scalar:ident, $r:ident, $r_scalar:ident) => {
+            integer_binary::<$t>($op, $l, $l_scalar, $r, $r_scalar)
+        };
+    }
+
+    let (l, l_scalar) = lhs.get();
+    let (r, r_scalar) = rhs.get();
+    downcast_integer! {
+        l.data_type(), r.data_type() => (integer_helper, op, l, l_scalar, r, r_scalar),
+        (Float16, Float16) if op.is_float() => {
+            float_binary::<Float16Type>(op, l, l_scalar, r, r_scalar)
+        },
+        (Float32, Float32) if op.is_float() => {
+            float_binary::<Float32Type>(op, l, l_scalar, r, r_scalar)
+        },
+        (Float64, Float64) if op.is_float() => {
+            float_binary::<Float64Type>(op, l, l_scalar, r, r_scalar)
+        },
+        (Decimal32(_, _), Decimal32(_, _))
+        | (Decimal64(_, _), Decimal64(_, _))
+        | (Decimal128(_, _), Decimal128(_, _))
+        | (Decimal256(_, _), Decimal256(_, _)) if op.is_float() => {
+            let l = to_float64(l).unwrap();
+            let r = to_float64(r).unwrap();
+            float_binary::<Float64Type>(op, &l, l_scalar, &r, r_scalar)
+        },
+        (l_t, r_t) => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid math operation: {op}({l_t}, {r_t})"
+        ))),
+    }
+}
+
+/// Perform an infallible binary operation on potentially scalar inputs
+fn binary_datum<T, F>(
+    l: &PrimitiveArray<T>,
+    l_s: bool,
+    r: &PrimitiveArray<T>,
+    r_s: bool,
+    op: F,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    Ok(match (l_s, r_s) {
+        (true, true) | (false, false) => binary(l, r, op)?,
+        (true, false) => match (l.null_count() == 0).then(|| l.value(0)) {
+            None => PrimitiveArray::new_null(r.len()),
+            Some(l) => r.unary(|r| op(l, r)),
+        },
+        (false, true) => match (r.null_count() == 0).then(|| r.value(0)) {
+            None => PrimitiveArray::new_null(l.len()),
+            Some(r) => l.unary(|l| op(l, r)),
+        },
+    })
+}
+
+/// Perform a fallible binary operation on potentially scalar inputs
+fn try_binary_datum<T, F>(
+    l: &PrimitiveArray<T>,
+    l_s: bool,
+    r: &PrimitiveArray<T>,
+    r_s: bool,
+    op: F,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native, T::Native) -> Result<T::Native, ArrowError>,
+{
+    Ok(match (l_s, r_s) {
+        (true, true) | (false, false) => try_binary(l, r, op)?,
+        (true, false) => match (l.null_count() == 0).then(|| l.value(0)) {
+            None => PrimitiveArray::new_null(r.len()),
+            Some(l) => r.try_unary(|r| op(l, r))?,
+        },
+        (false, true) => match (r.null_count() == 0).then(|| r.value(0)) {
+            None => PrimitiveArray::new_null(l.len()),
+            Some(r) => l.try_unary(|l| op(l, r))?,
+        },
+    })
+}
+
+/// Perform a binary math operation on floats
+fn float_binary<T>(
+    op: BinaryOp,
+    l: &dyn Array,
+    l_s: bool,
+    r: &dyn Array,
+    r_s: bool,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Float,
+{
+    let l = l.as_primitive::<T>();
+    let r = r.as_primitive::<T>();
+    let array = match op {
+        BinaryOp::Pow | BinaryOp::PowWrapping => binary_datum(l, l_s, r, r_s, |l, r| l.powf(r))?,
+        BinaryOp::Atan2 => binary_datum(l, l_s, r, r_s, |l, r| l.atan2(r))?,
+        BinaryOp::Gcd | BinaryOp::Lcm | BinaryOp::LcmWrapping => unreachable!(),
+    };
+    Ok(Arc::new(array))
+}
+
+/// Perform a binary math operation on integers
+fn integer_binary<T>(
+    op: BinaryOp,
+    l: &dyn Array,
+    l_s: bool,
+    r: &dyn Array,
+    r_s: bool,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: AsPrimitive<i128> + NumCast,
+    i128: AsPrimitive<T::Native>,
+{
+    if matches!(op, BinaryOp::Atan2) {
+        let l = to_float64(l).unwrap();
+        let r = to_float64(r).unwrap();
+        return float_binary::<Float64Type>(op, &l, l_s, &r, r_s);
+    }
+
+    let l = l.as_primitive::<T>();
+    let r = r.as_primitive::<T>();
+    let overflow = |l: T::Native, r: T::Native| {
+        ArrowError::ArithmeticOverflow(format!("Overflow happened on: {op}({l:?}, {r:?})"))
+    };
+    let exponent = |r: T::Native| {
+        ArrowNativeType::to_usize(r)
+            .and_then(|r| u32::try_from(r).ok())
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!("Invalid exponent for integer pow: {r:?}"))
+            })
+    };
+
+    let array = match op {
+        BinaryOp::Pow => try_binary_datum(l, l_s, r, r_s, |l, r| l.pow_checked(exponent(r)?))?,
+        BinaryOp::PowWrapping => {
+            try_binary_datum(l, l_s, r, r_s, |l, r| Ok(l.pow_wrapping(exponent(r)?)))?
+        }
+        BinaryOp::Gcd => try_binary_datum(l, l_s, r, r_s, |l, r| {
+            let g = gcd_u128(l.as_().unsigned_abs(), r.as_().unsigned_abs());
+            <T::Native as NumCast>::from(g).ok_or_else(|| overflow(l, r))
+        })?,
+        BinaryOp::Lcm => try_binary_datum(l, l_s, r, r_s, |l, r| {
+            let v = lcm_u128(l.as_().unsigned_abs(), r.as_().unsigned_abs());
+            v.and_then(<T::Native as NumCast>::from)
+                .ok_or_else(|| overflow(l, r))
+        })?,
+        BinaryOp::LcmWrapping => binary_datum(l, l_s, r, r_s, |l, r| {
+            let (l, r) = (l.as_().unsigned_abs(), r.as_().unsigned_abs());
+            let v = match l == 0 || r == 0 {
+                true => 0,
+                false => (l / gcd_u128(l, r)).wrapping_mul(r),
+            };
+            (v as i128).as_()
+        })?,
+        BinaryOp::Atan2 => unreachable!(),
+    };
+    Ok(Arc::new(array))
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn lcm_u128(a: u128, b: u128) -> Option<u128> {
+    match a == 0 || b == 0 {
+        true => Some(0),
+        false => (a / gcd_u128(a, b)).checked_mul(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::i256;
+
+    type F16 = <Float16Type as ArrowPrimitiveType>::Native;
+
+    #[test]
+    fn test_float_functions() {
+        let a = Int32Array::from(vec![Some(4), None, Some(-1), Some(0)]);
+        let r = sqrt(&a).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.value(0), 2.0);
+        assert!(r.is_null(1));
+        assert!(r.value(2).is_nan());
+        assert_eq!(r.value(3), 0.0);
+
+        let a = Float32Array::from(vec![1.0, 100.0]);
+        let r = log10(&a).unwrap();
+        assert_eq!(r.as_primitive::<Float32Type>().values(), &[0.0, 2.0]);
+
+        let a = Float16Array::from(vec![F16::from_f32(0.0), F16::from_f32(4.0)]);
+        let r = sqrt(&a).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float16Type>().values(),
+            &[F16::from_f32(0.0), F16::from_f32(2.0)]
+        );
+
+        let a = Decimal128Array::from(vec![225, 400])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let r = sqrt(&a).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>().values(), &[1.5, 2.0]);
+
+        let a = Float64Array::from(vec![0.0]);
+        assert_eq!(exp(&a).unwrap().as_primitive::<Float64Type>().value(0), 1.0);
+        assert_eq!(cos(&a).unwrap().as_primitive::<Float64Type>().value(0), 1.0);
+
+        let err = ln(&StringArray::from(vec!["1"])).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Invalid math operation: ln(Utf8)"
+        );
+    }
+
+    #[test]
+    fn test_abs_and_sign() {
+        let a = Int8Array::from(vec![Some(-5), None, Some(3), Some(i8::MIN)]);
+        let err = abs(&a).unwrap_err().to_string();
+        assert!(err.contains("Overflow happened on: - -128"), "{err}");
+
+        let r = abs_wrapping(&a).unwrap();
+        let expected = Int8Array::from(vec![Some(5), None, Some(3), Some(i8::MIN)]);
+        assert_eq!(r.as_primitive::<Int8Type>(), &expected);
+
+        let r = sign(&a).unwrap();
+        let expected = Int8Array::from(vec![Some(-1), None, Some(1), Some(-1)]);
+        assert_eq!(r.as_primitive::<Int8Type>(), &expected);
+
+        let a = Float64Array::from(vec![-2.5, -0.0, f64::NAN, 3.0]);
+        let r = sign(&a).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.value(0), -1.0);
+        assert!(r.value(1) == 0.0 && r.value(1).is_sign_negative());
+        assert!(r.value(2).is_nan());
+        assert_eq!(r.value(3), 1.0);
+
+        let a = Decimal128Array::from(vec![-150, 0, 25])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let r = abs(&a).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(5, 2));
+        assert_eq!(r.as_primitive::<Decimal128Type>().values(), &[150, 0, 25]);
+
+        let r = sign(&a).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(5, 0));
+        assert_eq!(r.as_primitive::<Decimal128Type>().values(), &[-1, 0, 1]);
+    }
+
+    #[test]
+    fn test_round_float() {
+        let a = Float64Array::from(vec![Some(1.25), Some(-1.25), None, Some(1250.0)]);
+        let r = round(&a, 1).unwrap();
+        let r = r.as_primitive::<Float64Type>();
+        assert_eq!(r.value(0), 1.3);
+        assert_eq!(r.value(1), -1.3);
+        assert!(r.is_null(2));
+        assert_eq!(r.value(3), 1250.0);
+
+        let r = round(&a, -2).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>().value(3), 1300.0);
+
+        let r = trunc(&a, 1).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>().value(1), -1.2);
+
+        let r = ceil(&a).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>().value(1), -1.0);
+        let r = floor(&a).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>().value(1), -2.0);
+
+        // Factors that cannot be represented
+        let a = Float32Array::from(vec![1.5, -1.5]);
+        let r = round(&a, 100).unwrap();
+        assert_eq!(r.as_primitive::<Float32Type>().values(), &[1.5, -1.5]);
+        let r = round(&a, -100).unwrap();
+        assert_eq!(r.as_primitive::<Float32Type>().values(), &[0.0, -0.0]);
+
+        // Scaled values that overflow are returned unchanged
+        let a = Float16Array::from(vec![F16::from_f32(100.0), F16::from_f32(-100.0)]);
+        let r = round(&a, 3).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float16Type>().values(),
+            &[F16::from_f32(100.0), F16::from_f32(-100.0)]
+        );
+        let a = Float32Array::from(vec![1e30, -1e30]);
+        let r = round(&a, 10).unwrap();
+        assert_eq!(r.as_primitive::<Float32Type>().values(), &[1e30, -1e30]);
+        let a = Float64Array::from(vec![1e300, -1e300]);
+        let r = trunc(&a, 100).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>().values(), &[1e300, -1e300]);
+        let r = round(&a, 100).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>().values(), &[1e300, -1e300]);
+    }
+
+    #[test]
+    fn test_round_integer() {
+        let a = Int32Array::from(vec![Some(1249), Some(1250), Some(-1250), None]);
+        let r = round(&a, 2).unwrap();
+        assert_eq!(r.as_primitive::<Int32Type>(), &a);
+
+        let r = round(&a, -2).unwrap();
+        let expected = Int32Array::from(vec![Some(1200), Some(1300), Some(-1300), None]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let r = trunc(&a, -2).unwrap();
+        let expected = Int32Array::from(vec![Some(1200), Some(1200), Some(-1200), None]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let a = UInt8Array::from(vec![249, 255]);
+        let err = round(&a, -1).unwrap_err().to_string();
+        assert!(err.contains("Overflow happened on: 250 + 10"), "{err}");
+        let r = trunc(&a, -1).unwrap();
+        assert_eq!(r.as_primitive::<UInt8Type>().values(), &[240, 250]);
+
+        // 10 ^ 3 does not fit in an i8
+        let a = Int8Array::from(vec![127, -100]);
+        let r = round(&a, -3).unwrap();
+        assert_eq!(r.as_primitive::<Int8Type>().values(), &[0, 0]);
+    }
+
+    #[test]
+    fn test_round_decimal() {
+        let a = Decimal128Array::from(vec![Some(1235), Some(-1235), Some(1234), None])
+            .with_precision_and_scale(6, 3)
+            .unwrap();
+        let r = round(&a, 2).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(6, 3));
+        let expected = Decimal128Array::from(vec![Some(1240), Some(-1240), Some(1230), None])
+            .with_precision_and_scale(6, 3)
+            .unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>(), &expected);
+
+        let r = trunc(&a, 0).unwrap();
+        let values = r.as_primitive::<Decimal128Type>().values();
+        assert_eq!(&values[..3], &[1000, -1000, 1000]);
+
+        let r = ceil(&a).unwrap();
+        let values = r.as_primitive::<Decimal128Type>().values();
+        assert_eq!(&values[..3], &[2000, -1000, 2000]);
+
+        let r = floor(&a).unwrap();
+        let values = r.as_primitive::<Decimal128Type>().values();
+        assert_eq!(&values[..3], &[1000, -2000, 1000]);
+
+        // More digits than the scale is a no-op
+        let r = round(&a, 5).unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>(), &a);
+
+        // Rounding beyond the precision
+        let a = Decimal32Array::from(vec![999])
+            .with_precision_and_scale(3, 1)
+            .unwrap();
+        let err = round(&a, 0).unwrap_err().to_string();
+        assert!(
+            err.contains("too large to store in a Decimal32 of precision 3"),
+            "{err}"
+        );
+
+        let a = Decimal256Array::from(vec![i256::from_i128(-15)])
+            .with_precision_and_scale(10, 1)
+            .unwrap();
+        let r = round(&a, 0).unwrap();
+        assert_eq!(
+            r.as_primitive::<Decimal256Type>().value(0),
+            i256::from_i128(-20)
+        );
+    }
+
+    #[test]
+    fn test_pow() {
+        let base = Int32Array::from(vec![Some(2), Some(-3), None, Some(2)]);
+        let exponent = Int32Array::from(vec![Some(10), Some(3), Some(1), Some(31)]);
+        let err = pow(&base, &exponent).unwrap_err().to_string();
+        assert!(err.contains("Overflow happened on: 2 ^ 31"), "{err}");
+
+        let r = pow_wrapping(&base, &exponent).unwrap();
+        let expected = Int32Array::from(vec![Some(1024), Some(-27), None, Some(i32::MIN)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let r = pow(&base, &Int32Array::new_scalar(2)).unwrap();
+        let expected = Int32Array::from(vec![Some(4), Some(9), None, Some(4)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let r = pow(&base, &Scalar::new(Int32Array::new_null(1))).unwrap();
+        assert_eq!(r.null_count(), 4);
+
+        let err = pow(&base, &Int32Array::new_scalar(-1))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Invalid exponent for integer pow: -1"
+        );
+
+        let base = Float64Array::from(vec![4.0, 2.0]);
+        let r = pow(&base, &Float64Array::new_scalar(0.5)).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float64Type>().values(),
+            &[2.0, 2.0_f64.sqrt()]
+        );
+
+        let base = Decimal128Array::from(vec![250])
+            .with_precision_and_scale(5, 2)
+            .unwrap();
+        let exponent = Decimal128Array::from(vec![20])
+            .with_precision_and_scale(3, 1)
+            .unwrap();
+        let r = pow(&base, &exponent).unwrap();
+        assert_eq!(r.as_primitive::<Float64Type>().values(), &[6.25]);
+
+        let err = pow(&base, &Float64Array::new_scalar(2.0))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Invalid math operation: pow(Decimal128(5, 2), Float64)"
+        );
+    }
+
+    #[test]
+    fn test_atan2() {
+        let y = Float64Array::from(vec![1.0, -1.0]);
+        let x = Float64Array::from(vec![1.0, 0.0]);
+        let r = atan2(&y, &x).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float64Type>().values(),
+            &[std::f64::consts::FRAC_PI_4, -std::f64::consts::FRAC_PI_2]
+        );
+
+        let y = Int64Array::from(vec![0]);
+        let r = atan2(&y, &Int64Array::new_scalar(-1)).unwrap();
+        assert_eq!(
+            r.as_primitive::<Float64Type>().values(),
+            &[std::f64::consts::PI]
+        );
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        let a = Int32Array::from(vec![Some(12), Some(-12), Some(0), None, Some(7)]);
+        let b = Int32Array::from(vec![Some(18), Some(8), Some(0), Some(1), Some(0)]);
+        let r = gcd(&a, &b).unwrap();
+        let expected = Int32Array::from(vec![Some(6), Some(4), Some(0), None, Some(7)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let r = lcm(&a, &b).unwrap();
+        let expected = Int32Array::from(vec![Some(36), Some(24), Some(0), None, Some(0)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let r = gcd(&Int32Array::new_scalar(9), &b).unwrap();
+        let expected = Int32Array::from(vec![9, 1, 9, 1, 9]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        // |i8::MIN| does not fit in an i8
+        let a = Int8Array::from(vec![i8::MIN]);
+        let err = gcd(&a, &a).unwrap_err().to_string();
+        assert!(
+            err.contains("Overflow happened on: gcd(-128, -128)"),
+            "{err}"
+        );
+
+        let a = UInt8Array::from(vec![16]);
+        let b = UInt8Array::from(vec![17]);
+        let err = lcm(&a, &b).unwrap_err().to_string();
+        assert!(err.contains("Overflow happened on: lcm(16, 17)"), "{err}");
+        let r = lcm_wrapping(&a, &b).unwrap();
+        assert_eq!(r.as_primitive::<UInt8Type>().values(), &[(16 * 17) as u8]);
+
+        let a = Float64Array::from(vec![1.0]);
+        let err = gcd(&a, &a).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Invalid math operation: gcd(Float64, Float64)"
+        );
+    }
+}
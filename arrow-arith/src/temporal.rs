@@ -35,6 +35,12 @@ use arrow_array::types::*;
 use arrow_array::*;
 use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
 
+mod transform;
+
+pub use transform::{
+    DstPolicy, TimeZoneMode, add_months, at_time_zone, date_bin, date_trunc, last_day,
+};
+
 /// Valid parts to extract from date/time/timestamp arrays.
 ///
 /// See [`date_part`].
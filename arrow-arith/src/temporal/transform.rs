@@ -0,0 +1,706 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels to truncate, bin and shift temporal values, and to convert them between timezones
+//!
+//! Timestamps with a timezone are transformed in the local time of their timezone, such that
+//! truncating to a day returns local midnight.
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::temporal_conversions::{as_datetime, as_datetime_with_timezone};
+use arrow_array::timezone::Tz;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::IntervalMonthDayNano;
+use arrow_schema::{ArrowError, DataType, TimeUnit};
+use chrono::{
+    Datelike, Days, LocalResult, Months, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta,
+    TimeZone, Timelike, Weekday,
+};
+
+use super::DatePart;
+
+const NANOSECONDS_IN_SECOND: i128 = 1_000_000_000;
+const NANOSECONDS_IN_DAY: i128 = 86_400 * NANOSECONDS_IN_SECOND;
+
+/// How to resolve local times that occur twice, or not at all, in a timezone due to a
+/// daylight saving time transition
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DstPolicy {
+    /// Resolve ambiguous times to their earlier instant, and shift nonexistent times
+    /// forward by the length of the transition
+    #[default]
+    Earlier,
+    /// Resolve ambiguous times to their later instant, and shift nonexistent times
+    /// forward by the length of the transition
+    Later,
+    /// Return null for ambiguous and nonexistent times
+    Null,
+    /// Return an error for ambiguous and nonexistent times
+    Error,
+}
+
+/// How [`at_time_zone`] changes the timezone of timestamps
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeZoneMode {
+    /// Keep the instant of each timestamp, changing only the timezone it is displayed in.
+    /// Timestamps without a timezone are interpreted as UTC.
+    #[default]
+    Convert,
+    /// Keep the local date and time of each timestamp, reinterpreting it as a local time
+    /// in the new timezone
+    Reinterpret,
+}
+
+/// Returns the UTC date and time of the `local` time in `tz`, named `name`, resolving
+/// daylight saving time transitions with `policy`
+fn local_to_utc(
+    tz: &Tz,
+    name: &str,
+    local: NaiveDateTime,
+    policy: DstPolicy,
+) -> Result<Option<NaiveDateTime>, ArrowError> {
+    let (kind, utc) = match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => return Ok(Some(dt.naive_utc())),
+        LocalResult::Ambiguous(earlier, later) => match policy {
+            DstPolicy::Later => ("ambiguous", Some(later.naive_utc())),
+            _ => ("ambiguous", Some(earlier.naive_utc())),
+        },
+        LocalResult::None => {
+            // Interpret the time with the offset in effect before the transition
+            let before = local
+                .checked_sub_signed(TimeDelta::days(1))
+                .map(|d| tz.offset_from_utc_datetime(&d).fix());
+            (
+                "nonexistent",
+                before.and_then(|o| local.checked_sub_offset(o)),
+            )
+        }
+    };
+    match policy {
+        DstPolicy::Earlier | DstPolicy::Later => Ok(utc),
+        DstPolicy::Null => Ok(None),
+        DstPolicy::Error => Err(ArrowError::ComputeError(format!(
+            "Local time {local} is {kind} in timezone {name}"
+        ))),
+    }
+}
+
+/// How [`map_temporal`] converts the result of an operation on the local time of a
+/// timestamp with a timezone back to UTC
+#[derive(Debug, Clone, Copy)]
+enum Frame {
+    /// Resolve the result in the timezone, with a [`DstPolicy`]
+    Local(DstPolicy),
+    /// Use the UTC offset of the original timestamp, for operations that change a
+    /// timestamp by less than a day. Results on the other side of a daylight saving
+    /// time transition are resolved in the timezone with [`DstPolicy::Earlier`]
+    Offset,
+}
+
+/// Applies `op` to the local date and time of each value of a date or timestamp `array`
+fn map_temporal<F>(
+    array: &dyn Array,
+    name: &str,
+    frame: Frame,
+    op: F,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: Fn(NaiveDateTime) -> Option<NaiveDateTime>,
+{
+    match array.data_type() {
+        DataType::Dictionary(_, _) => {
+            let array = array.as_any_dictionary();
+            let values = map_values(array.values().as_ref(), name, frame, &op)?;
+            Ok(array.with_values(values))
+        }
+        DataType::RunEndEncoded(_, _) => {
+            let array = array.as_any_ree();
+            let values = map_values(array.values().as_ref(), name, frame, &op)?;
+            Ok(array.with_values(values))
+        }
+        _ => map_values(array, name, frame, &op),
+    }
+}
+
+fn map_values<F>(
+    array: &dyn Array,
+    name: &str,
+    frame: Frame,
+    op: &F,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: Fn(NaiveDateTime) -> Option<NaiveDateTime>,
+{
+    match array.data_type() {
+        DataType::Date32 => {
+            let array: Date32Array = array.as_primitive::<Date32Type>().unary_opt(|v| {
+                let date = op(midnight(Date32Type::to_naive_date_opt(v)?))?;
+                Some(Date32Type::from_naive_date(date.date()))
+            });
+            Ok(Arc::new(array))
+        }
+        DataType::Date64 => {
+            let array: Date64Array = array.as_primitive::<Date64Type>().unary_opt(|v| {
+                let date = op(as_datetime::<Date64Type>(v)?)?;
+                Some(Date64Type::from_naive_date(date.date()))
+            });
+            Ok(Arc::new(array))
+        }
+        DataType::Timestamp(TimeUnit::Second, tz) => {
+            map_timestamps::<TimestampSecondType, _>(array.as_primitive(), tz, frame, op)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, tz) => {
+            map_timestamps::<TimestampMillisecondType, _>(array.as_primitive(), tz, frame, op)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, tz) => {
+            map_timestamps::<TimestampMicrosecondType, _>(array.as_primitive(), tz, frame, op)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
+            map_timestamps::<TimestampNanosecondType, _>(array.as_primitive(), tz, frame, op)
+        }
+        t => Err(ArrowError::ComputeError(format!(
+            "{name} does not support type {t}"
+        ))),
+    }
+}
+
+fn map_timestamps<T, F>(
+    array: &PrimitiveArray<T>,
+    tz: &Option<Arc<str>>,
+    frame: Frame,
+    op: &F,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowTimestampType,
+    F: Fn(NaiveDateTime) -> Option<NaiveDateTime>,
+{
+    let Some(name) = tz else {
+        let array: PrimitiveArray<T> =
+            array.unary_opt(|v| T::from_naive_datetime(op(as_datetime::<T>(v)?)?, None));
+        return Ok(Arc::new(array));
+    };
+
+    let tz: Tz = name.parse()?;
+    let array = array
+        .iter()
+        .map(|v| {
+            let Some(local) = v.and_then(|v| as_datetime_with_timezone::<T>(v, tz)) else {
+                return Ok(None);
+            };
+            let Some(result) = op(local.naive_local()) else {
+                return Ok(None);
+            };
+            let utc = match frame {
+                Frame::Local(policy) => local_to_utc(&tz, name, result, policy)?,
+                Frame::Offset => {
+                    let utc = result
+                        .checked_sub_offset(local.offset().fix())
+                        .filter(|utc| tz.from_utc_datetime(utc).naive_local() == result);
+                    match utc {
+                        Some(utc) => Some(utc),
+                        None => local_to_utc(&tz, name, result, DstPolicy::default())?,
+                    }
+                }
+            };
+            Ok(utc.and_then(|d| T::from_naive_datetime(d, None)))
+        })
+        .collect::<Result<PrimitiveArray<T>, ArrowError>>()?;
+    Ok(Arc::new(array.with_timezone(name.clone())))
+}
+
+fn midnight(date: NaiveDate) -> NaiveDateTime {
+    date.and_time(NaiveTime::MIN)
+}
+
+fn shift_months(datetime: NaiveDateTime, months: i32) -> Option<NaiveDateTime> {
+    match months >= 0 {
+        true => datetime.checked_add_months(Months::new(months as u32)),
+        false => datetime.checked_sub_months(Months::new(months.unsigned_abs())),
+    }
+}
+
+/// Returns `array` with each value truncated to the start of its `unit`
+///
+/// Supports [`DatePart::Year`], [`DatePart::YearISO`], [`DatePart::Quarter`],
+/// [`DatePart::Month`], [`DatePart::Week`] and [`DatePart::WeekISO`] (starting on Monday),
+/// [`DatePart::Day`], [`DatePart::Hour`], [`DatePart::Minute`], [`DatePart::Second`],
+/// [`DatePart::Millisecond`], [`DatePart::Microsecond`] and [`DatePart::Nanosecond`].
+///
+/// Supports `Date32`, `Date64` and `Timestamp` arrays, or dictionary and run-end encoded
+/// arrays of them, returning an array of the same type. Timestamps with a timezone are
+/// truncated in their local time, with local times made ambiguous or nonexistent by
+/// daylight saving time resolved with [`DstPolicy::Earlier`].
+///
+/// ```
+/// # use arrow_array::TimestampSecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::TimestampSecondType;
+/// # use arrow_arith::temporal::{date_trunc, DatePart};
+/// // 2024-05-17T13:45:30Z
+/// let array = TimestampSecondArray::from(vec![Some(1715953530), None]);
+/// let result = date_trunc(&array, DatePart::Month).unwrap();
+/// // 2024-05-01T00:00:00Z
+/// let expected = TimestampSecondArray::from(vec![Some(1714521600), None]);
+/// assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+///
+/// let array = array.with_timezone("+10:00");
+/// let result = date_trunc(&array, DatePart::Day).unwrap();
+/// // 2024-05-17T00:00:00+10:00
+/// let expected = TimestampSecondArray::from(vec![Some(1715868000), None]);
+/// assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected.with_timezone("+10:00"));
+/// ```
+pub fn date_trunc(array: &dyn Array, unit: DatePart) -> Result<ArrayRef, ArrowError> {
+    let op: fn(NaiveDateTime) -> Option<NaiveDateTime> = match unit {
+        DatePart::Year => |d| NaiveDate::from_ymd_opt(d.year(), 1, 1).map(midnight),
+        DatePart::YearISO => {
+            |d| NaiveDate::from_isoywd_opt(d.iso_week().year(), 1, Weekday::Mon).map(midnight)
+        }
+        DatePart::Quarter => {
+            |d| NaiveDate::from_ymd_opt(d.year(), d.month0() / 3 * 3 + 1, 1).map(midnight)
+        }
+        DatePart::Month => |d| NaiveDate::from_ymd_opt(d.year(), d.month(), 1).map(midnight),
+        DatePart::Week | DatePart::WeekISO => |d| {
+            let days = Days::new(d.weekday().num_days_from_monday() as u64);
+            d.date().checked_sub_days(days).map(midnight)
+        },
+        DatePart::Day => |d| Some(midnight(d.date())),
+        DatePart::Hour => |d| d.with_nanosecond(0)?.with_second(0)?.with_minute(0),
+        DatePart::Minute => |d| d.with_nanosecond(0)?.with_second(0),
+        DatePart::Second => |d| d.with_nanosecond(0),
+        DatePart::Millisecond => |d| d.with_nanosecond(d.nanosecond() / 1_000_000 * 1_000_000),
+        DatePart::Microsecond => |d| d.with_nanosecond(d.nanosecond() / 1_000 * 1_000),
+        DatePart::Nanosecond => Some,
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "date_trunc does not support {unit}"
+            )));
+        }
+    };
+    let frame = match unit {
+        DatePart::Hour
+        | DatePart::Minute
+        | DatePart::Second
+        | DatePart::Millisecond
+        | DatePart::Microsecond
+        | DatePart::Nanosecond => Frame::Offset,
+        _ => Frame::Local(DstPolicy::default()),
+    };
+    map_temporal(array, "date_trunc", frame, op)
+}
+
+/// Returns `array` with each value moved to the start of the bin of width `stride`,
+/// aligned to `origin`, that contains it
+///
+/// `stride` must either be a positive number of months, or a positive number of days
+/// and nanoseconds, where a day is 24 hours. Month strides bin by calendar months, such
+/// that bins start on the day of the month of `origin`, or the last day of shorter months.
+///
+/// Supports `Date32`, `Date64` and `Timestamp` arrays, or dictionary and run-end encoded
+/// arrays of them, returning an array of the same type. Timestamps with a timezone are
+/// binned in their local time, with `origin` interpreted as a local time. Bin starts made
+/// ambiguous or nonexistent by daylight saving time are resolved with
+/// [`DstPolicy::Earlier`], as in [`date_trunc`], except that strides shorter than a day
+/// keep the UTC offset of the value where it is valid for the bin start.
+///
+/// ```
+/// # use arrow_array::TimestampSecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::{IntervalMonthDayNanoType, TimestampSecondType};
+/// # use arrow_arith::temporal::date_bin;
+/// # use chrono::NaiveDate;
+/// // 2024-05-17T13:45:30Z
+/// let array = TimestampSecondArray::from(vec![1715953530]);
+/// let origin = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 5, 0).unwrap();
+/// let stride = IntervalMonthDayNanoType::make_value(0, 0, 15 * 60 * 1_000_000_000);
+/// let result = date_bin(&array, stride, origin).unwrap();
+/// // 2024-05-17T13:35:00Z
+/// let expected = TimestampSecondArray::from(vec![1715952900]);
+/// assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+/// ```
+pub fn date_bin(
+    array: &dyn Array,
+    stride: IntervalMonthDayNano,
+    origin: NaiveDateTime,
+) -> Result<ArrayRef, ArrowError> {
+    match (stride.months, stride.days, stride.nanoseconds) {
+        (months, 0, 0) if months > 0 => {
+            let frame = Frame::Local(DstPolicy::default());
+            map_temporal(array, "date_bin", frame, |d| {
+                let elapsed =
+                    (d.year() - origin.year()) * 12 + d.month() as i32 - origin.month() as i32;
+                let start = elapsed.div_euclid(months) * months;
+                let bin = shift_months(origin, start)?;
+                match bin > d {
+                    true => shift_months(origin, start - months),
+                    false => Some(bin),
+                }
+            })
+        }
+        (0, days, nanoseconds)
+            if days >= 0 && nanoseconds >= 0 && (days, nanoseconds) != (0, 0) =>
+        {
+            let stride = days as i128 * NANOSECONDS_IN_DAY + nanoseconds as i128;
+            let frame = match stride >= NANOSECONDS_IN_DAY {
+                true => Frame::Local(DstPolicy::default()),
+                false => Frame::Offset,
+            };
+            map_temporal(array, "date_bin", frame, |d| {
+                let elapsed = d.signed_duration_since(origin);
+                let elapsed = elapsed.num_seconds() as i128 * NANOSECONDS_IN_SECOND
+                    + elapsed.subsec_nanos() as i128;
+                let start = elapsed.div_euclid(stride) * stride;
+                let start = TimeDelta::new(
+                    start.div_euclid(NANOSECONDS_IN_SECOND).try_into().ok()?,
+                    start.rem_euclid(NANOSECONDS_IN_SECOND) as u32,
+                )?;
+                origin.checked_add_signed(start)
+            })
+        }
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "date_bin stride must be a positive number of months, or of days and \
+            nanoseconds, got {stride:?}"
+        ))),
+    }
+}
+
+/// Returns `array` with `months` calendar months added to each value
+///
+/// If the resulting month is shorter than the day of the month of a value, the value is
+/// moved to the last day of the month. `months` may be negative.
+///
+/// Supports `Date32`, `Date64` and `Timestamp` arrays, or dictionary and run-end encoded
+/// arrays of them, returning an array of the same type. Timestamps with a timezone keep
+/// their local time of day, with local times made ambiguous or nonexistent by daylight
+/// saving time resolved with [`DstPolicy::Earlier`].
+///
+/// ```
+/// # use arrow_array::Date32Array;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Date32Type;
+/// # use arrow_arith::temporal::add_months;
+/// # use chrono::NaiveDate;
+/// let date = |y, m, d| Date32Type::from_naive_date(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+/// let array = Date32Array::from(vec![date(2024, 1, 31), date(2024, 3, 15)]);
+/// let result = add_months(&array, 1).unwrap();
+/// let expected = Date32Array::from(vec![date(2024, 2, 29), date(2024, 4, 15)]);
+/// assert_eq!(result.as_primitive::<Date32Type>(), &expected);
+/// ```
+pub fn add_months(array: &dyn Array, months: i32) -> Result<ArrayRef, ArrowError> {
+    let frame = Frame::Local(DstPolicy::default());
+    map_temporal(array, "add_months", frame, |d| shift_months(d, months))
+}
+
+/// Returns `array` with each value moved to the start of the last day of its month
+///
+/// Supports `Date32`, `Date64` and `Timestamp` arrays, or dictionary and run-end encoded
+/// arrays of them, returning an array of the same type. Timestamps with a timezone are
+/// moved to local midnight, resolved with [`DstPolicy::Earlier`] if ambiguous or
+/// nonexistent.
+///
+/// ```
+/// # use arrow_array::Date32Array;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Date32Type;
+/// # use arrow_arith::temporal::last_day;
+/// # use chrono::NaiveDate;
+/// let date = |y, m, d| Date32Type::from_naive_date(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+/// let array = Date32Array::from(vec![Some(date(2024, 2, 10)), None, Some(date(2023, 12, 31))]);
+/// let result = last_day(&array).unwrap();
+/// let expected = Date32Array::from(vec![Some(date(2024, 2, 29)), None, Some(date(2023, 12, 31))]);
+/// assert_eq!(result.as_primitive::<Date32Type>(), &expected);
+/// ```
+pub fn last_day(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    let frame = Frame::Local(DstPolicy::default());
+    map_temporal(array, "last_day", frame, |d| {
+        let first = NaiveDate::from_ymd_opt(d.year(), d.month(), 1)?;
+        let last = first.checked_add_months(Months::new(1))?.pred_opt()?;
+        Some(midnight(last))
+    })
+}
+
+/// Returns the timestamps of `array` in the timezone `tz`, or without a timezone if `tz`
+/// is `None`
+///
+/// With [`TimeZoneMode::Convert`] the values of `array` are unchanged, only its type. With
+/// [`TimeZoneMode::Reinterpret`] the local time of each value is kept, resolving local
+/// times made ambiguous or nonexistent in `tz` by daylight saving time with `policy`.
+///
+/// Supports `Timestamp` arrays, or dictionary and run-end encoded arrays of them.
+///
+/// ```
+/// # use arrow_array::TimestampSecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::TimestampSecondType;
+/// # use arrow_arith::temporal::{at_time_zone, DstPolicy, TimeZoneMode};
+/// // 2024-05-17T12:00:00
+/// let array = TimestampSecondArray::from(vec![1715947200]);
+///
+/// // 2024-05-17T12:00:00Z is 2024-05-17T14:00:00+02:00
+/// let policy = DstPolicy::Earlier;
+/// let result = at_time_zone(&array, Some("+02:00"), TimeZoneMode::Convert, policy);
+/// let expected = TimestampSecondArray::from(vec![1715947200]).with_timezone("+02:00");
+/// assert_eq!(result.unwrap().as_primitive::<TimestampSecondType>(), &expected);
+///
+/// // 2024-05-17T12:00:00+02:00 is 2024-05-17T10:00:00Z
+/// let result = at_time_zone(&array, Some("+02:00"), TimeZoneMode::Reinterpret, policy);
+/// let expected = TimestampSecondArray::from(vec![1715940000]).with_timezone("+02:00");
+/// assert_eq!(result.unwrap().as_primitive::<TimestampSecondType>(), &expected);
+/// ```
+pub fn at_time_zone(
+    array: &dyn Array,
+    tz: Option<&str>,
+    mode: TimeZoneMode,
+    policy: DstPolicy,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Dictionary(_, _) => {
+            let array = array.as_any_dictionary();
+            let values = at_time_zone(array.values().as_ref(), tz, mode, policy)?;
+            Ok(array.with_values(values))
+        }
+        DataType::RunEndEncoded(_, _) => {
+            let array = array.as_any_ree();
+            let values = at_time_zone(array.values().as_ref(), tz, mode, policy)?;
+            Ok(array.with_values(values))
+        }
+        DataType::Timestamp(TimeUnit::Second, from) => {
+            change_tz::<TimestampSecondType>(array.as_primitive(), from, tz, mode, policy)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, from) => {
+            change_tz::<TimestampMillisecondType>(array.as_primitive(), from, tz, mode, policy)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, from) => {
+            change_tz::<TimestampMicrosecondType>(array.as_primitive(), from, tz, mode, policy)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, from) => {
+            change_tz::<TimestampNanosecondType>(array.as_primitive(), from, tz, mode, policy)
+        }
+        t => Err(ArrowError::ComputeError(format!(
+            "at_time_zone does not support type {t}"
+        ))),
+    }
+}
+
+fn change_tz<T: ArrowTimestampType>(
+    array: &PrimitiveArray<T>,
+    from: &Option<Arc<str>>,
+    to: Option<&str>,
+    mode: TimeZoneMode,
+    policy: DstPolicy,
+) -> Result<ArrayRef, ArrowError> {
+    let to_tz = to
+        .map(|name| name.parse::<Tz>().map(|tz| (tz, name)))
+        .transpose()?;
+    if mode == TimeZoneMode::Convert {
+        return Ok(Arc::new(array.clone().with_timezone_opt(to)));
+    }
+
+    let from_tz = from.as_deref().map(str::parse::<Tz>).transpose()?;
+    let array = array
+        .iter()
+        .map(|v| {
+            let local = match from_tz {
+                Some(tz) => v
+                    .and_then(|v| as_datetime_with_timezone::<T>(v, tz))
+                    .map(|d| d.naive_local()),
+                None => v.and_then(as_datetime::<T>),
+            };
+            let Some(local) = local else {
+                return Ok(None);
+            };
+            let utc = match &to_tz {
+                Some((tz, name)) => local_to_utc(tz, name, local, policy)?,
+                None => Some(local),
+            };
+            Ok(utc.and_then(|d| T::from_naive_datetime(d, None)))
+        })
+        .collect::<Result<PrimitiveArray<T>, ArrowError>>()?;
+    Ok(Arc::new(array.with_timezone_opt(to)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(s: &str) -> NaiveDateTime {
+        s.parse().unwrap()
+    }
+
+    fn timestamps(values: &[&str], tz: Option<&str>) -> TimestampMicrosecondArray {
+        let values: Vec<_> = values
+            .iter()
+            .map(|v| TimestampMicrosecondType::from_naive_datetime(datetime(v), None).unwrap())
+            .collect();
+        TimestampMicrosecondArray::from(values).with_timezone_opt(tz)
+    }
+
+    #[test]
+    fn test_date_trunc() {
+        let array = timestamps(&["2024-05-17T13:45:30.123456", "2021-01-01T00:00:00"], None);
+        let cases = [
+            (
+                DatePart::Year,
+                ["2024-01-01T00:00:00", "2021-01-01T00:00:00"],
+            ),
+            (
+                DatePart::YearISO,
+                ["2024-01-01T00:00:00", "2019-12-30T00:00:00"],
+            ),
+            (
+                DatePart::Quarter,
+                ["2024-04-01T00:00:00", "2021-01-01T00:00:00"],
+            ),
+            (
+                DatePart::Week,
+                ["2024-05-13T00:00:00", "2020-12-28T00:00:00"],
+            ),
+            (
+                DatePart::Hour,
+                ["2024-05-17T13:00:00", "2021-01-01T00:00:00"],
+            ),
+            (
+                DatePart::Millisecond,
+                ["2024-05-17T13:45:30.123", "2021-01-01T00:00:00"],
+            ),
+        ];
+        for (unit, expected) in cases {
+            let result = date_trunc(&array, unit).unwrap();
+            assert_eq!(
+                result.as_primitive::<TimestampMicrosecondType>(),
+                &timestamps(&expected, None),
+                "{unit}"
+            );
+        }
+
+        let err = date_trunc(&array, DatePart::DayOfYear).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: date_trunc does not support DayOfYear"
+        );
+        let err = date_trunc(&Int32Array::from(vec![1]), DatePart::Day).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: date_trunc does not support type Int32"
+        );
+    }
+
+    #[test]
+    fn test_dates() {
+        let date = |s: &str| Date32Type::from_naive_date(s.parse().unwrap());
+        let array = Date32Array::from(vec![Some(date("2024-05-17")), None]);
+        let result = date_trunc(&array, DatePart::Quarter).unwrap();
+        let expected = Date32Array::from(vec![Some(date("2024-04-01")), None]);
+        assert_eq!(result.as_primitive::<Date32Type>(), &expected);
+
+        let array = Date64Array::from(vec![Date64Type::from_naive_date(
+            "2024-05-31".parse().unwrap(),
+        )]);
+        let result = add_months(&array, -3).unwrap();
+        let expected = Date64Array::from(vec![Date64Type::from_naive_date(
+            "2024-02-29".parse().unwrap(),
+        )]);
+        assert_eq!(result.as_primitive::<Date64Type>(), &expected);
+
+        let stride = IntervalMonthDayNanoType::make_value(0, 7, 0);
+        let result = date_bin(&array, stride, datetime("2024-01-01T00:00:00")).unwrap();
+        let expected = Date64Array::from(vec![Date64Type::from_naive_date(
+            "2024-05-27".parse().unwrap(),
+        )]);
+        assert_eq!(result.as_primitive::<Date64Type>(), &expected);
+    }
+
+    #[test]
+    fn test_date_bin() {
+        let array = timestamps(
+            &[
+                "2024-05-17T13:45:30",
+                "2023-12-31T23:59:59",
+                "2024-01-31T00:00:00",
+            ],
+            None,
+        );
+        let origin = datetime("2024-01-31T00:00:00");
+        let stride = IntervalMonthDayNanoType::make_value(2, 0, 0);
+        let result = date_bin(&array, stride, origin).unwrap();
+        let expected = timestamps(
+            &[
+                "2024-03-31T00:00:00",
+                "2023-11-30T00:00:00",
+                "2024-01-31T00:00:00",
+            ],
+            None,
+        );
+        assert_eq!(result.as_primitive::<TimestampMicrosecondType>(), &expected);
+
+        let stride = IntervalMonthDayNanoType::make_value(0, 1, 3_600_000_000_000);
+        let result = date_bin(&array, stride, origin).unwrap();
+        let expected = timestamps(
+            &[
+                "2024-05-17T07:00:00",
+                "2023-12-31T19:00:00",
+                "2024-01-31T00:00:00",
+            ],
+            None,
+        );
+        assert_eq!(result.as_primitive::<TimestampMicrosecondType>(), &expected);
+
+        let stride = IntervalMonthDayNanoType::make_value(1, 1, 0);
+        let err = date_bin(&array, stride, origin).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("date_bin stride must be a positive")
+        );
+    }
+
+    #[test]
+    fn test_at_time_zone_dictionary() {
+        let values = timestamps(&["2024-05-17T12:00:00"], None);
+        let keys = Int8Array::from(vec![Some(0), None, Some(0)]);
+        let array = DictionaryArray::new(keys, Arc::new(values));
+        let result = at_time_zone(
+            &array,
+            Some("+01:00"),
+            TimeZoneMode::Convert,
+            DstPolicy::Error,
+        )
+        .unwrap();
+        let result = result.as_dictionary::<Int8Type>();
+        assert_eq!(
+            result.values().data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, Some("+01:00".into()))
+        );
+        let result = last_day(result).unwrap();
+        let values = result.as_dictionary::<Int8Type>().values();
+        let expected = timestamps(&["2024-05-30T23:00:00"], Some("+01:00"));
+        assert_eq!(values.as_primitive::<TimestampMicrosecondType>(), &expected);
+
+        let err = at_time_zone(
+            &Date32Array::from(vec![0]),
+            None,
+            TimeZoneMode::Convert,
+            DstPolicy::Error,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: at_time_zone does not support type Date32"
+        );
+    }
+}
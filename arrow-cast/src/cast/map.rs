@@ -16,6 +16,7 @@
 // under the License.
 
 use crate::cast::*;
+use arrow_buffer::ScalarBuffer;
 
 /// Helper function that takes a map container and casts the inner datatype.
 pub(crate) fn cast_map_values(
@@ -72,3 +73,162 @@ pub(crate) fn value_field(entries_field: &FieldRef) -> Option<FieldRef> {
         None
     }
 }
+
+/// Returns true if `data_type` is a struct with two fields, as used for map entries
+pub(crate) fn is_entries_struct(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Struct(fields) if fields.len() == 2)
+}
+
+/// Casts a struct array to a map with an entry per field, keyed by the field name.
+///
+/// NULL structs become NULL maps, NULL field values are kept as entries with NULL values.
+pub(crate) fn cast_struct_to_map(
+    array: &StructArray,
+    entries_field: &FieldRef,
+    ordered: bool,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let key_field = key_field(entries_field).ok_or(ArrowError::CastError(
+        "map is missing key field".to_string(),
+    ))?;
+    let value_field = value_field(entries_field).ok_or(ArrowError::CastError(
+        "map is missing value field".to_string(),
+    ))?;
+
+    let names = StringArray::from_iter_values(array.fields().iter().map(|f| f.name()));
+    let names = cast_with_options(&names, key_field.data_type(), cast_options)?;
+    let columns = array
+        .columns()
+        .iter()
+        .map(|c| cast_with_options(c, value_field.data_type(), cast_options))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let num_fields = columns.len();
+    let valid_rows: Vec<usize> = (0..array.len()).filter(|r| array.is_valid(*r)).collect();
+    if valid_rows.len() * num_fields > i32::MAX as usize {
+        return Err(ArrowError::ComputeError(format!(
+            "Offset overflow when casting from {} to map",
+            array.data_type()
+        )));
+    }
+
+    let key_indices: UInt32Array = valid_rows
+        .iter()
+        .flat_map(|_| 0..num_fields as u32)
+        .collect();
+    let value_indices: Vec<(usize, usize)> = valid_rows
+        .iter()
+        .flat_map(|row| (0..num_fields).map(move |field| (field, *row)))
+        .collect();
+    let columns: Vec<&dyn Array> = columns.iter().map(|c| c.as_ref()).collect();
+    let values = match columns.is_empty() {
+        true => new_empty_array(value_field.data_type()),
+        false => arrow_select::interleave::interleave(&columns, &value_indices)?,
+    };
+    let lengths = (0..array.len()).map(|row| match array.is_valid(row) {
+        true => num_fields,
+        false => 0,
+    });
+
+    Ok(Arc::new(MapArray::try_new(
+        entries_field.clone(),
+        OffsetBuffer::from_lengths(lengths),
+        StructArray::try_new(
+            Fields::from(vec![key_field, value_field]),
+            vec![take(&names, &key_indices, None)?, values],
+            None,
+        )?,
+        array.nulls().cloned(),
+        ordered,
+    )?))
+}
+
+/// Casts a map array to a struct, taking the value of each field from the entry whose
+/// key is the field name.
+///
+/// Fields without a matching entry are NULL. If several entries match, the last one is used.
+pub(crate) fn cast_map_to_struct(
+    array: &MapArray,
+    to_fields: &Fields,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let keys = cast_with_options(array.keys(), &DataType::Utf8, cast_options)?;
+    let keys = keys.as_string::<i32>();
+    let offsets = array.value_offsets();
+
+    let columns = to_fields
+        .iter()
+        .map(|field| {
+            let indices: UInt32Array = (0..array.len())
+                .map(|row| {
+                    let start = offsets[row].as_usize();
+                    let end = offsets[row + 1].as_usize();
+                    (start..end)
+                        .rev()
+                        .find(|idx| keys.is_valid(*idx) && keys.value(*idx) == field.name())
+                        .filter(|_| array.is_valid(row))
+                        .map(|idx| idx as u32)
+                })
+                .collect();
+            let values = take(array.values(), &indices, None)?;
+            cast_with_options(&values, field.data_type(), cast_options)
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    Ok(Arc::new(StructArray::try_new(
+        to_fields.clone(),
+        columns,
+        array.nulls().cloned(),
+    )?))
+}
+
+/// Casts a list of two field structs to a map, using the first field as key and the
+/// second field as value.
+pub(crate) fn cast_list_to_map<O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    entries_field: &FieldRef,
+    ordered: bool,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let entries = cast_with_options(array.values(), entries_field.data_type(), cast_options)?;
+    let offsets = array
+        .offsets()
+        .iter()
+        .map(|o| i32::try_from(o.as_usize()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| {
+            ArrowError::ComputeError(format!(
+                "Offset overflow when casting from {} to map",
+                array.data_type()
+            ))
+        })?;
+
+    Ok(Arc::new(MapArray::try_new(
+        entries_field.clone(),
+        OffsetBuffer::new(offsets.into()),
+        entries.as_struct().clone(),
+        array.nulls().cloned(),
+        ordered,
+    )?))
+}
+
+/// Casts a map to a list of its entries, as two field structs of type `to`.
+pub(crate) fn cast_map_to_list<O: OffsetSizeTrait>(
+    array: &MapArray,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let entries = cast_with_options(array.entries(), to.data_type(), cast_options)?;
+    let offsets = array
+        .offsets()
+        .iter()
+        .map(|o| O::usize_as(o.as_usize()))
+        .collect::<ScalarBuffer<O>>();
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        to.clone(),
+        OffsetBuffer::new(offsets),
+        entries,
+        array.nulls().cloned(),
+    )?))
+}
@@ -41,6 +41,7 @@ mod decimal;
 mod dictionary;
 mod list;
 mod map;
+mod nested;
 mod run_array;
 mod string;
 mod union;
@@ -49,6 +50,7 @@ use crate::cast::decimal::*;
 use crate::cast::dictionary::*;
 use crate::cast::list::*;
 use crate::cast::map::*;
+use crate::cast::nested::*;
 use crate::cast::run_array::*;
 use crate::cast::string::*;
 pub use crate::cast::union::*;
@@ -61,8 +63,8 @@ use std::sync::Arc;
 
 use crate::display::{ArrayFormatter, FormatOptions};
 use crate::parse::{
-    Parser, parse_interval_day_time, parse_interval_month_day_nano, parse_interval_year_month,
-    string_to_datetime,
+    ParseOptions, Parser, parse_interval_day_time, parse_interval_month_day_nano,
+    parse_interval_year_month,
 };
 use arrow_array::{builder::*, cast::*, temporal_conversions::*, timezone::Tz, types::*, *};
 use arrow_buffer::{ArrowNativeType, OffsetBuffer, i256};
@@ -92,21 +94,89 @@ where
 }
 
 /// CastOptions provides a way to override the default cast behaviors
+///
+/// Construct them with [`CastOptions::new`] or [`Default`] and the `with_*` methods,
+/// as options may be added in future releases.
+///
+/// ```
+/// # use arrow_cast::CastOptions;
+/// # use arrow_cast::parse::ParseOptions;
+/// let options = CastOptions::new()
+///     .with_safe(false)
+///     .with_parse_options(ParseOptions::new().with_formats(["%d/%m/%Y"]));
+/// assert!(!options.safe);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CastOptions<'a> {
     /// how to handle cast failures, either return NULL (safe=true) or return ERR (safe=false)
     pub safe: bool,
     /// Formatting options when casting from temporal types to string
     pub format_options: FormatOptions<'a>,
+    /// Parsing options when casting from string to temporal types
+    parse_options: ParseOptions,
+    /// Parse strings cast to lists as list literals
+    list_literals: bool,
 }
 
 impl Default for CastOptions<'_> {
     fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CastOptions<'a> {
+    /// Creates the default cast options, which return NULL on cast failures
+    pub const fn new() -> Self {
         Self {
             safe: true,
-            format_options: FormatOptions::default(),
+            format_options: FormatOptions::new(),
+            parse_options: ParseOptions::new(),
+            list_literals: false,
         }
     }
+
+    /// Sets whether to return NULL (`true`) or an error (`false`) on cast failures
+    pub const fn with_safe(mut self, safe: bool) -> Self {
+        self.safe = safe;
+        self
+    }
+
+    /// Sets the formatting options used when casting from temporal types to string
+    pub const fn with_format_options(mut self, format_options: FormatOptions<'a>) -> Self {
+        self.format_options = format_options;
+        self
+    }
+
+    /// Sets the parsing options used when casting from string to temporal types
+    pub fn with_parse_options(self, parse_options: ParseOptions) -> Self {
+        Self {
+            parse_options,
+            ..self
+        }
+    }
+
+    /// Returns the parsing options used when casting from string to temporal types
+    pub fn parse_options(&self) -> &ParseOptions {
+        &self.parse_options
+    }
+
+    /// Sets whether strings cast to a list type are parsed as list literals, such as
+    /// `[1, 2, 3]`
+    ///
+    /// By default, casting a string to a list creates a list with the string as its
+    /// single value. When enabled, strings enclosed in `[` and `]` are parsed as the
+    /// text representation produced by casting a list to a string, and other strings
+    /// still become a single value list. Lists nested within a struct, map or list
+    /// literal are always parsed as list literals.
+    pub const fn with_list_literals(mut self, list_literals: bool) -> Self {
+        self.list_literals = list_literals;
+        self
+    }
+
+    /// Returns true if strings cast to a list type are parsed as list literals
+    pub fn list_literals(&self) -> bool {
+        self.list_literals
+    }
 }
 
 /// Return true if a value of type `from_type` can be cast into a value of `to_type`.
@@ -127,8 +197,11 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             can_cast_types(from_value_type, to_value_type)
         }
         (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
+        (Union(from_fields, _), Union(to_fields, _)) => {
+            union::union_field_mapping(from_fields, to_fields).is_some()
+        }
         (Union(fields, _), _) => union::resolve_child_array(fields, to_type).is_some(),
-        (_, Union(_, _)) => false,
+        (_, Union(fields, _)) => union::resolve_target_child(fields, from_type).is_some(),
         (RunEndEncoded(_, value_type), _) => can_cast_types(value_type.data_type(), to_type),
         (_, RunEndEncoded(_, value_type)) => can_cast_types(from_type, value_type.data_type()),
         (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
@@ -136,6 +209,16 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             List(list_from) | LargeList(list_from) | ListView(list_from) | LargeListView(list_from),
             List(list_to) | LargeList(list_to) | ListView(list_to) | LargeListView(list_to),
         ) => can_cast_types(list_from.data_type(), list_to.data_type()),
+        (List(list_from) | LargeList(list_from), Map(entries, _))
+            if is_entries_struct(list_from.data_type()) =>
+        {
+            can_cast_types(list_from.data_type(), entries.data_type())
+        }
+        (Map(entries, _), List(list_to) | LargeList(list_to))
+            if is_entries_struct(list_to.data_type()) =>
+        {
+            can_cast_types(entries.data_type(), list_to.data_type())
+        }
         (
             List(list_from) | LargeList(list_from) | ListView(list_from) | LargeListView(list_from),
             Utf8 | LargeUtf8 | Utf8View,
@@ -160,6 +243,30 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (FixedSizeList(list_from, size), _) if *size == 1 => {
             can_cast_types(list_from.data_type(), to_type)
         }
+        (FixedSizeList(list_from, _), Utf8 | LargeUtf8 | Utf8View) => {
+            can_cast_types(list_from.data_type(), to_type)
+        }
+        (Utf8 | LargeUtf8 | Utf8View, FixedSizeList(list_to, _)) => {
+            can_cast_types(from_type, list_to.data_type())
+        }
+        (Map(entries, _), Utf8 | LargeUtf8 | Utf8View) => {
+            match (key_field(entries), value_field(entries)) {
+                (Some(key), Some(value)) => {
+                    can_cast_types(key.data_type(), to_type)
+                        && can_cast_types(value.data_type(), to_type)
+                }
+                _ => false,
+            }
+        }
+        (Utf8 | LargeUtf8 | Utf8View, Map(entries, _)) => {
+            match (key_field(entries), value_field(entries)) {
+                (Some(key), Some(value)) => {
+                    can_cast_types(from_type, key.data_type())
+                        && can_cast_types(from_type, value.data_type())
+                }
+                _ => false,
+            }
+        }
         (Map(from_entries, ordered_from), Map(to_entries, ordered_to))
             if ordered_from == ordered_to =>
         {
@@ -249,6 +356,30 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
                 .zip(to_fields.iter())
                 .all(|(f1, f2)| can_cast_types(f1.data_type(), f2.data_type()))
         }
+        (Struct(fields), Map(entries, _)) => match (key_field(entries), value_field(entries)) {
+            (Some(key), Some(value)) => {
+                can_cast_types(&Utf8, key.data_type())
+                    && fields
+                        .iter()
+                        .all(|f| can_cast_types(f.data_type(), value.data_type()))
+            }
+            _ => false,
+        },
+        (Map(entries, _), Struct(fields)) => match (key_field(entries), value_field(entries)) {
+            (Some(key), Some(value)) => {
+                can_cast_types(key.data_type(), &Utf8)
+                    && fields
+                        .iter()
+                        .all(|f| can_cast_types(value.data_type(), f.data_type()))
+            }
+            _ => false,
+        },
+        (Struct(fields), Utf8 | LargeUtf8 | Utf8View) => fields
+            .iter()
+            .all(|f| can_cast_types(f.data_type(), to_type)),
+        (Utf8 | LargeUtf8 | Utf8View, Struct(fields)) => fields
+            .iter()
+            .all(|f| can_cast_types(from_type, f.data_type())),
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (_, Boolean) => from_type.is_integer() || from_type.is_floating() || from_type.is_string(),
@@ -638,6 +769,21 @@ fn timestamp_to_date32<T: ArrowTimestampType>(
 /// * `List` to `FixedSizeList`: the underlying data type is cast. If safe is true and a list element
 ///   has the wrong length it will be replaced with NULL, otherwise an error will be returned
 /// * Primitive to `List`: a list array with 1 value per slot is created
+/// * Any type to `Union`: values are stored in the child whose type matches best,
+///   preferring an identical type, then a type of the same family, then any castable type
+/// * `Union` to `Union`: children are matched and cast by field name
+/// * `Struct` to `Map`: each field becomes an entry keyed by the field name
+/// * `Map` to `Struct`: each field takes the value of the entry keyed by the field name
+/// * `List` of two field `Struct` to and from `Map`: the struct fields are the keys and values
+/// * `Struct`, `List`, `FixedSizeList` and `Map` to string: values are formatted with
+///   [`ArrayFormatter`], e.g. `[1, 2]` or `{a: 1, b: x}`
+/// * String to `Struct`, `List`, `FixedSizeList` and `Map`: values are parsed from the
+///   text representation produced when casting to string. Casting to a `List`, or to a
+///   `FixedSizeList` of size 1, only parses list literals if enabled with
+///   [`CastOptions::with_list_literals`], and otherwise creates a list with each string
+///   as its single value. Unquoted items equal to `null` or to [`FormatOptions::null`]
+///   are parsed as NULL, so use [`FormatOptions::with_null`] with a non-empty string for
+///   lossless round trips
 /// * `Date32` and `Date64`: precision lost when going to higher interval
 /// * `Time32` and `Time64`: precision lost when going to higher interval
 /// * `Timestamp` and `Date{32|64}`: precision lost when going to higher interval
@@ -648,7 +794,7 @@ fn timestamp_to_date32<T: ArrowTimestampType>(
 ///   range become `INFINITY` or `-INFINITY` without error.
 ///
 /// Unsupported Casts (check with `can_cast_types` before calling):
-/// * To or from `StructArray`
+/// * To or from `StructArray`, other than to or from strings
 /// * `List` to `Primitive`
 /// * `Interval` and `Duration`
 ///
@@ -794,14 +940,15 @@ pub fn cast_with_options(
                 ))),
             }
         }
+        (Union(_, _), Union(to_fields, to_mode)) => {
+            cast_union_to_union(array.as_union(), to_fields, *to_mode, cast_options)
+        }
         (Union(_, _), _) => union_extract_by_type(
             array.as_any().downcast_ref::<UnionArray>().unwrap(),
             to_type,
             cast_options,
         ),
-        (_, Union(_, _)) => Err(ArrowError::CastError(format!(
-            "Casting from {from_type} to {to_type} not supported"
-        ))),
+        (_, Union(fields, mode)) => cast_to_union(array, fields, *mode, cast_options),
         (Dictionary(index_type, _), _) => match **index_type {
             Int8 => dictionary_cast::<Int8Type>(array, to_type, cast_options),
             Int16 => dictionary_cast::<Int16Type>(array, to_type, cast_options),
@@ -916,8 +1063,16 @@ pub fn cast_with_options(
         (FixedSizeList(_, size), _) if *size == 1 => {
             cast_single_element_fixed_size_list_to_values(array, to_type, cast_options)
         }
-        // NOTE: we could support FSL to string here too but might be confusing
-        //       since behaviour for size 1 would be different (see arm above)
+        // NOTE: FSL of size 1 casts its single element to string (see arm above)
+        (FixedSizeList(_, _), Utf8) => value_to_string::<i32>(array, cast_options),
+        (FixedSizeList(_, _), LargeUtf8) => value_to_string::<i64>(array, cast_options),
+        (FixedSizeList(_, _), Utf8View) => value_to_string_view(array, cast_options),
+        (List(_), Map(entries, ordered)) => {
+            cast_list_to_map(array.as_list::<i32>(), entries, *ordered, cast_options)
+        }
+        (LargeList(_), Map(entries, ordered)) => {
+            cast_list_to_map(array.as_list::<i64>(), entries, *ordered, cast_options)
+        }
         (List(_) | LargeList(_) | ListView(_) | LargeListView(_), _) => match to_type {
             Utf8 => value_to_string::<i32>(array, cast_options),
             LargeUtf8 => value_to_string::<i64>(array, cast_options),
@@ -926,6 +1081,39 @@ pub fn cast_with_options(
                 "Cannot cast LIST to non-list data type {dt}"
             ))),
         },
+        // String to nested types parses the text representation of the values,
+        // lists only when enabled as otherwise strings are wrapped in a list below
+        (Utf8 | LargeUtf8 | Utf8View, List(to)) if cast_options.list_literals() => {
+            cast_string_to_list::<i32>(array, to, cast_options)
+        }
+        (Utf8 | LargeUtf8 | Utf8View, LargeList(to)) if cast_options.list_literals() => {
+            cast_string_to_list::<i64>(array, to, cast_options)
+        }
+        (Utf8 | LargeUtf8 | Utf8View, ListView(to)) if cast_options.list_literals() => {
+            let list = cast_string_to_list::<i32>(array, to, cast_options)?;
+            cast_list_to_list_view::<i32, i32>(&list, to, cast_options)
+        }
+        (Utf8 | LargeUtf8 | Utf8View, LargeListView(to)) if cast_options.list_literals() => {
+            let list = cast_string_to_list::<i64>(array, to, cast_options)?;
+            cast_list_to_list_view::<i64, i64>(&list, to, cast_options)
+        }
+        (Utf8 | LargeUtf8 | Utf8View, FixedSizeList(to, size))
+            if *size != 1 || cast_options.list_literals() =>
+        {
+            cast_string_to_fixed_size_list(array, to, *size, cast_options)
+        }
+        (Utf8 | LargeUtf8 | Utf8View, Struct(fields)) => {
+            cast_string_to_struct(array, fields, cast_options)
+        }
+        (Utf8 | LargeUtf8 | Utf8View, Map(entries, ordered)) => {
+            cast_string_to_map(array, entries, *ordered, cast_options)
+        }
+        (Map(_, _), List(to)) if is_entries_struct(to.data_type()) => {
+            cast_map_to_list::<i32>(array.as_map(), to, cast_options)
+        }
+        (Map(_, _), LargeList(to)) if is_entries_struct(to.data_type()) => {
+            cast_map_to_list::<i64>(array.as_map(), to, cast_options)
+        }
         (_, List(to)) => cast_values_to_list::<i32>(array, to, cast_options),
         (_, LargeList(to)) => cast_values_to_list::<i64>(array, to, cast_options),
         (_, ListView(to)) => cast_values_to_list_view::<i32>(array, to, cast_options),
@@ -939,6 +1127,9 @@ pub fn cast_with_options(
         (Map(_, ordered1), Map(_, ordered2)) if ordered1 == ordered2 => {
             cast_map_values(array.as_map(), to_type, cast_options, ordered1.to_owned())
         }
+        (Map(_, _), Utf8) => value_to_string::<i32>(array, cast_options),
+        (Map(_, _), LargeUtf8) => value_to_string::<i64>(array, cast_options),
+        (Map(_, _), Utf8View) => value_to_string_view(array, cast_options),
         // Decimal to decimal, same width
         (Decimal32(p1, s1), Decimal32(p2, s2)) => {
             cast_decimal_to_decimal_same_type::<Decimal32Type>(
@@ -1197,6 +1388,13 @@ pub fn cast_with_options(
             to_fields.clone(),
             cast_options,
         ),
+        (Struct(_), Map(entries, ordered)) => {
+            cast_struct_to_map(array.as_struct(), entries, *ordered, cast_options)
+        }
+        (Map(_, _), Struct(fields)) => cast_map_to_struct(array.as_map(), fields, cast_options),
+        (Struct(_), Utf8) => value_to_string::<i32>(array, cast_options),
+        (Struct(_), LargeUtf8) => value_to_string::<i64>(array, cast_options),
+        (Struct(_), Utf8View) => value_to_string_view(array, cast_options),
         (Struct(_), _) => Err(ArrowError::CastError(format!(
             "Casting from {from_type} to {to_type} not supported"
         ))),
@@ -2881,6 +3079,7 @@ mod tests {
             let cast_option = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let result = cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
             assert_eq!($OUTPUT_TYPE, result.data_type());
@@ -3286,6 +3485,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3334,6 +3534,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3392,6 +3593,7 @@ mod tests {
                 &CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    ..Default::default()
                 },
             );
             assert!(
@@ -3495,6 +3697,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3517,6 +3720,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3596,6 +3800,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3617,6 +3822,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3811,6 +4017,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3824,6 +4031,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -3838,6 +4046,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -3851,6 +4060,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -4028,6 +4238,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -4041,6 +4252,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -4267,6 +4479,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -4280,6 +4493,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -4292,6 +4506,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -4305,6 +4520,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -4667,6 +4883,7 @@ mod tests {
         let cast_option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
@@ -4898,6 +5115,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         match result {
@@ -4937,6 +5155,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         match casted {
@@ -5294,6 +5513,7 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let res = cast_with_options(&str, &DataType::Int16, &options).expect("should cast to i16");
         let expected =
@@ -5367,6 +5587,7 @@ mod tests {
                 let options = CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    ..Default::default()
                 };
                 let err = cast_with_options(array, &to_type, &options).unwrap_err();
                 assert_eq!(
@@ -5385,6 +5606,95 @@ mod tests {
         assert_eq!(result.values(), &[247112596800]);
     }
 
+    #[test]
+    fn test_cast_string_to_timestamp_with_parse_options() {
+        let array = Arc::new(StringArray::from(vec![
+            Some("25/12/2018 10:30"),
+            Some("20181225"),
+            Some("2018-12-25T10:30:00+02:00"),
+            Some("1545733800"),
+            None,
+        ])) as ArrayRef;
+        let to_type = DataType::Timestamp(TimeUnit::Second, None);
+
+        let options = CastOptions::new()
+            .with_parse_options(ParseOptions::new().with_formats(["%d/%m/%Y %H:%M", "%Y%m%d"]));
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = b.as_primitive::<TimestampSecondType>();
+        assert_eq!(c.value(0), 1545733800);
+        assert_eq!(c.value(1), 1545696000);
+        // formats replace the default heuristics
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+        assert!(c.is_null(4));
+
+        let options = CastOptions::new().with_parse_options(
+            ParseOptions::new()
+                .with_formats(["%d/%m/%Y %H:%M", "%Y-%m-%dT%H:%M:%S%z"])
+                .with_timezone("+01:00")
+                .unwrap()
+                .with_epoch_unit(TimeUnit::Second),
+        );
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = b.as_primitive::<TimestampSecondType>();
+        assert_eq!(c.value(0), 1545733800 - 3600);
+        // integers are epoch values, even when they match a format
+        assert_eq!(c.value(1), 20181225);
+        assert_eq!(c.value(2), 1545733800 - 7200);
+        assert_eq!(c.value(3), 1545733800);
+
+        // the timezone of the parse options takes precedence over the target's
+        let to_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+05:00".into()));
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = b.as_primitive::<TimestampMillisecondType>();
+        assert_eq!(c.timezone(), Some("+05:00"));
+        assert_eq!(c.value(0), (1545733800 - 3600) * 1000);
+
+        let options = options.with_safe(false);
+        let array = StringArray::from(vec!["2018-12-25"]);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error parsing timestamp from '2018-12-25': no matching format in [\"%d/%m/%Y %H:%M\", \"%Y-%m-%dT%H:%M:%S%z\"]"
+        );
+    }
+
+    #[test]
+    fn test_cast_string_to_date_and_time_with_parse_options() {
+        let array = Arc::new(StringViewArray::from(vec![
+            Some("25/12/2018 10:30"),
+            Some("1545733800000"),
+            Some("2018-12-25"),
+        ])) as ArrayRef;
+        let options = CastOptions::new().with_parse_options(
+            ParseOptions::new()
+                .with_formats(["%d/%m/%Y %H:%M"])
+                .with_epoch_unit(TimeUnit::Millisecond),
+        );
+
+        let b = cast_with_options(&array, &DataType::Date32, &options).unwrap();
+        let c = b.as_primitive::<Date32Type>();
+        assert_eq!(c.value(0), 17890);
+        assert_eq!(c.value(1), 17890);
+        assert!(c.is_null(2));
+
+        let b = cast_with_options(&array, &DataType::Date64, &options).unwrap();
+        let c = b.as_primitive::<Date64Type>();
+        assert_eq!(c.value(0), 1545733800000);
+        assert_eq!(c.value(1), 1545733800000);
+        assert!(c.is_null(2));
+
+        let array = Arc::new(StringArray::from(vec!["10h30", "37800000"])) as ArrayRef;
+        let options = CastOptions::new().with_parse_options(
+            ParseOptions::new()
+                .with_formats(["%Hh%M"])
+                .with_epoch_unit(TimeUnit::Millisecond),
+        );
+        let b = cast_with_options(&array, &DataType::Time32(TimeUnit::Second), &options).unwrap();
+        let c = b.as_primitive::<Time32SecondType>();
+        assert_eq!(c.values(), &[37800, 37800]);
+    }
+
     #[test]
     fn test_cast_string_to_date32() {
         let a0 = Arc::new(StringViewArray::from(vec![
@@ -5413,6 +5723,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -5436,6 +5747,7 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let b = cast_with_options(&array, &to_type, &options).unwrap();
         let c = b.as_primitive::<Date32Type>();
@@ -5456,6 +5768,7 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let err = cast_with_options(&array, &to_type, &options).unwrap_err();
         assert_eq!(
@@ -5484,6 +5797,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let result = cast_with_options(&array, &to_type, &options).unwrap();
             let c = result.as_primitive::<Date32Type>();
@@ -5534,6 +5848,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -5579,6 +5894,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -5616,6 +5932,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -5653,6 +5970,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -5690,6 +6008,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -5706,6 +6025,7 @@ mod tests {
             let options = CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
 
             let target_interval_array = cast_with_options(
@@ -5833,6 +6153,7 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             };
             let arrow_err = cast_with_options(
                 &string_array.clone(),
@@ -5942,6 +6263,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(array_ref.is_err());
@@ -5952,6 +6274,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(array_ref.is_err());
@@ -6637,6 +6960,7 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let b = cast_with_options(&array, &DataType::Date64, &options);
         assert!(b.is_err());
@@ -7205,6 +7529,7 @@ mod tests {
             format_options: FormatOptions::default()
                 .with_timestamp_format(Some(ts_format))
                 .with_timestamp_tz_format(Some(ts_format)),
+            ..Default::default()
         };
 
         // "2018-12-25T00:00:02.001", "1997-05-19T00:00:03.005", None
@@ -10421,6 +10746,126 @@ mod tests {
         assert_eq!(&values_string, &vec!["44", "22"]);
     }
 
+    fn map_type(value_type: DataType) -> DataType {
+        DataType::Map(
+            Arc::new(Field::new(
+                Field::MAP_ENTRIES_FIELD_DEFAULT_NAME,
+                DataType::Struct(
+                    vec![
+                        Field::new(Field::MAP_KEY_FIELD_DEFAULT_NAME, DataType::Utf8, false),
+                        Field::new(Field::MAP_VALUE_FIELD_DEFAULT_NAME, value_type, true),
+                    ]
+                    .into(),
+                ),
+                false,
+            )),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_cast_struct_to_map_and_back() {
+        let struct_array = StructArray::try_new(
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Int64, true),
+            ]
+            .into(),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), Some(2), Some(3)])),
+                Arc::new(Int64Array::from(vec![Some(10), None, Some(30)])),
+            ],
+            Some(vec![true, false, true].into()),
+        )
+        .unwrap();
+
+        let to_type = map_type(DataType::Int64);
+        assert!(can_cast_types(struct_array.data_type(), &to_type));
+        let map = cast(&struct_array, &to_type).unwrap();
+        assert_eq!(
+            array_to_strings(&map),
+            vec!["{a: 1, b: 10}", "null", "{a: 3, b: 30}"]
+        );
+
+        // fields are looked up by key, missing keys are null
+        let fields: Fields = vec![
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Int32, true),
+        ]
+        .into();
+        assert!(can_cast_types(&to_type, &DataType::Struct(fields.clone())));
+        let back = cast(&map, &DataType::Struct(fields)).unwrap();
+        assert_eq!(
+            array_to_strings(&back),
+            vec!["{b: 10, c: null}", "null", "{b: 30, c: null}"]
+        );
+
+        // all fields must be castable to the map value type
+        let to_type = map_type(DataType::Struct(
+            vec![Field::new("x", DataType::Int32, true)].into(),
+        ));
+        assert!(!can_cast_types(struct_array.data_type(), &to_type));
+    }
+
+    #[test]
+    fn test_cast_map_to_struct_duplicate_keys() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("a");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        let to_type = DataType::Struct(vec![Field::new("a", DataType::Int32, false)].into());
+        let out = cast(&map, &to_type).unwrap();
+        assert_eq!(array_to_strings(&out), vec!["{a: 2}"]);
+    }
+
+    #[test]
+    fn test_cast_list_of_entries_to_map_and_back() {
+        let entries: Fields = vec![
+            Field::new("k", DataType::Utf8, false),
+            Field::new("v", DataType::Int32, true),
+        ]
+        .into();
+        let values = StructArray::try_new(
+            entries.clone(),
+            vec![
+                Arc::new(StringArray::from(vec!["x", "y", "z"])),
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+            ],
+            None,
+        )
+        .unwrap();
+        let list_field = Arc::new(Field::new_list_field(DataType::Struct(entries), false));
+        let list = LargeListArray::new(
+            list_field.clone(),
+            OffsetBuffer::from_lengths([2, 0, 1]),
+            Arc::new(values),
+            Some(vec![true, false, true].into()),
+        );
+
+        let to_type = map_type(DataType::Int64);
+        assert!(can_cast_types(list.data_type(), &to_type));
+        let map = cast(&list, &to_type).unwrap();
+        assert_eq!(map.data_type(), &to_type);
+        assert_eq!(
+            array_to_strings(&map),
+            vec!["{x: 1, y: null}", "null", "{z: 3}"]
+        );
+
+        assert!(can_cast_types(&to_type, list.data_type()));
+        let back = cast(&map, list.data_type()).unwrap();
+        assert_eq!(back.as_list::<i64>(), &list);
+
+        // lists of other element types still wrap the map in a single element list
+        let wrapped = DataType::List(Arc::new(Field::new_list_field(to_type.clone(), true)));
+        assert!(can_cast_types(&to_type, &wrapped));
+        let wrapped = cast(&map, &wrapped).unwrap();
+        assert_eq!(wrapped.as_list::<i32>().value_length(0), 1);
+    }
+
     #[test]
     fn test_utf8_cast_offsets() {
         // test if offset of the array is taken into account during cast
@@ -10502,6 +10947,269 @@ mod tests {
         assert_cast(&array, &["[0, 1, 2]", "[3, 4, 5]", "[6, 7]"]);
     }
 
+    #[test]
+    fn test_nested_to_string_round_trip() {
+        let options = CastOptions::new()
+            .with_safe(false)
+            .with_format_options(FormatOptions::new().with_null("null"))
+            .with_list_literals(true);
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>([
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+        ]);
+        let fixed = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            [Some([Some(1), Some(2)])],
+            2,
+        );
+        let strings = Arc::new(StringArray::from(vec![Some("x, y"), None, Some("z")]));
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, true)),
+                Arc::new(Int32Array::from(vec![Some(1), Some(2), None])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", list.data_type().clone(), true)),
+                Arc::new(list.clone()) as ArrayRef,
+            ),
+        ]);
+        let mut map_builder = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+        map_builder.keys().append_value("k1");
+        map_builder.values().append_value(1);
+        map_builder.keys().append_value("k2");
+        map_builder.values().append_null();
+        map_builder.append(true).unwrap();
+        map_builder.append(false).unwrap();
+        map_builder.append(true).unwrap();
+        let map = map_builder.finish();
+
+        let quoted_options = CastOptions {
+            format_options: options.format_options.clone().with_quoted_strings(true),
+            ..options.clone()
+        };
+        let quoted = ListArray::new(
+            Field::new_list_field(DataType::Utf8, true).into(),
+            OffsetBuffer::from_lengths([2, 0, 1]),
+            strings,
+            None,
+        );
+
+        let cases: Vec<(ArrayRef, &CastOptions, Vec<Option<&str>>)> = vec![
+            (
+                Arc::new(list),
+                &options,
+                vec![Some("[1, null]"), None, Some("[]")],
+            ),
+            (Arc::new(fixed), &options, vec![Some("[1, 2]")]),
+            (
+                Arc::new(structs),
+                &options,
+                vec![
+                    Some("{a: 1, b: [1, null]}"),
+                    Some("{a: 2, b: null}"),
+                    Some("{a: null, b: []}"),
+                ],
+            ),
+            (
+                Arc::new(map),
+                &options,
+                vec![Some("{k1: 1, k2: null}"), None, Some("{}")],
+            ),
+            (
+                Arc::new(quoted),
+                &quoted_options,
+                vec![Some("[\"x, y\", null]"), Some("[]"), Some("[\"z\"]")],
+            ),
+        ];
+
+        for (array, options, expected) in cases {
+            for to_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+                assert!(can_cast_types(array.data_type(), &to_type));
+                assert!(can_cast_types(&to_type, array.data_type()));
+
+                let out = cast_with_options(&array, &to_type, options).unwrap();
+                let as_utf8 = cast(&out, &DataType::Utf8).unwrap();
+                let actual = as_utf8.as_string::<i32>().iter().collect::<Vec<_>>();
+                assert_eq!(actual, expected);
+
+                let back = cast_with_options(&out, array.data_type(), options).unwrap();
+                assert_eq!(back.as_ref(), array.as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_nested() {
+        let strings = StringArray::from(vec![
+            Some(" [1, 2 , 3] "),
+            Some("[]"),
+            Some("4"),
+            Some("[\"5\", null]"),
+            None,
+        ]);
+        let literals = CastOptions::new().with_list_literals(true);
+        let list_type = DataType::List(Field::new_list_field(DataType::Int32, true).into());
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>([
+            Some(vec![Some(1), Some(2), Some(3)]),
+            Some(vec![]),
+            Some(vec![Some(4)]),
+            Some(vec![Some(5), None]),
+            None,
+        ]);
+        let out = cast_with_options(&strings, &list_type, &literals).unwrap();
+        assert_eq!(out.as_list::<i32>(), &expected);
+
+        let list_view_type =
+            DataType::LargeListView(Field::new_list_field(DataType::Int32, true).into());
+        let out = cast_with_options(&strings, &list_view_type, &literals).unwrap();
+        let expected = cast(&expected, &list_view_type).unwrap();
+        assert_eq!(out.as_ref(), expected.as_ref());
+
+        // nested lists with invalid elements
+        let strings = StringArray::from(vec!["[[1], [2, x]]", "[[]]"]);
+        let inner = Field::new_list_field(DataType::Int32, true);
+        let to_type =
+            DataType::List(Field::new_list_field(DataType::List(inner.into()), true).into());
+        let out = cast_with_options(&strings, &to_type, &literals).unwrap();
+        assert_eq!(
+            crate::display::array_value_to_string(&out, 0).unwrap(),
+            "[[1], [2, ]]",
+            "invalid elements are null when safe"
+        );
+        assert_eq!(
+            crate::display::array_value_to_string(&out, 1).unwrap(),
+            "[[]]"
+        );
+        let err =
+            cast_with_options(&strings, &to_type, &literals.clone().with_safe(false)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string 'x' to value of Int32 type"
+        );
+
+        // fixed size lists need exactly `size` elements
+        let strings = StringViewArray::from(vec!["[1, 2]", "[1]", "1"]);
+        let to_type =
+            DataType::FixedSizeList(Field::new_list_field(DataType::Int8, true).into(), 2);
+        let out = cast(&strings, &to_type).unwrap();
+        let out = out.as_fixed_size_list();
+        assert!(out.is_valid(0));
+        assert!(out.is_null(1));
+        assert!(out.is_null(2));
+        let err = cast_with_options(
+            &strings,
+            &to_type,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '[1]' to value of FixedSizeList(2 x Int8) type"
+        );
+
+        // structs match fields by name, missing fields are null
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let strings = LargeStringArray::from(vec![
+            "{b: x, a: 1}",
+            "{\"a\": 2}",
+            "{a: 3, c: 4}",
+            "{a: 1, a: 2}",
+            "nope",
+        ]);
+        let out = cast(&strings, &DataType::Struct(fields.clone())).unwrap();
+        let out = out.as_struct();
+        assert_eq!(
+            out.column(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(1), Some(2), None, None, None])
+        );
+        assert_eq!(
+            out.column(1).as_string::<i32>(),
+            &StringArray::from(vec![Some("x"), None, None, None, None])
+        );
+        assert_eq!(out.null_count(), 3);
+        let err = cast_with_options(
+            &strings,
+            &DataType::Struct(fields),
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string '{a: 3, c: 4}' to value of Struct(\"a\": Int32, \"b\": Utf8) type"
+        );
+
+        // structs without fields keep the length and nulls of the input
+        let strings = StringArray::from(vec![Some("{}"), None]);
+        let to_type = DataType::Struct(Fields::empty());
+        assert!(can_cast_types(strings.data_type(), &to_type));
+        let out = cast(&strings, &to_type).unwrap();
+        assert_eq!(out.len(), 2);
+        assert!(out.is_valid(0));
+        assert!(out.is_null(1));
+
+        // maps reject null keys
+        let entries = Field::new(
+            "entries",
+            DataType::Struct(Fields::from(vec![
+                Field::new("keys", DataType::Utf8, false),
+                Field::new("values", DataType::Float64, true),
+            ])),
+            false,
+        );
+        let to_type = DataType::Map(entries.into(), false);
+        let strings = StringArray::from(vec!["{a: 1.5, \"b:c\": }", "{null: 1}"]);
+        let out = cast(&strings, &to_type).unwrap();
+        assert_eq!(
+            crate::display::array_value_to_string(&out, 0).unwrap(),
+            "{a: 1.5, b:c: }"
+        );
+        assert!(out.is_null(1));
+    }
+
+    #[test]
+    fn test_cast_string_to_list_wraps_value() {
+        let strings = StringArray::from(vec![Some("[a]"), Some("b, c"), None]);
+        let field = Arc::new(Field::new_list_field(DataType::Utf8, true));
+
+        // by default strings are not parsed as list literals
+        let expected = ListArray::new(
+            field.clone(),
+            OffsetBuffer::from_lengths([1, 1, 1]),
+            Arc::new(strings.clone()),
+            None,
+        );
+        let out = cast(&strings, &DataType::List(field.clone())).unwrap();
+        assert_eq!(out.as_list::<i32>(), &expected);
+
+        let to_type = DataType::FixedSizeList(field.clone(), 1);
+        let out = cast(&strings, &to_type).unwrap();
+        let out = out.as_fixed_size_list();
+        assert_eq!(out.values().as_string::<i32>(), &strings);
+
+        let options = CastOptions::new().with_list_literals(true);
+        let out = cast_with_options(&strings, &DataType::List(field), &options).unwrap();
+        let out = out.as_list::<i32>();
+        assert_eq!(
+            out.value(0).as_string::<i32>(),
+            &StringArray::from(vec!["a"])
+        );
+        assert_eq!(
+            out.value(1).as_string::<i32>(),
+            &StringArray::from(vec!["b, c"])
+        );
+        assert!(out.is_null(2));
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Takes too long
     fn test_cast_f64_to_decimal128() {
@@ -10558,6 +11266,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -10569,6 +11278,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -10584,6 +11294,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -10595,6 +11306,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -10610,6 +11322,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -10621,6 +11334,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -10642,6 +11356,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -10653,6 +11368,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -10671,6 +11387,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -10682,6 +11399,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -10700,6 +11418,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -10714,6 +11433,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -10734,6 +11454,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -10745,6 +11466,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -10762,6 +11484,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -10773,6 +11496,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -10793,6 +11517,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -10804,6 +11529,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -11299,6 +12025,7 @@ mod tests {
         let option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
         assert!(
@@ -11354,6 +12081,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -11365,6 +12093,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -11440,6 +12169,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -11451,6 +12181,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -11500,6 +12231,7 @@ mod tests {
         let cast_options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
 
         let result = cast_string_to_timestamp::<i32, TimestampNanosecondType>(
@@ -11583,6 +12315,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(err.is_err());
@@ -11606,6 +12339,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(err.is_err());
@@ -11673,6 +12407,7 @@ mod tests {
                 &CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    ..Default::default()
                 },
             )
             .unwrap();
@@ -11724,6 +12459,7 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let array = cast_with_options(&s, &DataType::Utf8, &options).unwrap();
         let a = array.as_string::<i32>();
@@ -11893,6 +12629,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -11904,6 +12641,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -11922,6 +12660,7 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_ok());
@@ -11933,6 +12672,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert_eq!(
@@ -11983,6 +12723,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -12016,6 +12757,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -12049,6 +12791,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         );
         assert!(casted_array.is_err());
@@ -12075,6 +12818,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -12105,6 +12849,7 @@ mod tests {
         let fallible = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
         let v = IntervalMonthDayNano::new(0, 0, 1234567);
 
@@ -12272,6 +13017,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap();
@@ -12333,12 +13079,17 @@ mod tests {
     const CAST_OPTIONS: CastOptions<'static> = CastOptions {
         safe: true,
         format_options: FormatOptions::new(),
+        parse_options: ParseOptions::new(),
+        list_literals: false,
     };
 
+    const CAST_OPTIONS_BUILDER: CastOptions<'static> = CastOptions::new().with_safe(false);
+
     #[test]
     #[allow(clippy::assertions_on_constants)]
     fn test_const_options() {
-        assert!(CAST_OPTIONS.safe)
+        assert!(CAST_OPTIONS.safe);
+        assert!(!CAST_OPTIONS_BUILDER.safe);
     }
 
     #[test]
@@ -12346,6 +13097,7 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default().with_null("null"),
+            ..Default::default()
         };
         let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
             Some(vec![Some(0), Some(1), Some(2)]),
@@ -12524,21 +13276,21 @@ mod tests {
             Arc::new(Field::new("a", DataType::Boolean, false)),
             boolean.clone() as ArrayRef,
         )]);
-        let to_type = DataType::Utf8;
+        let to_type = DataType::Int32;
         let result = cast(&struct_array, &to_type);
         assert_eq!(
-            r#"Cast error: Casting from Struct("a": non-null Boolean) to Utf8 not supported"#,
+            r#"Cast error: Casting from Struct("a": non-null Boolean) to Int32 not supported"#,
             result.unwrap_err().to_string()
         );
     }
 
     #[test]
     fn test_cast_non_struct_to_struct() {
-        let array = StringArray::from(vec!["a", "b"]);
+        let array = Int32Array::from(vec![1, 2]);
         let to_type = DataType::Struct(vec![Field::new("a", DataType::Boolean, false)].into());
         let result = cast(&array, &to_type);
         assert_eq!(
-            r#"Cast error: Casting from Utf8 to Struct("a": non-null Boolean) not supported"#,
+            r#"Cast error: Casting from Int32 to Struct("a": non-null Boolean) not supported"#,
             result.unwrap_err().to_string()
         );
     }
@@ -13150,6 +13902,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap_err();
@@ -13165,6 +13918,7 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                ..Default::default()
             },
         )
         .unwrap_err();
@@ -13490,6 +14244,7 @@ mod tests {
         let cast_options = CastOptions {
             safe: false, // This should make it fail instead of returning nulls
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
 
         // This should fail due to run-end overflow
@@ -13520,6 +14275,7 @@ mod tests {
         let cast_options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
 
         // This fails even though safe is true because the run_ends array has null values
@@ -13550,6 +14306,7 @@ mod tests {
         let cast_options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
 
         // This should succeed due to valid upcast
@@ -13586,6 +14343,7 @@ mod tests {
         let cast_options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
 
         // This should succeed
@@ -14106,6 +14864,7 @@ mod tests {
         let cast_options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            ..Default::default()
         };
 
         let result = cast_with_options(&array_ref, &target_type, &cast_options).unwrap();
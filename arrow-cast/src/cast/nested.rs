@@ -0,0 +1,468 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parsing of the text representation of nested types, as produced by [`ArrayFormatter`]
+//!
+//! * lists are written as `[1, 2, 3]`
+//! * structs are written as `{a: 1, b: foo}`
+//! * maps are written as `{key1: 1, key2: 2}`
+//!
+//! Items may be enclosed in double quotes, in which case they may contain separators
+//! and `\` escapes, as written when [`FormatOptions::with_quoted_strings`] is enabled.
+//! Unquoted items equal to `null` or to [`FormatOptions::null`] are parsed as null.
+
+use crate::cast::*;
+use arrow_buffer::NullBufferBuilder;
+use std::borrow::Cow;
+
+/// Parses a string array into a list array of type `to`
+///
+/// Values that are not list literals are cast as a single element list,
+/// consistent with casting non-list values to a list.
+pub(crate) fn cast_string_to_list<O: OffsetSizeTrait>(
+    array: &dyn Array,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let null = cast_options.format_options.null();
+    let mut items = Vec::with_capacity(array.len());
+    let mut lengths = Vec::with_capacity(array.len());
+    let mut nulls = NullBufferBuilder::new(array.len());
+
+    for value in string_values(array)? {
+        match value {
+            Some(s) => {
+                match split_literal(s, b'[', b']') {
+                    Some(parts) => {
+                        lengths.push(parts.len());
+                        items.extend(parts.into_iter().map(|p| parse_item(p, null)));
+                    }
+                    None => {
+                        lengths.push(1);
+                        items.push(Some(Cow::Borrowed(s)));
+                    }
+                }
+                nulls.append_non_null();
+            }
+            None => {
+                lengths.push(0);
+                nulls.append_null();
+            }
+        }
+    }
+
+    if items.len() > O::MAX_OFFSET {
+        return Err(ArrowError::ComputeError(format!(
+            "Offset overflow when casting from {} to {}",
+            array.data_type(),
+            to.data_type()
+        )));
+    }
+    let values = cast_items(&items, to.data_type(), cast_options)?;
+    let offsets = OffsetBuffer::<O>::from_lengths(lengths);
+    let list = GenericListArray::<O>::try_new(to.clone(), offsets, values, nulls.finish())?;
+    Ok(Arc::new(list))
+}
+
+/// Parses a string array into a fixed size list array of type `to` with `size` elements
+///
+/// Literals with the wrong number of elements are replaced with NULL if `safe` is
+/// true, otherwise an error is returned.
+pub(crate) fn cast_string_to_fixed_size_list(
+    array: &dyn Array,
+    to: &FieldRef,
+    size: i32,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let null = cast_options.format_options.null();
+    let len = size.as_usize();
+    let mut items = Vec::with_capacity(array.len() * len);
+    let mut nulls = NullBufferBuilder::new(array.len());
+
+    for value in string_values(array)? {
+        let parts = value.map(|s| match split_literal(s, b'[', b']') {
+            Some(parts) if parts.len() == len => Ok(parts),
+            // keep casting a single value to a list of size 1 working
+            None if len == 1 => Ok(vec![s]),
+            _ => Err(invalid_literal(s, to, size)),
+        });
+        match parts {
+            Some(Ok(parts)) => {
+                items.extend(parts.into_iter().map(|p| parse_item(p, null)));
+                nulls.append_non_null();
+            }
+            Some(Err(e)) if !cast_options.safe => return Err(e),
+            _ => {
+                items.extend(std::iter::repeat_n(None, len));
+                nulls.append_null();
+            }
+        }
+    }
+
+    let values = cast_items(&items, to.data_type(), cast_options)?;
+    let list = FixedSizeListArray::try_new(to.clone(), size, values, nulls.finish())?;
+    Ok(Arc::new(list))
+}
+
+/// Parses a string array into a struct array with `fields`
+///
+/// Fields are matched by name, missing fields are NULL. Literals with unknown
+/// or repeated field names are replaced with NULL if `safe` is true, otherwise
+/// an error is returned.
+pub(crate) fn cast_string_to_struct(
+    array: &dyn Array,
+    fields: &Fields,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let null = cast_options.format_options.null();
+    let mut columns = vec![Vec::with_capacity(array.len()); fields.len()];
+    let mut nulls = NullBufferBuilder::new(array.len());
+
+    for value in string_values(array)? {
+        let row = value.map(|s| {
+            parse_struct_literal(s, fields, null).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Cannot cast string '{s}' to value of {} type",
+                    DataType::Struct(fields.clone())
+                ))
+            })
+        });
+        match row {
+            Some(Ok(row)) => {
+                columns.iter_mut().zip(row).for_each(|(c, v)| c.push(v));
+                nulls.append_non_null();
+            }
+            Some(Err(e)) if !cast_options.safe => return Err(e),
+            _ => {
+                columns.iter_mut().for_each(|c| c.push(None));
+                nulls.append_null();
+            }
+        }
+    }
+
+    let columns = fields
+        .iter()
+        .zip(&columns)
+        .map(|(f, c)| cast_items(c, f.data_type(), cast_options))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Arc::new(StructArray::try_new_with_length(
+        fields.clone(),
+        columns,
+        nulls.finish(),
+        array.len(),
+    )?))
+}
+
+/// Parses a string array into a map array with `entries_field`
+///
+/// Literals with NULL keys are replaced with NULL if `safe` is true, otherwise
+/// an error is returned.
+pub(crate) fn cast_string_to_map(
+    array: &dyn Array,
+    entries_field: &FieldRef,
+    ordered: bool,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let key_field = key_field(entries_field).ok_or(ArrowError::CastError(
+        "map is missing key field".to_string(),
+    ))?;
+    let value_field = value_field(entries_field).ok_or(ArrowError::CastError(
+        "map is missing value field".to_string(),
+    ))?;
+
+    let null = cast_options.format_options.null();
+    let mut keys = Vec::with_capacity(array.len());
+    let mut values = Vec::with_capacity(array.len());
+    let mut lengths = Vec::with_capacity(array.len());
+    let mut nulls = NullBufferBuilder::new(array.len());
+
+    for value in string_values(array)? {
+        let entries = value.map(|s| {
+            parse_map_literal(s, null).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Cannot cast string '{s}' to value of {} type",
+                    DataType::Map(entries_field.clone(), ordered)
+                ))
+            })
+        });
+        match entries {
+            Some(Ok(entries)) => {
+                lengths.push(entries.len());
+                for (k, v) in entries {
+                    keys.push(Some(k));
+                    values.push(v);
+                }
+                nulls.append_non_null();
+            }
+            Some(Err(e)) if !cast_options.safe => return Err(e),
+            _ => {
+                lengths.push(0);
+                nulls.append_null();
+            }
+        }
+    }
+
+    if keys.len() > i32::MAX_OFFSET {
+        return Err(ArrowError::ComputeError(format!(
+            "Offset overflow when casting from {} to map",
+            array.data_type(),
+        )));
+    }
+    let key_array = cast_items(&keys, key_field.data_type(), cast_options)?;
+    let value_array = cast_items(&values, value_field.data_type(), cast_options)?;
+
+    Ok(Arc::new(MapArray::try_new(
+        entries_field.clone(),
+        OffsetBuffer::from_lengths(lengths),
+        StructArray::try_new(
+            Fields::from(vec![key_field, value_field]),
+            vec![key_array, value_array],
+            None,
+        )?,
+        nulls.finish(),
+        ordered,
+    )?))
+}
+
+fn invalid_literal(s: &str, to: &FieldRef, size: i32) -> ArrowError {
+    ArrowError::CastError(format!(
+        "Cannot cast string '{s}' to value of {} type",
+        DataType::FixedSizeList(to.clone(), size)
+    ))
+}
+
+/// Returns the values of a `Utf8`, `LargeUtf8` or `Utf8View` array
+fn string_values(array: &dyn Array) -> Result<Vec<Option<&str>>, ArrowError> {
+    match array.data_type() {
+        DataType::Utf8 => Ok(array.as_string::<i32>().iter().collect()),
+        DataType::LargeUtf8 => Ok(array.as_string::<i64>().iter().collect()),
+        DataType::Utf8View => Ok(array.as_string_view().iter().collect()),
+        dt => Err(ArrowError::CastError(format!(
+            "Cannot parse nested value from {dt}"
+        ))),
+    }
+}
+
+/// Casts the parsed `items` of a nested literal to `to_type`
+///
+/// Lists nested within a literal are always parsed as list literals
+fn cast_items(
+    items: &[Option<Cow<'_, str>>],
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let strings: StringArray = items.iter().map(|x| x.as_deref()).collect();
+    let cast_options = cast_options.clone().with_list_literals(true);
+    cast_with_options(&strings, to_type, &cast_options)
+}
+
+/// Parses a `{name: value, ...}` literal into the values of `fields`, in order
+fn parse_struct_literal<'a>(
+    s: &'a str,
+    fields: &Fields,
+    null: &str,
+) -> Option<Vec<Option<Cow<'a, str>>>> {
+    let mut row = vec![None; fields.len()];
+    let mut seen = vec![false; fields.len()];
+    for item in split_literal(s, b'{', b'}')? {
+        let (name, value) = split_key_value(item)?;
+        let (idx, _) = fields.find(&unquote(name))?;
+        if std::mem::replace(&mut seen[idx], true) {
+            return None;
+        }
+        row[idx] = parse_item(value, null);
+    }
+    Some(row)
+}
+
+/// A parsed map entry, keys can not be null
+type MapEntry<'a> = (Cow<'a, str>, Option<Cow<'a, str>>);
+
+/// Parses a `{key: value, ...}` literal into its entries
+fn parse_map_literal<'a>(s: &'a str, null: &str) -> Option<Vec<MapEntry<'a>>> {
+    split_literal(s, b'{', b'}')?
+        .into_iter()
+        .map(|item| {
+            let (key, value) = split_key_value(item)?;
+            Some((parse_item(key, null)?, parse_item(value, null)))
+        })
+        .collect()
+}
+
+/// Parses a single item of a nested literal, returning `None` if it is null
+fn parse_item<'a>(item: &'a str, null: &str) -> Option<Cow<'a, str>> {
+    match item {
+        "null" => None,
+        _ if item == null => None,
+        _ => Some(unquote(item)),
+    }
+}
+
+/// Splits `s`, enclosed in `open` and `close`, into its top-level comma separated items
+///
+/// Returns `None` if `s` is not enclosed in `open` and `close`, or if its brackets or
+/// quotes are unbalanced
+fn split_literal(s: &str, open: u8, close: u8) -> Option<Vec<&str>> {
+    let s = s.trim();
+    let inner = s
+        .strip_prefix(open as char)
+        .and_then(|s| s.strip_suffix(close as char))?;
+    let separators = top_level_separators(inner, b',')?;
+    if inner.trim().is_empty() {
+        return Some(vec![]);
+    }
+
+    let mut start = 0;
+    let mut items = Vec::with_capacity(separators.len() + 1);
+    for end in separators.into_iter().chain(std::iter::once(inner.len())) {
+        items.push(inner[start..end].trim());
+        start = end + 1;
+    }
+    Some(items)
+}
+
+/// Splits a `key: value` item at its first top-level colon
+fn split_key_value(item: &str) -> Option<(&str, &str)> {
+    let idx = *top_level_separators(item, b':')?.first()?;
+    Some((item[..idx].trim(), item[idx + 1..].trim()))
+}
+
+/// Returns the byte offsets of `separator` in `s` that are not nested within
+/// brackets or quotes, or `None` if the brackets or quotes are unbalanced
+fn top_level_separators(s: &str, separator: u8) -> Option<Vec<usize>> {
+    let mut separators = vec![];
+    let mut depth = 0_usize;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (idx, b) in s.bytes().enumerate() {
+        if in_quotes {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_quotes = false,
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_quotes = true,
+            b'[' | b'{' => depth += 1,
+            b']' | b'}' => depth = depth.checked_sub(1)?,
+            _ if b == separator && depth == 0 => separators.push(idx),
+            _ => {}
+        }
+    }
+    (depth == 0 && !in_quotes).then_some(separators)
+}
+
+/// Removes the double quotes surrounding `s` and resolves its escapes
+///
+/// Both [`Debug`](std::fmt::Debug) style `\u{..}` and JSON style `\uXXXX` unicode
+/// escapes are supported. Returns `s` unchanged if it is not quoted or contains
+/// invalid escapes.
+fn unquote(s: &str) -> Cow<'_, str> {
+    let Some(inner) = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|_| s.len() >= 2)
+    else {
+        return Cow::Borrowed(s);
+    };
+    if !inner.contains('\\') {
+        return Cow::Borrowed(inner);
+    }
+    unescape(inner).map_or(Cow::Borrowed(s), Cow::Owned)
+}
+
+fn unescape(s: &str) -> Option<String> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        let c = match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            'u' => {
+                let rest = chars.as_str();
+                let (hex, remainder) = match rest.strip_prefix('{') {
+                    Some(rest) => {
+                        let end = rest.find('}')?;
+                        (&rest[..end], &rest[end + 1..])
+                    }
+                    None => (rest.get(..4)?, &rest[4..]),
+                };
+                chars = remainder.chars();
+                char::from_u32(u32::from_str_radix(hex, 16).ok()?)?
+            }
+            c @ ('\\' | '"' | '\'' | '/') => c,
+            _ => return None,
+        };
+        out.push(c);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_literal() {
+        assert_eq!(split_literal("[]", b'[', b']'), Some(vec![]));
+        assert_eq!(split_literal(" [ ] ", b'[', b']'), Some(vec![]));
+        assert_eq!(
+            split_literal("[1, 2,3]", b'[', b']'),
+            Some(vec!["1", "2", "3"])
+        );
+        assert_eq!(
+            split_literal("[[1, 2], {a: [3]}, \"x, ]\"]", b'[', b']'),
+            Some(vec!["[1, 2]", "{a: [3]}", "\"x, ]\""])
+        );
+        assert_eq!(
+            split_literal("[1, , 3]", b'[', b']'),
+            Some(vec!["1", "", "3"])
+        );
+        assert_eq!(split_literal("1, 2", b'[', b']'), None);
+        assert_eq!(split_literal("[[1, 2]", b'[', b']'), None);
+        assert_eq!(split_literal("[1]]", b'[', b']'), None);
+        assert_eq!(split_literal("[\"1]", b'[', b']'), None);
+    }
+
+    #[test]
+    fn test_split_key_value() {
+        assert_eq!(split_key_value("a: 1"), Some(("a", "1")));
+        assert_eq!(split_key_value("t: 12:30:00"), Some(("t", "12:30:00")));
+        assert_eq!(split_key_value("\"a:b\": 1"), Some(("\"a:b\"", "1")));
+        assert_eq!(split_key_value("{a: 1}"), None);
+        assert_eq!(split_key_value("a"), None);
+    }
+
+    #[test]
+    fn test_unquote() {
+        assert_eq!(unquote("abc"), "abc");
+        assert_eq!(unquote("\"abc\""), "abc");
+        assert_eq!(unquote("\""), "\"");
+        assert_eq!(unquote("\"a\\\"b\\\\c\\n\""), "a\"b\\c\n");
+        assert_eq!(unquote("\"\\u{1F600}\\u00e9\""), "\u{1F600}\u{e9}");
+        assert_eq!(unquote("\"\\x\""), "\"\\x\"");
+    }
+}
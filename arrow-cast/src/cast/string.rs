@@ -94,8 +94,9 @@ fn parse_string_iter<
     cast_options: &CastOptions,
     nulls: F,
 ) -> Result<ArrayRef, ArrowError> {
+    let parse_options = cast_options.parse_options();
     let array = if cast_options.safe {
-        let iter = iter.map(|x| x.and_then(P::parse));
+        let iter = iter.map(|x| x.and_then(|v| P::parse_with_options(v, parse_options)));
 
         // Benefit:
         //     20% performance improvement
@@ -105,7 +106,7 @@ fn parse_string_iter<
     } else {
         let v = iter
             .map(|x| match x {
-                Some(v) => P::parse(v).ok_or_else(|| {
+                Some(v) => P::parse_with_options(v, parse_options).ok_or_else(|| {
                     ArrowError::CastError(format!(
                         "Cannot cast string '{v}' to value of {} type",
                         P::DATA_TYPE
@@ -127,9 +128,11 @@ pub(crate) fn cast_string_to_timestamp<O: OffsetSizeTrait, T: ArrowTimestampType
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let array = array.as_string::<O>();
-    let out: PrimitiveArray<T> = match to_tz {
+    // inputs without an offset are in the timezone of the parse options, if any
+    let naive_tz = cast_options.parse_options().timezone().or(to_tz.as_deref());
+    let out: PrimitiveArray<T> = match naive_tz {
         Some(tz) => {
-            let tz: Tz = tz.as_ref().parse()?;
+            let tz: Tz = tz.parse()?;
             cast_string_to_timestamp_impl(array.iter(), &tz, cast_options)?
         }
         None => cast_string_to_timestamp_impl(array.iter(), &Utc, cast_options)?,
@@ -144,9 +147,11 @@ pub(crate) fn cast_view_to_timestamp<T: ArrowTimestampType>(
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     let array = array.as_string_view();
-    let out: PrimitiveArray<T> = match to_tz {
+    // inputs without an offset are in the timezone of the parse options, if any
+    let naive_tz = cast_options.parse_options().timezone().or(to_tz.as_deref());
+    let out: PrimitiveArray<T> = match naive_tz {
         Some(tz) => {
-            let tz: Tz = tz.as_ref().parse()?;
+            let tz: Tz = tz.parse()?;
             cast_string_to_timestamp_impl(array.iter(), &tz, cast_options)?
         }
         None => cast_string_to_timestamp_impl(array.iter(), &Utc, cast_options)?,
@@ -164,10 +169,11 @@ fn cast_string_to_timestamp_impl<
     tz: &Tz,
     cast_options: &CastOptions,
 ) -> Result<PrimitiveArray<T>, ArrowError> {
+    let parse_options = cast_options.parse_options();
     if cast_options.safe {
         let iter = iter.map(|v| {
             v.and_then(|v| {
-                let naive = string_to_datetime(tz, v).ok()?.naive_utc();
+                let naive = parse_options.string_to_datetime(tz, v).ok()?.naive_utc();
                 T::from_naive_datetime(naive, None)
             })
         });
@@ -181,7 +187,7 @@ fn cast_string_to_timestamp_impl<
         let vec = iter
             .map(|v| {
                 v.map(|v| {
-                    let naive = string_to_datetime(tz, v)?.naive_utc();
+                    let naive = parse_options.string_to_datetime(tz, v)?.naive_utc();
                     T::from_naive_datetime(naive, None).ok_or_else(|| match T::UNIT {
                         TimeUnit::Nanosecond => ArrowError::CastError(format!(
                             "Overflow converting {naive} to Nanosecond. The dates that can be represented as nanoseconds have to be between 1677-09-21T00:12:44.0 and 2262-04-11T23:47:16.854775804"
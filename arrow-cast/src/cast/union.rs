@@ -19,11 +19,14 @@
 
 use crate::cast::can_cast_types;
 use crate::cast_with_options;
-use arrow_array::{Array, ArrayRef, UnionArray};
-use arrow_schema::{ArrowError, DataType, FieldRef, UnionFields};
+use arrow_array::{Array, ArrayRef, UInt32Array, UnionArray, new_empty_array, new_null_array};
+use arrow_buffer::ScalarBuffer;
+use arrow_schema::{ArrowError, DataType, FieldRef, UnionFields, UnionMode};
+use arrow_select::take::take;
 use arrow_select::union_extract::union_extract_by_id;
 
 use super::CastOptions;
+use std::sync::Arc;
 
 // this is used during child array selection to prefer a "close" type over a distant cast
 // for example: when targeting Utf8View, a Utf8 child is preferred over Int32 despite both being castable
@@ -157,10 +160,202 @@ pub fn union_extract_by_type(
     cast_with_options(&extracted, target_type, cast_options)
 }
 
+/// Selects the child of a union with `fields` that values of `from_type` are cast into
+///
+/// This uses the same passes as [`resolve_child_array`], in the opposite direction:
+/// an exact match, then a field of the same type family, then the first field
+/// that `from_type` can be cast to. Dictionary and run end encoded types without
+/// an exact match are resolved by their value type, as in [`can_cast_types`].
+pub(crate) fn resolve_target_child<'a>(
+    fields: &'a UnionFields,
+    from_type: &DataType,
+) -> Option<(i8, &'a FieldRef)> {
+    let exact = fields.iter().find(|(_, f)| f.data_type() == from_type);
+    if exact.is_some() {
+        return exact;
+    }
+    match from_type {
+        DataType::Dictionary(_, value_type) => resolve_target_child(fields, value_type),
+        DataType::RunEndEncoded(_, values) => resolve_target_child(fields, values.data_type()),
+        _ => fields
+            .iter()
+            .find(|(_, f)| same_type_family(from_type, f.data_type()))
+            .or_else(|| {
+                fields
+                    .iter()
+                    .find(|(_, f)| can_cast_types(from_type, f.data_type()))
+            }),
+    }
+}
+
+/// Maps each field of a union with `from` fields to the field of a union with `to` fields
+/// with the same name, returning `(from_type_id, to_type_id)` pairs
+///
+/// Returns `None` if a field has no castable counterpart, or if several fields map
+/// to the same target field
+pub(crate) fn union_field_mapping(from: &UnionFields, to: &UnionFields) -> Option<Vec<(i8, i8)>> {
+    let mut mapping: Vec<(i8, i8)> = Vec::with_capacity(from.len());
+    for (from_id, from_field) in from.iter() {
+        let (to_id, to_field) = to.iter().find(|(_, f)| f.name() == from_field.name())?;
+        if !can_cast_types(from_field.data_type(), to_field.data_type())
+            || mapping.iter().any(|(_, id)| *id == to_id)
+        {
+            return None;
+        }
+        mapping.push((from_id, to_id));
+    }
+    Some(mapping)
+}
+
+/// Casts `array` to a union with `fields`, storing every value in the child selected
+/// by [`resolve_target_child`]
+pub(crate) fn cast_to_union(
+    array: &dyn Array,
+    fields: &UnionFields,
+    mode: UnionMode,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let Some((type_id, field)) = resolve_target_child(fields, array.data_type()) else {
+        return Err(ArrowError::CastError(format!(
+            "Casting from {} to {} not supported",
+            array.data_type(),
+            DataType::Union(fields.clone(), mode)
+        )));
+    };
+
+    let len = array.len();
+    let values = cast_with_options(array, field.data_type(), cast_options)?;
+    let children = fields
+        .iter()
+        .map(|(id, f)| match mode {
+            _ if id == type_id => Arc::clone(&values),
+            UnionMode::Sparse => new_null_array(f.data_type(), len),
+            UnionMode::Dense => new_empty_array(f.data_type()),
+        })
+        .collect();
+    let offsets = match mode {
+        UnionMode::Sparse => None,
+        UnionMode::Dense => Some(dense_offsets(len)?),
+    };
+
+    Ok(Arc::new(UnionArray::try_new(
+        fields.clone(),
+        vec![type_id; len].into(),
+        offsets,
+        children,
+    )?))
+}
+
+/// Casts a union to a union with `to_fields` and `to_mode`, matching fields by name
+///
+/// Each child is cast to the type of the target field with the same name. Target
+/// fields without a counterpart are left empty.
+pub(crate) fn cast_union_to_union(
+    array: &UnionArray,
+    to_fields: &UnionFields,
+    to_mode: UnionMode,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let DataType::Union(from_fields, _) = array.data_type() else {
+        unreachable!("cast_union_to_union called on non-union array")
+    };
+    let to_type = DataType::Union(to_fields.clone(), to_mode);
+    let Some(mapping) = union_field_mapping(from_fields, to_fields) else {
+        return Err(ArrowError::CastError(format!(
+            "Casting from {} to {to_type} not supported",
+            array.data_type()
+        )));
+    };
+
+    let max_id = from_fields
+        .iter()
+        .map(|(id, _)| id)
+        .max()
+        .unwrap_or_default();
+    let mut lookup = vec![0_i8; max_id as usize + 1];
+    mapping
+        .iter()
+        .for_each(|(from, to)| lookup[*from as usize] = *to);
+
+    let len = array.len();
+    let type_ids: ScalarBuffer<i8> = array
+        .type_ids()
+        .iter()
+        .map(|id| lookup[*id as usize])
+        .collect();
+
+    let children = to_fields
+        .iter()
+        .map(|(to_id, to_field)| {
+            let Some((from_id, _)) = mapping.iter().find(|(_, id)| *id == to_id) else {
+                return Ok(match to_mode {
+                    UnionMode::Sparse => new_null_array(to_field.data_type(), len),
+                    UnionMode::Dense => new_empty_array(to_field.data_type()),
+                });
+            };
+            let child = array.child(*from_id);
+            let selected = |row: &usize| array.type_id(*row) == *from_id;
+            let values = match (array.offsets(), to_mode) {
+                (None, UnionMode::Sparse) => Arc::clone(child),
+                (_, UnionMode::Sparse) => {
+                    let indices: UInt32Array = (0..len)
+                        .map(|row| selected(&row).then(|| array.value_offset(row) as u32))
+                        .collect();
+                    take(child, &indices, None)?
+                }
+                (_, UnionMode::Dense) => {
+                    let indices: UInt32Array = (0..len)
+                        .filter(selected)
+                        .map(|row| array.value_offset(row) as u32)
+                        .collect();
+                    take(child, &indices, None)?
+                }
+            };
+            cast_with_options(&values, to_field.data_type(), cast_options)
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let offsets = match to_mode {
+        UnionMode::Sparse => None,
+        UnionMode::Dense => {
+            let mut next =
+                vec![0_i32; lookup.iter().max().copied().unwrap_or_default() as usize + 1];
+            Some(
+                type_ids
+                    .iter()
+                    .map(|id| {
+                        let offset = next[*id as usize];
+                        next[*id as usize] += 1;
+                        offset
+                    })
+                    .collect(),
+            )
+        }
+    };
+
+    Ok(Arc::new(UnionArray::try_new(
+        to_fields.clone(),
+        type_ids,
+        offsets,
+        children,
+    )?))
+}
+
+fn dense_offsets(len: usize) -> Result<ScalarBuffer<i32>, ArrowError> {
+    let end = i32::try_from(len).map_err(|_| {
+        ArrowError::CastError(format!(
+            "Offset overflow casting {len} values to dense union"
+        ))
+    })?;
+    Ok((0..end).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::cast;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int64Type};
     use arrow_array::*;
     use arrow_schema::{Field, UnionFields, UnionMode};
     use std::sync::Arc;
@@ -540,4 +735,136 @@ mod tests {
         assert_eq!(result.data_type(), &target);
         assert_eq!(result.len(), 0);
     }
+
+    // casting into a union stores every value in the best matching child.
+    // Utf8View prefers the Utf8 child over Int32, Int64 falls back to the first
+    // castable child, other children are null (sparse) or empty (dense).
+    #[test]
+    fn test_cast_to_union() {
+        let strings = StringViewArray::from(vec![Some("a"), None, Some("c")]);
+
+        for mode in [UnionMode::Sparse, UnionMode::Dense] {
+            let target = int_str_union_type(mode);
+            assert!(can_cast_types(strings.data_type(), &target));
+
+            let result = cast::cast(&strings, &target).unwrap();
+            let union = result.as_any().downcast_ref::<UnionArray>().unwrap();
+            assert_eq!(union.type_ids(), &[1_i8, 1, 1]);
+            assert_eq!(union.is_dense(), mode == UnionMode::Dense);
+            let child = union.child(1).as_string::<i32>();
+            assert_eq!(child, &StringArray::from(vec![Some("a"), None, Some("c")]));
+            let expected_len = if mode == UnionMode::Dense { 0 } else { 3 };
+            assert_eq!(union.child(0).len(), expected_len);
+
+            // round trip back through union extraction
+            let back = cast::cast(&result, &DataType::Utf8View).unwrap();
+            assert_eq!(back.as_string_view(), &strings);
+        }
+
+        let ints = Int64Array::from(vec![1, 2]);
+        let result = cast::cast(&ints, &int_str_union_type(UnionMode::Sparse)).unwrap();
+        let union = result.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(union.type_ids(), &[0_i8, 0]);
+        assert_eq!(
+            union.child(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![1, 2])
+        );
+
+        let fields = UnionFields::try_new([0], [Field::new("b", DataType::Boolean, true)]).unwrap();
+        let target = DataType::Union(fields, UnionMode::Sparse);
+        let binary = BinaryArray::from(vec![b"a".as_ref()]);
+        assert!(!can_cast_types(binary.data_type(), &target));
+        assert_eq!(
+            cast::cast(&binary, &target).unwrap_err().to_string(),
+            "Cast error: Casting from Binary to Union(Sparse, 0: (\"b\": Boolean)) not supported"
+        );
+    }
+
+    // dictionary and run end encoded values are stored in the child matching their value type
+    #[test]
+    fn test_cast_dictionary_and_run_end_encoded_to_union() {
+        let target = int_str_union_type(UnionMode::Sparse);
+        let dict: DictionaryArray<Int32Type> = vec!["a", "a", "b"].into_iter().collect();
+        let run_ends = Int32Array::from(vec![2, 3]);
+        let ree = RunArray::try_new(&run_ends, &StringArray::from(vec!["a", "b"])).unwrap();
+        let inputs: [ArrayRef; 2] = [Arc::new(dict), Arc::new(ree)];
+
+        for array in inputs {
+            assert!(can_cast_types(array.data_type(), &target));
+            let result = cast::cast(&array, &target).unwrap();
+            let union = result.as_any().downcast_ref::<UnionArray>().unwrap();
+            assert_eq!(union.type_ids(), &[1_i8, 1, 1]);
+            assert_eq!(
+                union.child(1).as_string::<i32>(),
+                &StringArray::from(vec!["a", "a", "b"])
+            );
+        }
+    }
+
+    // union to union matches fields by name, so children can be reordered, widened,
+    // change type id and switch between sparse and dense. New target fields stay empty.
+    #[test]
+    fn test_cast_union_to_union() {
+        let to_fields = UnionFields::try_new(
+            [5, 3, 1],
+            [
+                Field::new("flag", DataType::Boolean, true),
+                Field::new("str", DataType::LargeUtf8, true),
+                Field::new("int", DataType::Int64, true),
+            ],
+        )
+        .unwrap();
+
+        let sparse = UnionArray::try_new(
+            int_str_fields(),
+            vec![1_i8, 0, 1].into(),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![None, Some(42), None])) as ArrayRef,
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("b")])),
+            ],
+        )
+        .unwrap();
+        let dense = UnionArray::try_new(
+            int_str_fields(),
+            vec![1_i8, 0, 1].into(),
+            Some(vec![0_i32, 0, 1].into()),
+            vec![
+                Arc::new(Int32Array::from(vec![42])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b"])),
+            ],
+        )
+        .unwrap();
+
+        for source in [sparse, dense] {
+            for mode in [UnionMode::Sparse, UnionMode::Dense] {
+                let target = DataType::Union(to_fields.clone(), mode);
+                assert!(can_cast_types(source.data_type(), &target));
+
+                let result = cast::cast(&source, &target).unwrap();
+                assert_eq!(result.data_type(), &target);
+                let union = result.as_any().downcast_ref::<UnionArray>().unwrap();
+                assert_eq!(union.type_ids(), &[3_i8, 1, 3]);
+
+                let ints = cast::cast(&result, &DataType::Int64).unwrap();
+                assert_eq!(
+                    ints.as_primitive::<Int64Type>(),
+                    &Int64Array::from(vec![None, Some(42), None])
+                );
+                let strings = cast::cast(&result, &DataType::LargeUtf8).unwrap();
+                assert_eq!(
+                    strings.as_string::<i64>(),
+                    &LargeStringArray::from(vec![Some("a"), None, Some("b")])
+                );
+            }
+        }
+
+        // every source field needs a castable target field with the same name
+        let missing =
+            UnionFields::try_new([0], [Field::new("int", DataType::Int64, true)]).unwrap();
+        assert!(!can_cast_types(
+            &int_str_union_type(UnionMode::Sparse),
+            &DataType::Union(missing, UnionMode::Sparse)
+        ));
+    }
 }
@@ -22,7 +22,7 @@ use arrow_array::ArrowNativeTypeOp;
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_buffer::ArrowNativeType;
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, TimeUnit};
 use chrono::prelude::*;
 use half::f16;
 use std::str::FromStr;
@@ -408,6 +408,226 @@ fn string_to_time(s: &str) -> Option<NaiveTime> {
     )
 }
 
+/// Options for parsing strings into temporal types
+///
+/// By default strings are parsed with the heuristics of [`string_to_datetime`]
+/// and [`Parser::parse`]. These options parse other layouts, such as
+/// `dd/MM/yyyy HH:mm`, `yyyyMMdd` or epoch values written as strings.
+///
+/// Used by [`CastOptions`](crate::CastOptions) when casting strings to
+/// temporal types, and by the CSV and JSON readers.
+///
+/// # Example
+///
+/// ```
+/// use arrow_cast::parse::{ParseOptions, Parser};
+/// use arrow_array::types::Date32Type;
+/// use arrow_schema::TimeUnit;
+///
+/// let options = ParseOptions::new()
+///     .with_formats(["%d/%m/%Y %H:%M", "%Y.%m.%d"])
+///     .with_epoch_unit(TimeUnit::Second);
+///
+/// assert_eq!(Date32Type::parse_with_options("01/01/2021 10:30", &options), Some(18628));
+/// assert_eq!(Date32Type::parse_with_options("2021.01.01", &options), Some(18628));
+/// assert_eq!(Date32Type::parse_with_options("1609459200", &options), Some(18628));
+/// assert_eq!(Date32Type::parse_with_options("2021-01-01", &options), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    /// [chrono format strings](chrono::format::strftime), tried in order
+    formats: Vec<String>,
+    /// Timezone of inputs without an explicit offset
+    timezone: Option<String>,
+    /// Unit of integer inputs
+    epoch_unit: Option<TimeUnit>,
+}
+
+impl ParseOptions {
+    /// Creates a new set of parse options, using the default heuristics
+    pub const fn new() -> Self {
+        Self {
+            formats: Vec::new(),
+            timezone: None,
+            epoch_unit: None,
+        }
+    }
+
+    /// Sets the [chrono format strings](chrono::format::strftime) used to parse
+    /// strings, tried in order
+    ///
+    /// A format may describe a datetime with or without an offset, or only a
+    /// date, in which case the time is midnight. When any format is set,
+    /// strings matching none of them fail to parse rather than falling back
+    /// to the default heuristics.
+    pub fn with_formats<I, S>(self, formats: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            formats: formats.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
+    /// Sets the timezone of inputs without an explicit offset, such as
+    /// `+02:00` or `Europe/Paris`
+    ///
+    /// When set, this takes precedence over the timezone of the target
+    /// [`DataType::Timestamp`](arrow_schema::DataType::Timestamp).
+    ///
+    /// Returns an error if `timezone` is not a valid [`Tz`]
+    pub fn with_timezone(self, timezone: impl Into<String>) -> Result<Self, ArrowError> {
+        let timezone = timezone.into();
+        timezone.parse::<Tz>()?;
+        Ok(Self {
+            timezone: Some(timezone),
+            ..self
+        })
+    }
+
+    /// Interprets integer strings as a number of `unit` since the UNIX epoch
+    ///
+    /// For time types, the integer is the number of `unit` since midnight.
+    /// Integer strings are always parsed this way, even if they match one of
+    /// the [formats](Self::with_formats).
+    pub fn with_epoch_unit(self, unit: TimeUnit) -> Self {
+        Self {
+            epoch_unit: Some(unit),
+            ..self
+        }
+    }
+
+    /// Returns the format strings, tried in order
+    pub fn formats(&self) -> &[String] {
+        &self.formats
+    }
+
+    /// Returns the timezone of inputs without an explicit offset, if any
+    pub fn timezone(&self) -> Option<&str> {
+        self.timezone.as_deref()
+    }
+
+    /// Returns the unit of integer inputs, if any
+    pub fn epoch_unit(&self) -> Option<TimeUnit> {
+        self.epoch_unit
+    }
+
+    /// Parses a string into a [`DateTime`] according to these options
+    ///
+    /// Inputs without an explicit offset are interpreted in `timezone`,
+    /// callers should pass the parsed [`Self::timezone`] if set. When no
+    /// formats are set this falls back to [`string_to_datetime`].
+    pub fn string_to_datetime<T: TimeZone>(
+        &self,
+        timezone: &T,
+        s: &str,
+    ) -> Result<DateTime<T>, ArrowError> {
+        if let Some((value, unit)) = self.epoch_value(s) {
+            let datetime = match unit {
+                TimeUnit::Second => DateTime::from_timestamp(value, 0),
+                TimeUnit::Millisecond => DateTime::from_timestamp_millis(value),
+                TimeUnit::Microsecond => DateTime::from_timestamp_micros(value),
+                TimeUnit::Nanosecond => Some(DateTime::from_timestamp_nanos(value)),
+            };
+            return datetime
+                .map(|datetime| datetime.with_timezone(timezone))
+                .ok_or_else(|| {
+                    ArrowError::ParseError(format!(
+                        "Error parsing timestamp from '{s}': epoch value out of range"
+                    ))
+                });
+        }
+
+        if self.formats.is_empty() {
+            return string_to_datetime(timezone, s);
+        }
+
+        self.formats
+            .iter()
+            .find_map(|format| parse_formatted_datetime(timezone, s, format))
+            .ok_or_else(|| {
+                ArrowError::ParseError(format!(
+                    "Error parsing timestamp from '{s}': no matching format in {:?}",
+                    self.formats
+                ))
+            })
+    }
+
+    /// Returns the value of `s` and [`Self::epoch_unit`] if set and `s` is an integer
+    fn epoch_value(&self, s: &str) -> Option<(i64, TimeUnit)> {
+        let unit = self.epoch_unit?;
+        Some((s.parse().ok()?, unit))
+    }
+
+    /// Parses `s` as an epoch value in `unit`, or with [`Parser::parse_formatted`]
+    fn parse_temporal<P: Parser>(
+        &self,
+        s: &str,
+        unit: TimeUnit,
+        from_epoch: impl FnOnce(i64) -> Option<P::Native>,
+        parse_formatted: impl Fn(&str, &str) -> Option<P::Native>,
+    ) -> Option<P::Native> {
+        if let Some((value, epoch_unit)) = self.epoch_value(s) {
+            return from_epoch(convert_time_unit(value, epoch_unit, unit)?);
+        }
+        match self.formats.is_empty() {
+            true => P::parse(s),
+            false => self.formats.iter().find_map(|f| parse_formatted(s, f)),
+        }
+    }
+}
+
+/// Parses `s` with `format` into a [`DateTime`], interpreting inputs without
+/// an offset in `timezone`
+fn parse_formatted_datetime<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+    format: &str,
+) -> Option<DateTime<T>> {
+    if let Ok(datetime) = DateTime::parse_from_str(s, format) {
+        return Some(datetime.with_timezone(timezone));
+    }
+    let naive = NaiveDateTime::parse_from_str(s, format).ok().or_else(|| {
+        NaiveDate::parse_from_str(s, format)
+            .ok()
+            .map(|date| date.and_time(NaiveTime::MIN))
+    })?;
+    timezone.from_local_datetime(&naive).single()
+}
+
+/// Converts `value` from `from` to `to`, rounding towards negative infinity
+fn convert_time_unit(value: i64, from: TimeUnit, to: TimeUnit) -> Option<i64> {
+    let nanos = |unit| match unit {
+        TimeUnit::Second => 1_000_000_000_i64,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    };
+    let (from, to) = (nanos(from), nanos(to));
+    match from >= to {
+        true => value.checked_mul(from / to),
+        false => Some(value.div_euclid(to / from)),
+    }
+}
+
+/// Parses a timestamp of `T` according to `options`, see [`Parser::parse_with_options`]
+fn parse_timestamp_with_options<T: ArrowTimestampType>(
+    string: &str,
+    options: &ParseOptions,
+) -> Option<i64> {
+    let datetime = match options.timezone() {
+        Some(tz) => {
+            // validated by ParseOptions::with_timezone
+            let tz: Tz = tz.parse().ok()?;
+            options.string_to_datetime(&tz, string).ok()?.naive_utc()
+        }
+        None => options.string_to_datetime(&Utc, string).ok()?.naive_utc(),
+    };
+    T::from_naive_datetime(datetime, None)
+}
+
 /// Specialized parsing implementations to convert strings to Arrow types.
 ///
 /// This is used by csv and json reader and can be used directly as well.
@@ -441,6 +661,13 @@ pub trait Parser: ArrowPrimitiveType {
     fn parse_formatted(string: &str, _format: &str) -> Option<Self::Native> {
         Self::parse(string)
     }
+
+    /// Parse a string to the native type according to [`ParseOptions`]
+    ///
+    /// When not implemented, the options are unused, and this method is equivalent to [parse](#tymethod.parse)
+    fn parse_with_options(string: &str, _options: &ParseOptions) -> Option<Self::Native> {
+        Self::parse(string)
+    }
 }
 
 impl Parser for Float16Type {
@@ -532,6 +759,10 @@ impl Parser for TimestampNanosecondType {
     fn parse(string: &str) -> Option<i64> {
         string_to_timestamp_nanos(string).ok()
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<i64> {
+        parse_timestamp_with_options::<Self>(string, options)
+    }
 }
 
 impl Parser for TimestampMicrosecondType {
@@ -539,6 +770,10 @@ impl Parser for TimestampMicrosecondType {
         let nanos = string_to_timestamp_nanos(string).ok();
         nanos.map(|x| x / 1000)
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<i64> {
+        parse_timestamp_with_options::<Self>(string, options)
+    }
 }
 
 impl Parser for TimestampMillisecondType {
@@ -546,6 +781,10 @@ impl Parser for TimestampMillisecondType {
         let nanos = string_to_timestamp_nanos(string).ok();
         nanos.map(|x| x / 1_000_000)
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<i64> {
+        parse_timestamp_with_options::<Self>(string, options)
+    }
 }
 
 impl Parser for TimestampSecondType {
@@ -553,6 +792,10 @@ impl Parser for TimestampSecondType {
         let nanos = string_to_timestamp_nanos(string).ok();
         nanos.map(|x| x / 1_000_000_000)
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<i64> {
+        parse_timestamp_with_options::<Self>(string, options)
+    }
 }
 
 impl Parser for Time64NanosecondType {
@@ -567,6 +810,10 @@ impl Parser for Time64NanosecondType {
         let nt = NaiveTime::parse_from_str(string, format).ok()?;
         Some(nt.num_seconds_from_midnight() as i64 * 1_000_000_000 + nt.nanosecond() as i64)
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<Self::Native> {
+        options.parse_temporal::<Self>(string, TimeUnit::Nanosecond, Some, Self::parse_formatted)
+    }
 }
 
 impl Parser for Time64MicrosecondType {
@@ -582,6 +829,10 @@ impl Parser for Time64MicrosecondType {
         let nt = NaiveTime::parse_from_str(string, format).ok()?;
         Some(nt.num_seconds_from_midnight() as i64 * 1_000_000 + nt.nanosecond() as i64 / 1_000)
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<Self::Native> {
+        options.parse_temporal::<Self>(string, TimeUnit::Microsecond, Some, Self::parse_formatted)
+    }
 }
 
 impl Parser for Time32MillisecondType {
@@ -597,6 +848,15 @@ impl Parser for Time32MillisecondType {
         let nt = NaiveTime::parse_from_str(string, format).ok()?;
         Some(nt.num_seconds_from_midnight() as i32 * 1_000 + nt.nanosecond() as i32 / 1_000_000)
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<Self::Native> {
+        options.parse_temporal::<Self>(
+            string,
+            TimeUnit::Millisecond,
+            |v| i32::try_from(v).ok(),
+            Self::parse_formatted,
+        )
+    }
 }
 
 impl Parser for Time32SecondType {
@@ -612,6 +872,15 @@ impl Parser for Time32SecondType {
         let nt = NaiveTime::parse_from_str(string, format).ok()?;
         Some(nt.num_seconds_from_midnight() as i32 + nt.nanosecond() as i32 / 1_000_000_000)
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<Self::Native> {
+        options.parse_temporal::<Self>(
+            string,
+            TimeUnit::Second,
+            |v| i32::try_from(v).ok(),
+            Self::parse_formatted,
+        )
+    }
 }
 
 /// Number of days between 0001-01-01 and 1970-01-01
@@ -756,6 +1025,16 @@ impl Parser for Date32Type {
         let date = NaiveDate::parse_from_str(string, format).ok()?;
         Some(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<i32> {
+        let from_epoch = |seconds: i64| i32::try_from(seconds.div_euclid(86_400)).ok();
+        options.parse_temporal::<Self>(string, TimeUnit::Second, from_epoch, |s, format| {
+            Self::parse_formatted(s, format).or_else(|| {
+                let datetime = parse_formatted_datetime(&Utc, s, format)?;
+                Some(datetime.date_naive().num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+            })
+        })
+    }
 }
 
 impl Parser for Date64Type {
@@ -794,6 +1073,12 @@ impl Parser for Date64Type {
             Some(date_time.and_utc().timestamp_millis())
         }
     }
+
+    fn parse_with_options(string: &str, options: &ParseOptions) -> Option<i64> {
+        options.parse_temporal::<Self>(string, TimeUnit::Millisecond, Some, |s, format| {
+            parse_formatted_datetime(&Utc, s, format).map(|datetime| datetime.timestamp_millis())
+        })
+    }
 }
 
 fn parse_e_notation<T: DecimalType>(
@@ -2953,4 +3238,16 @@ mod tests {
         assert_eq!(Int32Type::parse("3j"), None);
         assert_eq!(Int32Type::parse("3"), Some(3));
     }
+
+    #[test]
+    fn test_parse_options_timezone() {
+        let options = ParseOptions::new().with_timezone("+01:00").unwrap();
+        assert_eq!(
+            TimestampSecondType::parse_with_options("2021-01-01T00:00:00", &options),
+            Some(1609455600)
+        );
+
+        let err = ParseOptions::new().with_timezone("Not/AZone").unwrap_err();
+        assert!(err.to_string().contains("Not/AZone"), "{err}");
+    }
 }
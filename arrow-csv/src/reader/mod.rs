@@ -168,7 +168,7 @@ mod records;
 use arrow_array::builder::{NullBuilder, PrimitiveBuilder};
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_cast::parse::{Parser, parse_decimal, string_to_datetime};
+use arrow_cast::parse::{ParseOptions, Parser, parse_decimal};
 use arrow_schema::*;
 use chrono::{TimeZone, Utc};
 use csv::StringRecord;
@@ -635,6 +635,9 @@ pub struct Decoder {
 
     /// Check if the string matches this pattern for `NULL`.
     null_regex: NullRegex,
+
+    /// Options for parsing temporal types
+    parse_options: ParseOptions,
 }
 
 impl Decoder {
@@ -695,6 +698,7 @@ impl Decoder {
             self.projection.as_ref(),
             self.line_number,
             &self.null_regex,
+            &self.parse_options,
         )?;
         self.line_number += rows.len();
         Ok(Some(batch))
@@ -732,6 +736,7 @@ fn parse(
     projection: Option<&Vec<usize>>,
     line_number: usize,
     null_regex: &NullRegex,
+    parse_options: &ParseOptions,
 ) -> Result<RecordBatch, ArrowError> {
     let projection: Vec<usize> = match projection {
         Some(v) => v.clone(),
@@ -810,23 +815,53 @@ fn parse(
                 DataType::Float64 => {
                     build_primitive_array::<Float64Type>(line_number, rows, i, null_regex)
                 }
-                DataType::Date32 => {
-                    build_primitive_array::<Date32Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Date64 => {
-                    build_primitive_array::<Date64Type>(line_number, rows, i, null_regex)
-                }
-                DataType::Time32(TimeUnit::Second) => {
-                    build_primitive_array::<Time32SecondType>(line_number, rows, i, null_regex)
-                }
+                DataType::Date32 => build_temporal_array::<Date32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    parse_options,
+                ),
+                DataType::Date64 => build_temporal_array::<Date64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    parse_options,
+                ),
+                DataType::Time32(TimeUnit::Second) => build_temporal_array::<Time32SecondType>(
+                    line_number,
+                    rows,
+                    i,
+                    null_regex,
+                    parse_options,
+                ),
                 DataType::Time32(TimeUnit::Millisecond) => {
-                    build_primitive_array::<Time32MillisecondType>(line_number, rows, i, null_regex)
+                    build_temporal_array::<Time32MillisecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        parse_options,
+                    )
                 }
                 DataType::Time64(TimeUnit::Microsecond) => {
-                    build_primitive_array::<Time64MicrosecondType>(line_number, rows, i, null_regex)
+                    build_temporal_array::<Time64MicrosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        parse_options,
+                    )
                 }
                 DataType::Time64(TimeUnit::Nanosecond) => {
-                    build_primitive_array::<Time64NanosecondType>(line_number, rows, i, null_regex)
+                    build_temporal_array::<Time64NanosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                        parse_options,
+                    )
                 }
                 DataType::Timestamp(TimeUnit::Second, tz) => {
                     build_timestamp_array::<TimestampSecondType>(
@@ -835,6 +870,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        parse_options,
                     )
                 }
                 DataType::Timestamp(TimeUnit::Millisecond, tz) => {
@@ -844,6 +880,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        parse_options,
                     )
                 }
                 DataType::Timestamp(TimeUnit::Microsecond, tz) => {
@@ -853,6 +890,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        parse_options,
                     )
                 }
                 DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
@@ -862,6 +900,7 @@ fn parse(
                         i,
                         tz.as_deref(),
                         null_regex,
+                        parse_options,
                     )
                 }
                 DataType::Null => Ok(Arc::new({
@@ -1033,6 +1072,29 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
     rows: &StringRecords<'_>,
     col_idx: usize,
     null_regex: &NullRegex,
+) -> Result<ArrayRef, ArrowError> {
+    parse_primitive_array::<T>(line_number, rows, col_idx, null_regex, T::parse)
+}
+
+// parses a specific column (col_idx) of dates or times into an Arrow Array.
+fn build_temporal_array<T: ArrowPrimitiveType + Parser>(
+    line_number: usize,
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    null_regex: &NullRegex,
+    parse_options: &ParseOptions,
+) -> Result<ArrayRef, ArrowError> {
+    parse_primitive_array::<T>(line_number, rows, col_idx, null_regex, |s| {
+        T::parse_with_options(s, parse_options)
+    })
+}
+
+fn parse_primitive_array<T: ArrowPrimitiveType>(
+    line_number: usize,
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    null_regex: &NullRegex,
+    parse: impl Fn(&str) -> Option<T::Native>,
 ) -> Result<ArrayRef, ArrowError> {
     rows.iter()
         .enumerate()
@@ -1042,7 +1104,7 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
                 return Ok(None);
             }
 
-            match T::parse(s) {
+            match parse(s) {
                 Some(e) => Ok(Some(e)),
                 None => Err(ArrowError::ParseError(format!(
                     // TODO: we should surface the underlying error here.
@@ -1065,15 +1127,31 @@ fn build_timestamp_array<T: ArrowTimestampType>(
     col_idx: usize,
     timezone: Option<&str>,
     null_regex: &NullRegex,
+    parse_options: &ParseOptions,
 ) -> Result<ArrayRef, ArrowError> {
-    Ok(Arc::new(match timezone {
-        Some(timezone) => {
-            let tz: Tz = timezone.parse()?;
-            build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &tz, null_regex)?
-                .with_timezone(timezone)
+    // inputs without an offset are in the timezone of the parse options, if any
+    let array = match parse_options.timezone().or(timezone) {
+        Some(naive_tz) => {
+            let tz: Tz = naive_tz.parse()?;
+            build_timestamp_array_impl::<T, _>(
+                line_number,
+                rows,
+                col_idx,
+                &tz,
+                null_regex,
+                parse_options,
+            )?
         }
-        None => build_timestamp_array_impl::<T, _>(line_number, rows, col_idx, &Utc, null_regex)?,
-    }))
+        None => build_timestamp_array_impl::<T, _>(
+            line_number,
+            rows,
+            col_idx,
+            &Utc,
+            null_regex,
+            parse_options,
+        )?,
+    };
+    Ok(Arc::new(array.with_timezone_opt(timezone)))
 }
 
 fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
@@ -1082,6 +1160,7 @@ fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
     col_idx: usize,
     timezone: &Tz,
     null_regex: &NullRegex,
+    parse_options: &ParseOptions,
 ) -> Result<PrimitiveArray<T>, ArrowError> {
     rows.iter()
         .enumerate()
@@ -1091,7 +1170,8 @@ fn build_timestamp_array_impl<T: ArrowTimestampType, Tz: TimeZone>(
                 return Ok(None);
             }
 
-            let date = string_to_datetime(timezone, s)
+            let date = parse_options
+                .string_to_datetime(timezone, s)
                 .and_then(|date| match T::UNIT {
                     TimeUnit::Second => Ok(date.timestamp()),
                     TimeUnit::Millisecond => Ok(date.timestamp_millis()),
@@ -1162,6 +1242,8 @@ pub struct ReaderBuilder {
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<usize>>,
+    /// Options for parsing temporal types
+    parse_options: ParseOptions,
 }
 
 impl ReaderBuilder {
@@ -1194,6 +1276,7 @@ impl ReaderBuilder {
             batch_size: 1024,
             bounds: None,
             projection: None,
+            parse_options: ParseOptions::default(),
         }
     }
 
@@ -1283,6 +1366,14 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set the [`ParseOptions`] used to parse date, time and timestamp columns
+    ///
+    /// By default these are parsed with the heuristics of [`arrow_cast::parse`]
+    pub fn with_parse_options(mut self, parse_options: ParseOptions) -> Self {
+        self.parse_options = parse_options;
+        self
+    }
+
     /// Create a new `Reader` from a non-buffered reader
     ///
     /// If `R: BufRead` consider using [`Self::build_buffered`] to avoid unnecessary additional
@@ -1325,6 +1416,7 @@ impl ReaderBuilder {
             projection: self.projection,
             batch_size: self.batch_size,
             null_regex: self.format.null_regex,
+            parse_options: self.parse_options,
         }
     }
 }
@@ -2143,6 +2235,53 @@ mod tests {
         test_parse_timestamp_impl::<TimestampSecondType>(Some("-03".into()), &[10_800, 0, -7_200]);
     }
 
+    #[test]
+    fn test_parse_with_parse_options() {
+        let csv = "01/01/1970 01:00,02/01/1970,7200\n";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("ts", DataType::Timestamp(TimeUnit::Second, None), true),
+            Field::new("date", DataType::Date32, true),
+            Field::new(
+                "epoch",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                true,
+            ),
+        ]));
+        let options = ParseOptions::new()
+            .with_formats(["%d/%m/%Y %H:%M", "%d/%m/%Y"])
+            .with_timezone("+01:00")
+            .unwrap()
+            .with_epoch_unit(TimeUnit::Second);
+
+        let mut reader = ReaderBuilder::new(schema)
+            .with_parse_options(options)
+            .build(Cursor::new(csv))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let ts = batch.column(0).as_primitive::<TimestampSecondType>();
+        assert_eq!(ts.values(), &[0]);
+        let date = batch.column(1).as_primitive::<Date32Type>();
+        assert_eq!(date.values(), &[1]);
+        let epoch = batch.column(2).as_primitive::<TimestampMillisecondType>();
+        assert_eq!(epoch.values(), &[7_200_000]);
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "ts",
+            DataType::Timestamp(TimeUnit::Second, None),
+            true,
+        )]));
+        let mut reader = ReaderBuilder::new(schema)
+            .with_parse_options(ParseOptions::new().with_formats(["%d/%m/%Y %H:%M"]))
+            .build(Cursor::new("1970-01-01T00:00:00\n"))
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error parsing column 0 at line 0: Parser error: Error parsing timestamp from '1970-01-01T00:00:00': no matching format in [\"%d/%m/%Y %H:%M\"]"
+        );
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // Takes too long
     fn test_infer_schema_from_multiple_files() {
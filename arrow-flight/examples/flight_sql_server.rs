@@ -43,7 +43,7 @@ use arrow_flight::sql::{
     ActionBeginTransactionResult, ActionCancelQueryRequest, ActionCancelQueryResult,
     ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
     ActionCreatePreparedStatementResult, ActionCreatePreparedSubstraitPlanRequest,
-    ActionEndSavepointRequest, ActionEndTransactionRequest, Any, CommandGetCatalogs,
+    ActionEndSavepointRequest, ActionEndTransactionRequest, Any, CancelResult, CommandGetCatalogs,
     CommandGetCrossReference, CommandGetDbSchemas, CommandGetExportedKeys, CommandGetImportedKeys,
     CommandGetPrimaryKeys, CommandGetSqlInfo, CommandGetTableTypes, CommandGetTables,
     CommandGetXdbcTypeInfo, CommandPreparedStatementQuery, CommandPreparedStatementUpdate,
@@ -69,6 +69,7 @@ macro_rules! status {
 const FAKE_TOKEN: &str = "uuid_token";
 const FAKE_HANDLE: &str = "uuid_handle";
 const FAKE_UPDATE_RESULT: i64 = 1;
+const FAKE_SAVEPOINT: &str = "uuid_savepoint";
 
 static INSTANCE_SQL_DATA: Lazy<SqlInfoData> = Lazy::new(|| {
     let mut builder = SqlInfoDataBuilder::new();
@@ -141,6 +142,51 @@ impl FlightSqlServiceImpl {
         let cols = vec![Arc::new(builder.finish()) as ArrayRef];
         RecordBatch::try_new(Arc::new(schema), cols)
     }
+
+    #[allow(clippy::result_large_err)]
+    fn fake_flight_info(handle: &str) -> Result<FlightInfo, Status> {
+        let batch = Self::fake_result().map_err(|e| status!("Could not fake a result", e))?;
+        let schema = (*batch.schema()).clone();
+        let num_rows = batch.num_rows();
+        let num_bytes = batch.get_array_memory_size();
+
+        let fetch = FetchResults {
+            handle: handle.to_string(),
+        };
+        let buf = fetch.as_any().encode_to_vec().into();
+        let ticket = Ticket { ticket: buf };
+        let endpoint = FlightEndpoint {
+            ticket: Some(ticket),
+            location: vec![],
+            expiration_time: None,
+            app_metadata: vec![].into(),
+        };
+        let info = FlightInfo::new()
+            .try_with_schema(&schema)
+            .map_err(|e| status!("Unable to serialize schema", e))?
+            .with_descriptor(FlightDescriptor::new_cmd(vec![]))
+            .with_endpoint(endpoint)
+            .with_total_records(num_rows as i64)
+            .with_total_bytes(num_bytes as i64)
+            .with_ordered(false);
+        Ok(info)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn fake_prepared_statement() -> Result<ActionCreatePreparedStatementResult, Status> {
+        let record_batch =
+            Self::fake_result().map_err(|e| status!("Error getting result schema", e))?;
+        let schema = record_batch.schema_ref();
+        let message = SchemaAsIpc::new(schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(schema_bytes) = message;
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: FAKE_HANDLE.into(),
+            dataset_schema: schema_bytes,
+            parameter_schema: Default::default(), // TODO: parameters
+        })
+    }
 }
 
 #[tonic::async_trait]
@@ -232,11 +278,10 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_substrait_plan(
         &self,
         _query: CommandStatementSubstraitPlan,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented(
-            "get_flight_info_substrait_plan not implemented",
-        ))
+        self.check_token(&request)?;
+        Ok(Response::new(Self::fake_flight_info(FAKE_HANDLE)?))
     }
 
     async fn get_flight_info_prepared_statement(
@@ -248,32 +293,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let handle = std::str::from_utf8(&cmd.prepared_statement_handle)
             .map_err(|e| status!("Unable to parse handle", e))?;
 
-        let batch = Self::fake_result().map_err(|e| status!("Could not fake a result", e))?;
-        let schema = (*batch.schema()).clone();
-        let num_rows = batch.num_rows();
-        let num_bytes = batch.get_array_memory_size();
-
-        let fetch = FetchResults {
-            handle: handle.to_string(),
-        };
-        let buf = fetch.as_any().encode_to_vec().into();
-        let ticket = Ticket { ticket: buf };
-        let endpoint = FlightEndpoint {
-            ticket: Some(ticket),
-            location: vec![],
-            expiration_time: None,
-            app_metadata: vec![].into(),
-        };
-        let info = FlightInfo::new()
-            .try_with_schema(&schema)
-            .map_err(|e| status!("Unable to serialize schema", e))?
-            .with_descriptor(FlightDescriptor::new_cmd(vec![]))
-            .with_endpoint(endpoint)
-            .with_total_records(num_rows as i64)
-            .with_total_bytes(num_bytes as i64)
-            .with_ordered(false);
-
-        let resp = Response::new(info);
+        let resp = Response::new(Self::fake_flight_info(handle)?);
         Ok(resp)
     }
 
@@ -630,9 +650,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _ticket: CommandStatementSubstraitPlan,
         _request: Request<PeekableFlightDataStream>,
     ) -> Result<i64, Status> {
-        Err(Status::unimplemented(
-            "do_put_substrait_plan not implemented",
-        ))
+        Ok(FAKE_UPDATE_RESULT)
     }
 
     async fn do_put_prepared_statement_query(
@@ -661,19 +679,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<Action>,
     ) -> Result<ActionCreatePreparedStatementResult, Status> {
         self.check_token(&request)?;
-        let record_batch =
-            Self::fake_result().map_err(|e| status!("Error getting result schema", e))?;
-        let schema = record_batch.schema_ref();
-        let message = SchemaAsIpc::new(schema, &IpcWriteOptions::default())
-            .try_into()
-            .map_err(|e| status!("Unable to serialize schema", e))?;
-        let IpcMessage(schema_bytes) = message;
-        let res = ActionCreatePreparedStatementResult {
-            prepared_statement_handle: FAKE_HANDLE.into(),
-            dataset_schema: schema_bytes,
-            parameter_schema: Default::default(), // TODO: parameters
-        };
-        Ok(res)
+        Self::fake_prepared_statement()
     }
 
     async fn do_action_close_prepared_statement(
@@ -687,11 +693,10 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_action_create_prepared_substrait_plan(
         &self,
         _query: ActionCreatePreparedSubstraitPlanRequest,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> Result<ActionCreatePreparedStatementResult, Status> {
-        Err(Status::unimplemented(
-            "Implement do_action_create_prepared_substrait_plan",
-        ))
+        self.check_token(&request)?;
+        Self::fake_prepared_statement()
     }
 
     async fn do_action_begin_transaction(
@@ -715,25 +720,37 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_action_begin_savepoint(
         &self,
         _query: ActionBeginSavepointRequest,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> Result<ActionBeginSavepointResult, Status> {
-        Err(Status::unimplemented("Implement do_action_begin_savepoint"))
+        self.check_token(&request)?;
+        Ok(ActionBeginSavepointResult {
+            savepoint_id: FAKE_SAVEPOINT.into(),
+        })
     }
 
     async fn do_action_end_savepoint(
         &self,
-        _query: ActionEndSavepointRequest,
-        _request: Request<Action>,
+        query: ActionEndSavepointRequest,
+        request: Request<Action>,
     ) -> Result<(), Status> {
-        Err(Status::unimplemented("Implement do_action_end_savepoint"))
+        self.check_token(&request)?;
+        if query.savepoint_id != FAKE_SAVEPOINT.as_bytes() {
+            return Err(Status::not_found("Unknown savepoint"));
+        }
+        Ok(())
     }
 
     async fn do_action_cancel_query(
         &self,
-        _query: ActionCancelQueryRequest,
-        _request: Request<Action>,
+        query: ActionCancelQueryRequest,
+        request: Request<Action>,
     ) -> Result<ActionCancelQueryResult, Status> {
-        Err(Status::unimplemented("Implement do_action_cancel_query"))
+        self.check_token(&request)?;
+        FlightInfo::decode(query.info).map_err(|e| status!("Unable to decode FlightInfo", e))?;
+        // queries are executed eagerly, so have already completed
+        Ok(ActionCancelQueryResult {
+            result: CancelResult::NotCancellable as i32,
+        })
     }
 
     async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
@@ -807,6 +824,8 @@ mod tests {
 
     use arrow_cast::pretty::pretty_format_batches;
     use arrow_flight::sql::client::FlightSqlServiceClient;
+    use arrow_flight::sql::{EndSavepoint, SubstraitPlan};
+    use bytes::Bytes;
     use tonic::transport::server::TcpIncoming;
     use tonic::transport::{Certificate, Endpoint};
     use tower::service_fn;
@@ -1047,6 +1066,108 @@ mod tests {
         .await
     }
 
+    fn substrait_plan() -> SubstraitPlan {
+        SubstraitPlan {
+            plan: Bytes::from_static(b"fake plan"),
+            version: "0.1.0".to_string(),
+        }
+    }
+
+    async fn fetch_results(
+        client: &mut FlightSqlServiceClient<Channel>,
+        flight_info: FlightInfo,
+    ) -> String {
+        let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+        let flight_data = client.do_get(ticket).await.unwrap();
+        let batches: Vec<_> = flight_data.try_collect().await.unwrap();
+        pretty_format_batches(batches.as_slice())
+            .unwrap()
+            .to_string()
+    }
+
+    const EXPECTED_RESULTS: &str = r#"+-------------------+
+| salutation        |
++-------------------+
+| Hello, FlightSQL! |
++-------------------+"#;
+
+    #[tokio::test]
+    async fn test_execute_substrait() {
+        test_all_clients(|mut client| async move {
+            auth_client(&mut client).await;
+
+            let flight_info = client
+                .execute_substrait(substrait_plan(), None)
+                .await
+                .unwrap();
+            assert_eq!(
+                fetch_results(&mut client, flight_info).await,
+                EXPECTED_RESULTS
+            );
+
+            let mut stmt = client
+                .prepare_substrait(substrait_plan(), None)
+                .await
+                .unwrap();
+            let flight_info = stmt.execute().await.unwrap();
+            assert_eq!(
+                fetch_results(&mut client, flight_info).await,
+                EXPECTED_RESULTS
+            );
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_execute_substrait_update() {
+        test_all_clients(|mut client| async move {
+            auth_client(&mut client).await;
+            let res = client
+                .execute_substrait_update(substrait_plan(), Some(Bytes::from_static(b"txn")))
+                .await
+                .unwrap();
+            assert_eq!(res, FAKE_UPDATE_RESULT);
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_savepoint() {
+        test_all_clients(|mut client| async move {
+            auth_client(&mut client).await;
+            let savepoint_id = client
+                .begin_savepoint(Bytes::from_static(b"txn"), "sp".to_string())
+                .await
+                .unwrap();
+            assert_eq!(savepoint_id, FAKE_SAVEPOINT.as_bytes());
+            client
+                .end_savepoint(savepoint_id, EndSavepoint::Rollback)
+                .await
+                .unwrap();
+
+            let err = client
+                .end_savepoint(Bytes::from_static(b"unknown"), EndSavepoint::Release)
+                .await
+                .unwrap_err();
+            assert_contains(err.to_string(), "Unknown savepoint");
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_cancel_query() {
+        test_all_clients(|mut client| async move {
+            auth_client(&mut client).await;
+            let flight_info = client
+                .execute_substrait(substrait_plan(), None)
+                .await
+                .unwrap();
+            let res = client.cancel_query(flight_info).await.unwrap();
+            assert_eq!(res, CancelResult::NotCancellable);
+        })
+        .await
+    }
+
     fn assert_contains(actual: impl AsRef<str>, searched_for: impl AsRef<str>) {
         let actual = actual.as_ref();
         let searched_for = searched_for.as_ref();
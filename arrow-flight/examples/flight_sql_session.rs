@@ -0,0 +1,341 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Example of Flight SQL session options, where the session is identified by
+//! an `arrow_flight_session_id` cookie.
+//!
+//! The server side [`SessionLayer`] assigns a session id to requests without a
+//! cookie and returns it in a `set-cookie` header, and the client side
+//! [`CookieService`] resends the most recently received cookie.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use arrow_flight::flight_service_server::FlightServiceServer;
+use arrow_flight::sql::SqlInfo;
+use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::server::FlightSqlService;
+use arrow_flight::{
+    Action, CloseSessionRequest, CloseSessionResult, GetSessionOptionsRequest,
+    GetSessionOptionsResult, SessionOptionValue, SetSessionOptionsRequest, SetSessionOptionsResult,
+    close_session_result, set_session_options_result,
+};
+use futures::future::BoxFuture;
+use http::header::{COOKIE, SET_COOKIE};
+use http::{HeaderMap, HeaderValue};
+use tokio::net::TcpListener;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::{Channel, Endpoint, Server};
+use tonic::{Request, Status};
+use tower::{Layer, Service};
+use uuid::Uuid;
+
+/// The cookie suggested by the Flight protocol to reference a session
+const SESSION_COOKIE: &str = "arrow_flight_session_id";
+
+/// Options this server understands
+const SUPPORTED_OPTIONS: &[&str] = &["catalog", "schema", "timezone"];
+
+/// The session id of a request, added to its extensions by [`SessionLayer`]
+#[derive(Debug, Clone)]
+struct SessionId(String);
+
+/// Server middleware assigning a [`SessionId`] to every request
+#[derive(Debug, Clone, Copy, Default)]
+struct SessionLayer;
+
+impl<S> Layer<S> for SessionLayer {
+    type Service = SessionService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        SessionService { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SessionService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for SessionService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<ReqBody>) -> Self::Future {
+        let (session_id, is_new) = match session_cookie(request.headers()) {
+            Some(session_id) => (session_id, false),
+            None => (Uuid::new_v4().to_string(), true),
+        };
+        request
+            .extensions_mut()
+            .insert(SessionId(session_id.clone()));
+
+        let response = self.inner.call(request);
+        Box::pin(async move {
+            let mut response = response.await?;
+            if is_new {
+                let cookie = format!("{SESSION_COOKIE}={session_id}");
+                let cookie = HeaderValue::from_str(&cookie).expect("valid cookie");
+                response.headers_mut().insert(SET_COOKIE, cookie);
+            }
+            Ok(response)
+        })
+    }
+}
+
+/// Returns the value of the session cookie, if any
+fn session_cookie(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(name, _)| *name == SESSION_COOKIE)
+        .map(|(_, value)| value.to_string())
+}
+
+/// Client middleware storing the cookie set by the server and sending it
+/// with every subsequent request
+#[derive(Debug, Clone)]
+pub struct CookieService<S> {
+    inner: S,
+    cookie: Arc<Mutex<Option<HeaderValue>>>,
+}
+
+impl<S> CookieService<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            cookie: Default::default(),
+        }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for CookieService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<ReqBody>) -> Self::Future {
+        if let Some(cookie) = self.cookie.lock().unwrap().clone() {
+            request.headers_mut().insert(COOKIE, cookie);
+        }
+
+        let cookie = Arc::clone(&self.cookie);
+        let response = self.inner.call(request);
+        Box::pin(async move {
+            let response = response.await?;
+            // Only keep the `name=value` pair, dropping attributes such as `Path`
+            let set_cookie = response
+                .headers()
+                .get(SET_COOKIE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.split(';').next())
+                .and_then(|value| HeaderValue::from_str(value.trim()).ok());
+            if let Some(set_cookie) = set_cookie {
+                *cookie.lock().unwrap() = Some(set_cookie);
+            }
+            Ok(response)
+        })
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct FlightSqlServiceImpl {
+    sessions: Arc<Mutex<HashMap<String, HashMap<String, SessionOptionValue>>>>,
+}
+
+impl FlightSqlServiceImpl {
+    #[allow(clippy::result_large_err)]
+    fn session_id<T>(request: &Request<T>) -> Result<String, Status> {
+        request
+            .extensions()
+            .get::<SessionId>()
+            .map(|SessionId(id)| id.clone())
+            .ok_or_else(|| Status::internal("SessionLayer not installed"))
+    }
+}
+
+#[tonic::async_trait]
+impl FlightSqlService for FlightSqlServiceImpl {
+    type FlightService = FlightSqlServiceImpl;
+
+    async fn do_action_set_session_options(
+        &self,
+        query: SetSessionOptionsRequest,
+        request: Request<Action>,
+    ) -> Result<SetSessionOptionsResult, Status> {
+        let session_id = Self::session_id(&request)?;
+        let mut sessions = self.sessions.lock().unwrap();
+        let options = sessions.entry(session_id).or_default();
+
+        let mut result = SetSessionOptionsResult::default();
+        for (name, value) in query.session_options {
+            if !SUPPORTED_OPTIONS.contains(&name.as_str()) {
+                result =
+                    result.with_error(name, set_session_options_result::ErrorValue::InvalidName);
+            } else if value.option_value.is_none() {
+                options.remove(&name);
+            } else {
+                options.insert(name, value);
+            }
+        }
+        Ok(result)
+    }
+
+    async fn do_action_get_session_options(
+        &self,
+        _query: GetSessionOptionsRequest,
+        request: Request<Action>,
+    ) -> Result<GetSessionOptionsResult, Status> {
+        let session_id = Self::session_id(&request)?;
+        match self.sessions.lock().unwrap().get(&session_id) {
+            Some(options) => Ok(GetSessionOptionsResult {
+                session_options: options.clone(),
+            }),
+            None => Err(Status::not_found(format!("Unknown session {session_id}"))),
+        }
+    }
+
+    async fn do_action_close_session(
+        &self,
+        _query: CloseSessionRequest,
+        request: Request<Action>,
+    ) -> Result<CloseSessionResult, Status> {
+        let session_id = Self::session_id(&request)?;
+        match self.sessions.lock().unwrap().remove(&session_id) {
+            Some(_) => Ok(CloseSessionResult::new(
+                close_session_result::Status::Closed,
+            )),
+            None => Err(Status::not_found(format!("Unknown session {session_id}"))),
+        }
+    }
+
+    async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+}
+
+/// Serve a [`FlightSqlServiceImpl`] with session support on `listener`
+async fn serve(listener: TcpListener) -> Result<(), tonic::transport::Error> {
+    let svc = FlightServiceServer::new(FlightSqlServiceImpl::default());
+    Server::builder()
+        .layer(SessionLayer)
+        .add_service(svc)
+        .serve_with_incoming(TcpIncoming::from(listener))
+        .await
+}
+
+/// Connect a client that keeps track of the session cookie
+async fn connect(
+    addr: SocketAddr,
+) -> Result<FlightSqlServiceClient<CookieService<Channel>>, tonic::transport::Error> {
+    let channel = Endpoint::new(format!("http://{addr}"))?.connect().await?;
+    Ok(FlightSqlServiceClient::new(CookieService::new(channel)))
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    tokio::spawn(serve(listener));
+
+    let mut client = connect(addr).await?;
+    let result = client
+        .set_session_options(SetSessionOptionsRequest::new([
+            ("catalog", "sales"),
+            ("timezone", "Europe/Berlin"),
+            ("unknown", "value"),
+        ]))
+        .await?;
+    for (name, error) in result.errors {
+        println!("Could not set {name}: {}", error.value().as_str_name());
+    }
+
+    let options = client.get_session_options().await?;
+    for (name, value) in options.session_options {
+        println!("{name} = {:?}", value.option_value);
+    }
+
+    let result = client.close_session().await?;
+    println!("Session {}", result.status().as_str_name());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_session_options() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener));
+
+        let mut client = connect(addr).await.unwrap();
+        let result = client
+            .set_session_options(SetSessionOptionsRequest::new([
+                ("catalog", "sales"),
+                ("unknown", "value"),
+            ]))
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            SetSessionOptionsResult::default().with_error(
+                "unknown",
+                set_session_options_result::ErrorValue::InvalidName
+            )
+        );
+
+        // the options are only visible to the same session
+        let mut other_client = connect(addr).await.unwrap();
+        let err = other_client.get_session_options().await.unwrap_err();
+        assert!(err.to_string().contains("Unknown session"), "{err}");
+
+        let options = client.get_session_options().await.unwrap();
+        assert_eq!(
+            options,
+            GetSessionOptionsResult::new([("catalog", "sales")])
+        );
+
+        let result = client.close_session().await.unwrap();
+        assert_eq!(result.status(), close_session_result::Status::Closed);
+
+        let err = client.get_session_options().await.unwrap_err();
+        assert!(err.to_string().contains("Unknown session"), "{err}");
+    }
+}
@@ -17,7 +17,13 @@
 
 //! A command line client for Arrow Flight SQL.
 
-use std::{sync::Arc, time::Duration};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use anyhow::{Context, Result, bail};
 use arrow_array::{ArrayRef, Datum, RecordBatch, StringArray};
@@ -25,12 +31,18 @@ use arrow_cast::{CastOptions, cast_with_options, pretty::pretty_format_batches};
 use arrow_flight::{
     FlightInfo,
     flight_service_client::FlightServiceClient,
-    sql::{CommandGetDbSchemas, CommandGetTables, client::FlightSqlServiceClient},
+    sql::{
+        CommandGetDbSchemas, CommandGetTables, EndSavepoint, SubstraitPlan,
+        client::{FlightSqlServiceClient, PreparedStatement},
+    },
 };
-use arrow_schema::Schema;
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{Schema, SchemaRef};
 use clap::{Parser, Subcommand, ValueEnum};
 use core::str;
 use futures::TryStreamExt;
+use parquet::arrow::ArrowWriter;
+use rustyline::{DefaultEditor, error::ReadlineError};
 use tonic::{
     metadata::MetadataMap,
     transport::{Channel, ClientTlsConfig, Endpoint},
@@ -74,6 +86,72 @@ impl From<CompressionEncoding> for tonic::codec::CompressionEncoding {
     }
 }
 
+/// Format in which query results are written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable table.
+    Table,
+    /// Comma separated values, with a header row.
+    Csv,
+    /// A single JSON array of objects.
+    Json,
+    /// Newline delimited JSON objects.
+    Ndjson,
+    /// Arrow IPC file.
+    Arrow,
+    /// Parquet file.
+    Parquet,
+}
+
+impl OutputFormat {
+    /// Whether this format is binary, and so must not be written to a terminal.
+    fn is_binary(self) -> bool {
+        matches!(self, Self::Arrow | Self::Parquet)
+    }
+}
+
+/// Action to take when ending a savepoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SavepointAction {
+    Release,
+    Rollback,
+}
+
+impl From<SavepointAction> for EndSavepoint {
+    fn from(action: SavepointAction) -> Self {
+        match action {
+            SavepointAction::Release => Self::Release,
+            SavepointAction::Rollback => Self::Rollback,
+        }
+    }
+}
+
+/// Substrait plan CLI config.
+#[derive(Debug, Parser)]
+struct SubstraitPlanArgs {
+    /// Path to a file containing a serialized Substrait plan.
+    ///
+    /// Required.
+    plan: PathBuf,
+
+    /// Substrait version used to produce the plan.
+    ///
+    /// Example: `--version 0.31.0`
+    #[clap(long, default_value = "")]
+    version: String,
+}
+
+impl SubstraitPlanArgs {
+    fn read_plan(&self) -> Result<SubstraitPlan> {
+        let plan = std::fs::read(&self.plan)
+            .with_context(|| format!("read substrait plan from {}", self.plan.display()))?;
+        Ok(SubstraitPlan {
+            plan: plan.into(),
+            version: self.version.clone(),
+        })
+    }
+}
+
 #[derive(Debug, Parser)]
 struct ClientArgs {
     /// Additional headers.
@@ -165,6 +243,17 @@ struct Args {
     #[clap(flatten)]
     client_args: ClientArgs,
 
+    /// Output format of query results.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+
+    /// File to write query results to.
+    ///
+    /// Defaults to stdout. Results are written as they are received. Required for
+    /// the binary `arrow` and `parquet` formats.
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+
     #[clap(subcommand)]
     cmd: Command,
 }
@@ -245,6 +334,74 @@ enum Command {
         #[clap(short, value_parser = parse_key_val)]
         params: Vec<(String, String)>,
     },
+
+    /// Execute given Substrait plan.
+    SubstraitQuery {
+        #[clap(flatten)]
+        plan: SubstraitPlanArgs,
+    },
+
+    /// Prepare given Substrait plan and then execute it.
+    PreparedSubstraitQuery {
+        #[clap(flatten)]
+        plan: SubstraitPlanArgs,
+    },
+
+    /// Execute given Substrait plan as an update and print the number of affected rows.
+    SubstraitUpdate {
+        #[clap(flatten)]
+        plan: SubstraitPlanArgs,
+
+        /// Transaction to execute the update in.
+        ///
+        /// If not provided, the update is executed outside of a transaction.
+        #[clap(long)]
+        transaction_id: Option<String>,
+    },
+
+    /// Create a savepoint within a transaction and print its id.
+    BeginSavepoint {
+        /// Transaction to create the savepoint in.
+        ///
+        /// Required.
+        transaction_id: String,
+
+        /// Name of the savepoint.
+        ///
+        /// Required.
+        name: String,
+    },
+
+    /// Release or roll back a savepoint.
+    EndSavepoint {
+        /// Savepoint id as returned by `begin-savepoint`.
+        ///
+        /// Required.
+        savepoint_id: String,
+
+        /// Whether to release or roll back the savepoint.
+        #[clap(value_enum)]
+        action: SavepointAction,
+    },
+
+    /// Execute given statement, request its cancellation and print the result.
+    CancelQuery {
+        /// SQL query.
+        ///
+        /// Required.
+        query: String,
+    },
+
+    /// Start an interactive session reading statements from stdin.
+    ///
+    /// Each line is executed as a statement using a single connection. Lines starting
+    /// with `\` are commands, see `\help`. On a terminal, lines can be edited and
+    /// previous lines recalled with the arrow keys.
+    Repl {
+        /// File to load history from and append executed lines to.
+        #[clap(long)]
+        history_file: Option<PathBuf>,
+    },
 }
 
 #[tokio::main]
@@ -295,45 +452,176 @@ async fn main() -> Result<()> {
                 .prepare(query, None)
                 .await
                 .context("prepare statement")?;
-
-            if !params.is_empty() {
-                prepared_stmt
-                    .set_parameters(
-                        construct_record_batch_from_params(
-                            &params,
-                            prepared_stmt
-                                .parameter_schema()
-                                .context("get parameter schema")?,
-                        )
-                        .context("construct parameters")?,
-                    )
-                    .context("bind parameters")?;
-            }
+            bind_parameters(&mut prepared_stmt, &params)?;
 
             prepared_stmt
                 .execute()
                 .await
                 .context("execute prepared statement")?
         }
+        Command::SubstraitQuery { plan } => client
+            .execute_substrait(plan.read_plan()?, None)
+            .await
+            .context("execute substrait plan")?,
+        Command::PreparedSubstraitQuery { plan } => client
+            .prepare_substrait(plan.read_plan()?, None)
+            .await
+            .context("prepare substrait plan")?
+            .execute()
+            .await
+            .context("execute prepared substrait plan")?,
+        Command::SubstraitUpdate {
+            plan,
+            transaction_id,
+        } => {
+            let count = client
+                .execute_substrait_update(plan.read_plan()?, transaction_id.map(Into::into))
+                .await
+                .context("execute substrait update")?;
+            println!("{count}");
+            return Ok(());
+        }
+        Command::BeginSavepoint {
+            transaction_id,
+            name,
+        } => {
+            let savepoint_id = client
+                .begin_savepoint(transaction_id.into(), name)
+                .await
+                .context("begin savepoint")?;
+            println!("{}", String::from_utf8_lossy(&savepoint_id));
+            return Ok(());
+        }
+        Command::EndSavepoint {
+            savepoint_id,
+            action,
+        } => {
+            client
+                .end_savepoint(savepoint_id.into(), action.into())
+                .await
+                .context("end savepoint")?;
+            return Ok(());
+        }
+        Command::CancelQuery { query } => {
+            let flight_info = client
+                .execute(query, None)
+                .await
+                .context("execute statement")?;
+            let result = client
+                .cancel_query(flight_info)
+                .await
+                .context("cancel query")?;
+            println!("{}", result.as_str_name());
+            return Ok(());
+        }
+        Command::Repl { history_file } => {
+            if args.output.is_some() {
+                bail!("--output is not supported in interactive mode");
+            }
+            if args.format.is_binary() {
+                bail!("binary output formats are not supported in interactive mode");
+            }
+            return run_repl(&mut client, args.format, history_file.as_deref()).await;
+        }
     };
 
-    let batches = execute_flight(&mut client, flight_info)
+    if args.format.is_binary() && args.output.is_none() {
+        bail!("binary output formats require --output");
+    }
+    let output = open_output(args.output.as_deref())?;
+    execute_flight(&mut client, flight_info, args.format, output)
         .await
         .context("read flight data")?;
 
-    let res = pretty_format_batches(batches.as_slice()).context("format results")?;
-    println!("{res}");
-
     Ok(())
 }
 
+/// Open the destination of query results, defaulting to stdout.
+fn open_output(path: Option<&Path>) -> Result<Box<dyn Write + Send>> {
+    Ok(match path {
+        Some(path) => Box::new(BufWriter::new(
+            File::create(path).with_context(|| format!("create {}", path.display()))?,
+        )),
+        None => Box::new(std::io::stdout()),
+    })
+}
+
+/// Writes record batches in an [`OutputFormat`] as they are received.
+enum BatchWriter {
+    /// Tables are only formatted once all batches are known, so buffer them.
+    Table(Vec<RecordBatch>, Box<dyn Write + Send>),
+    Csv(arrow_csv::Writer<Box<dyn Write + Send>>),
+    Json(arrow_json::ArrayWriter<Box<dyn Write + Send>>),
+    Ndjson(arrow_json::LineDelimitedWriter<Box<dyn Write + Send>>),
+    Arrow(FileWriter<Box<dyn Write + Send>>),
+    Parquet(ArrowWriter<Box<dyn Write + Send>>),
+}
+
+impl BatchWriter {
+    fn try_new(
+        format: OutputFormat,
+        schema: SchemaRef,
+        output: Box<dyn Write + Send>,
+    ) -> Result<Self> {
+        Ok(match format {
+            // Start with an empty batch so that the header is written even without any rows
+            OutputFormat::Table => Self::Table(vec![RecordBatch::new_empty(schema)], output),
+            OutputFormat::Csv => {
+                let mut writer = arrow_csv::Writer::new(output);
+                writer.write(&RecordBatch::new_empty(schema))?;
+                Self::Csv(writer)
+            }
+            OutputFormat::Json => Self::Json(arrow_json::ArrayWriter::new(output)),
+            OutputFormat::Ndjson => Self::Ndjson(arrow_json::LineDelimitedWriter::new(output)),
+            OutputFormat::Arrow => Self::Arrow(FileWriter::try_new(output, &schema)?),
+            OutputFormat::Parquet => Self::Parquet(ArrowWriter::try_new(output, schema, None)?),
+        })
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        match self {
+            Self::Table(batches, _) => batches.push(batch.clone()),
+            Self::Csv(writer) => writer.write(batch)?,
+            Self::Json(writer) => writer.write(batch)?,
+            Self::Ndjson(writer) => writer.write(batch)?,
+            Self::Arrow(writer) => writer.write(batch)?,
+            Self::Parquet(writer) => writer.write(batch)?,
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        let mut output = match self {
+            Self::Table(batches, mut output) => {
+                let res = pretty_format_batches(&batches).context("format results")?;
+                writeln!(output, "{res}")?;
+                output
+            }
+            Self::Csv(writer) => writer.into_inner(),
+            Self::Json(mut writer) => {
+                writer.finish()?;
+                writer.into_inner()
+            }
+            Self::Ndjson(mut writer) => {
+                writer.finish()?;
+                writer.into_inner()
+            }
+            Self::Arrow(writer) => writer.into_inner()?,
+            Self::Parquet(writer) => writer.into_inner()?,
+        };
+        output.flush().context("flush output")?;
+        Ok(())
+    }
+}
+
 async fn execute_flight(
     client: &mut FlightSqlServiceClient<Channel>,
     info: FlightInfo,
-) -> Result<Vec<RecordBatch>> {
+    format: OutputFormat,
+    output: Box<dyn Write + Send>,
+) -> Result<()> {
     let schema = Arc::new(Schema::try_from(info.clone()).context("valid schema")?);
-    let mut batches = Vec::with_capacity(info.endpoint.len() + 1);
-    batches.push(RecordBatch::new_empty(schema));
+    let mut writer = BatchWriter::try_new(format, schema, output).context("create writer")?;
     info!("decoded schema");
 
     for endpoint in info.endpoint {
@@ -344,11 +632,9 @@ async fn execute_flight(
         let mut flight_data = client.do_get(ticket.clone()).await.context("do get")?;
         log_metadata(flight_data.headers(), "header");
 
-        let mut endpoint_batches: Vec<_> = (&mut flight_data)
-            .try_collect()
-            .await
-            .context("collect data stream")?;
-        batches.append(&mut endpoint_batches);
+        while let Some(batch) = flight_data.try_next().await.context("read data stream")? {
+            writer.write(&batch).context("write batch")?;
+        }
 
         if let Some(trailers) = flight_data.trailers() {
             log_metadata(&trailers, "trailer");
@@ -356,7 +642,163 @@ async fn execute_flight(
     }
     info!("received data");
 
-    Ok(batches)
+    writer.finish()
+}
+
+const REPL_HELP: &str = "\
+Statements are executed as typed, one per line.
+
+Commands:
+  \\prepare <query>      prepare a statement, which may contain placeholders like `$1`
+  \\param <name>=<value> set a parameter of the prepared statement
+  \\execute              execute the prepared statement with the parameters set so far
+  \\history              show the history
+  \\help                 show this help
+  \\quit                 exit";
+
+/// Parameter names and values, as given on the command line.
+type Params = Vec<(String, String)>;
+
+/// Run an interactive session, executing each line read from stdin.
+async fn run_repl(
+    client: &mut FlightSqlServiceClient<Channel>,
+    format: OutputFormat,
+    history_file: Option<&Path>,
+) -> Result<()> {
+    let mut editor = DefaultEditor::new().context("create line editor")?;
+    if let Some(path) = history_file.filter(|path| path.exists()) {
+        let history = std::fs::read_to_string(path)
+            .with_context(|| format!("read history from {}", path.display()))?;
+        for line in history.lines() {
+            editor.add_history_entry(line).context("load history")?;
+        }
+    }
+    let mut history_writer = history_file
+        .map(|path| {
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("open history file {}", path.display()))
+        })
+        .transpose()?;
+
+    // The current prepared statement, with the parameters set for it so far
+    let mut prepared: Option<(PreparedStatement<Channel>, Params)> = None;
+    loop {
+        // stdin is read synchronously, so do not block other tasks on this worker
+        let line = match tokio::task::block_in_place(|| editor.readline("> ")) {
+            Ok(line) => line,
+            // Ctrl-C discards the current line
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e).context("read line"),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        editor.add_history_entry(line).context("add history")?;
+        if let Some(writer) = &mut history_writer {
+            writeln!(writer, "{line}").context("write history")?;
+        }
+
+        let result = match line.split_once(' ').unwrap_or((line, "")) {
+            ("\\quit" | "\\q", _) => break,
+            ("\\help", _) => {
+                eprintln!("{REPL_HELP}");
+                Ok(())
+            }
+            ("\\history", _) => {
+                for (i, line) in editor.history().into_iter().enumerate() {
+                    println!("{:>5}  {line}", i + 1);
+                }
+                Ok(())
+            }
+            ("\\prepare", query) => match client.prepare(query.to_string(), None).await {
+                Ok(stmt) => match prepared.replace((stmt, vec![])) {
+                    Some((previous, _)) => previous
+                        .close()
+                        .await
+                        .context("close previous prepared statement"),
+                    None => Ok(()),
+                },
+                Err(e) => Err(anyhow::Error::new(e).context("prepare statement")),
+            },
+            ("\\param", param) => match (&mut prepared, parse_key_val(param)) {
+                (None, _) => Err(anyhow::anyhow!("no prepared statement, use \\prepare")),
+                (_, Err(e)) => Err(anyhow::anyhow!(e)),
+                (Some((_, params)), Ok(param)) => {
+                    params.retain(|(name, _)| name != &param.0);
+                    params.push(param);
+                    Ok(())
+                }
+            },
+            ("\\execute", _) => match &mut prepared {
+                None => Err(anyhow::anyhow!("no prepared statement, use \\prepare")),
+                Some((stmt, params)) => execute_prepared(client, stmt, params, format).await,
+            },
+            (command, _) if command.starts_with('\\') => {
+                Err(anyhow::anyhow!("unknown command {command}, see \\help"))
+            }
+            _ => execute_statement(client, line, format).await,
+        };
+        if let Err(e) = result {
+            eprintln!("Error: {e:#}");
+        }
+    }
+
+    if let Some((stmt, _)) = prepared
+        && let Err(e) = stmt.close().await
+    {
+        eprintln!("Error: close prepared statement: {e}");
+    }
+    Ok(())
+}
+
+async fn execute_statement(
+    client: &mut FlightSqlServiceClient<Channel>,
+    query: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    let flight_info = client
+        .execute(query.to_string(), None)
+        .await
+        .context("execute statement")?;
+    execute_flight(client, flight_info, format, Box::new(std::io::stdout())).await
+}
+
+async fn execute_prepared(
+    client: &mut FlightSqlServiceClient<Channel>,
+    stmt: &mut PreparedStatement<Channel>,
+    params: &[(String, String)],
+    format: OutputFormat,
+) -> Result<()> {
+    bind_parameters(stmt, params)?;
+    let flight_info = stmt.execute().await.context("execute prepared statement")?;
+    execute_flight(client, flight_info, format, Box::new(std::io::stdout())).await
+}
+
+/// Bind `params` to the prepared statement, converting them to its parameter schema.
+fn bind_parameters(
+    prepared_stmt: &mut PreparedStatement<Channel>,
+    params: &[(String, String)],
+) -> Result<()> {
+    if !params.is_empty() {
+        prepared_stmt
+            .set_parameters(
+                construct_record_batch_from_params(
+                    params,
+                    prepared_stmt
+                        .parameter_schema()
+                        .context("get parameter schema")?,
+                )
+                .context("construct parameters")?,
+            )
+            .context("bind parameters")?;
+    }
+    Ok(())
 }
 
 fn construct_record_batch_from_params(
@@ -19,8 +19,14 @@ use crate::{
     Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
     HandshakeRequest, PollInfo, PutResult, Ticket,
     decode::FlightRecordBatchStream,
+    encode::FlightDataEncoderBuilder,
+    exchange::{ExchangeReceiver, ExchangeSender, ExchangeSession},
     flight_service_client::FlightServiceClient,
-    r#gen::{CancelFlightInfoRequest, CancelFlightInfoResult, RenewFlightEndpointRequest},
+    r#gen::{
+        CancelFlightInfoRequest, CancelFlightInfoResult, CloseSessionRequest, CloseSessionResult,
+        GetSessionOptionsRequest, GetSessionOptionsResult, RenewFlightEndpointRequest,
+        SetSessionOptionsRequest, SetSessionOptionsResult,
+    },
     trailers::extract_lazy_trailers,
 };
 use arrow_schema::Schema;
@@ -439,6 +445,56 @@ where
         Ok(FlightRecordBatchStream::new_from_flight_data(error_stream))
     }
 
+    /// Make a `DoExchange` call, returning an [`ExchangeSession`] to send
+    /// and receive [`RecordBatch`]es along with their `app_metadata`.
+    ///
+    /// Outgoing messages are encoded as configured by `encoder`. Unlike
+    /// [`Self::do_exchange`], messages can be sent after the call has
+    /// started, for example in response to those received from the server.
+    ///
+    /// See [`crate::exchange`] for more details.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use futures::TryStreamExt;
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{RecordBatch, UInt64Array};
+    /// # use arrow_flight::{FlightClient, FlightDescriptor};
+    /// # use arrow_flight::encode::FlightDataEncoderBuilder;
+    /// # let batch = RecordBatch::try_from_iter(vec![
+    /// #  ("col2", Arc::new(UInt64Array::from_iter([10, 23, 33])) as _)
+    /// # ]).unwrap();
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// let mut client = FlightClient::new(channel);
+    ///
+    /// let descriptor = FlightDescriptor::new_cmd("transform");
+    /// let encoder = FlightDataEncoderBuilder::new().with_flight_descriptor(Some(descriptor));
+    /// let mut session = client
+    ///   .do_exchange_session(encoder)
+    ///   .await
+    ///   .expect("error making request");
+    ///
+    /// // send a batch, tagged with app_metadata
+    /// session.send_with_metadata(batch, "request-1").await.unwrap();
+    ///
+    /// // and receive the server's reply
+    /// let reply = session.try_next().await.unwrap().expect("reply");
+    /// println!("{:?} for {:?}", reply.batch, reply.app_metadata);
+    /// # }
+    /// ```
+    ///
+    /// [`RecordBatch`]: arrow_array::RecordBatch
+    pub async fn do_exchange_session(
+        &mut self,
+        encoder: FlightDataEncoderBuilder,
+    ) -> Result<ExchangeSession> {
+        let (sender, request) = ExchangeSender::new(encoder);
+        let response = self.do_exchange(request).await?;
+        let receiver = ExchangeReceiver::new(response.into_inner());
+        Ok(ExchangeSession::from_parts(sender, receiver))
+    }
+
     /// Make a `ListFlights` call to the server with the provided
     /// criteria and returning a [`Stream`] of [`FlightInfo`].
     ///
@@ -670,6 +726,67 @@ where
         FlightEndpoint::decode(response).map_err(|e| FlightError::DecodeError(e.to_string()))
     }
 
+    /// Make a `SetSessionOptions` call to the server and return
+    /// the [`SetSessionOptionsResult`], which lists any options that
+    /// could not be set.
+    ///
+    /// The session is identified by the transport, typically a cookie, so
+    /// the underlying service must resend whatever the server returned. See
+    /// the `flight_sql_session` example for a cookie middleware.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use arrow_flight::{FlightClient, SetSessionOptionsRequest};
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// let mut client = FlightClient::new(channel);
+    ///
+    /// let request = SetSessionOptionsRequest::new([("catalog", "sales"), ("timezone", "UTC")]);
+    /// let result = client
+    ///   .set_session_options(request)
+    ///   .await
+    ///   .expect("error setting session options");
+    /// assert!(result.errors.is_empty());
+    /// # }
+    /// ```
+    pub async fn set_session_options(
+        &mut self,
+        request: SetSessionOptionsRequest,
+    ) -> Result<SetSessionOptionsResult> {
+        let action = Action::new("SetSessionOptions", request.encode_to_vec());
+        let response = self.do_action(action).await?.try_next().await?;
+        let response = response.ok_or(FlightError::protocol(
+            "Received no response for set_session_options call",
+        ))?;
+        SetSessionOptionsResult::decode(response)
+            .map_err(|e| FlightError::DecodeError(e.to_string()))
+    }
+
+    /// Make a `GetSessionOptions` call to the server and return
+    /// the current session options.
+    pub async fn get_session_options(&mut self) -> Result<GetSessionOptionsResult> {
+        let request = GetSessionOptionsRequest {};
+        let action = Action::new("GetSessionOptions", request.encode_to_vec());
+        let response = self.do_action(action).await?.try_next().await?;
+        let response = response.ok_or(FlightError::protocol(
+            "Received no response for get_session_options call",
+        ))?;
+        GetSessionOptionsResult::decode(response)
+            .map_err(|e| FlightError::DecodeError(e.to_string()))
+    }
+
+    /// Make a `CloseSession` call to the server and return
+    /// the [`CloseSessionResult`].
+    pub async fn close_session(&mut self) -> Result<CloseSessionResult> {
+        let request = CloseSessionRequest {};
+        let action = Action::new("CloseSession", request.encode_to_vec());
+        let response = self.do_action(action).await?.try_next().await?;
+        let response = response.ok_or(FlightError::protocol(
+            "Received no response for close_session call",
+        ))?;
+        CloseSessionResult::decode(response).map_err(|e| FlightError::DecodeError(e.to_string()))
+    }
+
     /// return a Request, adding any configured metadata
     fn make_request<R>(&self, t: R) -> tonic::Request<R> {
         // Pass along metadata
@@ -19,7 +19,7 @@ use crate::{FlightData, trailers::LazyTrailers};
 use arrow_array::{ArrayRef, RecordBatch};
 use arrow_buffer::Buffer;
 use arrow_data::UnsafeFlag;
-use arrow_schema::{Schema, SchemaRef};
+use arrow_schema::{Metadata, Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{Stream, StreamExt, ready, stream::BoxStream};
 use std::{collections::HashMap, fmt::Debug, pin::Pin, sync::Arc, task::Poll};
@@ -201,7 +201,8 @@ impl futures::Stream for FlightRecordBatchStream {
 /// The client handles flight messages as followes:
 ///
 /// - **None:** This message has no effect. This is useful to
-///   transmit metadata without any actual payload.
+///   transmit metadata without any actual payload. Messages with an
+///   empty `data_header` are treated the same way.
 ///
 /// - **Schema:** The schema is (re-)set. Dictionaries are cleared and
 ///   the decoded schema is returned.
@@ -274,6 +275,11 @@ impl FlightDataDecoder {
     /// state as necessary.
     fn extract_message(&mut self, data: FlightData) -> Result<Option<DecodedFlightData>> {
         use arrow_ipc::MessageHeader;
+        // Messages carrying only `app_metadata`, such as those sent by
+        // `ExchangeSender::send_metadata`, may have no IPC header at all
+        if data.data_header.is_empty() {
+            return Ok(Some(DecodedFlightData::new_none(data)));
+        }
         let message = arrow_ipc::root_as_message(&data.data_header[..])
             .map_err(|e| FlightError::DecodeError(format!("Error decoding root message: {e}")))?;
 
@@ -450,6 +456,18 @@ impl DecodedFlightData {
     pub fn app_metadata(&self) -> Bytes {
         self.inner.app_metadata.clone()
     }
+
+    /// Return the custom metadata of the IPC message of the inner flight data
+    ///
+    /// For record batches this is the per-batch metadata, for example
+    /// written by [`FlightDataEncoderBuilder::build_with_metadata`]
+    ///
+    /// [`FlightDataEncoderBuilder::build_with_metadata`]: crate::encode::FlightDataEncoderBuilder::build_with_metadata
+    pub fn custom_metadata(&self) -> Metadata {
+        arrow_ipc::root_as_message(&self.inner.data_header)
+            .map(|message| arrow_ipc::convert::fb_to_metadata(message.custom_metadata()))
+            .unwrap_or_default()
+    }
 }
 
 /// The result of decoding [`FlightData`]
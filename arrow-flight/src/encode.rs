@@ -17,12 +17,12 @@
 
 use std::{collections::VecDeque, fmt::Debug, pin::Pin, sync::Arc, task::Poll};
 
-use crate::{FlightData, FlightDescriptor, SchemaAsIpc, error::Result};
+use crate::{FlightData, FlightDescriptor, SchemaAsIpc, error::Result, exchange::ExchangeMessage};
 
 use arrow_array::{Array, ArrayRef, RecordBatch, RecordBatchOptions, UnionArray};
 use arrow_ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteContext, IpcWriteOptions};
 
-use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef, UnionMode};
+use arrow_schema::{DataType, Field, FieldRef, Fields, Metadata, Schema, SchemaRef, UnionMode};
 use bytes::Bytes;
 use futures::{Stream, StreamExt, ready, stream::BoxStream};
 
@@ -241,6 +241,53 @@ impl FlightDataEncoderBuilder {
     pub fn build<S>(self, input: S) -> FlightDataEncoder
     where
         S: Stream<Item = Result<RecordBatch>> + Send + 'static,
+    {
+        self.build_with_metadata(input.map(|batch| Ok((batch?, Metadata::new()))))
+    }
+
+    /// Takes a [`Stream`] of [`Result<(RecordBatch, Metadata)>`] and returns
+    /// a [`Stream`] of [`FlightData`], attaching each [`Metadata`] to the IPC
+    /// message of its [`RecordBatch`]
+    ///
+    /// If a batch is split into several messages, see
+    /// [`Self::with_max_flight_data_size`], each carries the same metadata.
+    /// It can be read back using [`DecodedFlightData::custom_metadata`]
+    ///
+    /// [`DecodedFlightData::custom_metadata`]: crate::decode::DecodedFlightData::custom_metadata
+    pub fn build_with_metadata<S>(self, input: S) -> FlightDataEncoder
+    where
+        S: Stream<Item = Result<(RecordBatch, Metadata)>> + Send + 'static,
+    {
+        self.build_input(input.map(|item| {
+            let (batch, metadata) = item?;
+            Ok(EncoderInput::Batch(batch, metadata, Bytes::new()))
+        }))
+    }
+
+    /// Takes a [`Stream`] of [`ExchangeMessage`]s and returns a [`Stream`] of
+    /// [`FlightData`], setting `app_metadata` on the messages of each batch
+    ///
+    /// Messages without a batch are sent as [`FlightData`] carrying only
+    /// their `app_metadata`
+    pub(crate) fn build_exchange<S>(self, input: S) -> FlightDataEncoder
+    where
+        S: Stream<Item = Result<ExchangeMessage>> + Send + 'static,
+    {
+        self.build_input(input.map(|message| {
+            let ExchangeMessage {
+                batch,
+                app_metadata,
+            } = message?;
+            Ok(match batch {
+                Some(batch) => EncoderInput::Batch(batch, Metadata::new(), app_metadata),
+                None => EncoderInput::AppMetadata(app_metadata),
+            })
+        }))
+    }
+
+    fn build_input<S>(self, input: S) -> FlightDataEncoder
+    where
+        S: Stream<Item = Result<EncoderInput>> + Send + 'static,
     {
         let Self {
             max_flight_data_size,
@@ -263,12 +310,21 @@ impl FlightDataEncoderBuilder {
     }
 }
 
+/// A single input item of a [`FlightDataEncoder`]
+enum EncoderInput {
+    /// A batch, the custom metadata of its IPC messages and the
+    /// `app_metadata` of its [`FlightData`]
+    Batch(RecordBatch, Metadata, Bytes),
+    /// `app_metadata` sent without any data
+    AppMetadata(Bytes),
+}
+
 /// Stream that encodes a stream of record batches to flight data.
 ///
 /// See [`FlightDataEncoderBuilder`] for details and example.
 pub struct FlightDataEncoder {
-    /// Input stream
-    inner: BoxStream<'static, Result<RecordBatch>>,
+    /// Input stream of batches and their metadata
+    inner: BoxStream<'static, Result<EncoderInput>>,
     /// schema, set after the first batch
     schema: Option<SchemaRef>,
     /// Target maximum size of flight data
@@ -291,7 +347,7 @@ pub struct FlightDataEncoder {
 
 impl FlightDataEncoder {
     fn new(
-        inner: BoxStream<'static, Result<RecordBatch>>,
+        inner: BoxStream<'static, Result<EncoderInput>>,
         schema: Option<SchemaRef>,
         max_flight_data_size: usize,
         options: IpcWriteOptions,
@@ -361,7 +417,12 @@ impl FlightDataEncoder {
     }
 
     /// Encodes batch into one or more `FlightData` messages in self.queue
-    fn encode_batch(&mut self, batch: RecordBatch) -> Result<()> {
+    fn encode_batch(
+        &mut self,
+        batch: RecordBatch,
+        metadata: &Metadata,
+        app_metadata: &Bytes,
+    ) -> Result<()> {
         let schema = match &self.schema {
             Some(schema) => schema.clone(),
             // encode the schema if this is the first time we have seen it
@@ -379,10 +440,12 @@ impl FlightDataEncoder {
             self.encoder
                 .ipc_write_context
                 .set_reserve_scratch(i != last);
-            let (flight_dictionaries, flight_batch) = self.encoder.encode_batch(&batch)?;
+            let (flight_dictionaries, mut flight_batch) =
+                self.encoder.encode_batch(&batch, metadata)?;
             for dict in flight_dictionaries {
                 self.queue_message(dict);
             }
+            flight_batch.app_metadata = app_metadata.clone();
             self.queue_message(flight_batch);
         }
 
@@ -424,14 +487,17 @@ impl Stream for FlightDataEncoder {
                     self.queue.clear();
                     return Poll::Ready(Some(Err(e)));
                 }
-                Some(Ok(batch)) => {
+                Some(Ok(EncoderInput::Batch(batch, metadata, app_metadata))) => {
                     // had data, encode into the queue
-                    if let Err(e) = self.encode_batch(batch) {
+                    if let Err(e) = self.encode_batch(batch, &metadata, &app_metadata) {
                         self.done = true;
                         self.queue.clear();
                         return Poll::Ready(Some(Err(e)));
                     }
                 }
+                Some(Ok(EncoderInput::AppMetadata(app_metadata))) => {
+                    self.queue_message(FlightData::new().with_app_metadata(app_metadata));
+                }
             }
         }
     }
@@ -727,9 +793,11 @@ impl FlightIpcEncoder {
     fn encode_batch(
         &mut self,
         batch: &RecordBatch,
+        metadata: &Metadata,
     ) -> Result<(impl Iterator<Item = FlightData> + use<>, FlightData)> {
-        let (encoded_dictionaries, encoded_batch) = self.data_gen.encode(
+        let (encoded_dictionaries, encoded_batch) = self.data_gen.encode_with_metadata(
             batch,
+            metadata,
             &mut self.dictionary_tracker,
             &self.options,
             &mut self.ipc_write_context,
@@ -1819,6 +1887,42 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_batch_metadata_round_trip() {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..1024)) as ArrayRef,
+        )])
+        .unwrap();
+        let input = vec![
+            Ok((batch.clone(), Metadata::from([("watermark", "1")]))),
+            Ok((batch.slice(0, 10), Metadata::new())),
+        ];
+
+        // split the first batch into several messages
+        let encoder = FlightDataEncoderBuilder::default()
+            .with_max_flight_data_size(1024)
+            .build_with_metadata(futures::stream::iter(input));
+
+        let mut decoder = FlightDataDecoder::new(encoder);
+        let mut num_messages = 0;
+        let mut num_rows = 0;
+        while let Some(decoded) = decoder.next().await {
+            let decoded = decoded.unwrap();
+            if let DecodedPayload::RecordBatch(b) = &decoded.payload {
+                let expected = match num_rows < 1024 {
+                    true => Metadata::from([("watermark", "1")]),
+                    false => Metadata::new(),
+                };
+                assert_eq!(decoded.custom_metadata(), expected);
+                num_messages += 1;
+                num_rows += b.num_rows();
+            }
+        }
+        assert!(num_messages > 2);
+        assert_eq!(num_rows, 1034);
+    }
+
     #[test]
     fn test_schema_metadata_encoded() {
         let schema = Schema::new(vec![Field::new("data", DataType::Int32, false)]).with_metadata(
@@ -0,0 +1,554 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Bidirectional [`RecordBatch`] streams for `DoExchange`
+//!
+//! A `DoExchange` call is symmetric: both peers send a stream of
+//! [`FlightData`] which may carry a schema, dictionaries, record batches and
+//! `app_metadata`. An [`ExchangeSession`] wraps both directions of such a call
+//! as [`ExchangeMessage`]s, pairing each [`RecordBatch`] with the
+//! `app_metadata` of its [`FlightData`]:
+//!
+//! - [`ExchangeSender`] encodes outgoing messages using a
+//!   [`FlightDataEncoderBuilder`], so the schema is sent before the first
+//!   batch (or immediately, see [`FlightDataEncoderBuilder::with_schema`])
+//!   and dictionaries are handled as configured
+//! - [`ExchangeReceiver`] decodes incoming messages using a
+//!   [`FlightDataDecoder`]
+//!
+//! Clients create a session with [`FlightClient::do_exchange_session`].
+//! Servers create one from the request stream in
+//! [`FlightService::do_exchange`] and return the [`FlightDataEncoder`] as the
+//! response:
+//!
+//! ```no_run
+//! # use arrow_flight::{FlightData, encode::FlightDataEncoderBuilder, error::FlightError};
+//! # use arrow_flight::exchange::ExchangeSession;
+//! # use futures::{StreamExt, TryStreamExt, stream::BoxStream};
+//! # use tonic::{Request, Response, Status, Streaming};
+//! async fn do_exchange(
+//!     request: Request<Streaming<FlightData>>,
+//! ) -> Result<Response<BoxStream<'static, Result<FlightData, Status>>>, Status> {
+//!     let input = request.into_inner().map_err(FlightError::from);
+//!     let (mut session, output) = ExchangeSession::new(input, FlightDataEncoderBuilder::new());
+//!
+//!     tokio::spawn(async move {
+//!         // echo every batch back, with its app_metadata
+//!         while let Some(Ok(message)) = session.next().await {
+//!             if session.send_message(message).await.is_err() {
+//!                 break;
+//!             }
+//!         }
+//!     });
+//!
+//!     Ok(Response::new(output.map_err(Status::from).boxed()))
+//! }
+//! ```
+//!
+//! [`FlightClient::do_exchange_session`]: crate::FlightClient::do_exchange_session
+//! [`FlightService::do_exchange`]: crate::flight_service_server::FlightService::do_exchange
+
+use std::collections::VecDeque;
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+use bytes::Bytes;
+use futures::channel::mpsc;
+use futures::{SinkExt, Stream, StreamExt, ready};
+
+use crate::decode::{DecodedPayload, FlightDataDecoder};
+use crate::encode::{FlightDataEncoder, FlightDataEncoderBuilder};
+use crate::error::{FlightError, Result};
+use crate::{FlightData, FlightDescriptor};
+
+/// A [`RecordBatch`] and/or `app_metadata` exchanged with a peer
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExchangeMessage {
+    /// The record batch, or `None` for a message carrying only `app_metadata`
+    pub batch: Option<RecordBatch>,
+    /// Application specific metadata sent along with the batch
+    pub app_metadata: Bytes,
+}
+
+impl ExchangeMessage {
+    /// Create a message for `batch` without `app_metadata`
+    pub fn new(batch: RecordBatch) -> Self {
+        Self {
+            batch: Some(batch),
+            app_metadata: Bytes::new(),
+        }
+    }
+
+    /// Create a message carrying only `app_metadata`
+    pub fn metadata(app_metadata: impl Into<Bytes>) -> Self {
+        Self {
+            batch: None,
+            app_metadata: app_metadata.into(),
+        }
+    }
+
+    /// Set the `app_metadata` of this message
+    pub fn with_app_metadata(mut self, app_metadata: impl Into<Bytes>) -> Self {
+        self.app_metadata = app_metadata.into();
+        self
+    }
+}
+
+/// Sends [`ExchangeMessage`]s to the peer of a `DoExchange` call
+///
+/// Dropping the sender ends the outgoing stream.
+#[derive(Debug, Clone)]
+pub struct ExchangeSender {
+    sender: mpsc::Sender<Result<ExchangeMessage>>,
+}
+
+impl ExchangeSender {
+    /// Create a new sender, returning it and the stream of [`FlightData`]
+    /// encoding the messages it sends, as configured by `encoder`
+    pub fn new(encoder: FlightDataEncoderBuilder) -> (Self, FlightDataEncoder) {
+        let (sender, receiver) = mpsc::channel(1);
+        (Self { sender }, encoder.build_exchange(receiver))
+    }
+
+    /// Send `batch` to the peer
+    pub async fn send(&mut self, batch: RecordBatch) -> Result<()> {
+        self.send_message(ExchangeMessage::new(batch)).await
+    }
+
+    /// Send `batch` to the peer with `app_metadata`
+    ///
+    /// If the batch is split into several [`FlightData`], see
+    /// [`FlightDataEncoderBuilder::with_max_flight_data_size`], each carries
+    /// the same `app_metadata`
+    pub async fn send_with_metadata(
+        &mut self,
+        batch: RecordBatch,
+        app_metadata: impl Into<Bytes>,
+    ) -> Result<()> {
+        self.send_message(ExchangeMessage::new(batch).with_app_metadata(app_metadata))
+            .await
+    }
+
+    /// Send `app_metadata` to the peer without any data
+    pub async fn send_metadata(&mut self, app_metadata: impl Into<Bytes>) -> Result<()> {
+        self.send_message(ExchangeMessage::metadata(app_metadata))
+            .await
+    }
+
+    /// Send `message` to the peer
+    ///
+    /// Waits while earlier messages have not yet been consumed by the
+    /// underlying transport. Returns an error if the outgoing stream has
+    /// been closed.
+    pub async fn send_message(&mut self, message: ExchangeMessage) -> Result<()> {
+        self.sender.send(Ok(message)).await.map_err(|_| closed())
+    }
+
+    /// End the outgoing stream with `error`
+    ///
+    /// The error is returned by the [`FlightDataEncoder`] of this sender:
+    /// servers report it to the client as the status of the call, and
+    /// clients return it from their [`ExchangeReceiver`].
+    pub async fn abort(mut self, error: FlightError) -> Result<()> {
+        self.sender.send(Err(error)).await.map_err(|_| closed())
+    }
+}
+
+fn closed() -> FlightError {
+    FlightError::protocol("DoExchange stream closed")
+}
+
+/// Receives [`ExchangeMessage`]s from the peer of a `DoExchange` call
+///
+/// Schema messages and other [`FlightData`] without a record batch are
+/// returned as messages carrying only their `app_metadata`, unless it is
+/// empty.
+pub struct ExchangeReceiver {
+    /// Decoder of the incoming stream
+    decoder: FlightDataDecoder,
+    /// Descriptor of the first incoming message, if any
+    descriptor: Option<FlightDescriptor>,
+    /// Has the first incoming message been received
+    started: bool,
+    /// Messages read ahead of the caller by [`Self::schema`] or [`Self::descriptor`]
+    pending: VecDeque<ExchangeMessage>,
+}
+
+impl std::fmt::Debug for ExchangeReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExchangeReceiver")
+            .field("decoder", &self.decoder)
+            .field("descriptor", &self.descriptor)
+            .field("started", &self.started)
+            .field("pending", &self.pending.len())
+            .finish()
+    }
+}
+
+impl ExchangeReceiver {
+    /// Create a receiver decoding messages from `decoder`
+    pub fn new(decoder: FlightDataDecoder) -> Self {
+        Self {
+            decoder,
+            descriptor: None,
+            started: false,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Wait for the schema of the incoming stream
+    ///
+    /// Returns `None` if the peer ends its stream without sending a schema.
+    /// Messages received in the meantime are returned by subsequent calls to
+    /// [`StreamExt::next`].
+    pub async fn schema(&mut self) -> Result<Option<SchemaRef>> {
+        while self.decoder.schema().is_none() {
+            if !self.read_ahead().await? {
+                break;
+            }
+        }
+        Ok(self.decoder.schema().cloned())
+    }
+
+    /// Wait for the [`FlightDescriptor`] of the incoming stream
+    ///
+    /// The descriptor is taken from the first message sent by the peer, see
+    /// [`FlightDataEncoderBuilder::with_flight_descriptor`]. Returns `None`
+    /// if that message has no descriptor or the peer sends no messages.
+    pub async fn descriptor(&mut self) -> Result<Option<&FlightDescriptor>> {
+        if !self.started {
+            self.read_ahead().await?;
+        }
+        Ok(self.descriptor.as_ref())
+    }
+
+    /// Read the next incoming [`FlightData`] into `self.pending`, returning
+    /// `false` if the stream has ended
+    async fn read_ahead(&mut self) -> Result<bool> {
+        match poll_fn(|cx| self.poll_decoded(cx)).await {
+            Some(message) => {
+                self.pending.extend(message?);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Decode the next incoming [`FlightData`], which may not result in a
+    /// message
+    fn poll_decoded(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Option<ExchangeMessage>>>> {
+        let Some(decoded) = ready!(self.decoder.poll_next_unpin(cx)) else {
+            return Poll::Ready(None);
+        };
+        let mut decoded = match decoded {
+            Ok(decoded) => decoded,
+            Err(e) => return Poll::Ready(Some(Err(e))),
+        };
+
+        if !self.started {
+            self.started = true;
+            self.descriptor = decoded.inner.flight_descriptor.take();
+        }
+
+        let app_metadata = decoded.inner.app_metadata;
+        let message = match decoded.payload {
+            DecodedPayload::RecordBatch(batch) => Some(ExchangeMessage {
+                batch: Some(batch),
+                app_metadata,
+            }),
+            DecodedPayload::Schema(_) | DecodedPayload::None if app_metadata.is_empty() => None,
+            DecodedPayload::Schema(_) | DecodedPayload::None => {
+                Some(ExchangeMessage::metadata(app_metadata))
+            }
+        };
+        Poll::Ready(Some(Ok(message)))
+    }
+}
+
+impl Stream for ExchangeReceiver {
+    type Item = Result<ExchangeMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(message) = self.pending.pop_front() {
+            return Poll::Ready(Some(Ok(message)));
+        }
+        loop {
+            match ready!(self.poll_decoded(cx)) {
+                Some(Ok(None)) => continue,
+                Some(Ok(Some(message))) => return Poll::Ready(Some(Ok(message))),
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// Both directions of a `DoExchange` call
+///
+/// Incoming messages are read using [`Stream`], outgoing messages are sent
+/// using [`Self::send_message`] and related methods. Use [`Self::split`] to
+/// send and receive from separate tasks.
+///
+/// See the [module level documentation](self) for an example.
+#[derive(Debug)]
+pub struct ExchangeSession {
+    sender: ExchangeSender,
+    receiver: ExchangeReceiver,
+}
+
+impl ExchangeSession {
+    /// Create a session reading `input` and encoding the messages it sends
+    /// as configured by `encoder`
+    ///
+    /// Returns the session and the stream of [`FlightData`] to send to the
+    /// peer, such as the response of [`FlightService::do_exchange`].
+    ///
+    /// [`FlightService::do_exchange`]: crate::flight_service_server::FlightService::do_exchange
+    pub fn new<S>(input: S, encoder: FlightDataEncoderBuilder) -> (Self, FlightDataEncoder)
+    where
+        S: Stream<Item = Result<FlightData>> + Send + 'static,
+    {
+        let (sender, output) = ExchangeSender::new(encoder);
+        let receiver = ExchangeReceiver::new(FlightDataDecoder::new(input));
+        (Self::from_parts(sender, receiver), output)
+    }
+
+    /// Create a session from its two halves
+    pub fn from_parts(sender: ExchangeSender, receiver: ExchangeReceiver) -> Self {
+        Self { sender, receiver }
+    }
+
+    /// Split the session into its two halves
+    pub fn split(self) -> (ExchangeSender, ExchangeReceiver) {
+        (self.sender, self.receiver)
+    }
+
+    /// Return the sending half of this session
+    pub fn sender(&mut self) -> &mut ExchangeSender {
+        &mut self.sender
+    }
+
+    /// Return the receiving half of this session
+    pub fn receiver(&mut self) -> &mut ExchangeReceiver {
+        &mut self.receiver
+    }
+
+    /// Send `batch` to the peer, see [`ExchangeSender::send`]
+    pub async fn send(&mut self, batch: RecordBatch) -> Result<()> {
+        self.sender.send(batch).await
+    }
+
+    /// Send `batch` with `app_metadata` to the peer, see
+    /// [`ExchangeSender::send_with_metadata`]
+    pub async fn send_with_metadata(
+        &mut self,
+        batch: RecordBatch,
+        app_metadata: impl Into<Bytes>,
+    ) -> Result<()> {
+        self.sender.send_with_metadata(batch, app_metadata).await
+    }
+
+    /// Send `app_metadata` to the peer, see [`ExchangeSender::send_metadata`]
+    pub async fn send_metadata(&mut self, app_metadata: impl Into<Bytes>) -> Result<()> {
+        self.sender.send_metadata(app_metadata).await
+    }
+
+    /// Send `message` to the peer, see [`ExchangeSender::send_message`]
+    pub async fn send_message(&mut self, message: ExchangeMessage) -> Result<()> {
+        self.sender.send_message(message).await
+    }
+}
+
+impl Stream for ExchangeSession {
+    type Item = Result<ExchangeMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::DictionaryHandling;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int64Type};
+    use arrow_array::{ArrayRef, DictionaryArray, Int64Array};
+    use arrow_schema::{DataType, Field, Schema};
+    use futures::TryStreamExt;
+    use std::sync::Arc;
+
+    /// Connect two sessions to each other without any transport
+    fn session_pair(
+        left: FlightDataEncoderBuilder,
+        right: FlightDataEncoderBuilder,
+    ) -> (ExchangeSession, ExchangeSession) {
+        let (left_sender, left_output) = ExchangeSender::new(left);
+        let (right_sender, right_output) = ExchangeSender::new(right);
+        let left = ExchangeSession::from_parts(
+            left_sender,
+            ExchangeReceiver::new(FlightDataDecoder::new(right_output)),
+        );
+        let right = ExchangeSession::from_parts(
+            right_sender,
+            ExchangeReceiver::new(FlightDataDecoder::new(left_output)),
+        );
+        (left, right)
+    }
+
+    fn int_batch(values: Vec<i64>) -> RecordBatch {
+        RecordBatch::try_from_iter([("a", Arc::new(Int64Array::from(values)) as ArrayRef)]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_exchange_app_metadata() {
+        let (client, server) = session_pair(
+            FlightDataEncoderBuilder::new(),
+            FlightDataEncoderBuilder::new(),
+        );
+
+        let server = tokio::spawn(async move {
+            let (mut sender, mut receiver) = server.split();
+            while let Some(message) = receiver.try_next().await.unwrap() {
+                let reply = match message.batch {
+                    Some(batch) => {
+                        let values = batch.column(0).as_primitive::<Int64Type>();
+                        ExchangeMessage::new(int_batch(
+                            values.values().iter().map(|v| v * 2).collect(),
+                        ))
+                    }
+                    None => ExchangeMessage::default(),
+                };
+                sender
+                    .send_message(reply.with_app_metadata(message.app_metadata))
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let (mut sender, receiver) = client.split();
+        sender
+            .send_with_metadata(int_batch(vec![1, 2]), "first")
+            .await
+            .unwrap();
+        sender.send_metadata("progress").await.unwrap();
+        sender
+            .send_with_metadata(int_batch(vec![3]), "second")
+            .await
+            .unwrap();
+        drop(sender);
+
+        let received: Vec<_> = receiver.try_collect().await.unwrap();
+        server.await.unwrap();
+
+        assert_eq!(
+            received,
+            vec![
+                ExchangeMessage::new(int_batch(vec![2, 4])).with_app_metadata("first"),
+                ExchangeMessage::metadata("progress"),
+                ExchangeMessage::new(int_batch(vec![6])).with_app_metadata("second"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exchange_dictionaries() {
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let dict2: DictionaryArray<Int32Type> = vec!["c", "c"].into_iter().collect();
+        let batch1 = RecordBatch::try_from_iter([("dict", Arc::new(dict) as ArrayRef)]).unwrap();
+        let batch2 = RecordBatch::try_from_iter([("dict", Arc::new(dict2) as ArrayRef)]).unwrap();
+
+        let (mut client, mut server) = session_pair(
+            FlightDataEncoderBuilder::new().with_dictionary_handling(DictionaryHandling::Resend),
+            FlightDataEncoderBuilder::new(),
+        );
+
+        client
+            .send_with_metadata(batch1.clone(), "1")
+            .await
+            .unwrap();
+        let message = server.try_next().await.unwrap().unwrap();
+        assert_eq!(message, ExchangeMessage::new(batch1).with_app_metadata("1"));
+
+        client
+            .send_with_metadata(batch2.clone(), "2")
+            .await
+            .unwrap();
+        let message = server.try_next().await.unwrap().unwrap();
+        assert_eq!(message, ExchangeMessage::new(batch2).with_app_metadata("2"));
+    }
+
+    #[tokio::test]
+    async fn test_exchange_schema_and_descriptor() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let descriptor = FlightDescriptor::new_path(vec!["transform".to_string()]);
+
+        let (mut client, server) = session_pair(
+            FlightDataEncoderBuilder::new()
+                .with_schema(Arc::clone(&schema))
+                .with_metadata(Bytes::from("hello"))
+                .with_flight_descriptor(Some(descriptor.clone())),
+            FlightDataEncoderBuilder::new(),
+        );
+        let (_server_sender, mut receiver) = server.split();
+
+        // the schema is known before any batch is sent
+        assert_eq!(receiver.schema().await.unwrap(), Some(Arc::clone(&schema)));
+        assert_eq!(receiver.descriptor().await.unwrap(), Some(&descriptor));
+
+        client.send(int_batch(vec![1])).await.unwrap();
+        drop(client);
+
+        // the schema message's app_metadata is returned first
+        let received: Vec<_> = receiver.try_collect().await.unwrap();
+        assert_eq!(
+            received,
+            vec![
+                ExchangeMessage::metadata("hello"),
+                ExchangeMessage::new(int_batch(vec![1])),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exchange_abort() {
+        let (client, mut server) = session_pair(
+            FlightDataEncoderBuilder::new(),
+            FlightDataEncoderBuilder::new(),
+        );
+        let (sender, client_receiver) = client.split();
+
+        sender
+            .abort(FlightError::protocol("cancelled"))
+            .await
+            .unwrap();
+        let err = server.try_next().await.unwrap_err();
+        assert!(matches!(err, FlightError::ProtocolError(e) if e == "cancelled"));
+
+        // sending fails once the peer is gone
+        let (mut sender, _) = server.split();
+        drop(client_receiver);
+        let err = sender.send_metadata("late").await.unwrap_err();
+        assert!(matches!(err, FlightError::ProtocolError(e) if e == "DoExchange stream closed"));
+    }
+}
@@ -72,6 +72,26 @@ pub mod flight_descriptor {
     pub use r#gen::flight_descriptor::DescriptorType;
 }
 
+/// Typed values of a session option.
+pub mod session_option_value {
+    use super::r#gen;
+    pub use r#gen::session_option_value::OptionValue;
+    pub use r#gen::session_option_value::StringListValue;
+}
+
+/// Per-option errors returned when setting session options.
+pub mod set_session_options_result {
+    use super::r#gen;
+    pub use r#gen::set_session_options_result::Error;
+    pub use r#gen::set_session_options_result::ErrorValue;
+}
+
+/// The outcome of closing a session.
+pub mod close_session_result {
+    use super::r#gen;
+    pub use r#gen::close_session_result::Status;
+}
+
 /// Low Level [tonic] [`FlightServiceClient`](gen::flight_service_client::FlightServiceClient).
 pub mod flight_service_client {
     use super::r#gen;
@@ -101,18 +121,30 @@ pub mod encode;
 /// Common error types
 pub mod error;
 
+/// Helpers for bidirectional `DoExchange` calls.
+/// See [`ExchangeSession`](exchange::ExchangeSession).
+pub mod exchange;
+
+/// Reader for all the endpoints of a [`FlightInfo`].
+/// See [`FlightInfoReaderBuilder`](reader::FlightInfoReaderBuilder).
+pub mod reader;
+
 pub use r#gen::Action;
 pub use r#gen::ActionType;
 pub use r#gen::BasicAuth;
 pub use r#gen::CancelFlightInfoRequest;
 pub use r#gen::CancelFlightInfoResult;
 pub use r#gen::CancelStatus;
+pub use r#gen::CloseSessionRequest;
+pub use r#gen::CloseSessionResult;
 pub use r#gen::Criteria;
 pub use r#gen::Empty;
 pub use r#gen::FlightData;
 pub use r#gen::FlightDescriptor;
 pub use r#gen::FlightEndpoint;
 pub use r#gen::FlightInfo;
+pub use r#gen::GetSessionOptionsRequest;
+pub use r#gen::GetSessionOptionsResult;
 pub use r#gen::HandshakeRequest;
 pub use r#gen::HandshakeResponse;
 pub use r#gen::Location;
@@ -121,6 +153,9 @@ pub use r#gen::PutResult;
 pub use r#gen::RenewFlightEndpointRequest;
 pub use r#gen::Result;
 pub use r#gen::SchemaResult;
+pub use r#gen::SessionOptionValue;
+pub use r#gen::SetSessionOptionsRequest;
+pub use r#gen::SetSessionOptionsResult;
 pub use r#gen::Ticket;
 
 /// Helper to extract HTTP/gRPC trailers from a tonic stream.
@@ -736,6 +771,120 @@ impl RenewFlightEndpointRequest {
     }
 }
 
+impl SessionOptionValue {
+    /// Create a [`SessionOptionValue`] without a value, which by convention
+    /// requests that the server unset the named option.
+    pub fn unset() -> Self {
+        Self { option_value: None }
+    }
+}
+
+impl From<String> for SessionOptionValue {
+    fn from(value: String) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::StringValue(value)),
+        }
+    }
+}
+
+impl From<&str> for SessionOptionValue {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl From<bool> for SessionOptionValue {
+    fn from(value: bool) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::BoolValue(value)),
+        }
+    }
+}
+
+impl From<i64> for SessionOptionValue {
+    fn from(value: i64) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::Int64Value(value)),
+        }
+    }
+}
+
+impl From<f64> for SessionOptionValue {
+    fn from(value: f64) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::DoubleValue(value)),
+        }
+    }
+}
+
+impl From<Vec<String>> for SessionOptionValue {
+    fn from(values: Vec<String>) -> Self {
+        Self {
+            option_value: Some(session_option_value::OptionValue::StringListValue(
+                session_option_value::StringListValue { values },
+            )),
+        }
+    }
+}
+
+impl SetSessionOptionsRequest {
+    /// Create a new [`SetSessionOptionsRequest`] from pairs of option names and values.
+    pub fn new<K, V>(options: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<SessionOptionValue>,
+    {
+        Self {
+            session_options: options
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        }
+    }
+}
+
+impl SetSessionOptionsResult {
+    /// Add an error for the named option, indicating it could not be set.
+    pub fn with_error(
+        mut self,
+        name: impl Into<String>,
+        error: set_session_options_result::ErrorValue,
+    ) -> Self {
+        self.errors.insert(
+            name.into(),
+            set_session_options_result::Error {
+                value: error as i32,
+            },
+        );
+        self
+    }
+}
+
+impl GetSessionOptionsResult {
+    /// Create a new [`GetSessionOptionsResult`] from pairs of option names and values.
+    pub fn new<K, V>(options: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<SessionOptionValue>,
+    {
+        Self {
+            session_options: options
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        }
+    }
+}
+
+impl CloseSessionResult {
+    /// Create a new [`CloseSessionResult`] from the provided [`close_session_result::Status`].
+    pub fn new(status: close_session_result::Status) -> Self {
+        Self {
+            status: status as i32,
+        }
+    }
+}
+
 impl Action {
     /// Create a new Action with type and body
     pub fn new(action_type: impl Into<String>, body: impl Into<Bytes>) -> Self {
@@ -0,0 +1,417 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read all the data described by a [`FlightInfo`]
+//!
+//! A [`FlightInfo`] lists one or more [`FlightEndpoint`]s, each with a
+//! [`Ticket`] that can be redeemed with `DoGet` at any of the endpoint's
+//! [`Location`]s. [`FlightInfoReader`] fetches every endpoint and returns
+//! their [`RecordBatch`]es as a single [`Stream`]:
+//!
+//! - Connections are made by a user supplied [`FlightConnector`]
+//! - Up to [`FlightInfoReaderBuilder::with_max_concurrency`] endpoints are
+//!   fetched at the same time
+//! - If [`FlightInfo::ordered`] is set, batches are returned in endpoint
+//!   order, see [`FlightInfoReaderBuilder::with_preserve_order`]
+//! - A failed endpoint is retried at its next location, see
+//!   [`FlightInfoReaderBuilder::with_max_attempts`]
+//!
+//! # Example
+//! ```no_run
+//! # async fn run() {
+//! # use arrow_flight::{FlightClient, FlightDescriptor, Location, error::FlightError};
+//! # use arrow_flight::reader::FlightInfoReaderBuilder;
+//! # use futures::TryStreamExt;
+//! # use tonic::transport::Channel;
+//! # let channel: Channel = unimplemented!();
+//! let mut client = FlightClient::new(channel.clone());
+//! let info = client
+//!     .get_flight_info(FlightDescriptor::new_cmd("my query"))
+//!     .await
+//!     .expect("error getting flight info");
+//!
+//! // Endpoints without a location are read from the service that returned
+//! // the FlightInfo, others by connecting to their location
+//! let connector = move |location: Option<Location>| {
+//!     let channel = channel.clone();
+//!     async move {
+//!         let channel = match location {
+//!             None => channel,
+//!             Some(location) => Channel::from_shared(location.uri.replace("grpc+tcp", "http"))
+//!                 .map_err(|e| FlightError::ExternalError(Box::new(e)))?
+//!                 .connect()
+//!                 .await
+//!                 .map_err(|e| FlightError::ExternalError(Box::new(e)))?,
+//!         };
+//!         Ok(FlightClient::new(channel))
+//!     }
+//! };
+//!
+//! let batches: Vec<_> = FlightInfoReaderBuilder::new(connector)
+//!     .with_max_concurrency(4)
+//!     .build(info)
+//!     .try_collect()
+//!     .await
+//!     .expect("error reading endpoints");
+//! # }
+//! ```
+//!
+//! [`Ticket`]: crate::Ticket
+
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use arrow_array::RecordBatch;
+use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream};
+use futures::{FutureExt, Stream, StreamExt, TryStreamExt};
+
+use crate::decode::FlightRecordBatchStream;
+use crate::error::{FlightError, Result};
+use crate::{FlightClient, FlightEndpoint, FlightInfo, Location};
+
+/// Location URI scheme meaning the ticket can be redeemed on the service
+/// that returned the [`FlightInfo`]
+const REUSE_CONNECTION_SCHEME: &str = "arrow-flight-reuse-connection:";
+
+/// Upper bound of the delay between two attempts to read an endpoint
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Creates [`FlightClient`]s connected to the [`Location`]s of
+/// [`FlightEndpoint`]s
+///
+/// This is implemented for closures taking an `Option<Location>` and
+/// returning a future of `Result<FlightClient>`.
+pub trait FlightConnector: Send + Sync {
+    /// Return a client connected to `location`
+    ///
+    /// `location` is `None` for endpoints that must be read from the service
+    /// that returned the [`FlightInfo`]: endpoints without locations or with
+    /// an `arrow-flight-reuse-connection://` location.
+    fn connect(&self, location: Option<&Location>) -> BoxFuture<'static, Result<FlightClient>>;
+}
+
+impl<F, Fut> FlightConnector for F
+where
+    F: Fn(Option<Location>) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<FlightClient>> + Send + 'static,
+{
+    fn connect(&self, location: Option<&Location>) -> BoxFuture<'static, Result<FlightClient>> {
+        self(location.cloned()).boxed()
+    }
+}
+
+/// Builder for a [`FlightInfoReader`]
+///
+/// See the [module level documentation](self) for an example.
+#[derive(Clone)]
+pub struct FlightInfoReaderBuilder {
+    /// Creates the clients used to read endpoints
+    connector: Arc<dyn FlightConnector>,
+    /// Maximum number of endpoints fetched at the same time
+    max_concurrency: usize,
+    /// Return batches in endpoint order if the `FlightInfo` is ordered
+    preserve_order: bool,
+    /// Maximum number of `DoGet` calls per endpoint, or one per location
+    max_attempts: Option<usize>,
+    /// Delay before the second attempt to read an endpoint
+    retry_backoff: Duration,
+}
+
+impl Debug for FlightInfoReaderBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlightInfoReaderBuilder")
+            .field("connector", &"<connector>")
+            .field("max_concurrency", &self.max_concurrency)
+            .field("preserve_order", &self.preserve_order)
+            .field("max_attempts", &self.max_attempts)
+            .field("retry_backoff", &self.retry_backoff)
+            .finish()
+    }
+}
+
+impl FlightInfoReaderBuilder {
+    /// Create a new builder connecting to endpoints using `connector`
+    pub fn new(connector: impl FlightConnector + 'static) -> Self {
+        Self {
+            connector: Arc::new(connector),
+            max_concurrency: 1,
+            preserve_order: true,
+            max_attempts: None,
+            retry_backoff: Duration::from_millis(100),
+        }
+    }
+
+    /// Set the maximum number of endpoints fetched at the same time
+    /// (defaults to 1)
+    ///
+    /// When order is preserved, the `DoGet` calls of up to this many
+    /// endpoints are started ahead of the one being read.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Set whether batches are returned in endpoint order when
+    /// [`FlightInfo::ordered`] is set (defaults to true)
+    ///
+    /// Batches of unordered [`FlightInfo`]s, or of any [`FlightInfo`] if this
+    /// is false, are returned as soon as they are received from any endpoint.
+    pub fn with_preserve_order(mut self, preserve_order: bool) -> Self {
+        self.preserve_order = preserve_order;
+        self
+    }
+
+    /// Set the maximum number of `DoGet` calls made to read each endpoint
+    /// (defaults to one per location)
+    ///
+    /// Each attempt after a failure uses the next location of the endpoint,
+    /// wrapping around to the first one. If a failure occurs after some
+    /// batches of the endpoint were returned, these are skipped when the
+    /// endpoint is read again, assuming every location returns the same
+    /// batches in the same order. An attempt that returns fewer batches than
+    /// were already returned fails.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = Some(max_attempts.max(1));
+        self
+    }
+
+    /// Set the delay before the second attempt to read an endpoint
+    /// (defaults to 100ms)
+    ///
+    /// The delay doubles with each further attempt, up to 30 seconds.
+    pub fn with_retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Return a [`FlightInfoReader`] for the endpoints of `info`
+    pub fn build(self, info: FlightInfo) -> FlightInfoReader {
+        let Self {
+            connector,
+            max_concurrency,
+            preserve_order,
+            max_attempts,
+            retry_backoff,
+        } = self;
+
+        let readers = info.endpoint.into_iter().map(move |endpoint| {
+            let max_attempts = max_attempts.unwrap_or(endpoint.location.len().max(1));
+            EndpointReader::new(
+                endpoint,
+                Arc::clone(&connector),
+                max_attempts,
+                retry_backoff,
+            )
+        });
+
+        let inner = if preserve_order && info.ordered {
+            stream::iter(readers)
+                .map(EndpointReader::start)
+                .buffered(max_concurrency)
+                .flat_map(EndpointReader::into_stream)
+                .boxed()
+        } else {
+            stream::iter(readers)
+                .map(EndpointReader::into_stream)
+                .flatten_unordered(max_concurrency)
+                .boxed()
+        };
+
+        FlightInfoReader { inner }
+    }
+}
+
+/// [`Stream`] of the [`RecordBatch`]es of all endpoints of a [`FlightInfo`]
+///
+/// Created by [`FlightInfoReaderBuilder::build`]. An error is returned for
+/// each endpoint that could not be read, after which the batches of the
+/// other endpoints are still returned.
+pub struct FlightInfoReader {
+    inner: BoxStream<'static, Result<RecordBatch>>,
+}
+
+impl Debug for FlightInfoReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlightInfoReader")
+            .field("inner", &"<stream>")
+            .finish()
+    }
+}
+
+impl Stream for FlightInfoReader {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+/// Reads the batches of a single endpoint, retrying at its other locations
+struct EndpointReader {
+    endpoint: FlightEndpoint,
+    connector: Arc<dyn FlightConnector>,
+    max_attempts: usize,
+    retry_backoff: Duration,
+    /// `DoGet` calls made so far
+    attempts: usize,
+    /// Batches returned so far, skipped when the endpoint is read again
+    returned: usize,
+    /// The current `DoGet` response
+    stream: Option<FlightRecordBatchStream>,
+    /// Error to return before reading, if starting the endpoint failed
+    error: Option<FlightError>,
+    /// Has the endpoint been read or failed
+    done: bool,
+}
+
+impl EndpointReader {
+    fn new(
+        endpoint: FlightEndpoint,
+        connector: Arc<dyn FlightConnector>,
+        max_attempts: usize,
+        retry_backoff: Duration,
+    ) -> Self {
+        Self {
+            endpoint,
+            connector,
+            max_attempts,
+            retry_backoff,
+            attempts: 0,
+            returned: 0,
+            stream: None,
+            error: None,
+            done: false,
+        }
+    }
+
+    /// Make the `DoGet` call of this endpoint ahead of reading it
+    async fn start(mut self) -> Self {
+        if let Err(e) = self.open().await {
+            self.error = Some(e);
+        }
+        self
+    }
+
+    fn into_stream(self) -> BoxStream<'static, Result<RecordBatch>> {
+        stream::unfold(self, |mut reader| async move {
+            let next = reader.next().await?;
+            Some((next, reader))
+        })
+        .boxed()
+    }
+
+    /// The location to use for the next attempt
+    fn location(&self) -> Option<&Location> {
+        let locations = &self.endpoint.location;
+        if locations.is_empty() {
+            return None;
+        }
+        let location = &locations[self.attempts % locations.len()];
+        (!location.uri.starts_with(REUSE_CONNECTION_SCHEME)).then_some(location)
+    }
+
+    /// Make `DoGet` calls until one succeeds or all attempts are used
+    async fn open(&mut self) -> Result<()> {
+        let ticket = self
+            .endpoint
+            .ticket
+            .clone()
+            .ok_or_else(|| FlightError::protocol("FlightEndpoint has no ticket"))?;
+
+        loop {
+            if self.attempts > 0 {
+                let exponent = (self.attempts - 1).min(16) as u32;
+                let backoff = self.retry_backoff.saturating_mul(1 << exponent);
+                tokio::time::sleep(backoff.min(MAX_RETRY_BACKOFF)).await;
+            }
+            let location = self.location().cloned();
+            self.attempts += 1;
+
+            let result = async {
+                let mut client = self.connector.connect(location.as_ref()).await?;
+                let mut stream = client.do_get(ticket.clone()).await?;
+                // resume after the batches already returned
+                for skipped in 0..self.returned {
+                    if stream.try_next().await?.is_none() {
+                        return Err(FlightError::protocol(format!(
+                            "Endpoint returned {skipped} batches when read again, \
+                            expected at least {}",
+                            self.returned
+                        )));
+                    }
+                }
+                Ok(stream)
+            }
+            .await;
+
+            match result {
+                Ok(stream) => {
+                    self.stream = Some(stream);
+                    return Ok(());
+                }
+                Err(e) if self.attempts >= self.max_attempts => return Err(e),
+                Err(_) => {}
+            }
+        }
+    }
+
+    async fn next(&mut self) -> Option<Result<RecordBatch>> {
+        if self.done {
+            return None;
+        }
+        if let Some(e) = self.error.take() {
+            self.done = true;
+            return Some(Err(e));
+        }
+
+        loop {
+            let stream = match self.stream.as_mut() {
+                Some(stream) => stream,
+                None => {
+                    if let Err(e) = self.open().await {
+                        self.done = true;
+                        return Some(Err(e));
+                    }
+                    continue;
+                }
+            };
+
+            match stream.next().await {
+                Some(Ok(batch)) => {
+                    self.returned += 1;
+                    return Some(Ok(batch));
+                }
+                None => {
+                    self.done = true;
+                    return None;
+                }
+                Some(Err(e)) => {
+                    self.stream = None;
+                    if self.attempts >= self.max_attempts {
+                        self.done = true;
+                        return Some(Err(e));
+                    }
+                }
+            }
+        }
+    }
+}
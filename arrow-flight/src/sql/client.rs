@@ -37,23 +37,29 @@ use crate::error::Result;
 use crate::flight_service_client::FlightServiceClient;
 use crate::sql::r#gen::action_end_transaction_request::EndTransaction;
 use crate::sql::server::{
-    BEGIN_TRANSACTION, CLOSE_PREPARED_STATEMENT, CREATE_PREPARED_STATEMENT, END_TRANSACTION,
+    BEGIN_SAVEPOINT, BEGIN_TRANSACTION, CANCEL_QUERY, CLOSE_PREPARED_STATEMENT, CLOSE_SESSION,
+    CREATE_PREPARED_STATEMENT, CREATE_PREPARED_SUBSTRAIT_PLAN, END_SAVEPOINT, END_TRANSACTION,
+    GET_SESSION_OPTIONS, SET_SESSION_OPTIONS,
 };
 use crate::sql::{
-    ActionBeginTransactionRequest, ActionBeginTransactionResult,
+    ActionBeginSavepointRequest, ActionBeginSavepointResult, ActionBeginTransactionRequest,
+    ActionBeginTransactionResult, ActionCancelQueryRequest, ActionCancelQueryResult,
     ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
-    ActionCreatePreparedStatementResult, ActionEndTransactionRequest, Any, CommandGetCatalogs,
+    ActionCreatePreparedStatementResult, ActionCreatePreparedSubstraitPlanRequest,
+    ActionEndSavepointRequest, ActionEndTransactionRequest, Any, CancelResult, CommandGetCatalogs,
     CommandGetCrossReference, CommandGetDbSchemas, CommandGetExportedKeys, CommandGetImportedKeys,
     CommandGetPrimaryKeys, CommandGetSqlInfo, CommandGetTableTypes, CommandGetTables,
     CommandGetXdbcTypeInfo, CommandPreparedStatementQuery, CommandPreparedStatementUpdate,
-    CommandStatementIngest, CommandStatementQuery, CommandStatementUpdate,
-    DoPutPreparedStatementResult, DoPutUpdateResult, ProstMessageExt, SqlInfo,
+    CommandStatementIngest, CommandStatementQuery, CommandStatementSubstraitPlan,
+    CommandStatementUpdate, DoPutPreparedStatementResult, DoPutUpdateResult, EndSavepoint,
+    ProstMessageExt, SqlInfo, SubstraitPlan,
 };
 use crate::streams::FallibleRequestStream;
 use crate::trailers::extract_lazy_trailers;
 use crate::{
-    Action, FlightData, FlightDescriptor, FlightInfo, HandshakeRequest, HandshakeResponse,
-    IpcMessage, PutResult, Ticket,
+    Action, CloseSessionRequest, CloseSessionResult, FlightData, FlightDescriptor, FlightInfo,
+    GetSessionOptionsRequest, GetSessionOptionsResult, HandshakeRequest, HandshakeResponse,
+    IpcMessage, PutResult, SetSessionOptionsRequest, SetSessionOptionsResult, Ticket,
 };
 use arrow_array::RecordBatch;
 use arrow_schema::{ArrowError, Schema};
@@ -155,6 +161,19 @@ where
         self.get_flight_info_for_command(cmd).await
     }
 
+    /// Execute a Substrait plan on the server.
+    pub async fn execute_substrait(
+        &mut self,
+        plan: SubstraitPlan,
+        transaction_id: Option<Bytes>,
+    ) -> Result<FlightInfo> {
+        let cmd = CommandStatementSubstraitPlan {
+            plan: Some(plan),
+            transaction_id,
+        };
+        self.get_flight_info_for_command(cmd).await
+    }
+
     /// Perform a `handshake` with the server, passing credentials and establishing a session.
     ///
     /// If the server returns an "authorization" header, it is automatically parsed and set as
@@ -213,6 +232,23 @@ where
             query,
             transaction_id,
         };
+        self.do_put_update_for_command(cmd).await
+    }
+
+    /// Execute an update Substrait plan on the server, and return the number of records affected
+    pub async fn execute_substrait_update(
+        &mut self,
+        plan: SubstraitPlan,
+        transaction_id: Option<Bytes>,
+    ) -> Result<i64> {
+        let cmd = CommandStatementSubstraitPlan {
+            plan: Some(plan),
+            transaction_id,
+        };
+        self.do_put_update_for_command(cmd).await
+    }
+
+    async fn do_put_update_for_command<M: ProstMessageExt>(&mut self, cmd: M) -> Result<i64> {
         let descriptor = FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec());
         let req = self.set_request_headers(
             stream::iter(vec![FlightData {
@@ -385,6 +421,33 @@ where
             r#type: CREATE_PREPARED_STATEMENT.to_string(),
             body: cmd.as_any().encode_to_vec().into(),
         };
+        self.create_prepared_statement(action).await
+    }
+
+    /// Create a prepared statement object from a Substrait plan.
+    pub async fn prepare_substrait(
+        &mut self,
+        plan: SubstraitPlan,
+        transaction_id: Option<Bytes>,
+    ) -> Result<PreparedStatement<T>>
+    where
+        T: Clone,
+    {
+        let cmd = ActionCreatePreparedSubstraitPlanRequest {
+            plan: Some(plan),
+            transaction_id,
+        };
+        let action = Action {
+            r#type: CREATE_PREPARED_SUBSTRAIT_PLAN.to_string(),
+            body: cmd.as_any().encode_to_vec().into(),
+        };
+        self.create_prepared_statement(action).await
+    }
+
+    async fn create_prepared_statement(&mut self, action: Action) -> Result<PreparedStatement<T>>
+    where
+        T: Clone,
+    {
         let req = self.set_request_headers(action.into_request())?;
         let mut result = self.flight_client.do_action(req).await?.into_inner();
         let result = result.message().await?.unwrap();
@@ -440,6 +503,110 @@ where
         Ok(())
     }
 
+    /// Request to create a savepoint with the given name within a transaction,
+    /// returning the savepoint id.
+    pub async fn begin_savepoint(&mut self, transaction_id: Bytes, name: String) -> Result<Bytes> {
+        let cmd = ActionBeginSavepointRequest {
+            transaction_id,
+            name,
+        };
+        let action = Action {
+            r#type: BEGIN_SAVEPOINT.to_string(),
+            body: cmd.as_any().encode_to_vec().into(),
+        };
+        let req = self.set_request_headers(action.into_request())?;
+        let mut result = self.flight_client.do_action(req).await?.into_inner();
+        let result = result.message().await?.unwrap();
+        let any = Any::decode(&*result.body)?;
+        let begin_result: ActionBeginSavepointResult = any.unpack()?.unwrap();
+        Ok(begin_result.savepoint_id)
+    }
+
+    /// Request to release/rollback a savepoint.
+    pub async fn end_savepoint(&mut self, savepoint_id: Bytes, action: EndSavepoint) -> Result<()> {
+        let cmd = ActionEndSavepointRequest {
+            savepoint_id,
+            action: action as i32,
+        };
+        let action = Action {
+            r#type: END_SAVEPOINT.to_string(),
+            body: cmd.as_any().encode_to_vec().into(),
+        };
+        let req = self.set_request_headers(action.into_request())?;
+        let _ = self.flight_client.do_action(req).await?.into_inner();
+        Ok(())
+    }
+
+    /// Request to cancel the query described by `info`, as returned when it was executed.
+    ///
+    /// Note this action is deprecated by the Flight SQL protocol in favor of the
+    /// `CancelFlightInfo` action, but is still supported by many servers.
+    pub async fn cancel_query(&mut self, info: FlightInfo) -> Result<CancelResult> {
+        let cmd = ActionCancelQueryRequest {
+            info: info.encode_to_vec().into(),
+        };
+        let action = Action {
+            r#type: CANCEL_QUERY.to_string(),
+            body: cmd.as_any().encode_to_vec().into(),
+        };
+        let req = self.set_request_headers(action.into_request())?;
+        let mut result = self.flight_client.do_action(req).await?.into_inner();
+        let result = result.message().await?.unwrap();
+        let any = Any::decode(&*result.body)?;
+        let cancel_result: ActionCancelQueryResult = any.unpack()?.unwrap();
+        CancelResult::try_from(cancel_result.result).map_err(|_| {
+            ArrowError::ParseError(format!(
+                "Unknown cancel query result: {}",
+                cancel_result.result
+            ))
+            .into()
+        })
+    }
+
+    /// Set options for the current session, returning the result which lists
+    /// any options the server could not set.
+    ///
+    /// The server identifies the session through the transport, typically a
+    /// cookie, which the underlying service is responsible for resending.
+    pub async fn set_session_options(
+        &mut self,
+        request: SetSessionOptionsRequest,
+    ) -> Result<SetSessionOptionsResult> {
+        let body = self.do_session_action(SET_SESSION_OPTIONS, request).await?;
+        Ok(SetSessionOptionsResult::decode(body)?)
+    }
+
+    /// Get the options of the current session.
+    pub async fn get_session_options(&mut self) -> Result<GetSessionOptionsResult> {
+        let body = self
+            .do_session_action(GET_SESSION_OPTIONS, GetSessionOptionsRequest {})
+            .await?;
+        Ok(GetSessionOptionsResult::decode(body)?)
+    }
+
+    /// Close the current session.
+    pub async fn close_session(&mut self) -> Result<CloseSessionResult> {
+        let body = self
+            .do_session_action(CLOSE_SESSION, CloseSessionRequest {})
+            .await?;
+        Ok(CloseSessionResult::decode(body)?)
+    }
+
+    /// Session actions are part of the core Flight protocol, so unlike other
+    /// Flight SQL actions their messages are not wrapped in an `Any`
+    async fn do_session_action<M: Message>(&mut self, r#type: &str, request: M) -> Result<Bytes> {
+        let action = Action {
+            r#type: r#type.to_string(),
+            body: request.encode_to_vec().into(),
+        };
+        let req = self.set_request_headers(action.into_request())?;
+        let mut result = self.flight_client.do_action(req).await?.into_inner();
+        let result = result.message().await?.ok_or_else(|| {
+            FlightError::protocol(format!("Received no response for {type} call"))
+        })?;
+        Ok(result.body)
+    }
+
     /// Explicitly shut down and clean up the client.
     #[expect(
         clippy::unused_async,
@@ -0,0 +1,779 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Builders for responses to the primary and foreign key metadata requests.
+//!
+//! - [`GetPrimaryKeysBuilder`] for building responses to [`CommandGetPrimaryKeys`] queries.
+//! - [`GetExportedKeysBuilder`] for building responses to [`CommandGetExportedKeys`] queries.
+//! - [`GetImportedKeysBuilder`] for building responses to [`CommandGetImportedKeys`] queries.
+//! - [`GetCrossReferenceBuilder`] for building responses to [`CommandGetCrossReference`] queries.
+//!
+//! [`CommandGetPrimaryKeys`]: crate::sql::CommandGetPrimaryKeys
+//! [`CommandGetExportedKeys`]: crate::sql::CommandGetExportedKeys
+//! [`CommandGetImportedKeys`]: crate::sql::CommandGetImportedKeys
+//! [`CommandGetCrossReference`]: crate::sql::CommandGetCrossReference
+
+use std::sync::Arc;
+
+use arrow_arith::boolean::and;
+use arrow_array::builder::{Int32Builder, StringBuilder, UInt8Builder};
+use arrow_array::{ArrayRef, BooleanArray, RecordBatch, StringArray};
+use arrow_ord::cmp::eq;
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use arrow_select::{filter::filter_record_batch, take::take};
+use once_cell::sync::Lazy;
+
+use super::lexsort_to_indices;
+use crate::error::*;
+use crate::sql::{
+    CommandGetCrossReference, CommandGetExportedKeys, CommandGetImportedKeys,
+    CommandGetPrimaryKeys, UpdateDeleteRules,
+};
+
+/// A builder for a [`CommandGetPrimaryKeys`] response.
+///
+/// Builds rows like this:
+///
+/// * catalog_name: utf8,
+/// * db_schema_name: utf8,
+/// * table_name: utf8 not null,
+/// * column_name: utf8 not null,
+/// * key_name: utf8,
+/// * key_sequence: int32 not null
+pub struct GetPrimaryKeysBuilder {
+    // Specifies the Catalog to search for the table.
+    // - An empty string retrieves those without a catalog.
+    // - If omitted the catalog name is not used to narrow the search.
+    catalog_filter: Option<String>,
+    // Specifies the schema to search for the table, with the same rules as the catalog
+    db_schema_filter: Option<String>,
+    // Specifies the table to get the primary keys for
+    table_filter: String,
+    // array builders for the columns
+    catalog_name: StringBuilder,
+    db_schema_name: StringBuilder,
+    table_name: StringBuilder,
+    column_name: StringBuilder,
+    key_name: StringBuilder,
+    key_sequence: Int32Builder,
+}
+
+impl CommandGetPrimaryKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetPrimaryKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetPrimaryKeys> for GetPrimaryKeysBuilder {
+    fn from(value: CommandGetPrimaryKeys) -> Self {
+        Self::new(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl GetPrimaryKeysBuilder {
+    /// Create a new instance of [`GetPrimaryKeysBuilder`]
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`:  Specifies the Catalog to search for the table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema to search for the table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the table to get the primary keys for.
+    pub fn new(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            catalog_filter: catalog.map(|v| v.into()),
+            db_schema_filter: db_schema.map(|v| v.into()),
+            table_filter: table.into(),
+            catalog_name: StringBuilder::new(),
+            db_schema_name: StringBuilder::new(),
+            table_name: StringBuilder::new(),
+            column_name: StringBuilder::new(),
+            key_name: StringBuilder::new(),
+            key_sequence: Int32Builder::new(),
+        }
+    }
+
+    /// Append a row for a column of a primary key
+    ///
+    /// In case the catalog or schema should be considered as empty, pass in an empty string '""'.
+    /// `key_sequence` is the 1-based position of the column within the key.
+    pub fn append(
+        &mut self,
+        catalog_name: impl AsRef<str>,
+        schema_name: impl AsRef<str>,
+        table_name: impl AsRef<str>,
+        column_name: impl AsRef<str>,
+        key_name: Option<impl AsRef<str>>,
+        key_sequence: i32,
+    ) {
+        self.catalog_name.append_value(catalog_name);
+        self.db_schema_name.append_value(schema_name);
+        self.table_name.append_value(table_name);
+        self.column_name.append_value(column_name);
+        self.key_name.append_option(key_name);
+        self.key_sequence.append_value(key_sequence);
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a `CommandGetPrimaryKeys` response
+    pub fn build(self) -> Result<RecordBatch> {
+        let schema = self.schema();
+        let Self {
+            catalog_filter,
+            db_schema_filter,
+            table_filter,
+            mut catalog_name,
+            mut db_schema_name,
+            mut table_name,
+            mut column_name,
+            mut key_name,
+            mut key_sequence,
+        } = self;
+
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(catalog_name.finish()) as ArrayRef,
+                Arc::new(db_schema_name.finish()) as ArrayRef,
+                Arc::new(table_name.finish()) as ArrayRef,
+                Arc::new(column_name.finish()) as ArrayRef,
+                Arc::new(key_name.finish()) as ArrayRef,
+                Arc::new(key_sequence.finish()) as ArrayRef,
+            ],
+        )?;
+
+        let filter = table_filter_mask(
+            &batch,
+            [0, 1, 2],
+            [catalog_filter, db_schema_filter, Some(table_filter)],
+        )?;
+
+        // Order by catalog_name, db_schema_name, table_name, key_name, then key_sequence
+        filter_and_sort(&batch, filter, &[0, 1, 2, 4, 5])
+    }
+
+    /// Return the schema of the RecordBatch that will be returned
+    /// from [`CommandGetPrimaryKeys`]
+    pub fn schema(&self) -> SchemaRef {
+        get_primary_keys_schema()
+    }
+}
+
+/// A foreign key column, as returned by the exported keys, imported keys and
+/// cross reference requests.
+///
+/// The primary key table is the table referenced by the foreign key table.
+/// An empty catalog or schema name means the table has none.
+#[derive(Debug, Clone, Default)]
+pub struct ForeignKey {
+    /// The catalog of the primary key table
+    pub pk_catalog_name: String,
+    /// The schema of the primary key table
+    pub pk_db_schema_name: String,
+    /// The name of the primary key table
+    pub pk_table_name: String,
+    /// The primary key column referenced by the foreign key column
+    pub pk_column_name: String,
+    /// The catalog of the foreign key table
+    pub fk_catalog_name: String,
+    /// The schema of the foreign key table
+    pub fk_db_schema_name: String,
+    /// The name of the foreign key table
+    pub fk_table_name: String,
+    /// The foreign key column
+    pub fk_column_name: String,
+    /// The 1-based position of the column within the foreign key
+    pub key_sequence: i32,
+    /// The name of the foreign key, if any
+    pub fk_key_name: Option<String>,
+    /// The name of the referenced primary key, if any
+    pub pk_key_name: Option<String>,
+    /// What happens to the foreign key when the primary key is updated
+    pub update_rule: UpdateDeleteRules,
+    /// What happens to the foreign key when the primary key is deleted
+    pub delete_rule: UpdateDeleteRules,
+}
+
+/// Array builders for the foreign key columns, shared by the foreign key response builders
+struct ForeignKeysBuilder {
+    pk_catalog_name: StringBuilder,
+    pk_db_schema_name: StringBuilder,
+    pk_table_name: StringBuilder,
+    pk_column_name: StringBuilder,
+    fk_catalog_name: StringBuilder,
+    fk_db_schema_name: StringBuilder,
+    fk_table_name: StringBuilder,
+    fk_column_name: StringBuilder,
+    key_sequence: Int32Builder,
+    fk_key_name: StringBuilder,
+    pk_key_name: StringBuilder,
+    update_rule: UInt8Builder,
+    delete_rule: UInt8Builder,
+}
+
+impl ForeignKeysBuilder {
+    fn new() -> Self {
+        Self {
+            pk_catalog_name: StringBuilder::new(),
+            pk_db_schema_name: StringBuilder::new(),
+            pk_table_name: StringBuilder::new(),
+            pk_column_name: StringBuilder::new(),
+            fk_catalog_name: StringBuilder::new(),
+            fk_db_schema_name: StringBuilder::new(),
+            fk_table_name: StringBuilder::new(),
+            fk_column_name: StringBuilder::new(),
+            key_sequence: Int32Builder::new(),
+            fk_key_name: StringBuilder::new(),
+            pk_key_name: StringBuilder::new(),
+            update_rule: UInt8Builder::new(),
+            delete_rule: UInt8Builder::new(),
+        }
+    }
+
+    fn append(&mut self, key: ForeignKey) {
+        self.pk_catalog_name.append_value(key.pk_catalog_name);
+        self.pk_db_schema_name.append_value(key.pk_db_schema_name);
+        self.pk_table_name.append_value(key.pk_table_name);
+        self.pk_column_name.append_value(key.pk_column_name);
+        self.fk_catalog_name.append_value(key.fk_catalog_name);
+        self.fk_db_schema_name.append_value(key.fk_db_schema_name);
+        self.fk_table_name.append_value(key.fk_table_name);
+        self.fk_column_name.append_value(key.fk_column_name);
+        self.key_sequence.append_value(key.key_sequence);
+        self.fk_key_name.append_option(key.fk_key_name);
+        self.pk_key_name.append_option(key.pk_key_name);
+        self.update_rule.append_value(key.update_rule as u8);
+        self.delete_rule.append_value(key.delete_rule as u8);
+    }
+
+    fn finish(mut self) -> Result<RecordBatch> {
+        Ok(RecordBatch::try_new(
+            get_foreign_keys_schema(),
+            vec![
+                Arc::new(self.pk_catalog_name.finish()) as ArrayRef,
+                Arc::new(self.pk_db_schema_name.finish()) as ArrayRef,
+                Arc::new(self.pk_table_name.finish()) as ArrayRef,
+                Arc::new(self.pk_column_name.finish()) as ArrayRef,
+                Arc::new(self.fk_catalog_name.finish()) as ArrayRef,
+                Arc::new(self.fk_db_schema_name.finish()) as ArrayRef,
+                Arc::new(self.fk_table_name.finish()) as ArrayRef,
+                Arc::new(self.fk_column_name.finish()) as ArrayRef,
+                Arc::new(self.key_sequence.finish()) as ArrayRef,
+                Arc::new(self.fk_key_name.finish()) as ArrayRef,
+                Arc::new(self.pk_key_name.finish()) as ArrayRef,
+                Arc::new(self.update_rule.finish()) as ArrayRef,
+                Arc::new(self.delete_rule.finish()) as ArrayRef,
+            ],
+        )?)
+    }
+}
+
+// Column indices of the foreign key schema
+const PK_TABLE_COLUMNS: [usize; 3] = [0, 1, 2];
+const FK_TABLE_COLUMNS: [usize; 3] = [4, 5, 6];
+const KEY_SEQUENCE: usize = 8;
+const FK_KEY_NAME: usize = 9;
+const PK_KEY_NAME: usize = 10;
+
+/// A builder for a [`CommandGetExportedKeys`] response.
+///
+/// Returns the foreign keys that reference the primary key of the requested
+/// table, with the schema of [`ForeignKey`], ordered by fk_catalog_name,
+/// fk_db_schema_name, fk_table_name, fk_key_name, then key_sequence.
+pub struct GetExportedKeysBuilder {
+    // Filters on the primary key table
+    catalog_filter: Option<String>,
+    db_schema_filter: Option<String>,
+    table_filter: String,
+    keys: ForeignKeysBuilder,
+}
+
+impl CommandGetExportedKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetExportedKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetExportedKeys> for GetExportedKeysBuilder {
+    fn from(value: CommandGetExportedKeys) -> Self {
+        Self::new(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl GetExportedKeysBuilder {
+    /// Create a new instance of [`GetExportedKeysBuilder`]
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`:  Specifies the Catalog to search for the primary key table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema to search for the primary key table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the table whose exported keys are requested.
+    pub fn new(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            catalog_filter: catalog.map(|v| v.into()),
+            db_schema_filter: db_schema.map(|v| v.into()),
+            table_filter: table.into(),
+            keys: ForeignKeysBuilder::new(),
+        }
+    }
+
+    /// Append a row
+    pub fn append(&mut self, key: ForeignKey) {
+        self.keys.append(key)
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a `CommandGetExportedKeys` response
+    pub fn build(self) -> Result<RecordBatch> {
+        let batch = self.keys.finish()?;
+        let filter = table_filter_mask(
+            &batch,
+            PK_TABLE_COLUMNS,
+            [
+                self.catalog_filter,
+                self.db_schema_filter,
+                Some(self.table_filter),
+            ],
+        )?;
+        let [catalog, schema, table] = FK_TABLE_COLUMNS;
+        filter_and_sort(
+            &batch,
+            filter,
+            &[catalog, schema, table, FK_KEY_NAME, KEY_SEQUENCE],
+        )
+    }
+
+    /// Return the schema of the RecordBatch that will be returned
+    /// from [`CommandGetExportedKeys`]
+    pub fn schema(&self) -> SchemaRef {
+        get_foreign_keys_schema()
+    }
+}
+
+/// A builder for a [`CommandGetImportedKeys`] response.
+///
+/// Returns the primary keys referenced by the foreign keys of the requested
+/// table, with the schema of [`ForeignKey`], ordered by pk_catalog_name,
+/// pk_db_schema_name, pk_table_name, pk_key_name, then key_sequence.
+pub struct GetImportedKeysBuilder {
+    // Filters on the foreign key table
+    catalog_filter: Option<String>,
+    db_schema_filter: Option<String>,
+    table_filter: String,
+    keys: ForeignKeysBuilder,
+}
+
+impl CommandGetImportedKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetImportedKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetImportedKeys> for GetImportedKeysBuilder {
+    fn from(value: CommandGetImportedKeys) -> Self {
+        Self::new(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl GetImportedKeysBuilder {
+    /// Create a new instance of [`GetImportedKeysBuilder`]
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`:  Specifies the Catalog to search for the foreign key table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema to search for the foreign key table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the table whose imported keys are requested.
+    pub fn new(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            catalog_filter: catalog.map(|v| v.into()),
+            db_schema_filter: db_schema.map(|v| v.into()),
+            table_filter: table.into(),
+            keys: ForeignKeysBuilder::new(),
+        }
+    }
+
+    /// Append a row
+    pub fn append(&mut self, key: ForeignKey) {
+        self.keys.append(key)
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a `CommandGetImportedKeys` response
+    pub fn build(self) -> Result<RecordBatch> {
+        let batch = self.keys.finish()?;
+        let filter = table_filter_mask(
+            &batch,
+            FK_TABLE_COLUMNS,
+            [
+                self.catalog_filter,
+                self.db_schema_filter,
+                Some(self.table_filter),
+            ],
+        )?;
+        let [catalog, schema, table] = PK_TABLE_COLUMNS;
+        filter_and_sort(
+            &batch,
+            filter,
+            &[catalog, schema, table, PK_KEY_NAME, KEY_SEQUENCE],
+        )
+    }
+
+    /// Return the schema of the RecordBatch that will be returned
+    /// from [`CommandGetImportedKeys`]
+    pub fn schema(&self) -> SchemaRef {
+        get_foreign_keys_schema()
+    }
+}
+
+/// A builder for a [`CommandGetCrossReference`] response.
+///
+/// Returns the foreign keys of the foreign key table that reference the
+/// primary key table, with the schema of [`ForeignKey`], ordered by
+/// pk_catalog_name, pk_db_schema_name, pk_table_name, pk_key_name, then
+/// key_sequence.
+pub struct GetCrossReferenceBuilder {
+    pk_catalog_filter: Option<String>,
+    pk_db_schema_filter: Option<String>,
+    pk_table_filter: String,
+    fk_catalog_filter: Option<String>,
+    fk_db_schema_filter: Option<String>,
+    fk_table_filter: String,
+    keys: ForeignKeysBuilder,
+}
+
+impl CommandGetCrossReference {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetCrossReferenceBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetCrossReference> for GetCrossReferenceBuilder {
+    fn from(value: CommandGetCrossReference) -> Self {
+        Self::new(
+            value.pk_catalog,
+            value.pk_db_schema,
+            value.pk_table,
+            value.fk_catalog,
+            value.fk_db_schema,
+            value.fk_table,
+        )
+    }
+}
+
+impl GetCrossReferenceBuilder {
+    /// Create a new instance of [`GetCrossReferenceBuilder`]
+    ///
+    /// The catalog and schema of both tables follow the same rules:
+    /// - An empty string retrieves those without a catalog / schema.
+    /// - If omitted the name is not used to narrow the search.
+    pub fn new(
+        pk_catalog: Option<impl Into<String>>,
+        pk_db_schema: Option<impl Into<String>>,
+        pk_table: impl Into<String>,
+        fk_catalog: Option<impl Into<String>>,
+        fk_db_schema: Option<impl Into<String>>,
+        fk_table: impl Into<String>,
+    ) -> Self {
+        Self {
+            pk_catalog_filter: pk_catalog.map(|v| v.into()),
+            pk_db_schema_filter: pk_db_schema.map(|v| v.into()),
+            pk_table_filter: pk_table.into(),
+            fk_catalog_filter: fk_catalog.map(|v| v.into()),
+            fk_db_schema_filter: fk_db_schema.map(|v| v.into()),
+            fk_table_filter: fk_table.into(),
+            keys: ForeignKeysBuilder::new(),
+        }
+    }
+
+    /// Append a row
+    pub fn append(&mut self, key: ForeignKey) {
+        self.keys.append(key)
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a `CommandGetCrossReference` response
+    pub fn build(self) -> Result<RecordBatch> {
+        let batch = self.keys.finish()?;
+        let pk_filter = table_filter_mask(
+            &batch,
+            PK_TABLE_COLUMNS,
+            [
+                self.pk_catalog_filter,
+                self.pk_db_schema_filter,
+                Some(self.pk_table_filter),
+            ],
+        )?;
+        let fk_filter = table_filter_mask(
+            &batch,
+            FK_TABLE_COLUMNS,
+            [
+                self.fk_catalog_filter,
+                self.fk_db_schema_filter,
+                Some(self.fk_table_filter),
+            ],
+        )?;
+        let filter = match (pk_filter, fk_filter) {
+            (Some(pk), Some(fk)) => Some(and(&pk, &fk)?),
+            (pk, fk) => pk.or(fk),
+        };
+        let [catalog, schema, table] = PK_TABLE_COLUMNS;
+        filter_and_sort(
+            &batch,
+            filter,
+            &[catalog, schema, table, PK_KEY_NAME, KEY_SEQUENCE],
+        )
+    }
+
+    /// Return the schema of the RecordBatch that will be returned
+    /// from [`CommandGetCrossReference`]
+    pub fn schema(&self) -> SchemaRef {
+        get_foreign_keys_schema()
+    }
+}
+
+/// Returns the mask of the rows whose catalog, schema and table columns
+/// equal the given names, ignoring the names that are `None`
+fn table_filter_mask(
+    batch: &RecordBatch,
+    columns: [usize; 3],
+    names: [Option<String>; 3],
+) -> Result<Option<BooleanArray>> {
+    let mut total_filter = None;
+    for (column, name) in columns.into_iter().zip(names) {
+        let Some(name) = name else { continue };
+        let scalar = StringArray::new_scalar(name);
+        let filter = eq(batch.column(column), &scalar)?;
+        total_filter = Some(match total_filter {
+            Some(total_filter) => and(&total_filter, &filter)?,
+            None => filter,
+        });
+    }
+    Ok(total_filter)
+}
+
+/// Applies the filter, if any, and orders the rows by the given columns
+fn filter_and_sort(
+    batch: &RecordBatch,
+    filter: Option<BooleanArray>,
+    sort_columns: &[usize],
+) -> Result<RecordBatch> {
+    let filtered_batch = match filter {
+        Some(filter) => filter_record_batch(batch, &filter)?,
+        None => batch.clone(),
+    };
+
+    let sort_arrays: Vec<_> = sort_columns
+        .iter()
+        .map(|i| Arc::clone(filtered_batch.column(*i)))
+        .collect();
+    let indices = lexsort_to_indices(&sort_arrays);
+    let columns = filtered_batch
+        .columns()
+        .iter()
+        .map(|c| take(c, &indices, None))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    Ok(RecordBatch::try_new(filtered_batch.schema(), columns)?)
+}
+
+fn get_primary_keys_schema() -> SchemaRef {
+    Arc::clone(&GET_PRIMARY_KEYS_SCHEMA)
+}
+
+/// The schema for GetPrimaryKeys
+static GET_PRIMARY_KEYS_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, true),
+        Field::new("table_name", DataType::Utf8, false),
+        Field::new("column_name", DataType::Utf8, false),
+        Field::new("key_name", DataType::Utf8, true),
+        Field::new("key_sequence", DataType::Int32, false),
+    ]))
+});
+
+fn get_foreign_keys_schema() -> SchemaRef {
+    Arc::clone(&GET_FOREIGN_KEYS_SCHEMA)
+}
+
+/// The schema for GetExportedKeys, GetImportedKeys and GetCrossReference
+static GET_FOREIGN_KEYS_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("pk_catalog_name", DataType::Utf8, true),
+        Field::new("pk_db_schema_name", DataType::Utf8, true),
+        Field::new("pk_table_name", DataType::Utf8, false),
+        Field::new("pk_column_name", DataType::Utf8, false),
+        Field::new("fk_catalog_name", DataType::Utf8, true),
+        Field::new("fk_db_schema_name", DataType::Utf8, true),
+        Field::new("fk_table_name", DataType::Utf8, false),
+        Field::new("fk_column_name", DataType::Utf8, false),
+        Field::new("key_sequence", DataType::Int32, false),
+        Field::new("fk_key_name", DataType::Utf8, true),
+        Field::new("pk_key_name", DataType::Utf8, true),
+        Field::new("update_rule", DataType::UInt8, false),
+        Field::new("delete_rule", DataType::UInt8, false),
+    ]))
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::metadata::tests::assert_batches_eq;
+
+    fn foreign_key(pk_table: &str, fk_table: &str, column: &str, key_sequence: i32) -> ForeignKey {
+        ForeignKey {
+            pk_catalog_name: "catalog".into(),
+            pk_db_schema_name: "schema".into(),
+            pk_table_name: pk_table.into(),
+            pk_column_name: format!("{pk_table}_{column}"),
+            fk_catalog_name: "catalog".into(),
+            fk_db_schema_name: "schema".into(),
+            fk_table_name: fk_table.into(),
+            fk_column_name: format!("{fk_table}_{column}"),
+            key_sequence,
+            fk_key_name: Some(format!("{fk_table}_fk")),
+            pk_key_name: Some(format!("{pk_table}_pk")),
+            update_rule: UpdateDeleteRules::Cascade,
+            delete_rule: UpdateDeleteRules::Restrict,
+        }
+    }
+
+    fn append_keys(mut append: impl FnMut(ForeignKey)) {
+        append(foreign_key("b", "c", "y", 2));
+        append(foreign_key("a", "c", "x", 1));
+        append(foreign_key("b", "c", "x", 1));
+        append(foreign_key("a", "b", "x", 1));
+    }
+
+    #[test]
+    fn test_primary_keys_are_filtered_and_sorted() {
+        let mut builder = GetPrimaryKeysBuilder::new(Some("catalog"), None::<String>, "a");
+        builder.append("catalog", "schema", "a", "id2", Some("a_pk"), 2);
+        builder.append("catalog", "schema", "b", "id", Some("b_pk"), 1);
+        builder.append("other", "schema", "a", "id", Some("a_pk"), 1);
+        builder.append("catalog", "", "a", "id", None::<&str>, 1);
+        builder.append("catalog", "schema", "a", "id1", Some("a_pk"), 1);
+        let batch = builder.build().unwrap();
+
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| catalog_name | db_schema_name | table_name | column_name | key_name | key_sequence |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| catalog      |                | a          | id          |          | 1            |",
+                "| catalog      | schema         | a          | id1         | a_pk     | 1            |",
+                "| catalog      | schema         | a          | id2         | a_pk     | 2            |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_exported_keys() {
+        let mut builder = CommandGetExportedKeys {
+            catalog: None,
+            db_schema: Some("schema".into()),
+            table: "b".into(),
+        }
+        .into_builder();
+        append_keys(|key| builder.append(key));
+        let batch = builder.build().unwrap();
+        assert_eq!(batch.schema(), get_foreign_keys_schema());
+
+        assert_batches_eq(
+            &[batch.project(&[2, 3, 6, 7, 8, 9, 11, 12]).unwrap()],
+            &[
+                "+---------------+----------------+---------------+----------------+--------------+-------------+-------------+-------------+",
+                "| pk_table_name | pk_column_name | fk_table_name | fk_column_name | key_sequence | fk_key_name | update_rule | delete_rule |",
+                "+---------------+----------------+---------------+----------------+--------------+-------------+-------------+-------------+",
+                "| b             | b_x            | c             | c_x            | 1            | c_fk        | 0           | 1           |",
+                "| b             | b_y            | c             | c_y            | 2            | c_fk        | 0           | 1           |",
+                "+---------------+----------------+---------------+----------------+--------------+-------------+-------------+-------------+",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_imported_keys() {
+        let mut builder = GetImportedKeysBuilder::new(None::<String>, None::<String>, "c");
+        append_keys(|key| builder.append(key));
+        let batch = builder.build().unwrap();
+
+        assert_batches_eq(
+            &[batch.project(&[2, 3, 6, 7, 8, 10]).unwrap()],
+            &[
+                "+---------------+----------------+---------------+----------------+--------------+-------------+",
+                "| pk_table_name | pk_column_name | fk_table_name | fk_column_name | key_sequence | pk_key_name |",
+                "+---------------+----------------+---------------+----------------+--------------+-------------+",
+                "| a             | a_x            | c             | c_x            | 1            | a_pk        |",
+                "| b             | b_x            | c             | c_x            | 1            | b_pk        |",
+                "| b             | b_y            | c             | c_y            | 2            | b_pk        |",
+                "+---------------+----------------+---------------+----------------+--------------+-------------+",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_cross_reference() {
+        let mut builder = CommandGetCrossReference {
+            pk_catalog: Some("catalog".into()),
+            pk_db_schema: None,
+            pk_table: "a".into(),
+            fk_catalog: None,
+            fk_db_schema: Some("schema".into()),
+            fk_table: "c".into(),
+        }
+        .into_builder();
+        append_keys(|key| builder.append(key));
+        let batch = builder.build().unwrap();
+
+        assert_batches_eq(
+            &[batch.project(&[2, 3, 6, 7]).unwrap()],
+            &[
+                "+---------------+----------------+---------------+----------------+",
+                "| pk_table_name | pk_column_name | fk_table_name | fk_column_name |",
+                "+---------------+----------------+---------------+----------------+",
+                "| a             | a_x            | c             | c_x            |",
+                "+---------------+----------------+---------------+----------------+",
+            ],
+        );
+    }
+}
@@ -41,7 +41,12 @@ use arrow_select::filter::filter_record_batch;
 use once_cell::sync::Lazy;
 
 use crate::error::Result;
-use crate::sql::{CommandGetSqlInfo, SqlInfo};
+use crate::sql::{
+    CommandGetSqlInfo, SqlInfo, SqlNullOrdering, SqlOuterJoinsSupportLevel,
+    SqlSupportedCaseSensitivity, SqlSupportedGroupBy, SqlSupportedResultSetConcurrency,
+    SqlSupportedResultSetType, SqlSupportedSubqueries, SqlSupportedTransaction, SqlSupportedUnions,
+    SqlTransactionIsolationLevel, SupportedAnsi92SqlGrammarLevel, SupportedSqlGrammar,
+};
 
 /// Represents a dynamic value
 #[derive(Debug, Clone, PartialEq)]
@@ -344,6 +349,210 @@ impl SqlInfoDataBuilder {
         Self::default()
     }
 
+    /// Create a SQL info builder describing a typical transactional SQL engine
+    ///
+    /// The preset reports ANSI-92 entry level SQL with transactions and
+    /// savepoints, double quoted case insensitive identifiers, no DDL for
+    /// catalogs and no limits on identifier or statement lengths. Servers
+    /// should [`append`](Self::append) at least their name and version, and
+    /// override any value that does not match their engine.
+    pub fn typical_sql_engine() -> Self {
+        let mut builder = Self::new();
+        builder.append(SqlInfo::FlightSqlServerName, "");
+        builder.append(SqlInfo::FlightSqlServerVersion, "");
+        builder.append(
+            SqlInfo::FlightSqlServerArrowVersion,
+            env!("CARGO_PKG_VERSION"),
+        );
+        builder.append(SqlInfo::FlightSqlServerReadOnly, false);
+        builder.append(SqlInfo::FlightSqlServerSql, true);
+        builder.append(SqlInfo::FlightSqlServerSubstrait, false);
+        builder.append(
+            SqlInfo::FlightSqlServerTransaction,
+            SqlSupportedTransaction::Savepoint as i32,
+        );
+        builder.append(SqlInfo::FlightSqlServerCancel, false);
+        builder.append(SqlInfo::FlightSqlServerBulkIngestion, false);
+        builder.append(SqlInfo::FlightSqlServerStatementTimeout, 0);
+        builder.append(SqlInfo::FlightSqlServerTransactionTimeout, 0);
+
+        builder.append(SqlInfo::SqlDdlCatalog, false);
+        builder.append(SqlInfo::SqlDdlSchema, true);
+        builder.append(SqlInfo::SqlDdlTable, true);
+        builder.append(
+            SqlInfo::SqlIdentifierCase,
+            SqlSupportedCaseSensitivity::SqlCaseSensitivityCaseInsensitive as i32,
+        );
+        builder.append(SqlInfo::SqlIdentifierQuoteChar, "\"");
+        builder.append(
+            SqlInfo::SqlQuotedIdentifierCase,
+            SqlSupportedCaseSensitivity::SqlCaseSensitivityCaseInsensitive as i32,
+        );
+        builder.append(SqlInfo::SqlAllTablesAreSelectable, true);
+        builder.append(
+            SqlInfo::SqlNullOrdering,
+            SqlNullOrdering::SqlNullsSortedHigh as i32,
+        );
+        builder.append(SqlInfo::SqlKeywords, &[] as &[&str]);
+        builder.append(
+            SqlInfo::SqlNumericFunctions,
+            &["ABS", "CEIL", "FLOOR", "MOD", "POWER", "ROUND", "SQRT"] as &[&str],
+        );
+        builder.append(
+            SqlInfo::SqlStringFunctions,
+            &[
+                "CHAR_LENGTH",
+                "CONCAT",
+                "LOWER",
+                "POSITION",
+                "REPLACE",
+                "SUBSTRING",
+                "TRIM",
+                "UPPER",
+            ] as &[&str],
+        );
+        builder.append(
+            SqlInfo::SqlSystemFunctions,
+            &["COALESCE", "NULLIF"] as &[&str],
+        );
+        builder.append(
+            SqlInfo::SqlDatetimeFunctions,
+            &[
+                "CURRENT_DATE",
+                "CURRENT_TIME",
+                "CURRENT_TIMESTAMP",
+                "EXTRACT",
+            ] as &[&str],
+        );
+        builder.append(SqlInfo::SqlSearchStringEscape, "\\");
+        builder.append(SqlInfo::SqlExtraNameCharacters, "");
+        builder.append(SqlInfo::SqlSupportsColumnAliasing, true);
+        builder.append(SqlInfo::SqlNullPlusNullIsNull, true);
+        builder.append(
+            SqlInfo::SqlSupportsConvert,
+            BTreeMap::<i32, Vec<i32>>::new(),
+        );
+        builder.append(SqlInfo::SqlSupportsTableCorrelationNames, true);
+        builder.append(SqlInfo::SqlSupportsDifferentTableCorrelationNames, false);
+        builder.append(SqlInfo::SqlSupportsExpressionsInOrderBy, true);
+        builder.append(SqlInfo::SqlSupportsOrderByUnrelated, true);
+        builder.append(
+            SqlInfo::SqlSupportedGroupBy,
+            bitmask(&[
+                SqlSupportedGroupBy::SqlGroupByUnrelated as i32,
+                SqlSupportedGroupBy::SqlGroupByBeyondSelect as i32,
+            ]),
+        );
+        builder.append(SqlInfo::SqlSupportsLikeEscapeClause, true);
+        builder.append(SqlInfo::SqlSupportsNonNullableColumns, true);
+        builder.append(
+            SqlInfo::SqlSupportedGrammar,
+            bitmask(&[
+                SupportedSqlGrammar::SqlMinimumGrammar as i32,
+                SupportedSqlGrammar::SqlCoreGrammar as i32,
+            ]),
+        );
+        builder.append(
+            SqlInfo::SqlAnsi92SupportedLevel,
+            bitmask(&[SupportedAnsi92SqlGrammarLevel::Ansi92EntrySql as i32]),
+        );
+        builder.append(SqlInfo::SqlSupportsIntegrityEnhancementFacility, false);
+        builder.append(
+            SqlInfo::SqlOuterJoinsSupportLevel,
+            SqlOuterJoinsSupportLevel::SqlFullOuterJoins as i32,
+        );
+        builder.append(SqlInfo::SqlSchemaTerm, "schema");
+        builder.append(SqlInfo::SqlProcedureTerm, "procedure");
+        builder.append(SqlInfo::SqlCatalogTerm, "catalog");
+        builder.append(SqlInfo::SqlCatalogAtStart, true);
+        builder.append(SqlInfo::SqlSchemasSupportedActions, 0);
+        builder.append(SqlInfo::SqlCatalogsSupportedActions, 0);
+        builder.append(SqlInfo::SqlSupportedPositionedCommands, 0);
+        builder.append(SqlInfo::SqlSelectForUpdateSupported, false);
+        builder.append(SqlInfo::SqlStoredProceduresSupported, false);
+        builder.append(
+            SqlInfo::SqlSupportedSubqueries,
+            bitmask(&[
+                SqlSupportedSubqueries::SqlSubqueriesInComparisons as i32,
+                SqlSupportedSubqueries::SqlSubqueriesInExists as i32,
+                SqlSupportedSubqueries::SqlSubqueriesInIns as i32,
+                SqlSupportedSubqueries::SqlSubqueriesInQuantifieds as i32,
+            ]),
+        );
+        builder.append(SqlInfo::SqlCorrelatedSubqueriesSupported, true);
+        builder.append(
+            SqlInfo::SqlSupportedUnions,
+            bitmask(&[
+                SqlSupportedUnions::SqlUnion as i32,
+                SqlSupportedUnions::SqlUnionAll as i32,
+            ]),
+        );
+        // zero means there is no limit or it is unknown
+        for limit in [
+            SqlInfo::SqlMaxBinaryLiteralLength,
+            SqlInfo::SqlMaxCharLiteralLength,
+            SqlInfo::SqlMaxColumnNameLength,
+            SqlInfo::SqlMaxColumnsInGroupBy,
+            SqlInfo::SqlMaxColumnsInIndex,
+            SqlInfo::SqlMaxColumnsInOrderBy,
+            SqlInfo::SqlMaxColumnsInSelect,
+            SqlInfo::SqlMaxColumnsInTable,
+            SqlInfo::SqlMaxConnections,
+            SqlInfo::SqlMaxCursorNameLength,
+            SqlInfo::SqlMaxIndexLength,
+            SqlInfo::SqlDbSchemaNameLength,
+            SqlInfo::SqlMaxProcedureNameLength,
+            SqlInfo::SqlMaxCatalogNameLength,
+            SqlInfo::SqlMaxRowSize,
+            SqlInfo::SqlMaxStatementLength,
+            SqlInfo::SqlMaxStatements,
+            SqlInfo::SqlMaxTableNameLength,
+            SqlInfo::SqlMaxTablesInSelect,
+            SqlInfo::SqlMaxUsernameLength,
+        ] {
+            builder.append(limit, 0_i64);
+        }
+        builder.append(SqlInfo::SqlMaxRowSizeIncludesBlobs, true);
+        builder.append(
+            SqlInfo::SqlDefaultTransactionIsolation,
+            SqlTransactionIsolationLevel::SqlTransactionReadCommitted as i32,
+        );
+        builder.append(SqlInfo::SqlTransactionsSupported, true);
+        builder.append(
+            SqlInfo::SqlSupportedTransactionsIsolationLevels,
+            bitmask(&[
+                SqlTransactionIsolationLevel::SqlTransactionReadCommitted as i32,
+                SqlTransactionIsolationLevel::SqlTransactionRepeatableRead as i32,
+                SqlTransactionIsolationLevel::SqlTransactionSerializable as i32,
+            ]),
+        );
+        builder.append(SqlInfo::SqlDataDefinitionCausesTransactionCommit, false);
+        builder.append(SqlInfo::SqlDataDefinitionsInTransactionsIgnored, false);
+        builder.append(
+            SqlInfo::SqlSupportedResultSetTypes,
+            bitmask(&[SqlSupportedResultSetType::SqlResultSetTypeForwardOnly as i32]),
+        );
+        builder.append(
+            SqlInfo::SqlSupportedConcurrenciesForResultSetForwardOnly,
+            bitmask(&[SqlSupportedResultSetConcurrency::SqlResultSetConcurrencyReadOnly as i32]),
+        );
+        builder.append(SqlInfo::SqlSupportedConcurrenciesForResultSetUnspecified, 0);
+        builder.append(
+            SqlInfo::SqlSupportedConcurrenciesForResultSetScrollSensitive,
+            0,
+        );
+        builder.append(
+            SqlInfo::SqlSupportedConcurrenciesForResultSetScrollInsensitive,
+            0,
+        );
+        builder.append(SqlInfo::SqlBatchUpdatesSupported, true);
+        builder.append(SqlInfo::SqlSavepointsSupported, true);
+        builder.append(SqlInfo::SqlNamedParametersSupported, false);
+        builder.append(SqlInfo::SqlLocatorsUpdateCopy, false);
+        builder.append(SqlInfo::SqlStoredFunctionsUsingCallSyntaxSupported, false);
+        builder
+    }
+
     /// register the specific sql metadata item
     pub fn append(&mut self, name: impl SqlInfoName, value: impl Into<SqlInfoValue>) {
         self.infos.insert(name.as_u32(), value.into());
@@ -380,6 +589,11 @@ impl SqlInfoDataBuilder {
     }
 }
 
+/// Returns the `int32_bitmask` with the bits of the given enum values set
+fn bitmask(values: &[i32]) -> i32 {
+    values.iter().fold(0, |mask, value| mask | (1 << value))
+}
+
 /// A builder for [`SqlInfoData`] which is used to create [`CommandGetSqlInfo`] responses.
 ///
 /// # Example
@@ -548,4 +762,39 @@ mod tests {
 
         assert_eq!(batch, ref_batch);
     }
+
+    #[test]
+    fn test_typical_sql_engine() {
+        let mut builder = SqlInfoDataBuilder::typical_sql_engine();
+        builder.append(SqlInfo::FlightSqlServerName, "server name");
+        let data = builder.build().unwrap();
+
+        let batch = data
+            .record_batch([
+                SqlInfo::FlightSqlServerName as u32,
+                SqlInfo::FlightSqlServerTransaction as u32,
+                SqlInfo::SqlIdentifierQuoteChar as u32,
+                SqlInfo::SqlSupportedGroupBy as u32,
+                SqlInfo::SqlSupportedUnions as u32,
+                SqlInfo::SqlMaxStatementLength as u32,
+                SqlInfo::SqlSavepointsSupported as u32,
+            ])
+            .unwrap();
+
+        let expected = vec![
+            "+-----------+----------------------------+",
+            "| info_name | value                      |",
+            "+-----------+----------------------------+",
+            "| 0         | {string_value=server name} |",
+            "| 8         | {int32_bitmask=2}          |",
+            "| 504       | {string_value=\"}           |",
+            "| 522       | {int32_bitmask=3}          |",
+            "| 540       | {int32_bitmask=3}          |",
+            "| 557       | {bigint_value=0}           |",
+            "| 573       | {bool_value=true}          |",
+            "+-----------+----------------------------+",
+        ];
+
+        assert_batches_eq(&[batch], &expected);
+    }
 }
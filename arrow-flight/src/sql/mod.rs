@@ -97,11 +97,14 @@ pub use r#gen::SqlSupportedUnions;
 pub use r#gen::SqlSupportsConvert;
 pub use r#gen::SqlTransactionIsolationLevel;
 pub use r#gen::SubstraitPlan;
+pub use r#gen::SupportedAnsi92SqlGrammarLevel;
 pub use r#gen::SupportedSqlGrammar;
 pub use r#gen::TicketStatementQuery;
 pub use r#gen::UpdateDeleteRules;
 pub use r#gen::XdbcDataType;
 pub use r#gen::XdbcDatetimeSubcode;
+pub use r#gen::action_cancel_query_result::CancelResult;
+pub use r#gen::action_end_savepoint_request::EndSavepoint;
 pub use r#gen::action_end_transaction_request::EndTransaction;
 pub use r#gen::command_statement_ingest::TableDefinitionOptions;
 pub use r#gen::command_statement_ingest::table_definition_options::{
@@ -111,6 +114,7 @@ pub use r#gen::command_statement_ingest::table_definition_options::{
 pub mod client;
 pub mod metadata;
 pub mod server;
+pub mod state;
 
 pub use crate::streams::FallibleRequestStream;
 
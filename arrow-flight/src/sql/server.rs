@@ -34,9 +34,10 @@ use super::{
     SqlInfo, TicketStatementQuery,
 };
 use crate::{
-    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
-    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
-    flight_service_server::FlightService, r#gen::PollInfo,
+    Action, ActionType, CloseSessionRequest, CloseSessionResult, Criteria, Empty, FlightData,
+    FlightDescriptor, FlightInfo, GetSessionOptionsRequest, GetSessionOptionsResult,
+    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, SetSessionOptionsRequest,
+    SetSessionOptionsResult, Ticket, flight_service_server::FlightService, r#gen::PollInfo,
 };
 use futures::{Stream, StreamExt, stream::Peekable};
 use prost::Message;
@@ -50,6 +51,9 @@ pub(crate) static END_TRANSACTION: &str = "EndTransaction";
 pub(crate) static BEGIN_SAVEPOINT: &str = "BeginSavepoint";
 pub(crate) static END_SAVEPOINT: &str = "EndSavepoint";
 pub(crate) static CANCEL_QUERY: &str = "CancelQuery";
+pub(crate) static SET_SESSION_OPTIONS: &str = "SetSessionOptions";
+pub(crate) static GET_SESSION_OPTIONS: &str = "GetSessionOptions";
+pub(crate) static CLOSE_SESSION: &str = "CloseSession";
 
 /// Implements FlightSqlService to handle the flight sql protocol
 #[tonic::async_trait]
@@ -560,6 +564,42 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
         ))
     }
 
+    /// Set options for the current session, creating the session if needed.
+    ///
+    /// The session is identified by the transport, e.g. a cookie available
+    /// from the metadata or extensions of `request`.
+    async fn do_action_set_session_options(
+        &self,
+        _query: SetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<SetSessionOptionsResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_set_session_options has no default implementation",
+        ))
+    }
+
+    /// Get the options of the current session
+    async fn do_action_get_session_options(
+        &self,
+        _query: GetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<GetSessionOptionsResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_get_session_options has no default implementation",
+        ))
+    }
+
+    /// Close the current session
+    async fn do_action_close_session(
+        &self,
+        _query: CloseSessionRequest,
+        _request: Request<Action>,
+    ) -> Result<CloseSessionResult, Status> {
+        Err(Status::unimplemented(
+            "do_action_close_session has no default implementation",
+        ))
+    }
+
     /// do_exchange
     /// Implementors may override to handle additional calls to do_exchange()
     async fn do_exchange_fallback(
@@ -842,6 +882,27 @@ where
                 Response Message: ActionCancelQueryResult"
                 .into(),
         };
+        let set_session_options_action_type = ActionType {
+            r#type: SET_SESSION_OPTIONS.to_string(),
+            description: "Sets options for the current session\n
+                Request Message: SetSessionOptionsRequest\n
+                Response Message: SetSessionOptionsResult"
+                .into(),
+        };
+        let get_session_options_action_type = ActionType {
+            r#type: GET_SESSION_OPTIONS.to_string(),
+            description: "Gets the options of the current session\n
+                Request Message: GetSessionOptionsRequest\n
+                Response Message: GetSessionOptionsResult"
+                .into(),
+        };
+        let close_session_action_type = ActionType {
+            r#type: CLOSE_SESSION.to_string(),
+            description: "Closes the current session\n
+                Request Message: CloseSessionRequest\n
+                Response Message: CloseSessionResult"
+                .into(),
+        };
         let mut actions: Vec<Result<ActionType, Status>> = vec![
             Ok(create_prepared_statement_action_type),
             Ok(close_prepared_statement_action_type),
@@ -851,6 +912,9 @@ where
             Ok(begin_savepoint_action_type),
             Ok(end_savepoint_action_type),
             Ok(cancel_query_action_type),
+            Ok(set_session_options_action_type),
+            Ok(get_session_options_action_type),
+            Ok(close_session_action_type),
         ];
 
         if let Some(mut custom_actions) = self.list_custom_actions().await {
@@ -908,9 +972,13 @@ where
                         "Unable to unpack ActionCreatePreparedSubstraitPlanRequest.",
                     )
                 })?;
-            self.do_action_create_prepared_substrait_plan(cmd, request)
+            let stmt = self
+                .do_action_create_prepared_substrait_plan(cmd, request)
                 .await?;
-            return Ok(Response::new(Box::pin(futures::stream::empty())));
+            let output = futures::stream::iter(vec![Ok(super::super::r#gen::Result {
+                body: stmt.as_any().encode_to_vec().into(),
+            })]);
+            return Ok(Response::new(Box::pin(output)));
         } else if request.get_ref().r#type == BEGIN_TRANSACTION {
             let any = Any::decode(&*request.get_ref().body).map_err(decode_error_to_status)?;
 
@@ -975,6 +1043,32 @@ where
                 body: stmt.as_any().encode_to_vec().into(),
             })]);
             return Ok(Response::new(Box::pin(output)));
+        } else if request.get_ref().r#type == SET_SESSION_OPTIONS {
+            // Session actions are defined by the core Flight protocol, so their
+            // messages are not wrapped in an `Any`
+            let cmd = SetSessionOptionsRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_set_session_options(cmd, request).await?;
+            let output = futures::stream::iter(vec![Ok(super::super::r#gen::Result {
+                body: result.encode_to_vec().into(),
+            })]);
+            return Ok(Response::new(Box::pin(output)));
+        } else if request.get_ref().r#type == GET_SESSION_OPTIONS {
+            let cmd = GetSessionOptionsRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_get_session_options(cmd, request).await?;
+            let output = futures::stream::iter(vec![Ok(super::super::r#gen::Result {
+                body: result.encode_to_vec().into(),
+            })]);
+            return Ok(Response::new(Box::pin(output)));
+        } else if request.get_ref().r#type == CLOSE_SESSION {
+            let cmd = CloseSessionRequest::decode(&*request.get_ref().body)
+                .map_err(decode_error_to_status)?;
+            let result = self.do_action_close_session(cmd, request).await?;
+            let output = futures::stream::iter(vec![Ok(super::super::r#gen::Result {
+                body: result.encode_to_vec().into(),
+            })]);
+            return Ok(Response::new(Box::pin(output)));
         }
 
         self.do_action_fallback(request).await
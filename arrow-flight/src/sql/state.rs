@@ -0,0 +1,810 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Optional server side state for [`FlightSqlService`] implementations
+//!
+//! Flight SQL servers hand out opaque handles for statements, prepared
+//! statements, transactions and savepoints, and must remember what each of
+//! them refers to across calls. [`FlightSqlStateManager`] keeps track of
+//! this state, so that a server only needs to execute queries:
+//!
+//! - [`FlightSqlStateManager::create_prepared_statement`] stores the query
+//!   of `CreatePreparedStatement` and returns its handle
+//! - [`FlightSqlStateManager::bind_parameter_stream`] stores the parameters
+//!   sent by `DoPut` for [`CommandPreparedStatementQuery`]
+//! - [`FlightSqlStateManager::get_prepared_statement`] returns the query and
+//!   parameters of a handle when it is executed
+//! - [`FlightSqlStateManager::create_statement`] stores an ad-hoc query for
+//!   [`CommandStatementQuery`], to be read back from its [`Ticket`]
+//! - Transactions and savepoints are tracked by
+//!   [`FlightSqlStateManager::begin_transaction`] and
+//!   [`FlightSqlStateManager::begin_savepoint`]
+//!
+//! Entries not used for longer than [`FlightSqlStateManager::with_ttl`] are
+//! removed. Each statement also stores a value of type `T` for any
+//! additional state of the server, such as the planned query.
+//!
+//! # Example
+//! ```no_run
+//! # use arrow_flight::sql::{ActionCreatePreparedStatementRequest, ActionCreatePreparedStatementResult};
+//! # use arrow_flight::sql::state::FlightSqlStateManager;
+//! # use std::time::Duration;
+//! # use tonic::Status;
+//! let state = FlightSqlStateManager::<()>::new().with_ttl(Duration::from_secs(300));
+//!
+//! // in `do_action_create_prepared_statement`
+//! # fn f(state: FlightSqlStateManager<()>, query: ActionCreatePreparedStatementRequest) -> Result<(), Status> {
+//! let handle = state.create_prepared_statement(query, ())?;
+//! let result = ActionCreatePreparedStatementResult {
+//!     prepared_statement_handle: handle,
+//!     ..Default::default() // the schemas of the query
+//! };
+//!
+//! // in `get_flight_info_prepared_statement` and `do_get_prepared_statement`
+//! let statement = state.get_prepared_statement(&result.prepared_statement_handle)?;
+//! println!("execute {:?} with {:?}", statement.query, statement.parameters);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`FlightSqlService`]: crate::sql::server::FlightSqlService
+
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use arrow_array::RecordBatch;
+use bytes::Bytes;
+use futures::TryStreamExt;
+use prost::Message;
+use tonic::Status;
+
+use super::server::PeekableFlightDataStream;
+use super::{
+    ActionBeginSavepointRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedSubstraitPlanRequest, ActionEndSavepointRequest,
+    ActionEndTransactionRequest, Any, Command, CommandPreparedStatementQuery,
+    CommandStatementQuery, CommandStatementSubstraitPlan, DoPutPreparedStatementResult,
+    EndSavepoint, EndTransaction, ProstMessageExt, SubstraitPlan, TicketStatementQuery,
+};
+use crate::Ticket;
+use crate::decode::FlightRecordBatchStream;
+
+/// The query of a statement
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatementQuery {
+    /// A SQL query
+    Sql(String),
+    /// A Substrait plan
+    Substrait(SubstraitPlan),
+}
+
+/// A statement or prepared statement tracked by a [`FlightSqlStateManager`]
+#[derive(Debug, Clone)]
+pub struct Statement<T> {
+    /// The handle of the statement
+    pub handle: Bytes,
+    /// The query to execute
+    pub query: StatementQuery,
+    /// The transaction the statement was created in, if any
+    pub transaction_id: Option<Bytes>,
+    /// The parameters bound to a prepared statement, if any
+    pub parameters: Vec<RecordBatch>,
+    /// Additional state of the server
+    pub data: T,
+}
+
+/// A savepoint tracked by a [`FlightSqlStateManager`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Savepoint {
+    /// The handle of the savepoint
+    pub savepoint_id: Bytes,
+    /// The name given to the savepoint by the client
+    pub name: String,
+    /// The transaction of the savepoint
+    pub transaction_id: Bytes,
+}
+
+/// A value and when it was last used
+#[derive(Debug)]
+struct Entry<V> {
+    value: V,
+    last_used: Instant,
+}
+
+impl<V> Entry<V> {
+    fn new(value: V) -> Self {
+        Self {
+            value,
+            last_used: Instant::now(),
+        }
+    }
+
+    /// Returns the value, marking it as used
+    fn touch(&mut self) -> &mut V {
+        self.last_used = Instant::now();
+        &mut self.value
+    }
+}
+
+#[derive(Debug)]
+struct State<T> {
+    statements: HashMap<Bytes, Entry<Statement<T>>>,
+    prepared_statements: HashMap<Bytes, Entry<Statement<T>>>,
+    /// Savepoints of each open transaction, in the order they were created
+    transactions: HashMap<Bytes, Entry<Vec<Savepoint>>>,
+}
+
+impl<T> State<T> {
+    /// Remove entries not used since `cutoff`, along with the statements and
+    /// savepoints of any transaction removed
+    fn expire(&mut self, cutoff: Instant) {
+        let mut expired = HashSet::new();
+        self.transactions.retain(|id, e| {
+            let keep = e.last_used >= cutoff;
+            if !keep {
+                expired.insert(id.clone());
+            }
+            keep
+        });
+        let keep = |e: &mut Entry<Statement<T>>| {
+            e.last_used >= cutoff
+                && !e
+                    .value
+                    .transaction_id
+                    .as_ref()
+                    .is_some_and(|id| expired.contains(id))
+        };
+        self.statements.retain(|_, e| keep(e));
+        self.prepared_statements.retain(|_, e| keep(e));
+    }
+
+    fn check_transaction(&mut self, transaction_id: Option<&Bytes>) -> Result<(), Status> {
+        match transaction_id {
+            Some(id) => self
+                .transactions
+                .get_mut(id)
+                .map(|t| {
+                    t.touch();
+                })
+                .ok_or_else(|| unknown("transaction", id)),
+            None => Ok(()),
+        }
+    }
+}
+
+struct Inner<T> {
+    state: Mutex<State<T>>,
+    /// Number of handles created so far
+    next_handle: AtomicU64,
+    /// Makes handles unpredictable
+    random: RandomState,
+}
+
+/// Tracks statements, prepared statements, transactions and savepoints of a
+/// Flight SQL server
+///
+/// Cloning a manager returns a handle to the same state. See the
+/// [module level documentation](self) for an example.
+pub struct FlightSqlStateManager<T = ()> {
+    inner: Arc<Inner<T>>,
+    /// Remove entries not used for this long
+    ttl: Option<Duration>,
+}
+
+impl<T> Clone for FlightSqlStateManager<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            ttl: self.ttl,
+        }
+    }
+}
+
+impl<T> Default for FlightSqlStateManager<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for FlightSqlStateManager<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state();
+        f.debug_struct("FlightSqlStateManager")
+            .field("ttl", &self.ttl)
+            .field("statements", &state.statements.len())
+            .field("prepared_statements", &state.prepared_statements.len())
+            .field("transactions", &state.transactions.len())
+            .finish()
+    }
+}
+
+impl<T> FlightSqlStateManager<T> {
+    /// Create a new manager, whose entries never expire
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                state: Mutex::new(State {
+                    statements: HashMap::new(),
+                    prepared_statements: HashMap::new(),
+                    transactions: HashMap::new(),
+                }),
+                next_handle: AtomicU64::new(0),
+                random: RandomState::new(),
+            }),
+            ttl: None,
+        }
+    }
+
+    /// Remove statements, prepared statements and transactions not used for
+    /// longer than `ttl`
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Lock the state, removing expired entries
+    fn state(&self) -> MutexGuard<'_, State<T>> {
+        let mut state = self.inner.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(cutoff) = self.ttl.and_then(|ttl| Instant::now().checked_sub(ttl)) {
+            state.expire(cutoff);
+        }
+        state
+    }
+
+    /// Return a new unique handle
+    ///
+    /// Handles are made of a counter followed by a hash of it, so that the
+    /// handles of other clients cannot easily be guessed. They are not
+    /// cryptographically secure.
+    pub fn new_handle(&self) -> Bytes {
+        let id = self.inner.next_handle.fetch_add(1, Ordering::Relaxed);
+        let mut handle = Vec::with_capacity(16);
+        handle.extend_from_slice(&id.to_be_bytes());
+        handle.extend_from_slice(&self.inner.random.hash_one(id).to_be_bytes());
+        handle.into()
+    }
+
+    fn new_statement(
+        &self,
+        query: StatementQuery,
+        transaction_id: Option<Bytes>,
+        data: T,
+    ) -> Statement<T> {
+        Statement {
+            handle: self.new_handle(),
+            query,
+            transaction_id,
+            parameters: vec![],
+            data,
+        }
+    }
+
+    /// Store the query of `CommandStatementQuery`, returning a [`Ticket`] to
+    /// read it back with [`Self::get_statement`]
+    ///
+    /// Returns an error if the transaction of the query is unknown.
+    pub fn create_statement(
+        &self,
+        query: CommandStatementQuery,
+        data: T,
+    ) -> Result<Ticket, Status> {
+        let statement =
+            self.new_statement(StatementQuery::Sql(query.query), query.transaction_id, data);
+        self.insert_statement(statement)
+    }
+
+    /// Store the plan of `CommandStatementSubstraitPlan`, returning a
+    /// [`Ticket`] to read it back with [`Self::get_statement`]
+    ///
+    /// Returns an error if the plan is missing or its transaction is unknown.
+    pub fn create_substrait_statement(
+        &self,
+        query: CommandStatementSubstraitPlan,
+        data: T,
+    ) -> Result<Ticket, Status> {
+        let plan = query
+            .plan
+            .ok_or_else(|| Status::invalid_argument("Substrait plan is missing"))?;
+        let statement =
+            self.new_statement(StatementQuery::Substrait(plan), query.transaction_id, data);
+        self.insert_statement(statement)
+    }
+
+    fn insert_statement(&self, statement: Statement<T>) -> Result<Ticket, Status> {
+        let mut state = self.state();
+        state.check_transaction(statement.transaction_id.as_ref())?;
+        let ticket = statement_ticket(statement.handle.clone());
+        state
+            .statements
+            .insert(statement.handle.clone(), Entry::new(statement));
+        Ok(ticket)
+    }
+
+    /// Remove the statement with `handle`, returning an error if it is unknown
+    ///
+    /// Statements are otherwise kept until they expire, so that their
+    /// [`Ticket`] can be redeemed more than once.
+    pub fn remove_statement(&self, handle: &[u8]) -> Result<Statement<T>, Status> {
+        self.state()
+            .statements
+            .remove(handle)
+            .map(|e| e.value)
+            .ok_or_else(|| unknown("statement", handle))
+    }
+
+    /// Store the query of `CreatePreparedStatement`, returning its handle
+    ///
+    /// Returns an error if the transaction of the query is unknown.
+    pub fn create_prepared_statement(
+        &self,
+        query: ActionCreatePreparedStatementRequest,
+        data: T,
+    ) -> Result<Bytes, Status> {
+        let statement =
+            self.new_statement(StatementQuery::Sql(query.query), query.transaction_id, data);
+        self.insert_prepared_statement(statement)
+    }
+
+    /// Store the plan of `CreatePreparedSubstraitPlan`, returning its handle
+    ///
+    /// Returns an error if the plan is missing or its transaction is unknown.
+    pub fn create_prepared_substrait_plan(
+        &self,
+        query: ActionCreatePreparedSubstraitPlanRequest,
+        data: T,
+    ) -> Result<Bytes, Status> {
+        let plan = query
+            .plan
+            .ok_or_else(|| Status::invalid_argument("Substrait plan is missing"))?;
+        let statement =
+            self.new_statement(StatementQuery::Substrait(plan), query.transaction_id, data);
+        self.insert_prepared_statement(statement)
+    }
+
+    fn insert_prepared_statement(&self, statement: Statement<T>) -> Result<Bytes, Status> {
+        let mut state = self.state();
+        state.check_transaction(statement.transaction_id.as_ref())?;
+        let handle = statement.handle.clone();
+        state
+            .prepared_statements
+            .insert(handle.clone(), Entry::new(statement));
+        Ok(handle)
+    }
+
+    /// Replace the parameters bound to the prepared statement with `handle`
+    pub fn bind_parameters(
+        &self,
+        handle: &[u8],
+        parameters: Vec<RecordBatch>,
+    ) -> Result<(), Status> {
+        self.update_prepared_statement(handle, |statement| statement.parameters = parameters)
+    }
+
+    /// Bind the parameters sent by `DoPut` for `CommandPreparedStatementQuery`
+    ///
+    /// This implements [`FlightSqlService::do_put_prepared_statement_query`],
+    /// returning the unchanged handle of the prepared statement.
+    ///
+    /// [`FlightSqlService::do_put_prepared_statement_query`]: crate::sql::server::FlightSqlService::do_put_prepared_statement_query
+    pub async fn bind_parameter_stream(
+        &self,
+        query: CommandPreparedStatementQuery,
+        request: PeekableFlightDataStream,
+    ) -> Result<DoPutPreparedStatementResult, Status> {
+        let handle = query.prepared_statement_handle;
+        let parameters: Vec<RecordBatch> =
+            FlightRecordBatchStream::new_from_flight_data(request.map_err(|e| e.into()))
+                .try_collect()
+                .await?;
+        self.bind_parameters(&handle, parameters)?;
+        Ok(DoPutPreparedStatementResult {
+            prepared_statement_handle: Some(handle),
+        })
+    }
+
+    /// Call `f` on the prepared statement with `handle`, returning an error if
+    /// it is unknown
+    pub fn update_prepared_statement<R>(
+        &self,
+        handle: &[u8],
+        f: impl FnOnce(&mut Statement<T>) -> R,
+    ) -> Result<R, Status> {
+        let mut state = self.state();
+        let statement = state
+            .prepared_statements
+            .get_mut(handle)
+            .ok_or_else(|| unknown("prepared statement", handle))?;
+        Ok(f(statement.touch()))
+    }
+
+    /// Remove the prepared statement with `handle`, returning an error if it
+    /// is unknown
+    pub fn close_prepared_statement(&self, handle: &[u8]) -> Result<Statement<T>, Status> {
+        self.state()
+            .prepared_statements
+            .remove(handle)
+            .map(|e| e.value)
+            .ok_or_else(|| unknown("prepared statement", handle))
+    }
+
+    /// Begin a new transaction, returning its id
+    pub fn begin_transaction(&self) -> Bytes {
+        let transaction_id = self.new_handle();
+        self.state()
+            .transactions
+            .insert(transaction_id.clone(), Entry::new(vec![]));
+        transaction_id
+    }
+
+    /// End the transaction of `request`, along with its savepoints and the
+    /// statements and prepared statements created in it
+    ///
+    /// Returns whether the transaction should be committed or rolled back,
+    /// or an error if it is unknown or the action is unspecified.
+    pub fn end_transaction(
+        &self,
+        request: &ActionEndTransactionRequest,
+    ) -> Result<EndTransaction, Status> {
+        let action = match EndTransaction::try_from(request.action) {
+            Ok(EndTransaction::Unspecified) | Err(_) => {
+                return Err(Status::invalid_argument("Invalid end transaction action"));
+            }
+            Ok(action) => action,
+        };
+        let mut state = self.state();
+        let transaction_id = &request.transaction_id;
+        state
+            .transactions
+            .remove(transaction_id)
+            .ok_or_else(|| unknown("transaction", transaction_id))?;
+        let other =
+            |e: &mut Entry<Statement<T>>| e.value.transaction_id.as_ref() != Some(transaction_id);
+        state.statements.retain(|_, e| other(e));
+        state.prepared_statements.retain(|_, e| other(e));
+        Ok(action)
+    }
+
+    /// Create a savepoint in the transaction of `request`, returning it
+    ///
+    /// Returns an error if the transaction is unknown.
+    pub fn begin_savepoint(
+        &self,
+        request: &ActionBeginSavepointRequest,
+    ) -> Result<Savepoint, Status> {
+        let savepoint = Savepoint {
+            savepoint_id: self.new_handle(),
+            name: request.name.clone(),
+            transaction_id: request.transaction_id.clone(),
+        };
+        let mut state = self.state();
+        let savepoints = state
+            .transactions
+            .get_mut(&request.transaction_id)
+            .ok_or_else(|| unknown("transaction", &request.transaction_id))?;
+        savepoints.touch().push(savepoint.clone());
+        Ok(savepoint)
+    }
+
+    /// End the savepoint of `request`, along with any savepoint created after it
+    ///
+    /// Returns the savepoint and whether it should be released or rolled back
+    /// to, or an error if it is unknown or the action is unspecified.
+    pub fn end_savepoint(
+        &self,
+        request: &ActionEndSavepointRequest,
+    ) -> Result<(Savepoint, EndSavepoint), Status> {
+        let action = match EndSavepoint::try_from(request.action) {
+            Ok(EndSavepoint::Unspecified) | Err(_) => {
+                return Err(Status::invalid_argument("Invalid end savepoint action"));
+            }
+            Ok(action) => action,
+        };
+        let mut state = self.state();
+        for savepoints in state.transactions.values_mut() {
+            if let Some(idx) = savepoints
+                .value
+                .iter()
+                .position(|s| s.savepoint_id == request.savepoint_id)
+            {
+                let savepoint = savepoints.touch().drain(idx..).next().unwrap();
+                return Ok((savepoint, action));
+            }
+        }
+        Err(unknown("savepoint", &request.savepoint_id))
+    }
+
+    /// Return the savepoints of the transaction with `transaction_id`, in the
+    /// order they were created, or an error if it is unknown
+    pub fn savepoints(&self, transaction_id: &[u8]) -> Result<Vec<Savepoint>, Status> {
+        self.state()
+            .transactions
+            .get_mut(transaction_id)
+            .map(|t| t.touch().clone())
+            .ok_or_else(|| unknown("transaction", transaction_id))
+    }
+}
+
+impl<T: Clone> FlightSqlStateManager<T> {
+    /// Return the statement of a [`TicketStatementQuery`] created by
+    /// [`Self::create_statement`], or an error if it is unknown
+    pub fn get_statement(&self, ticket: &TicketStatementQuery) -> Result<Statement<T>, Status> {
+        let handle = &ticket.statement_handle;
+        self.state()
+            .statements
+            .get_mut(handle)
+            .map(|e| e.touch().clone())
+            .ok_or_else(|| unknown("statement", handle))
+    }
+
+    /// Return the prepared statement with `handle` and its bound parameters,
+    /// or an error if it is unknown
+    pub fn get_prepared_statement(&self, handle: &[u8]) -> Result<Statement<T>, Status> {
+        self.update_prepared_statement(handle, |statement| statement.clone())
+    }
+}
+
+fn unknown(kind: &str, handle: &[u8]) -> Status {
+    Status::not_found(format!("Unknown {kind} handle: {handle:?}"))
+}
+
+/// Return a [`Ticket`] for the statement with `handle`, which
+/// [`FlightSqlService::do_get`] passes to
+/// [`FlightSqlService::do_get_statement`] as a [`TicketStatementQuery`]
+///
+/// [`FlightSqlService::do_get`]: crate::flight_service_server::FlightService::do_get
+/// [`FlightSqlService::do_get_statement`]: crate::sql::server::FlightSqlService::do_get_statement
+pub fn statement_ticket(handle: Bytes) -> Ticket {
+    let ticket = TicketStatementQuery {
+        statement_handle: handle,
+    };
+    Ticket::new(ticket.as_any().encode_to_vec())
+}
+
+/// Return a [`Ticket`] for the prepared statement with `handle`, which
+/// [`FlightSqlService::do_get`] passes to
+/// [`FlightSqlService::do_get_prepared_statement`]
+///
+/// [`FlightSqlService::do_get`]: crate::flight_service_server::FlightService::do_get
+/// [`FlightSqlService::do_get_prepared_statement`]: crate::sql::server::FlightSqlService::do_get_prepared_statement
+pub fn prepared_statement_ticket(handle: Bytes) -> Ticket {
+    let command = CommandPreparedStatementQuery {
+        prepared_statement_handle: handle,
+    };
+    Ticket::new(command.as_any().encode_to_vec())
+}
+
+/// Decode the Flight SQL [`Command`] of a [`Ticket`]
+pub fn decode_ticket(ticket: &Ticket) -> Result<Command, Status> {
+    let any = Any::decode(&*ticket.ticket)
+        .map_err(|e| Status::invalid_argument(format!("Invalid ticket: {e}")))?;
+    Command::try_from(any).map_err(|e| Status::invalid_argument(format!("Invalid ticket: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Int64Array;
+    use std::sync::Arc;
+
+    fn create_prepared(state: &FlightSqlStateManager<u32>, query: &str, data: u32) -> Bytes {
+        state
+            .create_prepared_statement(
+                ActionCreatePreparedStatementRequest {
+                    query: query.to_string(),
+                    transaction_id: None,
+                },
+                data,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_prepared_statement() {
+        let state = FlightSqlStateManager::new();
+        let handle = create_prepared(&state, "SELECT ?", 7);
+        let other = create_prepared(&state, "SELECT 1", 8);
+        assert_ne!(handle, other);
+
+        let params =
+            RecordBatch::try_from_iter([("$1", Arc::new(Int64Array::from(vec![42])) as _)])
+                .unwrap();
+        state
+            .bind_parameters(&handle, vec![params.clone()])
+            .unwrap();
+        state
+            .update_prepared_statement(&handle, |s| s.data += 1)
+            .unwrap();
+
+        let statement = state.get_prepared_statement(&handle).unwrap();
+        assert_eq!(statement.query, StatementQuery::Sql("SELECT ?".to_string()));
+        assert_eq!(statement.parameters, vec![params]);
+        assert_eq!(statement.data, 8);
+        assert!(
+            state
+                .get_prepared_statement(&other)
+                .unwrap()
+                .parameters
+                .is_empty()
+        );
+
+        state.close_prepared_statement(&handle).unwrap();
+        let err = state.get_prepared_statement(&handle).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+        assert!(state.close_prepared_statement(&handle).is_err());
+    }
+
+    #[test]
+    fn test_statement_ticket() {
+        let state = FlightSqlStateManager::new();
+        let query = CommandStatementQuery {
+            query: "SELECT 1".to_string(),
+            transaction_id: None,
+        };
+        let ticket = state.create_statement(query, 1).unwrap();
+
+        let Command::TicketStatementQuery(ticket) = decode_ticket(&ticket).unwrap() else {
+            panic!("unexpected ticket");
+        };
+        let statement = state.get_statement(&ticket).unwrap();
+        assert_eq!(statement.query, StatementQuery::Sql("SELECT 1".to_string()));
+        assert_eq!(statement.data, 1);
+
+        // tickets can be redeemed until the statement is removed
+        state.get_statement(&ticket).unwrap();
+        state.remove_statement(&ticket.statement_handle).unwrap();
+        assert!(state.get_statement(&ticket).is_err());
+
+        let ticket = prepared_statement_ticket(Bytes::from("handle"));
+        assert_eq!(
+            decode_ticket(&ticket).unwrap(),
+            Command::CommandPreparedStatementQuery(CommandPreparedStatementQuery {
+                prepared_statement_handle: Bytes::from("handle"),
+            })
+        );
+        assert!(decode_ticket(&Ticket::new("not a ticket")).is_err());
+    }
+
+    #[test]
+    fn test_transactions() {
+        let state = FlightSqlStateManager::<u32>::new();
+        let transaction_id = state.begin_transaction();
+
+        let savepoint = |name: &str| {
+            state
+                .begin_savepoint(&ActionBeginSavepointRequest {
+                    transaction_id: transaction_id.clone(),
+                    name: name.to_string(),
+                })
+                .unwrap()
+        };
+        let a = savepoint("a");
+        let b = savepoint("b");
+        let c = savepoint("c");
+        assert_eq!(
+            state.savepoints(&transaction_id).unwrap(),
+            vec![a.clone(), b.clone(), c]
+        );
+
+        // rolling back to b also ends c
+        let (ended, action) = state
+            .end_savepoint(&ActionEndSavepointRequest {
+                savepoint_id: b.savepoint_id.clone(),
+                action: EndSavepoint::Rollback.into(),
+            })
+            .unwrap();
+        assert_eq!(ended, b);
+        assert_eq!(action, EndSavepoint::Rollback);
+        assert_eq!(state.savepoints(&transaction_id).unwrap().len(), 1);
+
+        // statements can only be created in open transactions
+        let query = ActionCreatePreparedStatementRequest {
+            query: "SELECT 1".to_string(),
+            transaction_id: Some(transaction_id.clone()),
+        };
+        let prepared = state.create_prepared_statement(query.clone(), 0).unwrap();
+        let ticket = state
+            .create_statement(
+                CommandStatementQuery {
+                    query: "SELECT 2".to_string(),
+                    transaction_id: Some(transaction_id.clone()),
+                },
+                0,
+            )
+            .unwrap();
+        let Command::TicketStatementQuery(statement) = decode_ticket(&ticket).unwrap() else {
+            panic!("unexpected ticket");
+        };
+        let outside = create_prepared(&state, "SELECT 3", 0);
+
+        let mut request = ActionEndTransactionRequest {
+            transaction_id: transaction_id.clone(),
+            action: EndTransaction::Unspecified.into(),
+        };
+        let err = state.end_transaction(&request).unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        let err = state
+            .end_savepoint(&ActionEndSavepointRequest {
+                savepoint_id: a.savepoint_id.clone(),
+                action: EndSavepoint::Unspecified.into(),
+            })
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        request.action = EndTransaction::Commit.into();
+        assert_eq!(
+            state.end_transaction(&request).unwrap(),
+            EndTransaction::Commit
+        );
+        assert!(state.end_transaction(&request).is_err());
+        assert!(state.savepoints(&transaction_id).is_err());
+        assert!(state.create_prepared_statement(query, 0).is_err());
+
+        // statements of the transaction end with it
+        assert!(state.get_prepared_statement(&prepared).is_err());
+        assert!(state.get_statement(&statement).is_err());
+        state.get_prepared_statement(&outside).unwrap();
+    }
+
+    /// Make every entry of `state` look last used `by` earlier
+    fn age<T>(state: &FlightSqlStateManager<T>, by: Duration) {
+        let mut state = state.inner.state.lock().unwrap();
+        let state = &mut *state;
+        let last_used = state
+            .statements
+            .values_mut()
+            .chain(state.prepared_statements.values_mut())
+            .map(|e| &mut e.last_used)
+            .chain(state.transactions.values_mut().map(|e| &mut e.last_used));
+        for last_used in last_used {
+            *last_used = last_used.checked_sub(by).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_ttl() {
+        let state = FlightSqlStateManager::new().with_ttl(Duration::from_secs(60));
+        let used = create_prepared(&state, "SELECT 1", 0);
+        let unused = create_prepared(&state, "SELECT 2", 0);
+        let transaction_id = state.begin_transaction();
+        let in_transaction = state
+            .create_prepared_statement(
+                ActionCreatePreparedStatementRequest {
+                    query: "SELECT 3".to_string(),
+                    transaction_id: Some(transaction_id.clone()),
+                },
+                0,
+            )
+            .unwrap();
+
+        age(&state, Duration::from_secs(40));
+        state.get_prepared_statement(&used).unwrap();
+        state.get_prepared_statement(&in_transaction).unwrap();
+        age(&state, Duration::from_secs(40));
+
+        // only entries used within the ttl remain
+        state.get_prepared_statement(&used).unwrap();
+        assert!(state.get_prepared_statement(&unused).is_err());
+        assert!(state.savepoints(&transaction_id).is_err());
+        // statements of an expired transaction are removed with it
+        assert!(state.get_prepared_statement(&in_transaction).is_err());
+    }
+}
@@ -22,10 +22,14 @@ mod common;
 use crate::common::fixture::TestFixture;
 use arrow_array::{RecordBatch, UInt64Array};
 use arrow_flight::{
-    Action, ActionType, CancelFlightInfoRequest, CancelFlightInfoResult, CancelStatus, Criteria,
-    Empty, FlightClient, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
-    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, RenewFlightEndpointRequest, Ticket,
-    decode::FlightRecordBatchStream, encode::FlightDataEncoderBuilder, error::FlightError,
+    Action, ActionType, CancelFlightInfoRequest, CancelFlightInfoResult, CancelStatus,
+    CloseSessionRequest, CloseSessionResult, Criteria, Empty, FlightClient, FlightData,
+    FlightDescriptor, FlightEndpoint, FlightInfo, GetSessionOptionsRequest,
+    GetSessionOptionsResult, HandshakeRequest, HandshakeResponse, PollInfo, PutResult,
+    RenewFlightEndpointRequest, SessionOptionValue, SetSessionOptionsRequest,
+    SetSessionOptionsResult, Ticket, close_session_result, decode::FlightRecordBatchStream,
+    encode::FlightDataEncoderBuilder, error::FlightError, exchange::ExchangeMessage,
+    set_session_options_result,
 };
 use arrow_schema::{DataType, Field, Schema};
 use bytes::Bytes;
@@ -513,6 +517,54 @@ async fn test_do_exchange() {
     .await;
 }
 
+#[tokio::test]
+async fn test_do_exchange_session() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+        test_server.set_do_exchange_echo();
+
+        let batch = RecordBatch::try_from_iter(vec![(
+            "col",
+            Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+        )])
+        .unwrap();
+
+        let mut session = client
+            .do_exchange_session(FlightDataEncoderBuilder::new())
+            .await
+            .expect("error making request");
+
+        // each message is echoed before the next one is sent
+        session
+            .send_with_metadata(batch.clone(), "request-1")
+            .await
+            .unwrap();
+        let message = session.try_next().await.unwrap().unwrap();
+        assert_eq!(
+            message,
+            ExchangeMessage::new(batch.clone()).with_app_metadata("request-1")
+        );
+
+        session.send_metadata("progress").await.unwrap();
+        let message = session.try_next().await.unwrap().unwrap();
+        assert_eq!(message, ExchangeMessage::metadata("progress"));
+
+        let (sender, mut receiver) = session.split();
+        drop(sender);
+        assert!(receiver.try_next().await.unwrap().is_none());
+
+        let mut expected_request = test_flight_data().await;
+        expected_request[1].app_metadata = Bytes::from("request-1");
+        expected_request.push(FlightData::new().with_app_metadata("progress"));
+        assert_eq!(
+            test_server.take_do_exchange_request(),
+            Some(expected_request)
+        );
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn test_do_exchange_error() {
     do_test(|test_server, mut client| async move {
@@ -1079,6 +1131,109 @@ async fn test_renew_flight_endpoint_error_no_response() {
     .await;
 }
 
+#[tokio::test]
+async fn test_set_session_options() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let expected_response = SetSessionOptionsResult::default()
+            .with_error("bogus", set_session_options_result::ErrorValue::InvalidName);
+        let response = expected_response.encode_to_vec();
+        let response = Ok(arrow_flight::Result::new(response));
+        test_server.set_do_action_response(vec![response]);
+
+        let request = SetSessionOptionsRequest::new([
+            ("catalog", SessionOptionValue::from("sales")),
+            ("limit", SessionOptionValue::from(100_i64)),
+            ("ratio", SessionOptionValue::from(0.5)),
+            ("verbose", SessionOptionValue::from(true)),
+            (
+                "search_path",
+                SessionOptionValue::from(vec!["a".to_string(), "b".to_string()]),
+            ),
+            ("bogus", SessionOptionValue::unset()),
+        ]);
+        let actual_response = client
+            .set_session_options(request.clone())
+            .await
+            .expect("error making request");
+
+        assert_eq!(actual_response, expected_response);
+        let actual_request = test_server.take_do_action_request().unwrap();
+        assert_eq!(actual_request.r#type, "SetSessionOptions");
+        assert_eq!(
+            SetSessionOptionsRequest::decode(actual_request.body).unwrap(),
+            request
+        );
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_get_session_options() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let expected_response = GetSessionOptionsResult::new([("timezone", "UTC")]);
+        let response = expected_response.encode_to_vec();
+        let response = Ok(arrow_flight::Result::new(response));
+        test_server.set_do_action_response(vec![response]);
+
+        let actual_response = client
+            .get_session_options()
+            .await
+            .expect("error making request");
+
+        let expected_request = Action::new(
+            "GetSessionOptions",
+            GetSessionOptionsRequest {}.encode_to_vec(),
+        );
+        assert_eq!(actual_response, expected_response);
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_close_session() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        let expected_response = CloseSessionResult::new(close_session_result::Status::Closed);
+        let response = expected_response.encode_to_vec();
+        let response = Ok(arrow_flight::Result::new(response));
+        test_server.set_do_action_response(vec![response]);
+
+        let actual_response = client.close_session().await.expect("error making request");
+
+        let expected_request = Action::new("CloseSession", CloseSessionRequest {}.encode_to_vec());
+        assert_eq!(actual_response, expected_response);
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_close_session_error_no_response() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+
+        test_server.set_do_action_response(vec![]);
+
+        let err = client.close_session().await.unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: Received no response for close_session call"
+        );
+        ensure_metadata(&client, &test_server);
+    })
+    .await;
+}
+
 async fn test_flight_data() -> Vec<FlightData> {
     let batch = RecordBatch::try_from_iter(vec![(
         "col",
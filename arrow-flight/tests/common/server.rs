@@ -145,6 +145,14 @@ impl TestFlightServer {
         state.do_exchange_response.replace(response);
     }
 
+    /// Make the next call to `do_exchange` echo each request message back
+    /// as soon as it is received, rather than returning the configured response
+    #[allow(dead_code)]
+    pub fn set_do_exchange_echo(&self) {
+        let mut state = self.state.lock().expect("mutex not poisoned");
+        state.do_exchange_echo = true;
+    }
+
     /// Take and return last do_exchange request send to the server,
     #[allow(dead_code)]
     pub fn take_do_exchange_request(&self) -> Option<Vec<FlightData>> {
@@ -268,6 +276,8 @@ struct State {
     pub do_exchange_request: Option<Vec<FlightData>>,
     /// The next response returned from `do_exchange`
     pub do_exchange_response: Option<Vec<Result<FlightData, Status>>>,
+    /// Echo the request of the next `do_exchange` call
+    pub do_exchange_echo: bool,
     /// The last list_flights request received
     pub list_flights_request: Option<Criteria>,
     /// The next response returned from `list_flights`
@@ -484,6 +494,25 @@ impl FlightService for TestFlightServer {
         request: Request<Streaming<FlightData>>,
     ) -> Result<Response<Self::DoExchangeStream>, Status> {
         self.save_metadata(&request);
+
+        if std::mem::take(
+            &mut self
+                .state
+                .lock()
+                .expect("mutex not poisoned")
+                .do_exchange_echo,
+        ) {
+            let state = Arc::clone(&self.state);
+            let stream = request.into_inner().inspect_ok(move |data| {
+                let mut state = state.lock().expect("mutex not poisoned");
+                state
+                    .do_exchange_request
+                    .get_or_insert_with(Vec::new)
+                    .push(data.clone());
+            });
+            return Ok(Response::new(stream.boxed()));
+        }
+
         let do_exchange_request: Vec<_> = request.into_inner().try_collect().await?;
 
         let mut state = self.state.lock().expect("mutex not poisoned");
@@ -0,0 +1,362 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Integration test for reading the endpoints of a `FlightInfo`
+
+mod common;
+
+use crate::common::fixture::TestFixture;
+use arrow_array::{Array, RecordBatch, UInt64Array, cast::AsArray, types::UInt64Type};
+use arrow_flight::{
+    FlightClient, FlightEndpoint, FlightInfo, Location, Ticket, error::FlightError,
+    reader::FlightInfoReaderBuilder,
+};
+use common::server::TestFlightServer;
+use futures::{StreamExt, TryStreamExt};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tonic::{Status, transport::Channel};
+
+#[tokio::test]
+async fn test_read_ordered() {
+    let servers = TestServers::new(3).await;
+    servers.set_response(0, vec![Ok(batch(&[1, 2])), Ok(batch(&[3]))]);
+    servers.set_response(1, vec![Ok(batch(&[4]))]);
+    servers.set_response(2, vec![Ok(batch(&[5, 6]))]);
+
+    let info = FlightInfo::new()
+        .with_endpoint(servers.endpoint("a", &[0]))
+        .with_endpoint(servers.endpoint("b", &[1]))
+        .with_endpoint(servers.endpoint("c", &[2]))
+        .with_ordered(true);
+
+    let batches: Vec<_> = FlightInfoReaderBuilder::new(servers.connector())
+        .with_max_concurrency(3)
+        .build(info)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        values(&batches),
+        vec![vec![1, 2], vec![3], vec![4], vec![5, 6]]
+    );
+    assert_eq!(servers.take_ticket(0), Some(Ticket::new("a")));
+    assert_eq!(servers.take_ticket(1), Some(Ticket::new("b")));
+    assert_eq!(servers.take_ticket(2), Some(Ticket::new("c")));
+    servers.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_read_unordered() {
+    let servers = TestServers::new(3).await;
+    servers.set_response(0, vec![Ok(batch(&[1, 2])), Ok(batch(&[3]))]);
+    servers.set_response(1, vec![Ok(batch(&[4]))]);
+    servers.set_response(2, vec![Ok(batch(&[5, 6]))]);
+
+    let info = FlightInfo::new()
+        .with_endpoint(servers.endpoint("a", &[0]))
+        .with_endpoint(servers.endpoint("b", &[1]))
+        .with_endpoint(servers.endpoint("c", &[2]));
+
+    let batches: Vec<_> = FlightInfoReaderBuilder::new(servers.connector())
+        .with_max_concurrency(2)
+        .build(info)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let mut values = values(&batches);
+    values.sort();
+    assert_eq!(values, vec![vec![1, 2], vec![3], vec![4], vec![5, 6]]);
+    servers.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_read_reuse_connection() {
+    let servers = TestServers::new(2).await;
+    servers.set_response(0, vec![Ok(batch(&[1]))]);
+    servers.set_response(1, vec![Ok(batch(&[2]))]);
+
+    // server 0 returned the FlightInfo
+    let info = FlightInfo::new()
+        .with_endpoint(FlightEndpoint::new().with_ticket(Ticket::new("a")))
+        .with_endpoint(
+            FlightEndpoint::new()
+                .with_ticket(Ticket::new("b"))
+                .with_location("arrow-flight-reuse-connection://?"),
+        )
+        .with_ordered(true);
+
+    let reader = FlightInfoReaderBuilder::new(servers.connector()).build(info);
+    let err = reader.try_collect::<Vec<_>>().await.unwrap_err();
+    // the second endpoint was also read from server 0, which has no response left
+    expect_status(err, Status::internal("No do_get response configured"));
+
+    assert_eq!(servers.take_ticket(0), Some(Ticket::new("b")));
+    assert_eq!(servers.take_ticket(1), None);
+    assert_eq!(servers.connected(), vec![None, None]);
+    servers.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_retry_alternate_location() {
+    let servers = TestServers::new(2).await;
+    // server 0 has no response configured and fails
+    servers.set_response(1, vec![Ok(batch(&[1, 2]))]);
+
+    let info = FlightInfo::new().with_endpoint(servers.endpoint("a", &[0, 1]));
+
+    let batches: Vec<_> = FlightInfoReaderBuilder::new(servers.connector())
+        .build(info)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(values(&batches), vec![vec![1, 2]]);
+    assert_eq!(servers.take_ticket(0), Some(Ticket::new("a")));
+    assert_eq!(servers.take_ticket(1), Some(Ticket::new("a")));
+    assert_eq!(
+        servers.connected(),
+        vec![Some(servers.location(0)), Some(servers.location(1))]
+    );
+    servers.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_resume_alternate_location() {
+    let servers = TestServers::new(2).await;
+    // server 0 fails after the first batch
+    servers.set_response(
+        0,
+        vec![
+            Ok(batch(&[1])),
+            Err(Status::unavailable("server shutting down")),
+        ],
+    );
+    servers.set_response(1, vec![Ok(batch(&[1])), Ok(batch(&[2])), Ok(batch(&[3]))]);
+
+    let info = FlightInfo::new().with_endpoint(servers.endpoint("a", &[0, 1]));
+
+    let batches: Vec<_> = FlightInfoReaderBuilder::new(servers.connector())
+        .build(info)
+        .try_collect()
+        .await
+        .unwrap();
+
+    // the first batch is not returned twice
+    assert_eq!(values(&batches), vec![vec![1], vec![2], vec![3]]);
+    servers.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_resume_truncated() {
+    let servers = TestServers::new(2).await;
+    // server 0 fails after the first batch, server 1 returns no batches
+    servers.set_response(
+        0,
+        vec![
+            Ok(batch(&[1])),
+            Err(Status::unavailable("server shutting down")),
+        ],
+    );
+    servers.set_response(1, vec![]);
+
+    let info = FlightInfo::new().with_endpoint(servers.endpoint("a", &[0, 1]));
+
+    let results: Vec<_> = FlightInfoReaderBuilder::new(servers.connector())
+        .build(info)
+        .collect()
+        .await;
+
+    assert_eq!(results.len(), 2);
+    let mut results = results.into_iter();
+    assert_eq!(values(&[results.next().unwrap().unwrap()]), vec![vec![1]]);
+    assert_eq!(
+        results.next().unwrap().unwrap_err().to_string(),
+        "Protocol error: Endpoint returned 0 batches when read again, expected at least 1"
+    );
+    servers.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_retry_backoff() {
+    let servers = TestServers::new(2).await;
+    // server 0 has no response configured and fails
+    servers.set_response(1, vec![Ok(batch(&[1]))]);
+
+    let info = FlightInfo::new().with_endpoint(servers.endpoint("a", &[0, 1]));
+
+    let start = Instant::now();
+    let batches: Vec<_> = FlightInfoReaderBuilder::new(servers.connector())
+        .with_retry_backoff(Duration::from_millis(200))
+        .build(info)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    assert_eq!(values(&batches), vec![vec![1]]);
+    servers.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_retries_exhausted() {
+    let servers = TestServers::new(2).await;
+    // neither location of the first endpoint has a response configured
+    servers.set_response(1, vec![Ok(batch(&[1]))]);
+
+    let info = FlightInfo::new()
+        .with_endpoint(servers.endpoint("a", &[0, 0]))
+        .with_endpoint(servers.endpoint("b", &[1]))
+        .with_ordered(true);
+
+    let reader = FlightInfoReaderBuilder::new(servers.connector())
+        .with_max_attempts(3)
+        .build(info);
+    let results: Vec<_> = reader.collect().await;
+
+    // the error of the first endpoint is returned, then the other endpoint is read
+    assert_eq!(results.len(), 2);
+    let mut results = results.into_iter();
+    expect_status(
+        results.next().unwrap().unwrap_err(),
+        Status::internal("No do_get response configured"),
+    );
+    assert_eq!(values(&[results.next().unwrap().unwrap()]), vec![vec![1]]);
+    assert_eq!(servers.connected().len(), 4);
+    servers.shutdown().await;
+}
+
+/// Several test servers, each listening on its own location
+struct TestServers {
+    servers: Vec<TestFlightServer>,
+    fixtures: Vec<TestFixture>,
+    /// Locations passed to the connector, in order
+    connected: Arc<Mutex<Vec<Option<Location>>>>,
+}
+
+impl TestServers {
+    async fn new(n: usize) -> Self {
+        let mut servers = vec![];
+        let mut fixtures = vec![];
+        for _ in 0..n {
+            let server = TestFlightServer::new();
+            fixtures.push(TestFixture::new(server.service()).await);
+            servers.push(server);
+        }
+        Self {
+            servers,
+            fixtures,
+            connected: Default::default(),
+        }
+    }
+
+    fn set_response(&self, server: usize, response: Vec<Result<RecordBatch, Status>>) {
+        self.servers[server].set_do_get_response(response);
+    }
+
+    fn take_ticket(&self, server: usize) -> Option<Ticket> {
+        self.servers[server].take_do_get_request()
+    }
+
+    fn location(&self, server: usize) -> Location {
+        Location {
+            uri: format!("grpc+tcp://{}", self.fixtures[server].addr),
+        }
+    }
+
+    fn endpoint(&self, ticket: &str, servers: &[usize]) -> FlightEndpoint {
+        servers.iter().fold(
+            FlightEndpoint::new().with_ticket(Ticket::new(ticket.to_string())),
+            |endpoint, server| endpoint.with_location(self.location(*server).uri),
+        )
+    }
+
+    fn connected(&self) -> Vec<Option<Location>> {
+        self.connected.lock().unwrap().clone()
+    }
+
+    /// Connects to the location, or to the first server if there is none
+    fn connector(
+        &self,
+    ) -> impl Fn(
+        Option<Location>,
+    ) -> futures::future::BoxFuture<'static, Result<FlightClient, FlightError>>
+    + Send
+    + Sync
+    + 'static {
+        let default = self.location(0);
+        let connected = Arc::clone(&self.connected);
+        move |location: Option<Location>| {
+            connected.lock().unwrap().push(location.clone());
+            let uri = location.unwrap_or_else(|| default.clone()).uri;
+            Box::pin(async move {
+                let channel = Channel::from_shared(uri.replace("grpc+tcp", "http"))
+                    .expect("valid uri")
+                    .connect()
+                    .await
+                    .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+                Ok(FlightClient::new(channel))
+            })
+        }
+    }
+
+    async fn shutdown(self) {
+        for fixture in self.fixtures {
+            fixture.shutdown_and_wait().await;
+        }
+    }
+}
+
+fn batch(values: &[u64]) -> RecordBatch {
+    RecordBatch::try_from_iter(vec![(
+        "col",
+        Arc::new(UInt64Array::from_iter_values(values.iter().copied())) as _,
+    )])
+    .unwrap()
+}
+
+fn values(batches: &[RecordBatch]) -> Vec<Vec<u64>> {
+    batches
+        .iter()
+        .map(|batch| {
+            let array = batch.column(0).as_primitive::<UInt64Type>();
+            assert_eq!(array.null_count(), 0);
+            array.values().to_vec()
+        })
+        .collect()
+}
+
+fn expect_status(error: FlightError, expected: Status) {
+    let status = if let FlightError::Tonic(status) = error {
+        status
+    } else {
+        panic!("Expected FlightError::Tonic, got: {error:?}");
+    };
+
+    assert_eq!(
+        status.code(),
+        expected.code(),
+        "Got {status:?} want {expected:?}"
+    );
+    assert_eq!(
+        status.message(),
+        expected.message(),
+        "Got {status:?} want {expected:?}"
+    );
+}
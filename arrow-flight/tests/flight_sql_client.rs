@@ -27,19 +27,27 @@ use arrow_flight::error::FlightError;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
 use arrow_flight::sql::server::{FlightSqlService, PeekableFlightDataStream};
+use arrow_flight::sql::state::{FlightSqlStateManager, StatementQuery, prepared_statement_ticket};
 use arrow_flight::sql::{
-    ActionBeginTransactionRequest, ActionBeginTransactionResult, ActionEndTransactionRequest,
-    CommandStatementIngest, EndTransaction, FallibleRequestStream, ProstMessageExt, SqlInfo,
-    TableDefinitionOptions, TableExistsOption, TableNotExistOption,
+    ActionBeginTransactionRequest, ActionBeginTransactionResult,
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, ActionEndTransactionRequest,
+    CommandPreparedStatementQuery, CommandStatementIngest, DoPutPreparedStatementResult,
+    EndTransaction, FallibleRequestStream, ProstMessageExt, SqlInfo, TableDefinitionOptions,
+    TableExistsOption, TableNotExistOption,
+};
+use arrow_flight::{
+    Action, CloseSessionRequest, CloseSessionResult, FlightData, FlightDescriptor, FlightEndpoint,
+    FlightInfo, GetSessionOptionsRequest, GetSessionOptionsResult, SessionOptionValue,
+    SetSessionOptionsRequest, SetSessionOptionsResult, Ticket, close_session_result,
+    flight_service_server::FlightService, set_session_options_result,
 };
-use arrow_flight::{Action, FlightData, FlightDescriptor};
 use futures::{StreamExt, TryStreamExt};
 use prost::Message;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use tonic::{IntoStreamingRequest, Request, Status};
-use uuid::Uuid;
+use tonic::{IntoStreamingRequest, Request, Response, Status};
 
 #[tokio::test]
 pub async fn test_begin_end_transaction() {
@@ -72,6 +80,95 @@ pub async fn test_begin_end_transaction() {
     );
 }
 
+#[tokio::test]
+pub async fn test_prepared_statement_parameters() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel);
+
+    let transaction_id = flight_sql_client.begin_transaction().await.unwrap();
+    let mut statement = flight_sql_client
+        .prepare("SELECT ?".to_string(), Some(transaction_id.clone()))
+        .await
+        .unwrap();
+
+    // the server echoes the bound parameters
+    let parameters = make_primitive_batch(5);
+    statement.set_parameters(parameters.clone()).unwrap();
+    let info = statement.execute().await.unwrap();
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+    let batches: Vec<_> = flight_sql_client
+        .do_get(ticket.clone())
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(batches, vec![parameters]);
+
+    // the statement is gone once closed
+    statement.close().await.unwrap();
+    assert!(flight_sql_client.do_get(ticket).await.is_err());
+
+    // statements can't be prepared in ended transactions
+    flight_sql_client
+        .end_transaction(transaction_id.clone(), EndTransaction::Commit)
+        .await
+        .unwrap();
+    assert!(
+        flight_sql_client
+            .prepare("SELECT ?".to_string(), Some(transaction_id))
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+pub async fn test_session_options() {
+    let test_server = FlightSqlServiceImpl::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel);
+
+    // no session yet
+    assert!(flight_sql_client.get_session_options().await.is_err());
+
+    let result = flight_sql_client
+        .set_session_options(SetSessionOptionsRequest::new([
+            ("catalog", SessionOptionValue::from("sales")),
+            ("timezone", SessionOptionValue::from("UTC")),
+            ("bogus", SessionOptionValue::from(true)),
+        ]))
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        SetSessionOptionsResult::default()
+            .with_error("bogus", set_session_options_result::ErrorValue::InvalidName)
+    );
+
+    // unset the timezone
+    flight_sql_client
+        .set_session_options(SetSessionOptionsRequest::new([(
+            "timezone",
+            SessionOptionValue::unset(),
+        )]))
+        .await
+        .unwrap();
+
+    let options = flight_sql_client.get_session_options().await.unwrap();
+    assert_eq!(
+        options,
+        GetSessionOptionsResult::new([("catalog", "sales")])
+    );
+
+    let result = flight_sql_client.close_session().await.unwrap();
+    assert_eq!(result.status(), close_session_result::Status::Closed);
+
+    assert!(flight_sql_client.get_session_options().await.is_err());
+}
+
 #[tokio::test]
 pub async fn test_execute_ingest() {
     let test_server = FlightSqlServiceImpl::new();
@@ -223,15 +320,17 @@ fn make_ingest_command() -> CommandStatementIngest {
 
 #[derive(Clone)]
 pub struct FlightSqlServiceImpl {
-    transactions: Arc<Mutex<HashSet<String>>>,
+    state: FlightSqlStateManager,
     ingested_batches: Arc<Mutex<Vec<RecordBatch>>>,
+    session_options: Arc<Mutex<Option<HashMap<String, SessionOptionValue>>>>,
 }
 
 impl FlightSqlServiceImpl {
     pub fn new() -> Self {
         Self {
-            transactions: Arc::new(Mutex::new(HashSet::new())),
+            state: FlightSqlStateManager::new(),
             ingested_batches: Arc::new(Mutex::new(Vec::new())),
+            session_options: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -258,13 +357,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionBeginTransactionRequest,
         _request: Request<Action>,
     ) -> Result<ActionBeginTransactionResult, Status> {
-        let transaction_id = Uuid::new_v4().to_string();
-        self.transactions
-            .lock()
-            .await
-            .insert(transaction_id.clone());
         Ok(ActionBeginTransactionResult {
-            transaction_id: transaction_id.as_bytes().to_vec().into(),
+            transaction_id: self.state.begin_transaction(),
         })
     }
 
@@ -273,14 +367,126 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: ActionEndTransactionRequest,
         _request: Request<Action>,
     ) -> Result<(), Status> {
-        let transaction_id = String::from_utf8(query.transaction_id.to_vec())
-            .map_err(|_| Status::invalid_argument("Invalid transaction id"))?;
-        if !self.transactions.lock().await.remove(&transaction_id) {
-            return Err(Status::invalid_argument("Transaction id not found"));
-        }
+        self.state.end_transaction(&query)?;
+        Ok(())
+    }
+
+    async fn do_action_create_prepared_statement(
+        &self,
+        query: ActionCreatePreparedStatementRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: self.state.create_prepared_statement(query, ())?,
+            ..Default::default()
+        })
+    }
+
+    async fn do_put_prepared_statement_query(
+        &self,
+        query: CommandPreparedStatementQuery,
+        request: Request<PeekableFlightDataStream>,
+    ) -> Result<DoPutPreparedStatementResult, Status> {
+        self.state
+            .bind_parameter_stream(query, request.into_inner())
+            .await
+    }
+
+    async fn get_flight_info_prepared_statement(
+        &self,
+        query: CommandPreparedStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let handle = query.prepared_statement_handle;
+        self.state.get_prepared_statement(&handle)?;
+        let endpoint = FlightEndpoint::new().with_ticket(prepared_statement_ticket(handle));
+        Ok(Response::new(
+            FlightInfo::new()
+                .with_descriptor(request.into_inner())
+                .with_endpoint(endpoint),
+        ))
+    }
+
+    async fn do_get_prepared_statement(
+        &self,
+        query: CommandPreparedStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
+        let statement = self
+            .state
+            .get_prepared_statement(&query.prepared_statement_handle)?;
+        assert_eq!(statement.query, StatementQuery::Sql("SELECT ?".to_string()));
+        // "execute" the query by returning its parameters
+        let stream = FlightDataEncoderBuilder::new()
+            .build(futures::stream::iter(statement.parameters).map(Ok))
+            .map_err(Status::from);
+        Ok(Response::new(stream.boxed()))
+    }
+
+    async fn do_action_close_prepared_statement(
+        &self,
+        query: ActionClosePreparedStatementRequest,
+        _request: Request<Action>,
+    ) -> Result<(), Status> {
+        self.state
+            .close_prepared_statement(&query.prepared_statement_handle)?;
         Ok(())
     }
 
+    async fn do_action_set_session_options(
+        &self,
+        query: SetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<SetSessionOptionsResult, Status> {
+        let mut session = self.session_options.lock().await;
+        let options = session.get_or_insert_with(HashMap::new);
+        let mut result = SetSessionOptionsResult::default();
+        for (name, value) in query.session_options {
+            match (name.as_str(), value.option_value) {
+                ("catalog" | "timezone", None) => {
+                    options.remove(&name);
+                }
+                ("catalog" | "timezone", Some(option_value)) => {
+                    let value = SessionOptionValue {
+                        option_value: Some(option_value),
+                    };
+                    options.insert(name, value);
+                }
+                _ => {
+                    result =
+                        result.with_error(name, set_session_options_result::ErrorValue::InvalidName)
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    async fn do_action_get_session_options(
+        &self,
+        _query: GetSessionOptionsRequest,
+        _request: Request<Action>,
+    ) -> Result<GetSessionOptionsResult, Status> {
+        match self.session_options.lock().await.as_ref() {
+            Some(options) => Ok(GetSessionOptionsResult {
+                session_options: options.clone(),
+            }),
+            None => Err(Status::not_found("No session")),
+        }
+    }
+
+    async fn do_action_close_session(
+        &self,
+        _query: CloseSessionRequest,
+        _request: Request<Action>,
+    ) -> Result<CloseSessionResult, Status> {
+        match self.session_options.lock().await.take() {
+            Some(_) => Ok(CloseSessionResult::new(
+                close_session_result::Status::Closed,
+            )),
+            None => Err(Status::not_found("No session")),
+        }
+    }
+
     async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
 
     async fn do_put_statement_ingest(
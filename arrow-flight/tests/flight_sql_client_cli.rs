@@ -17,7 +17,7 @@
 
 mod common;
 
-use std::{pin::Pin, sync::Arc};
+use std::{io::Write, net::SocketAddr, pin::Pin, sync::Arc};
 
 use crate::common::fixture::TestFixture;
 use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray, TimestampNanosecondArray};
@@ -28,19 +28,25 @@ use arrow_flight::{
     encode::FlightDataEncoderBuilder,
     flight_service_server::{FlightService, FlightServiceServer},
     sql::{
-        ActionCreatePreparedStatementRequest, ActionCreatePreparedStatementResult, Any,
-        CommandGetCatalogs, CommandGetDbSchemas, CommandGetTableTypes, CommandGetTables,
-        CommandPreparedStatementQuery, CommandStatementQuery, DoPutPreparedStatementResult,
-        ProstMessageExt, SqlInfo,
+        ActionBeginSavepointRequest, ActionBeginSavepointResult, ActionCancelQueryRequest,
+        ActionCancelQueryResult, ActionCreatePreparedStatementRequest,
+        ActionCreatePreparedStatementResult, ActionCreatePreparedSubstraitPlanRequest,
+        ActionEndSavepointRequest, Any, CancelResult, CommandGetCatalogs, CommandGetDbSchemas,
+        CommandGetTableTypes, CommandGetTables, CommandPreparedStatementQuery,
+        CommandStatementQuery, CommandStatementSubstraitPlan, DoPutPreparedStatementResult,
+        EndSavepoint, ProstMessageExt, SqlInfo,
         server::{FlightSqlService, PeekableFlightDataStream},
     },
     utils::batches_to_flight_data,
 };
+use arrow_ipc::reader::FileReader;
 use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::{ArrowError, DataType, Field, Schema, TimeUnit};
+use arrow_select::concat::concat_batches;
 use assert_cmd::Command;
 use bytes::Bytes;
 use futures::{Stream, TryStreamExt};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use prost::Message;
 use tonic::{Request, Response, Status, Streaming};
 
@@ -345,6 +351,397 @@ pub async fn test_do_put_prepared_statement_stateful() {
     .await
 }
 
+const SUBSTRAIT_PLAN: &[u8] = b"fake substrait plan";
+const SUBSTRAIT_VERSION: &str = "0.1.0";
+const TRANSACTION_ID: &str = "transaction_id";
+const SAVEPOINT_NAME: &str = "savepoint_name";
+const SAVEPOINT_ID: &str = "savepoint_id";
+const UPDATE_COUNT: i64 = 7;
+
+/// Run the CLI against the server at `addr` and return its trimmed stdout
+async fn run_client(addr: SocketAddr, args: Vec<String>) -> String {
+    let stdout = tokio::task::spawn_blocking(move || {
+        flight_sql_client_cmd()
+            .env_clear()
+            .env("RUST_BACKTRACE", "1")
+            .env("RUST_LOG", "warn")
+            .arg("--host")
+            .arg(addr.ip().to_string())
+            .arg("--port")
+            .arg(addr.port().to_string())
+            .args(args)
+            .assert()
+            .success()
+            .get_output()
+            .stdout
+            .clone()
+    })
+    .await
+    .unwrap();
+
+    String::from_utf8(stdout).unwrap().trim().to_string()
+}
+
+fn substrait_plan_file() -> tempfile::NamedTempFile {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(SUBSTRAIT_PLAN).unwrap();
+    file
+}
+
+const EXPECTED_RESULT: &str = "+--------------+-----------+---------------------------+-----------------------------+\
+    \n| field_string | field_int | field_timestamp_nano_notz | field_timestamp_nano_berlin |\
+    \n+--------------+-----------+---------------------------+-----------------------------+\
+    \n| Hello        | 42        |                           |                             |\
+    \n| lovely       |           | 1970-01-01T00:00:00       | 1970-01-01T01:00:00+01:00   |\
+    \n| FlightSQL!   | 1337      | 2024-10-30T11:36:57       | 2024-10-30T12:36:57+01:00   |\
+    \n+--------------+-----------+---------------------------+-----------------------------+";
+
+#[tokio::test]
+async fn test_substrait_query() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let plan = substrait_plan_file();
+
+    let stdout = run_client(
+        fixture.addr,
+        vec![
+            "substrait-query".to_string(),
+            plan.path().display().to_string(),
+            "--version".to_string(),
+            SUBSTRAIT_VERSION.to_string(),
+        ],
+    )
+    .await;
+
+    fixture.shutdown_and_wait().await;
+
+    assert_eq!(stdout, EXPECTED_RESULT);
+}
+
+#[tokio::test]
+async fn test_prepared_substrait_query() {
+    // the plan is not re-bound with parameters, so the original handle is used
+    let test_server = FlightSqlServiceImpl {
+        stateless_prepared_statements: false,
+    };
+    let fixture = TestFixture::new(test_server.service()).await;
+    let plan = substrait_plan_file();
+
+    let stdout = run_client(
+        fixture.addr,
+        vec![
+            "prepared-substrait-query".to_string(),
+            plan.path().display().to_string(),
+            "--version".to_string(),
+            SUBSTRAIT_VERSION.to_string(),
+        ],
+    )
+    .await;
+
+    fixture.shutdown_and_wait().await;
+
+    assert_eq!(stdout, EXPECTED_RESULT);
+}
+
+#[tokio::test]
+async fn test_substrait_update() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let plan = substrait_plan_file();
+
+    let stdout = run_client(
+        fixture.addr,
+        vec![
+            "substrait-update".to_string(),
+            plan.path().display().to_string(),
+            "--version".to_string(),
+            SUBSTRAIT_VERSION.to_string(),
+            "--transaction-id".to_string(),
+            TRANSACTION_ID.to_string(),
+        ],
+    )
+    .await;
+
+    fixture.shutdown_and_wait().await;
+
+    assert_eq!(stdout, UPDATE_COUNT.to_string());
+}
+
+#[tokio::test]
+async fn test_savepoint() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+
+    let begin = run_client(
+        fixture.addr,
+        vec![
+            "begin-savepoint".to_string(),
+            TRANSACTION_ID.to_string(),
+            SAVEPOINT_NAME.to_string(),
+        ],
+    )
+    .await;
+    let end = run_client(
+        fixture.addr,
+        vec![
+            "end-savepoint".to_string(),
+            begin.clone(),
+            "release".to_string(),
+        ],
+    )
+    .await;
+
+    fixture.shutdown_and_wait().await;
+
+    assert_eq!(begin, SAVEPOINT_ID);
+    assert_eq!(end, "");
+}
+
+#[tokio::test]
+async fn test_cancel_query() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+
+    let stdout = run_client(
+        fixture.addr,
+        vec!["cancel-query".to_string(), QUERY.to_string()],
+    )
+    .await;
+
+    fixture.shutdown_and_wait().await;
+
+    assert_eq!(stdout, "CANCEL_RESULT_CANCELLED");
+}
+
+const EXPECTED_CSV: &str = "field_string,field_int,field_timestamp_nano_notz,field_timestamp_nano_berlin\
+    \nHello,42,,\
+    \nlovely,,1970-01-01T00:00:00,1970-01-01T01:00:00+01:00\
+    \nFlightSQL!,1337,2024-10-30T11:36:57,2024-10-30T12:36:57+01:00";
+
+#[tokio::test]
+async fn test_format_csv() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+
+    let stdout = run_client(
+        fixture.addr,
+        vec![
+            "--format".to_string(),
+            "csv".to_string(),
+            "statement-query".to_string(),
+            QUERY.to_string(),
+        ],
+    )
+    .await;
+
+    fixture.shutdown_and_wait().await;
+
+    assert_eq!(stdout, EXPECTED_CSV);
+}
+
+#[tokio::test]
+async fn test_format_json() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+
+    let json = run_client(
+        fixture.addr,
+        vec![
+            "--format".to_string(),
+            "json".to_string(),
+            "statement-query".to_string(),
+            QUERY.to_string(),
+        ],
+    )
+    .await;
+    let ndjson = run_client(
+        fixture.addr,
+        vec![
+            "--format".to_string(),
+            "ndjson".to_string(),
+            "statement-query".to_string(),
+            QUERY.to_string(),
+        ],
+    )
+    .await;
+
+    fixture.shutdown_and_wait().await;
+
+    let rows = [
+        r#"{"field_string":"Hello","field_int":42}"#,
+        r#"{"field_string":"lovely","field_timestamp_nano_notz":"1970-01-01T00:00:00","field_timestamp_nano_berlin":"1970-01-01T01:00:00+01:00"}"#,
+        r#"{"field_string":"FlightSQL!","field_int":1337,"field_timestamp_nano_notz":"2024-10-30T11:36:57","field_timestamp_nano_berlin":"2024-10-30T12:36:57+01:00"}"#,
+    ];
+    assert_eq!(json, format!("[{}]", rows.join(",")));
+    assert_eq!(ndjson, rows.join("\n"));
+}
+
+#[tokio::test]
+async fn test_output_arrow() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let output = tempfile::NamedTempFile::new().unwrap();
+
+    let stdout = run_client(
+        fixture.addr,
+        vec![
+            "--format".to_string(),
+            "arrow".to_string(),
+            "--output".to_string(),
+            output.path().display().to_string(),
+            "statement-query".to_string(),
+            QUERY.to_string(),
+        ],
+    )
+    .await;
+
+    fixture.shutdown_and_wait().await;
+
+    assert_eq!(stdout, "");
+    let reader = FileReader::try_new(output.reopen().unwrap(), None).unwrap();
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    let expected = FlightSqlServiceImpl::fake_result().unwrap();
+    assert_eq!(
+        concat_batches(&expected.schema(), &batches).unwrap(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn test_output_parquet() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let output = tempfile::NamedTempFile::new().unwrap();
+
+    let stdout = run_client(
+        fixture.addr,
+        vec![
+            "--format".to_string(),
+            "parquet".to_string(),
+            "--output".to_string(),
+            output.path().display().to_string(),
+            "statement-query".to_string(),
+            QUERY.to_string(),
+        ],
+    )
+    .await;
+
+    fixture.shutdown_and_wait().await;
+
+    assert_eq!(stdout, "");
+    let reader = ParquetRecordBatchReaderBuilder::try_new(output.reopen().unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    let expected = FlightSqlServiceImpl::fake_result().unwrap();
+    assert_eq!(
+        concat_batches(&expected.schema(), &batches).unwrap(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn test_binary_output_requires_file() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let addr = fixture.addr;
+
+    let output = tokio::task::spawn_blocking(move || {
+        flight_sql_client_cmd()
+            .env_clear()
+            .env("RUST_BACKTRACE", "1")
+            .env("RUST_LOG", "warn")
+            .arg("--host")
+            .arg(addr.ip().to_string())
+            .arg("--port")
+            .arg(addr.port().to_string())
+            .arg("--format")
+            .arg("parquet")
+            .arg("statement-query")
+            .arg(QUERY)
+            .assert()
+            .failure()
+            .get_output()
+            .clone()
+    })
+    .await
+    .unwrap();
+
+    fixture.shutdown_and_wait().await;
+
+    assert!(output.stdout.is_empty());
+    let stderr = std::str::from_utf8(&output.stderr).unwrap();
+    assert!(
+        stderr.contains("binary output formats require --output"),
+        "{stderr}"
+    );
+}
+
+#[tokio::test]
+async fn test_repl() {
+    let test_server = FlightSqlServiceImpl::default();
+    let fixture = TestFixture::new(test_server.service()).await;
+    let addr = fixture.addr;
+    let history = tempfile::NamedTempFile::new().unwrap();
+    let history_path = history.path().to_path_buf();
+
+    let input = format!(
+        "{QUERY}\n\\prepare {PREPARED_QUERY}\n\\param $1=string\n\\param $2=64\n\\execute\n\\bogus\n\\history\n"
+    );
+    let output = tokio::task::spawn_blocking(move || {
+        flight_sql_client_cmd()
+            .env_clear()
+            .env("RUST_BACKTRACE", "1")
+            .env("RUST_LOG", "warn")
+            .arg("--host")
+            .arg(addr.ip().to_string())
+            .arg("--port")
+            .arg(addr.port().to_string())
+            .arg("--format")
+            .arg("csv")
+            .arg("repl")
+            .arg("--history-file")
+            .arg(history_path)
+            .write_stdin(input)
+            .assert()
+            .success()
+            .get_output()
+            .clone()
+    })
+    .await
+    .unwrap();
+
+    fixture.shutdown_and_wait().await;
+
+    let stdout = std::str::from_utf8(&output.stdout).unwrap();
+    let stderr = std::str::from_utf8(&output.stderr).unwrap();
+    assert_eq!(
+        stdout,
+        format!(
+            "{EXPECTED_CSV}\n{EXPECTED_CSV}\
+            \n    1  {QUERY}\
+            \n    2  \\prepare {PREPARED_QUERY}\
+            \n    3  \\param $1=string\
+            \n    4  \\param $2=64\
+            \n    5  \\execute\
+            \n    6  \\bogus\
+            \n    7  \\history\n"
+        )
+    );
+    assert!(
+        stderr.contains("Error: unknown command \\bogus"),
+        "{stderr}"
+    );
+    assert_eq!(
+        std::fs::read_to_string(history.path())
+            .unwrap()
+            .lines()
+            .count(),
+        7
+    );
+}
+
 #[derive(Clone)]
 pub struct FlightSqlServiceImpl {
     /// Whether to emulate stateless (true) or stateful (false) behavior for
@@ -750,6 +1147,78 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .map_err(|e| Status::internal(format!("Unable to serialize schema: {e}")))
     }
 
+    async fn get_flight_info_substrait_plan(
+        &self,
+        query: CommandStatementSubstraitPlan,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let plan = query.plan.unwrap();
+        assert_eq!(plan.plan, SUBSTRAIT_PLAN);
+        assert_eq!(plan.version, SUBSTRAIT_VERSION);
+
+        let resp = Response::new(self.fake_flight_info().unwrap());
+        Ok(resp)
+    }
+
+    async fn do_put_substrait_plan(
+        &self,
+        query: CommandStatementSubstraitPlan,
+        _request: Request<PeekableFlightDataStream>,
+    ) -> Result<i64, Status> {
+        let plan = query.plan.unwrap();
+        assert_eq!(plan.plan, SUBSTRAIT_PLAN);
+        assert_eq!(plan.version, SUBSTRAIT_VERSION);
+        assert_eq!(query.transaction_id.unwrap(), TRANSACTION_ID.as_bytes());
+        Ok(UPDATE_COUNT)
+    }
+
+    async fn do_action_create_prepared_substrait_plan(
+        &self,
+        query: ActionCreatePreparedSubstraitPlanRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        let plan = query.plan.unwrap();
+        assert_eq!(plan.plan, SUBSTRAIT_PLAN);
+        assert_eq!(plan.version, SUBSTRAIT_VERSION);
+
+        Self::create_fake_prepared_stmt()
+            .map_err(|e| Status::internal(format!("Unable to serialize schema: {e}")))
+    }
+
+    async fn do_action_begin_savepoint(
+        &self,
+        query: ActionBeginSavepointRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionBeginSavepointResult, Status> {
+        assert_eq!(query.transaction_id, TRANSACTION_ID.as_bytes());
+        assert_eq!(query.name, SAVEPOINT_NAME);
+        Ok(ActionBeginSavepointResult {
+            savepoint_id: SAVEPOINT_ID.into(),
+        })
+    }
+
+    async fn do_action_end_savepoint(
+        &self,
+        query: ActionEndSavepointRequest,
+        _request: Request<Action>,
+    ) -> Result<(), Status> {
+        assert_eq!(query.savepoint_id, SAVEPOINT_ID.as_bytes());
+        assert_eq!(query.action, EndSavepoint::Release as i32);
+        Ok(())
+    }
+
+    async fn do_action_cancel_query(
+        &self,
+        query: ActionCancelQueryRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionCancelQueryResult, Status> {
+        let info = FlightInfo::decode(query.info).unwrap();
+        assert_eq!(info, self.fake_flight_info().unwrap());
+        Ok(ActionCancelQueryResult {
+            result: CancelResult::Cancelled as i32,
+        })
+    }
+
     async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
 }
 
@@ -19,7 +19,7 @@
 //! in `testing/arrow-ipc-stream/integration/...`
 
 use arrow::error::ArrowError;
-use arrow::ipc::reader::{FileReader, StreamDecoder, StreamReader};
+use arrow::ipc::reader::{FileReader, FileReaderBuilder, StreamDecoder, StreamReader};
 use arrow::util::test_util::arrow_test_data;
 use arrow_buffer::Buffer;
 use arrow_integration_testing::read_gzip_json;
@@ -86,9 +86,57 @@ fn read_1_0_0_bigendian() {
         let err = reader.err().unwrap();
         assert!(matches!(err, ArrowError::IpcError(_)));
         assert_eq!(err.to_string(), "Ipc error: the endianness of the source system does not match the endianness of the target system.");
+
+        verify_swapped_arrow_file(&testdata, "1.0.0-bigendian", path);
+        verify_swapped_arrow_stream(&testdata, "1.0.0-bigendian", path);
     });
 }
 
+/// Verifies an arrow file written with a non-native endianness by reading
+/// it with byte swapping enabled
+fn verify_swapped_arrow_file(testdata: &str, version: &str, path: &str) {
+    let filename = format!("{testdata}/arrow-ipc-stream/integration/{version}/{path}.arrow_file");
+    println!("Verifying {filename} with byte swapping");
+
+    let file = File::open(&filename).unwrap();
+    let mut reader = FileReaderBuilder::new()
+        .with_swap_endianness(true)
+        .build(file)
+        .unwrap();
+
+    let arrow_json = read_gzip_json(version, path);
+    assert!(arrow_json.equals_reader(&mut reader).unwrap());
+}
+
+/// Verifies an arrow stream written with a non-native endianness by reading
+/// it with byte swapping enabled
+fn verify_swapped_arrow_stream(testdata: &str, version: &str, path: &str) {
+    let filename = format!("{testdata}/arrow-ipc-stream/integration/{version}/{path}.stream");
+    println!("Verifying {filename} with byte swapping");
+
+    let arrow_json = read_gzip_json(version, path);
+
+    let file = File::open(&filename).unwrap();
+    let mut reader = StreamReader::try_new(file, None)
+        .unwrap()
+        .with_swap_endianness(true);
+    assert!(arrow_json.equals_reader(&mut reader).unwrap());
+    assert!(reader.next().is_none());
+
+    let expected = arrow_json.get_record_batches().unwrap();
+    let mut decoder = StreamDecoder::new().with_swap_endianness(true);
+    let mut actual = Vec::with_capacity(expected.len());
+    for mut x in chunked_file(&filename, 8) {
+        while !x.is_empty() {
+            if let Some(x) = decoder.decode(&mut x).unwrap() {
+                actual.push(x);
+            }
+        }
+    }
+    decoder.finish().unwrap();
+    assert_eq!(expected, actual);
+}
+
 #[test]
 fn read_1_0_0_littleendian() {
     let testdata = arrow_test_data();
@@ -65,6 +65,7 @@ use DataType::*;
 #[derive(Debug)]
 pub struct IpcSchemaEncoder<'a> {
     dictionary_tracker: Option<&'a mut DictionaryTracker>,
+    endianness: crate::Endianness,
 }
 
 impl Default for IpcSchemaEncoder<'_> {
@@ -78,6 +79,7 @@ impl<'a> IpcSchemaEncoder<'a> {
     pub fn new() -> IpcSchemaEncoder<'a> {
         IpcSchemaEncoder {
             dictionary_tracker: None,
+            endianness: crate::Endianness::native(),
         }
     }
 
@@ -90,6 +92,12 @@ impl<'a> IpcSchemaEncoder<'a> {
         self
     }
 
+    /// Specify the endianness to record in the schema (defaults to the native endianness)
+    pub fn with_endianness(mut self, endianness: crate::Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
     /// Serialize a schema in IPC format, returning a completed [`FlatBufferBuilder`]
     ///
     /// Note: Call [`FlatBufferBuilder::finished_data`] to get the serialized bytes
@@ -120,6 +128,7 @@ impl<'a> IpcSchemaEncoder<'a> {
             (!schema.metadata().is_empty()).then(|| metadata_to_fb(fbb, schema.metadata()));
 
         let mut builder = crate::SchemaBuilder::new(fbb);
+        builder.add_endianness(self.endianness);
         builder.add_fields(fb_field_list);
         if let Some(fb_metadata_list) = fb_metadata_list {
             builder.add_custom_metadata(fb_metadata_list);
@@ -149,6 +158,17 @@ pub fn metadata_to_fb<'a>(
     fbb.create_vector(&custom_metadata)
 }
 
+/// Convert key-value metadata read from a flatbuffer to [Metadata]
+///
+/// Entries with a missing key or value are ignored
+pub fn fb_to_metadata(metadata: Option<Vector<'_, ForwardsUOffset<KeyValue<'_>>>>) -> Metadata {
+    metadata
+        .into_iter()
+        .flatten()
+        .filter_map(|kv| Some((kv.key()?, kv.value()?)))
+        .collect()
+}
+
 /// Adds a [Schema] to a flatbuffer and returns the offset
 pub fn schema_to_fb_offset<'a>(
     fbb: &mut FlatBufferBuilder<'a>,
@@ -197,12 +217,6 @@ pub fn fb_to_schema(fb: crate::Schema) -> Schema {
     let len = c_fields.len();
     for i in 0..len {
         let c_field: crate::Field = c_fields.get(i);
-        match c_field.type_type() {
-            crate::Type::Decimal if fb.endianness() == crate::Endianness::Big => {
-                unimplemented!("Big Endian is not supported for Decimal!")
-            }
-            _ => (),
-        };
         fields.push(c_field.into());
     }
 
@@ -0,0 +1,149 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Byte swapping of IPC buffers written with a non-native endianness
+
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::{DataType, IntervalUnit};
+
+/// Returns the byte widths of the fields making up a single value of
+/// `data_type`, or `None` if its values are not affected by endianness
+///
+/// Most types consist of a single integer or float, however, some interval
+/// types are composed of several integers which are swapped individually.
+pub(crate) fn value_widths(data_type: &DataType) -> Option<&'static [usize]> {
+    match data_type {
+        DataType::Interval(IntervalUnit::DayTime) => Some(&[4, 4]),
+        DataType::Interval(IntervalUnit::MonthDayNano) => Some(&[4, 4, 8]),
+        _ => match data_type.primitive_width()? {
+            2 => Some(&[2]),
+            4 => Some(&[4]),
+            8 => Some(&[8]),
+            16 => Some(&[16]),
+            32 => Some(&[32]),
+            _ => None,
+        },
+    }
+}
+
+/// Returns a copy of `buffer` with the byte order of each value reversed,
+/// where a value is composed of fields of the given `widths`
+///
+/// Any trailing bytes that do not make up a whole value are copied unchanged.
+pub(crate) fn swap_values(buffer: &Buffer, widths: &[usize]) -> Buffer {
+    let value_width: usize = widths.iter().sum();
+    let mut out = MutableBuffer::new(buffer.len());
+    let mut chunks = buffer.chunks_exact(value_width);
+    for value in &mut chunks {
+        let mut start = 0;
+        for width in widths {
+            out.extend(value[start..start + width].iter().rev().copied());
+            start += width;
+        }
+    }
+    out.extend_from_slice(chunks.remainder());
+    out.into()
+}
+
+/// Returns a copy of the views `buffer` of a `Utf8View` or `BinaryView`
+/// array with the byte order of the integers of each view reversed
+///
+/// `from_native` indicates if the views are currently in native byte order,
+/// and therefore how the length of each view is to be interpreted.
+pub(crate) fn swap_views(buffer: &Buffer, from_native: bool) -> Buffer {
+    let mut out = MutableBuffer::new(buffer.len());
+    let mut chunks = buffer.chunks_exact(16);
+    for view in &mut chunks {
+        let len_bytes: [u8; 4] = view[..4].try_into().unwrap();
+        let len = match from_native {
+            true => u32::from_ne_bytes(len_bytes),
+            false => u32::from_ne_bytes(len_bytes).swap_bytes(),
+        };
+        out.extend(len_bytes.into_iter().rev());
+        if len <= 12 {
+            // inline data
+            out.extend_from_slice(&view[4..]);
+        } else {
+            // prefix, buffer index and offset
+            out.extend_from_slice(&view[4..8]);
+            out.extend(view[8..12].iter().rev().copied());
+            out.extend(view[12..16].iter().rev().copied());
+        }
+    }
+    out.extend_from_slice(chunks.remainder());
+    out.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::IntervalMonthDayNano;
+    use arrow_array::{Array, IntervalMonthDayNanoArray, StringViewArray};
+    use arrow_buffer::ScalarBuffer;
+
+    #[test]
+    fn test_swap_values() {
+        let buffer = Buffer::from_slice_ref([1_i32, -2, 0x01020304]);
+        let swapped = swap_values(&buffer, &[4]);
+        let values: &[i32] = swapped.typed_data();
+        assert_eq!(
+            values,
+            &[1_i32.swap_bytes(), (-2_i32).swap_bytes(), 0x04030201]
+        );
+        assert_eq!(swap_values(&swapped, &[4]), buffer);
+
+        let buffer = Buffer::from(vec![1_u8, 2, 3]);
+        assert_eq!(swap_values(&buffer, &[2]).as_slice(), &[2, 1, 3]);
+    }
+
+    #[test]
+    fn test_swap_month_day_nano() {
+        let value = IntervalMonthDayNano::new(1, -2, 3);
+        let array = IntervalMonthDayNanoArray::from(vec![value]);
+        let data = array.to_data();
+        let buffer = &data.buffers()[0];
+        let widths = value_widths(array.data_type()).unwrap();
+        let swapped = swap_values(buffer, widths);
+
+        let mut expected = 1_i32.swap_bytes().to_ne_bytes().to_vec();
+        expected.extend((-2_i32).swap_bytes().to_ne_bytes());
+        expected.extend(3_i64.swap_bytes().to_ne_bytes());
+        assert_eq!(swapped.as_slice(), expected);
+        assert_eq!(&swap_values(&swapped, widths), buffer);
+    }
+
+    #[test]
+    fn test_swap_views() {
+        let array = StringViewArray::from(vec!["short", "a string longer than twelve bytes"]);
+        let views = array.views().inner();
+        let swapped = swap_views(views, true);
+        let swapped_views: ScalarBuffer<u128> = swapped.clone().into();
+
+        // inline view: only the length is swapped
+        let inline = swapped_views[0].to_ne_bytes();
+        assert_eq!(inline[..4], 5_u32.swap_bytes().to_ne_bytes());
+        assert_eq!(&inline[4..9], b"short");
+
+        // out of line view: prefix is kept, buffer index and offset are swapped
+        let out_of_line = swapped_views[1].to_ne_bytes();
+        assert_eq!(out_of_line[..4], 33_u32.swap_bytes().to_ne_bytes());
+        assert_eq!(&out_of_line[4..8], b"a st");
+        assert_eq!(out_of_line[8..12], 0_u32.to_ne_bytes());
+
+        assert_eq!(&swap_views(&swapped, false), views);
+    }
+}
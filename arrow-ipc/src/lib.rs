@@ -49,6 +49,7 @@ pub mod reader;
 pub mod writer;
 
 mod compression;
+mod endian;
 
 #[cfg(test)]
 mod tests;
@@ -73,6 +74,15 @@ const ARROW_MAGIC: [u8; 6] = *b"ARROW1";
 const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
 
 impl Endianness {
+    /// Returns the endianness of the target system.
+    pub fn native() -> Self {
+        if cfg!(target_endian = "big") {
+            Self::Big
+        } else {
+            Self::Little
+        }
+    }
+
     /// Returns true if the endianness of the source system matches the endianness of the target system.
     pub fn equals_to_target_endianness(self) -> bool {
         match self {
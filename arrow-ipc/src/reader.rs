@@ -43,6 +43,7 @@ use arrow_data::{ArrayData, ArrayDataBuilder, UnsafeFlag};
 use arrow_schema::*;
 
 use crate::compression::{CompressionCodec, DecompressionContext};
+use crate::endian::{swap_values, swap_views, value_widths};
 use crate::r#gen::Message;
 use crate::{Block, CONTINUATION_MARKER, FieldNode, MetadataVersion};
 use DataType::*;
@@ -94,9 +95,13 @@ impl RecordBatchDecoder<'_> {
         match data_type {
             Utf8 | Binary | LargeBinary | LargeUtf8 => {
                 let field_node = self.next_node(field)?;
+                let offset_width = match data_type {
+                    LargeBinary | LargeUtf8 => 8,
+                    _ => 4,
+                };
                 let buffers = [
                     self.next_buffer()?,
-                    self.next_buffer()?,
+                    self.next_swapped_buffer(&[offset_width])?,
                     self.next_buffer()?,
                 ];
                 self.create_primitive_array(field_node, data_type, &buffers)
@@ -108,9 +113,12 @@ impl RecordBatchDecoder<'_> {
                         "Missing variadic count for {data_type} column"
                     )))?;
                 let count = count + 2; // view and null buffer.
-                let buffers = (0..count)
+                let mut buffers = (0..count)
                     .map(|_| self.next_buffer())
                     .collect::<Result<Vec<_>, _>>()?;
+                if self.swap_endianness {
+                    buffers[1] = swap_views(&buffers[1], false);
+                }
                 let field_node = self.next_node(field)?;
                 self.create_primitive_array(field_node, data_type, &buffers)
             }
@@ -121,16 +129,27 @@ impl RecordBatchDecoder<'_> {
             }
             List(list_field) | LargeList(list_field) | Map(list_field, _) => {
                 let list_node = self.next_node(field)?;
-                let list_buffers = [self.next_buffer()?, self.next_buffer()?];
+                let offset_width = match data_type {
+                    LargeList(_) => 8,
+                    _ => 4,
+                };
+                let list_buffers = [
+                    self.next_buffer()?,
+                    self.next_swapped_buffer(&[offset_width])?,
+                ];
                 let values = self.create_array(list_field, variadic_counts)?;
                 self.create_list_array(list_node, data_type, &list_buffers, values)
             }
             ListView(list_field) | LargeListView(list_field) => {
                 let list_node = self.next_node(field)?;
+                let offset_width = match data_type {
+                    LargeListView(_) => 8,
+                    _ => 4,
+                };
                 let list_buffers = [
-                    self.next_buffer()?, // null buffer
-                    self.next_buffer()?, // offsets
-                    self.next_buffer()?, // sizes
+                    self.next_buffer()?,                        // null buffer
+                    self.next_swapped_buffer(&[offset_width])?, // offsets
+                    self.next_swapped_buffer(&[offset_width])?, // sizes
                 ];
                 let values = self.create_array(list_field, variadic_counts)?;
                 self.create_list_view_array(list_node, data_type, &list_buffers, values)
@@ -171,9 +190,12 @@ impl RecordBatchDecoder<'_> {
                 self.create_array_from_builder(builder)
             }
             // Create dictionary array from RecordBatch
-            Dictionary(_, _) => {
+            Dictionary(key_type, _) => {
                 let index_node = self.next_node(field)?;
-                let index_buffers = [self.next_buffer()?, self.next_buffer()?];
+                let index_buffers = [
+                    self.next_buffer()?,
+                    self.next_swapped_buffer(value_widths(key_type).unwrap_or_default())?,
+                ];
 
                 #[allow(deprecated)]
                 let dict_id = field.dict_id().ok_or_else(|| {
@@ -211,8 +233,10 @@ impl RecordBatchDecoder<'_> {
 
                 let value_offsets = match mode {
                     UnionMode::Dense => {
-                        let offsets: ScalarBuffer<i32> =
-                            self.next_buffer()?.slice_with_length(0, len * 4).into();
+                        let offsets: ScalarBuffer<i32> = self
+                            .next_swapped_buffer(&[4])?
+                            .slice_with_length(0, len * 4)
+                            .into();
                         Some(offsets)
                     }
                     UnionMode::Sparse => None,
@@ -253,7 +277,10 @@ impl RecordBatchDecoder<'_> {
             }
             _ => {
                 let field_node = self.next_node(field)?;
-                let buffers = [self.next_buffer()?, self.next_buffer()?];
+                let buffers = [
+                    self.next_buffer()?,
+                    self.next_swapped_buffer(value_widths(data_type).unwrap_or_default())?,
+                ];
                 self.create_primitive_array(field_node, data_type, &buffers)
             }
         }
@@ -470,6 +497,9 @@ pub struct RecordBatchDecoder<'a> {
     ///
     /// See [`FileDecoder::with_skip_validation`] for details.
     skip_validation: UnsafeFlag,
+    /// Should fixed-width values be byte swapped into native order? See
+    /// [`RecordBatchDecoder::with_endianness`] for details
+    swap_endianness: bool,
 }
 
 impl<'a> RecordBatchDecoder<'a> {
@@ -506,6 +536,7 @@ impl<'a> RecordBatchDecoder<'a> {
             projection: None,
             require_alignment: false,
             skip_validation: UnsafeFlag::new(),
+            swap_endianness: false,
         })
     }
 
@@ -545,6 +576,20 @@ impl<'a> RecordBatchDecoder<'a> {
         self
     }
 
+    /// Set the endianness the data was written with (default: the native endianness)
+    ///
+    /// If this does not match the endianness of the target system, fixed-width
+    /// values such as primitives, offsets, views, dictionary keys and union
+    /// offsets are byte swapped into native order, which requires copying them.
+    pub fn with_endianness(self, endianness: crate::Endianness) -> Self {
+        self.with_swap_endianness(!endianness.equals_to_target_endianness())
+    }
+
+    fn with_swap_endianness(mut self, swap_endianness: bool) -> Self {
+        self.swap_endianness = swap_endianness;
+        self
+    }
+
     /// Read the record batch, consuming the reader
     pub fn read_record_batch(mut self) -> Result<RecordBatch, ArrowError> {
         let mut variadic_counts: VecDeque<i64> = self
@@ -633,6 +678,16 @@ impl<'a> RecordBatchDecoder<'a> {
         )
     }
 
+    /// Reads the next buffer, byte swapping values composed of fields of the
+    /// given `widths` into native order if required
+    fn next_swapped_buffer(&mut self, widths: &[usize]) -> Result<Buffer, ArrowError> {
+        let buffer = self.next_buffer()?;
+        match self.swap_endianness && !widths.is_empty() {
+            true => Ok(swap_values(&buffer, widths)),
+            false => Ok(buffer),
+        }
+    }
+
     fn skip_buffer(&mut self) {
         self.buffers.next().unwrap();
     }
@@ -819,6 +874,7 @@ pub fn read_dictionary_impl(
         metadata,
         require_alignment,
         skip_validation,
+        false,
     )?;
 
     update_dictionaries(dictionaries_by_id, batch.isDelta(), id, dictionary_values)?;
@@ -866,6 +922,7 @@ fn update_dictionaries(
 /// Given a dictionary batch IPC message/body along with the full state of a
 /// stream including schema, dictionary cache, metadata, and other flags, this
 /// function will parse the buffer into an array of dictionary values.
+#[allow(clippy::too_many_arguments)]
 fn get_dictionary_values(
     buf: &Buffer,
     batch: crate::DictionaryBatch,
@@ -874,6 +931,7 @@ fn get_dictionary_values(
     metadata: &MetadataVersion,
     require_alignment: bool,
     skip_validation: UnsafeFlag,
+    swap_endianness: bool,
 ) -> Result<ArrayRef, ArrowError> {
     let id = batch.id();
     #[allow(deprecated)]
@@ -900,6 +958,7 @@ fn get_dictionary_values(
             )?
             .with_require_alignment(require_alignment)
             .with_skip_validation(skip_validation)
+            .with_swap_endianness(swap_endianness)
             .read_record_batch()?;
 
             Some(record_batch.column(0).clone())
@@ -913,8 +972,20 @@ fn get_dictionary_values(
     Ok(dictionary_values)
 }
 
+/// Returns the error for data written with a non-native endianness when byte
+/// swapping has not been enabled
+fn endianness_mismatch_error() -> ArrowError {
+    ArrowError::IpcError(
+        "the endianness of the source system does not match the endianness of the target system."
+            .to_owned(),
+    )
+}
+
 /// Read the data for a given block
-fn read_block<R: Read + Seek>(mut reader: R, block: &Block) -> Result<Buffer, ArrowError> {
+pub(crate) fn read_block<R: Read + Seek>(
+    mut reader: R,
+    block: &Block,
+) -> Result<Buffer, ArrowError> {
     reader.seek(SeekFrom::Start(block.offset() as u64))?;
     let body_len = block.bodyLength().to_usize().unwrap();
     let metadata_len = block.metaDataLength().to_usize().unwrap();
@@ -1026,6 +1097,7 @@ pub struct FileDecoder {
     projection: Option<Vec<usize>>,
     require_alignment: bool,
     skip_validation: UnsafeFlag,
+    swap_endianness: bool,
 }
 
 impl FileDecoder {
@@ -1038,6 +1110,7 @@ impl FileDecoder {
             projection: None,
             require_alignment: false,
             skip_validation: UnsafeFlag::new(),
+            swap_endianness: false,
         }
     }
 
@@ -1079,6 +1152,22 @@ impl FileDecoder {
         self
     }
 
+    /// Specifies the endianness the file was written with, as recorded in the
+    /// schema of its footer (defaults to the native endianness)
+    ///
+    /// If this does not match the endianness of the target system, fixed-width
+    /// values are byte swapped into native order when read, see
+    /// [`RecordBatchDecoder::with_endianness`].
+    pub fn with_endianness(mut self, endianness: crate::Endianness) -> Self {
+        self.swap_endianness = !endianness.equals_to_target_endianness();
+        self
+    }
+
+    /// Returns the dictionaries read so far, keyed by dictionary id
+    pub(crate) fn dictionaries(&self) -> &HashMap<i64, ArrayRef> {
+        &self.dictionaries
+    }
+
     fn read_message<'a>(&self, buf: &'a [u8]) -> Result<Message::Message<'a>, ArrowError> {
         let message = parse_message(buf)?;
 
@@ -1097,7 +1186,7 @@ impl FileDecoder {
         match message.header_type() {
             crate::MessageHeader::DictionaryBatch => {
                 let batch = message.header_as_dictionary_batch().unwrap();
-                read_dictionary_impl(
+                let values = get_dictionary_values(
                     &buf.slice(block.metaDataLength() as _),
                     batch,
                     &self.schema,
@@ -1105,7 +1194,9 @@ impl FileDecoder {
                     &message.version(),
                     self.require_alignment,
                     self.skip_validation.clone(),
-                )
+                    self.swap_endianness,
+                )?;
+                update_dictionaries(&mut self.dictionaries, batch.isDelta(), batch.id(), values)
             }
             t => Err(ArrowError::ParseError(format!(
                 "Expecting DictionaryBatch in dictionary blocks, found {t:?}."
@@ -1119,6 +1210,18 @@ impl FileDecoder {
         block: &Block,
         buf: &Buffer,
     ) -> Result<Option<RecordBatch>, ArrowError> {
+        Ok(self
+            .read_record_batch_with_metadata(block, buf)?
+            .map(|(batch, _)| batch))
+    }
+
+    /// Read the RecordBatch with the given block and data buffer, along with
+    /// the custom metadata of its IPC message
+    pub fn read_record_batch_with_metadata(
+        &self,
+        block: &Block,
+        buf: &Buffer,
+    ) -> Result<Option<(RecordBatch, Metadata)>, ArrowError> {
         let message = self.read_message(buf)?;
         match message.header_type() {
             crate::MessageHeader::Schema => Err(ArrowError::IpcError(
@@ -1129,7 +1232,7 @@ impl FileDecoder {
                     ArrowError::IpcError("Unable to read IPC message as record batch".to_string())
                 })?;
                 // read the block that makes up the record batch into a buffer
-                RecordBatchDecoder::try_new(
+                let batch = RecordBatchDecoder::try_new(
                     &buf.slice(block.metaDataLength() as _),
                     batch,
                     self.schema.clone(),
@@ -1139,8 +1242,10 @@ impl FileDecoder {
                 .with_projection(self.projection.as_deref())
                 .with_require_alignment(self.require_alignment)
                 .with_skip_validation(self.skip_validation.clone())
-                .read_record_batch()
-                .map(Some)
+                .with_swap_endianness(self.swap_endianness)
+                .read_record_batch()?;
+                let metadata = crate::convert::fb_to_metadata(message.custom_metadata());
+                Ok(Some((batch, metadata)))
             }
             crate::MessageHeader::NONE => Ok(None),
             t => Err(ArrowError::InvalidArgumentError(format!(
@@ -1159,6 +1264,8 @@ pub struct FileReaderBuilder {
     max_footer_fb_tables: usize,
     /// Passed through to construct [`VerifierOptions`]
     max_footer_fb_depth: usize,
+    /// Byte swap data written with a non-native endianness
+    swap_endianness: bool,
 }
 
 impl Default for FileReaderBuilder {
@@ -1168,6 +1275,7 @@ impl Default for FileReaderBuilder {
             max_footer_fb_tables: verifier_options.max_tables,
             max_footer_fb_depth: verifier_options.max_depth,
             projection: None,
+            swap_endianness: false,
         }
     }
 }
@@ -1220,6 +1328,15 @@ impl FileReaderBuilder {
         self
     }
 
+    /// Read files written with a different endianness than the target system
+    /// by byte swapping their data into native order (defaults to `false`).
+    ///
+    /// By default, [`Self::build`] returns an error for such files.
+    pub fn with_swap_endianness(mut self, swap_endianness: bool) -> Self {
+        self.swap_endianness = swap_endianness;
+        self
+    }
+
     /// Build [`FileReader`] with given reader.
     pub fn build<R: Read + Seek>(self, mut reader: R) -> Result<FileReader<R>, ArrowError> {
         // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
@@ -1250,10 +1367,9 @@ impl FileReaderBuilder {
         let total_blocks = blocks.len();
 
         let ipc_schema = footer.schema().unwrap();
-        if !ipc_schema.endianness().equals_to_target_endianness() {
-            return Err(ArrowError::IpcError(
-                "the endianness of the source system does not match the endianness of the target system.".to_owned()
-            ));
+        let endianness = ipc_schema.endianness();
+        if !endianness.equals_to_target_endianness() && !self.swap_endianness {
+            return Err(endianness_mismatch_error());
         }
 
         let schema = crate::convert::fb_to_schema(ipc_schema);
@@ -1268,7 +1384,8 @@ impl FileReaderBuilder {
             }
         }
 
-        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version());
+        let mut decoder =
+            FileDecoder::new(Arc::new(schema), footer.version()).with_endianness(endianness);
         if let Some(projection) = self.projection {
             decoder = decoder.with_projection(projection)
         }
@@ -1288,6 +1405,7 @@ impl FileReaderBuilder {
             total_blocks,
             decoder,
             custom_metadata,
+            batch_metadata: Metadata::new(),
         })
     }
 }
@@ -1356,6 +1474,9 @@ pub struct FileReader<R> {
 
     /// User defined metadata
     custom_metadata: HashMap<String, String>,
+
+    /// User defined metadata of the most recently read record batch
+    batch_metadata: Metadata,
 }
 
 impl<R> fmt::Debug for FileReader<R> {
@@ -1388,7 +1509,8 @@ impl<R: Read + Seek> FileReader<R> {
     ///
     /// An [`Err`] may be returned if:
     /// - the file does not meet the Arrow Format footer requirements, or
-    /// - file endianness does not match the target endianness.
+    /// - file endianness does not match the target endianness, see
+    ///   [`FileReaderBuilder::with_swap_endianness`] to read such files.
     pub fn try_new(reader: R, projection: Option<Vec<usize>>) -> Result<Self, ArrowError> {
         let builder = FileReaderBuilder {
             projection,
@@ -1402,6 +1524,17 @@ impl<R: Read + Seek> FileReader<R> {
         &self.custom_metadata
     }
 
+    /// Return the user defined metadata of the most recently read [`RecordBatch`]
+    ///
+    /// This is the metadata attached to the batch's IPC message, for example
+    /// by [`FileWriter::write_with_metadata`], and is empty if no batch has
+    /// been read yet
+    ///
+    /// [`FileWriter::write_with_metadata`]: crate::writer::FileWriter::write_with_metadata
+    pub fn batch_metadata(&self) -> &Metadata {
+        &self.batch_metadata
+    }
+
     /// Return the number of batches in the file
     pub fn num_batches(&self) -> usize {
         self.total_blocks
@@ -1433,7 +1566,14 @@ impl<R: Read + Seek> FileReader<R> {
 
         // read length
         let buffer = read_block(&mut self.reader, block)?;
-        self.decoder.read_record_batch(block, &buffer)
+        let Some((batch, metadata)) = self
+            .decoder
+            .read_record_batch_with_metadata(block, &buffer)?
+        else {
+            return Ok(None);
+        };
+        self.batch_metadata = metadata;
+        Ok(Some(batch))
     }
 
     /// Gets a reference to the underlying reader.
@@ -1537,6 +1677,15 @@ pub struct StreamReader<R> {
     ///
     /// See [`FileDecoder::with_skip_validation`] for details.
     skip_validation: UnsafeFlag,
+
+    /// The endianness the stream was written with
+    endianness: crate::Endianness,
+
+    /// Byte swap data written with a non-native endianness? Defaults to false.
+    swap_endianness: bool,
+
+    /// User defined metadata of the most recently read record batch
+    batch_metadata: Metadata,
 }
 
 impl<R> fmt::Debug for StreamReader<R> {
@@ -1594,6 +1743,7 @@ impl<R: Read> StreamReader<R> {
         let schema = message.header_as_schema().ok_or_else(|| {
             ArrowError::ParseError("Failed to parse schema from message header".to_string())
         })?;
+        let endianness = schema.endianness();
         let schema = crate::convert::fb_to_schema(schema);
 
         // Create an array of optional dictionary value arrays, one per field.
@@ -1614,6 +1764,9 @@ impl<R: Read> StreamReader<R> {
             dictionaries_by_id,
             projection,
             skip_validation: UnsafeFlag::new(),
+            endianness,
+            swap_endianness: false,
+            batch_metadata: Metadata::new(),
         })
     }
 
@@ -1627,6 +1780,37 @@ impl<R: Read> StreamReader<R> {
         self.finished
     }
 
+    /// Return the user defined metadata of the most recently read [`RecordBatch`]
+    ///
+    /// This is the metadata attached to the batch's IPC message, for example
+    /// by [`StreamWriter::write_with_metadata`], and is empty if no batch has
+    /// been read yet
+    ///
+    /// [`StreamWriter::write_with_metadata`]: crate::writer::StreamWriter::write_with_metadata
+    pub fn batch_metadata(&self) -> &Metadata {
+        &self.batch_metadata
+    }
+
+    /// Read streams written with a different endianness than the target system
+    /// by byte swapping their data into native order (defaults to `false`).
+    ///
+    /// By default, reading record batches or dictionaries from such streams
+    /// returns an error.
+    pub fn with_swap_endianness(mut self, swap_endianness: bool) -> Self {
+        self.swap_endianness = swap_endianness;
+        self
+    }
+
+    /// Returns whether data needs to be byte swapped, or an error if it does
+    /// but byte swapping is not enabled
+    fn needs_swap(&self) -> Result<bool, ArrowError> {
+        match self.endianness.equals_to_target_endianness() {
+            true => Ok(false),
+            false if self.swap_endianness => Ok(true),
+            false => Err(endianness_mismatch_error()),
+        }
+    }
+
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         if self.finished {
             return Ok(None);
@@ -1665,6 +1849,7 @@ impl<R: Read> StreamReader<R> {
     /// This is useful primarily for testing reader/writer behaviors as it
     /// allows a full view into the messages that have been written to a stream.
     pub(crate) fn next_ipc_message(&mut self) -> Result<Option<IpcMessage>, ArrowError> {
+        let swap_endianness = self.needs_swap();
         let message = self.reader.maybe_next()?;
         let Some((message, body)) = message else {
             // If the message is None, we have reached the end of the stream.
@@ -1696,7 +1881,9 @@ impl<R: Read> StreamReader<R> {
                 .with_projection(self.projection.as_ref().map(|x| x.0.as_ref()))
                 .with_require_alignment(false)
                 .with_skip_validation(self.skip_validation.clone())
+                .with_swap_endianness(swap_endianness?)
                 .read_record_batch()?;
+                self.batch_metadata = crate::convert::fb_to_metadata(message.custom_metadata());
                 IpcMessage::RecordBatch(record_batch)
             }
             Message::MessageHeader::DictionaryBatch => {
@@ -1715,6 +1902,7 @@ impl<R: Read> StreamReader<R> {
                     &version,
                     false,
                     self.skip_validation.clone(),
+                    swap_endianness?,
                 )?;
 
                 update_dictionaries(
@@ -2553,6 +2741,82 @@ mod tests {
         assert_eq!(reader.custom_metadata(), &test_metadata);
     }
 
+    #[test]
+    fn test_roundtrip_with_batch_metadata() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let values = Arc::new(DictionaryArray::<Int32Type>::from_iter(["x", "y", "x"]));
+        let dict_schema = Arc::new(Schema::new(vec![Field::new(
+            "d",
+            values.data_type().clone(),
+            false,
+        )]));
+        let batches = [
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![1, 2]))])
+                .unwrap(),
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![3]))])
+                .unwrap(),
+            RecordBatch::try_new(dict_schema.clone(), vec![values]).unwrap(),
+        ];
+        let metadata = [
+            Metadata::from([("watermark", "10"), ("source", "a")]),
+            Metadata::new(),
+            Metadata::from([("watermark", "20")]),
+        ];
+
+        // file
+        for (batch, metadata) in batches.iter().zip(&metadata) {
+            let mut buf = Vec::new();
+            let mut writer = crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+            writer.write(batch).unwrap();
+            writer.write_with_metadata(batch, metadata).unwrap();
+            writer.finish().unwrap();
+            drop(writer);
+
+            let mut reader = FileReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+            assert!(reader.batch_metadata().is_empty());
+            assert_eq!(&reader.next().unwrap().unwrap(), batch);
+            assert!(reader.batch_metadata().is_empty());
+            assert_eq!(&reader.next().unwrap().unwrap(), batch);
+            assert_eq!(reader.batch_metadata(), metadata);
+            assert!(reader.custom_metadata().is_empty());
+        }
+
+        // stream
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::StreamWriter::try_new(&mut buf, &schema).unwrap();
+        writer
+            .write_with_metadata(&batches[0], &metadata[0])
+            .unwrap();
+        writer
+            .write_with_metadata(&batches[1], &metadata[1])
+            .unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        for (batch, metadata) in batches.iter().zip(&metadata).take(2) {
+            assert_eq!(&reader.next().unwrap().unwrap(), batch);
+            assert_eq!(reader.batch_metadata(), metadata);
+        }
+
+        // encoder / decoder, including dictionary messages
+        let mut encoder = crate::writer::StreamEncoder::try_new(&dict_schema).unwrap();
+        let mut decoder = StreamDecoder::new();
+        for metadata in &metadata {
+            let buffers = encoder.encode_with_metadata(&batches[2], metadata).unwrap();
+            let mut decoded = None;
+            for mut buffer in buffers {
+                while !buffer.is_empty() {
+                    if let Some(batch) = decoder.decode(&mut buffer).unwrap() {
+                        decoded = Some(batch);
+                    }
+                }
+            }
+            assert_eq!(decoded.unwrap(), batches[2]);
+            assert_eq!(decoder.batch_metadata(), metadata);
+        }
+    }
+
     #[test]
     fn test_roundtrip_nested_dict() {
         let inner: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
@@ -3981,4 +4245,278 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    /// Returns the endianness that does not match the target system
+    fn non_native_endianness() -> crate::Endianness {
+        match crate::Endianness::native() {
+            crate::Endianness::Little => crate::Endianness::Big,
+            _ => crate::Endianness::Little,
+        }
+    }
+
+    /// Returns a record batch with a column for each kind of buffer that is
+    /// affected by endianness
+    fn create_endianness_test_batch() -> RecordBatch {
+        let mut union_builder = UnionBuilder::new_dense();
+        union_builder.append::<Int32Type>("a", 1).unwrap();
+        union_builder.append::<Float64Type>("b", 2.5).unwrap();
+        union_builder.append::<Int32Type>("a", -3).unwrap();
+        let dense_union = union_builder.build().unwrap();
+
+        let mut union_builder = UnionBuilder::new_sparse();
+        union_builder.append::<Int64Type>("a", 1).unwrap();
+        union_builder.append_null::<Int64Type>("a").unwrap();
+        union_builder.append::<Int16Type>("b", -2).unwrap();
+        let sparse_union = union_builder.build().unwrap();
+
+        let mut run_builder = PrimitiveRunBuilder::<Int32Type, Int64Type>::new();
+        run_builder.extend([Some(7), Some(7), None]);
+        let run_array = run_builder.finish();
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![Some(300)]),
+        ]);
+        let large_list = LargeListArray::from_iter_primitive::<UInt16Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![]),
+            Some(vec![Some(2), Some(u16::MAX)]),
+        ]);
+        let list_view = ListViewArray::new(
+            Arc::new(Field::new_list_field(DataType::Float32, false)),
+            ScalarBuffer::from(vec![2, 0, 1]),
+            ScalarBuffer::from(vec![1, 2, 0]),
+            Arc::new(Float32Array::from(vec![1.5, -2.0, 3.25])),
+            None,
+        );
+
+        RecordBatch::try_from_iter([
+            (
+                "i16",
+                Arc::new(Int16Array::from(vec![1, -2, 0x0102])) as ArrayRef,
+            ),
+            (
+                "u32",
+                Arc::new(UInt32Array::from(vec![Some(1), None, Some(u32::MAX)])) as _,
+            ),
+            (
+                "i64",
+                Arc::new(Int64Array::from(vec![i64::MIN, 0, 0x0102030405])) as _,
+            ),
+            (
+                "f64",
+                Arc::new(Float64Array::from(vec![1.5, f64::NAN, -0.0])) as _,
+            ),
+            (
+                "ts",
+                Arc::new(TimestampMicrosecondArray::from(vec![
+                    1,
+                    1_700_000_000_000_000,
+                    -1,
+                ])) as _,
+            ),
+            (
+                "d128",
+                Arc::new(
+                    Decimal128Array::from(vec![Some(123456789), None, Some(-1)])
+                        .with_precision_and_scale(20, 2)
+                        .unwrap(),
+                ) as _,
+            ),
+            (
+                "d256",
+                Arc::new(Decimal256Array::from(vec![
+                    arrow_buffer::i256::from_i128(-5),
+                    arrow_buffer::i256::MAX,
+                    arrow_buffer::i256::from_parts(1, 2),
+                ])) as _,
+            ),
+            (
+                "day_time",
+                Arc::new(IntervalDayTimeArray::from(vec![
+                    IntervalDayTime::new(1, -2),
+                    IntervalDayTime::new(3, 4),
+                    IntervalDayTime::new(-5, 6),
+                ])) as _,
+            ),
+            (
+                "month_day_nano",
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    IntervalMonthDayNano::new(1, 2, -3),
+                    IntervalMonthDayNano::new(4, -5, 6),
+                    IntervalMonthDayNano::new(-7, 8, 9),
+                ])) as _,
+            ),
+            (
+                "bool",
+                Arc::new(BooleanArray::from(vec![true, false, true])) as _,
+            ),
+            (
+                "fsb",
+                Arc::new(
+                    FixedSizeBinaryArray::try_from_iter([b"ab", b"cd", b"ef"].into_iter()).unwrap(),
+                ) as _,
+            ),
+            (
+                "utf8",
+                Arc::new(StringArray::from(vec!["a", "bc", "def"])) as _,
+            ),
+            (
+                "large_binary",
+                Arc::new(LargeBinaryArray::from(vec![b"x".as_ref(), b"", b"yz"])) as _,
+            ),
+            (
+                "utf8_view",
+                Arc::new(StringViewArray::from(vec![
+                    "inline",
+                    "a string that is longer than twelve bytes",
+                    "another string that is not inlined",
+                ])) as _,
+            ),
+            ("list", Arc::new(list) as _),
+            ("large_list", Arc::new(large_list) as _),
+            ("list_view", Arc::new(list_view) as _),
+            (
+                "dict",
+                Arc::new(
+                    vec![Some("foo"), None, Some("bar")]
+                        .into_iter()
+                        .collect::<DictionaryArray<Int16Type>>(),
+                ) as _,
+            ),
+            ("dense_union", Arc::new(dense_union) as _),
+            ("sparse_union", Arc::new(sparse_union) as _),
+            ("run", Arc::new(run_array) as _),
+        ])
+        .unwrap()
+    }
+
+    fn write_ipc_with_options(rb: &RecordBatch, options: IpcWriteOptions) -> Vec<u8> {
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::FileWriter::try_new_with_options(&mut buf, rb.schema_ref(), options)
+                .unwrap();
+        writer.write(rb).unwrap();
+        writer.finish().unwrap();
+        buf
+    }
+
+    fn write_stream_with_options(rb: &RecordBatch, options: IpcWriteOptions) -> Vec<u8> {
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::StreamWriter::try_new_with_options(&mut buf, rb.schema_ref(), options)
+                .unwrap();
+        writer.write(rb).unwrap();
+        writer.finish().unwrap();
+        buf
+    }
+
+    fn check_swap_endianness_roundtrip(batch: &RecordBatch, options: IpcWriteOptions) {
+        let options = options.with_endianness(non_native_endianness());
+
+        // FileReader
+        let file = write_ipc_with_options(batch, options.clone());
+        assert_ne!(file, write_ipc(batch));
+        let mut reader = FileReaderBuilder::new()
+            .with_swap_endianness(true)
+            .build(Cursor::new(&file))
+            .unwrap();
+        assert_eq!(&reader.next().unwrap().unwrap(), batch);
+
+        // FileDecoder
+        let buffer = Buffer::from_vec(file);
+        let trailer_start = buffer.len() - 10;
+        let footer_len = read_footer_length(buffer[trailer_start..].try_into().unwrap()).unwrap();
+        let footer = root_as_footer(&buffer[trailer_start - footer_len..trailer_start]).unwrap();
+        let ipc_schema = footer.schema().unwrap();
+        assert_eq!(ipc_schema.endianness(), non_native_endianness());
+        let mut decoder = FileDecoder::new(Arc::new(fb_to_schema(ipc_schema)), footer.version())
+            .with_endianness(ipc_schema.endianness());
+        for block in footer.dictionaries().iter().flatten() {
+            let block_len = block.bodyLength() as usize + block.metaDataLength() as usize;
+            let data = buffer.slice_with_length(block.offset() as _, block_len);
+            decoder.read_dictionary(block, &data).unwrap();
+        }
+        let block = footer.recordBatches().unwrap().get(0);
+        let block_len = block.bodyLength() as usize + block.metaDataLength() as usize;
+        let data = buffer.slice_with_length(block.offset() as _, block_len);
+        let read = decoder.read_record_batch(block, &data).unwrap().unwrap();
+        assert_eq!(&read, batch);
+
+        // StreamReader
+        let stream = write_stream_with_options(batch, options);
+        let mut reader = StreamReader::try_new(Cursor::new(&stream), None)
+            .unwrap()
+            .with_swap_endianness(true);
+        assert_eq!(&reader.next().unwrap().unwrap(), batch);
+        assert!(reader.next().is_none());
+
+        // StreamDecoder
+        let mut decoder = StreamDecoder::new().with_swap_endianness(true);
+        let mut buffer = Buffer::from_vec(stream);
+        let read = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(&read, batch);
+        decoder.finish().unwrap();
+    }
+
+    #[test]
+    fn test_swap_endianness_roundtrip() {
+        let batch = create_endianness_test_batch();
+        check_swap_endianness_roundtrip(&batch, IpcWriteOptions::default());
+        check_swap_endianness_roundtrip(&batch.slice(1, 2), IpcWriteOptions::default());
+
+        // run end encoded arrays are not supported by metadata V4
+        let batch = batch.project(&(0..batch.num_columns() - 1).collect::<Vec<_>>());
+        let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V4).unwrap();
+        check_swap_endianness_roundtrip(&batch.unwrap(), options);
+    }
+
+    #[test]
+    #[cfg(feature = "lz4")]
+    fn test_swap_endianness_roundtrip_compressed() {
+        let options = IpcWriteOptions::default()
+            .try_with_compression(Some(crate::CompressionType::LZ4_FRAME))
+            .unwrap();
+        check_swap_endianness_roundtrip(&create_endianness_test_batch(), options);
+    }
+
+    #[test]
+    fn test_swap_endianness_not_enabled() {
+        let batch = create_endianness_test_batch();
+        let options = IpcWriteOptions::default().with_endianness(non_native_endianness());
+        let expected = "Ipc error: the endianness of the source system does not match the endianness of the target system.";
+
+        let file = write_ipc_with_options(&batch, options.clone());
+        let err = FileReader::try_new(Cursor::new(&file), None).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        let stream = write_stream_with_options(&batch, options);
+        let mut reader = StreamReader::try_new(Cursor::new(&stream), None).unwrap();
+        assert_eq!(reader.schema(), batch.schema());
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        let mut decoder = StreamDecoder::new();
+        let err = decoder.decode(&mut Buffer::from_vec(stream)).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn test_swap_endianness_native_data() {
+        // enabling byte swapping has no effect on data with native endianness
+        let batch = create_endianness_test_batch();
+        let file = write_ipc(&batch);
+        let mut reader = FileReaderBuilder::new()
+            .with_swap_endianness(true)
+            .build(Cursor::new(&file))
+            .unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+
+        let stream = write_stream(&batch);
+        let mut reader = StreamReader::try_new(Cursor::new(&stream), None)
+            .unwrap()
+            .with_swap_endianness(true);
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+    }
 }
@@ -22,10 +22,12 @@ use std::sync::Arc;
 use arrow_array::{ArrayRef, RecordBatch};
 use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_data::UnsafeFlag;
-use arrow_schema::{ArrowError, SchemaRef};
+use arrow_schema::{ArrowError, Metadata, SchemaRef};
 
 use crate::convert::MessageBuffer;
-use crate::reader::{RecordBatchDecoder, read_dictionary_impl};
+use crate::reader::{
+    RecordBatchDecoder, endianness_mismatch_error, get_dictionary_values, update_dictionaries,
+};
 use crate::{CONTINUATION_MARKER, MessageHeader};
 
 /// A low-level interface for reading [`RecordBatch`] data from a stream of bytes
@@ -48,6 +50,14 @@ pub struct StreamDecoder {
     /// See [`StreamDecoder::with_skip_validation`] for details.
     ///
     skip_validation: UnsafeFlag,
+    /// Whether data written with a non-native endianness may be byte swapped
+    ///
+    /// See [`StreamDecoder::with_swap_endianness`] for details.
+    allow_swap_endianness: bool,
+    /// Whether the data of the decoded stream needs to be byte swapped
+    swap_endianness: bool,
+    /// The custom metadata of the most recently decoded record batch
+    batch_metadata: Metadata,
 }
 
 #[derive(Debug)]
@@ -113,6 +123,14 @@ impl StreamDecoder {
         self.schema.as_ref().map(|schema| schema.clone())
     }
 
+    /// Return the custom metadata of the most recently decoded [`RecordBatch`]
+    ///
+    /// This is empty if no batch has been decoded, or the batch's IPC message
+    /// has no custom metadata
+    pub fn batch_metadata(&self) -> &Metadata {
+        &self.batch_metadata
+    }
+
     /// Specifies if validation should be skipped when reading data (defaults to `false`)
     ///
     /// # Safety
@@ -127,6 +145,16 @@ impl StreamDecoder {
         self
     }
 
+    /// Specifies if streams written with a different endianness than the
+    /// target system should be read by byte swapping their data into native
+    /// order (defaults to `false`).
+    ///
+    /// By default, decoding the schema of such a stream returns an error.
+    pub fn with_swap_endianness(mut self, swap_endianness: bool) -> Self {
+        self.allow_swap_endianness = swap_endianness;
+        self
+    }
+
     /// Try to read the next [`RecordBatch`] from the provided [`Buffer`]
     ///
     /// [`Buffer::advance`] will be called on `buffer` for any consumed bytes.
@@ -230,6 +258,11 @@ impl StreamDecoder {
                             }
 
                             let ipc_schema = message.header_as_schema().unwrap();
+                            self.swap_endianness =
+                                !ipc_schema.endianness().equals_to_target_endianness();
+                            if self.swap_endianness && !self.allow_swap_endianness {
+                                return Err(endianness_mismatch_error());
+                            }
                             let schema = crate::convert::fb_to_schema(ipc_schema);
                             self.state = DecoderState::default();
                             self.schema = Some(Arc::new(schema));
@@ -247,7 +280,10 @@ impl StreamDecoder {
                                 &version,
                             )?
                             .with_require_alignment(self.require_alignment)
+                            .with_swap_endianness(self.swap_endianness)
                             .read_record_batch()?;
+                            self.batch_metadata =
+                                crate::convert::fb_to_metadata(message.custom_metadata());
                             self.state = DecoderState::default();
                             return Ok(Some(batch));
                         }
@@ -256,7 +292,7 @@ impl StreamDecoder {
                             let schema = self.schema.as_deref().ok_or_else(|| {
                                 ArrowError::IpcError("Missing schema".to_string())
                             })?;
-                            read_dictionary_impl(
+                            let values = get_dictionary_values(
                                 &body,
                                 dictionary,
                                 schema,
@@ -264,6 +300,13 @@ impl StreamDecoder {
                                 &version,
                                 self.require_alignment,
                                 self.skip_validation.clone(),
+                                self.swap_endianness,
+                            )?;
+                            update_dictionaries(
+                                &mut self.dictionaries,
+                                dictionary.isDelta(),
+                                dictionary.id(),
+                                values,
                             )?;
                             self.state = DecoderState::default();
                         }
@@ -26,7 +26,8 @@
 
 use std::cmp::min;
 use std::collections::HashMap;
-use std::io::{BufWriter, Write};
+use std::fs::File;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
 use std::mem::size_of;
 use std::sync::Arc;
 
@@ -46,6 +47,8 @@ use crate::compression::CompressionCodec;
 #[expect(deprecated)]
 pub use crate::compression::{CompressionContext, IpcWriteContext};
 use crate::convert::IpcSchemaEncoder;
+use crate::endian::{swap_values, swap_views, value_widths};
+use crate::reader::{FileDecoder, read_block, read_footer_length};
 
 /// IPC write options used to control the behaviour of the [`IpcDataGenerator`]
 #[derive(Debug, Clone)]
@@ -71,6 +74,8 @@ pub struct IpcWriteOptions {
     batch_compression_level: Option<i32>,
     /// How to handle updating dictionaries in IPC messages
     dictionary_handling: DictionaryHandling,
+    /// The endianness to write data with, defaults to the native endianness
+    endianness: crate::Endianness,
 }
 
 /// A single buffer segment ready to be written to the output stream.
@@ -491,6 +496,7 @@ impl IpcWriteOptions {
                 batch_compression_type: None,
                 batch_compression_level: None,
                 dictionary_handling: DictionaryHandling::default(),
+                endianness: crate::Endianness::native(),
             }),
             crate::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -505,6 +511,7 @@ impl IpcWriteOptions {
                         batch_compression_type: None,
                         batch_compression_level: None,
                         dictionary_handling: DictionaryHandling::default(),
+                        endianness: crate::Endianness::native(),
                     })
                 }
             }
@@ -519,6 +526,16 @@ impl IpcWriteOptions {
         self.dictionary_handling = dictionary_handling;
         self
     }
+
+    /// Configure the endianness to write data with (defaults to the native endianness)
+    ///
+    /// If this does not match the endianness of the target system, fixed-width
+    /// values are byte swapped when written, which requires copying them.
+    /// This is mostly useful to produce test data for big-endian systems.
+    pub fn with_endianness(mut self, endianness: crate::Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
 }
 
 impl Default for IpcWriteOptions {
@@ -530,6 +547,7 @@ impl Default for IpcWriteOptions {
             batch_compression_type: None,
             batch_compression_level: None,
             dictionary_handling: DictionaryHandling::default(),
+            endianness: crate::Endianness::native(),
         }
     }
 }
@@ -583,6 +601,7 @@ impl IpcDataGenerator {
         let schema = {
             let fb = IpcSchemaEncoder::new()
                 .with_dictionary_tracker(dictionary_tracker)
+                .with_endianness(write_options.endianness)
                 .schema_to_fb_offset(&mut fbb, schema);
             fb.as_union_value()
         };
@@ -856,12 +875,32 @@ impl IpcDataGenerator {
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
         ipc_write_context: &mut IpcWriteContext,
+    ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
+        self.encode_with_metadata(
+            batch,
+            &Metadata::new(),
+            dictionary_tracker,
+            write_options,
+            ipc_write_context,
+        )
+    }
+
+    /// Encodes a batch like [`Self::encode`], attaching `custom_metadata` to
+    /// the record batch [`crate::Message`]
+    pub fn encode_with_metadata(
+        &self,
+        batch: &RecordBatch,
+        custom_metadata: &Metadata,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+        ipc_write_context: &mut IpcWriteContext,
     ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
         let encoded_dictionaries =
             self.encode_all_dicts(batch, dictionary_tracker, write_options, ipc_write_context)?;
         let mut arrow_data = ipc_write_context.scratch();
         let (metadata, _, tail_pad) = self.record_batch_to_bytes(
             batch,
+            custom_metadata,
             write_options,
             ipc_write_context,
             &mut IpcBodySink::Write(&mut arrow_data),
@@ -908,6 +947,7 @@ impl IpcDataGenerator {
     fn write<W: Write>(
         &self,
         batch: &RecordBatch,
+        custom_metadata: &Metadata,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
         ipc_write_context: &mut IpcWriteContext,
@@ -915,6 +955,7 @@ impl IpcDataGenerator {
     ) -> Result<IpcWriteMetadata, ArrowError> {
         self.write_to_sink(
             batch,
+            custom_metadata,
             dictionary_tracker,
             write_options,
             ipc_write_context,
@@ -927,6 +968,7 @@ impl IpcDataGenerator {
     fn encode_to_buffers(
         &self,
         batch: &RecordBatch,
+        custom_metadata: &Metadata,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
         ipc_write_context: &mut IpcWriteContext,
@@ -935,6 +977,7 @@ impl IpcDataGenerator {
         let mut sink = Buffers { out };
         self.write_to_sink(
             batch,
+            custom_metadata,
             dictionary_tracker,
             write_options,
             ipc_write_context,
@@ -945,6 +988,7 @@ impl IpcDataGenerator {
     fn write_to_sink<S: IpcRecordBatchSink>(
         &self,
         batch: &RecordBatch,
+        custom_metadata: &Metadata,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
         ipc_write_context: &mut IpcWriteContext,
@@ -966,6 +1010,7 @@ impl IpcDataGenerator {
         let mut encoded_buffers: Vec<EncodedBuffer> = Vec::with_capacity(capacity);
         let (metadata, body_len, tail_pad) = self.record_batch_to_bytes(
             batch,
+            custom_metadata,
             write_options,
             ipc_write_context,
             &mut IpcBodySink::Collect(&mut encoded_buffers),
@@ -1007,6 +1052,7 @@ impl IpcDataGenerator {
     fn record_batch_to_bytes(
         &self,
         batch: &RecordBatch,
+        custom_metadata: &Metadata,
         write_options: &IpcWriteOptions,
         ipc_write_context: &mut IpcWriteContext,
         sink: &mut IpcBodySink<'_>,
@@ -1075,11 +1121,16 @@ impl IpcDataGenerator {
             }
             batch_builder.finish().as_union_value()
         };
+        let fb_custom_metadata = (!custom_metadata.is_empty())
+            .then(|| crate::convert::metadata_to_fb(fbb, custom_metadata));
         let mut message = crate::MessageBuilder::new(fbb);
         message.add_version(write_options.metadata_version);
         message.add_header_type(crate::MessageHeader::RecordBatch);
         message.add_bodyLength(body_len as i64);
         message.add_header(root);
+        if let Some(fb_custom_metadata) = fb_custom_metadata {
+            message.add_custom_metadata(fb_custom_metadata);
+        }
         let root = message.finish();
         fbb.finish(root, None);
 
@@ -1519,6 +1570,20 @@ impl DictionaryTracker {
         }
     }
 
+    /// Record `values` as the dictionary already written for `dict_id`, such as
+    /// when appending to an existing IPC file
+    pub(crate) fn insert_written(
+        &mut self,
+        dict_id: i64,
+        values: &ArrayRef,
+    ) -> Result<(), ArrowError> {
+        // Only the values of a written dictionary are compared, so the key type is irrelevant
+        let keys = Int32Array::from(Vec::<i32>::new());
+        let dictionary = DictionaryArray::<Int32Type>::try_new(keys, values.clone())?;
+        self.written.insert(dict_id, dictionary.into_data());
+        Ok(())
+    }
+
     /// Clears the state of the dictionary tracker.
     ///
     /// This allows the dictionary tracker to be reused for a new IPC stream while avoiding the
@@ -1624,6 +1689,65 @@ impl<W: Write> FileWriter<BufWriter<W>> {
     }
 }
 
+impl FileWriter<BufWriter<File>> {
+    /// Try to open an existing file for appending with the writer wrapped in a BufWriter.
+    ///
+    /// See [`FileWriter::try_append`] for an unbuffered version.
+    pub fn try_append_buffered(file: File, schema: &Schema) -> Result<Self, ArrowError> {
+        Self::try_append_impl(file, schema, IpcWriteOptions::default(), BufWriter::new)
+    }
+}
+
+impl FileWriter<File> {
+    /// Try to open an existing Arrow IPC file for appending record batches
+    ///
+    /// `file` must be opened for both reading and writing, and `schema` must match
+    /// the schema of the file. The footer of the file is read and truncated, new
+    /// batches are written after the existing ones, and [`Self::finish`] writes a
+    /// footer covering both the existing and new batches. The file is not a valid
+    /// IPC file until [`Self::finish`] is called.
+    ///
+    /// The custom metadata of the existing file is retained. As the IPC file format
+    /// only supports a single dictionary per field, new batches must use the same
+    /// dictionaries as the existing batches, or extend them if
+    /// [`DictionaryHandling::Delta`] is used.
+    ///
+    /// Note the created writer is not buffered. See [`FileWriter::try_append_buffered`] for details.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use arrow_array::record_batch;
+    /// # use arrow_ipc::writer::FileWriter;
+    /// # use std::fs::OpenOptions;
+    /// let batch = record_batch!(("a", Int32, [1, 2, 3])).unwrap();
+    /// let file = OpenOptions::new().read(true).write(true).open("data.arrow").unwrap();
+    /// let mut writer = FileWriter::try_append(file, &batch.schema()).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.finish().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An [`Err`] may be returned if:
+    /// - the file is not a valid Arrow IPC file,
+    /// - the schema of the file does not match `schema`, or
+    /// - the metadata version or endianness of the file does not match the write options
+    pub fn try_append(file: File, schema: &Schema) -> Result<Self, ArrowError> {
+        Self::try_append_with_options(file, schema, IpcWriteOptions::default())
+    }
+
+    /// Try to open an existing Arrow IPC file for appending with IpcWriteOptions
+    ///
+    /// See [`FileWriter::try_append`] for details.
+    pub fn try_append_with_options(
+        file: File,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        Self::try_append_impl(file, schema, write_options, |file| file)
+    }
+}
+
 impl<W: Write> FileWriter<W> {
     /// Try to create a new writer, with the schema written as part of the header
     ///
@@ -1680,6 +1804,121 @@ impl<W: Write> FileWriter<W> {
         })
     }
 
+    /// Reads the footer of the IPC file `file`, truncates it, and returns a writer
+    /// positioned after the last existing message
+    fn try_append_impl(
+        mut file: File,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+        wrap: impl FnOnce(File) -> W,
+    ) -> Result<Self, ArrowError> {
+        ensure_supported_ipc_schema(schema)?;
+
+        // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
+        let mut buffer = [0; 10];
+        file.seek(SeekFrom::End(-10))?;
+        file.read_exact(&mut buffer)?;
+        let footer_len = read_footer_length(buffer)?;
+
+        let mut footer_data = vec![0; footer_len];
+        let footer_offset = file.seek(SeekFrom::End(-10 - footer_len as i64))?;
+        file.read_exact(&mut footer_data)?;
+        let footer = crate::root_as_footer(&footer_data).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+        })?;
+
+        if footer.version() != write_options.metadata_version {
+            return Err(ArrowError::IpcError(format!(
+                "Cannot append to IPC file with metadata version {:?} using metadata version {:?}",
+                footer.version(),
+                write_options.metadata_version
+            )));
+        }
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::ParseError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        if ipc_schema.endianness() != write_options.endianness {
+            return Err(ArrowError::IpcError(format!(
+                "Cannot append to IPC file with endianness {:?} using endianness {:?}",
+                ipc_schema.endianness(),
+                write_options.endianness
+            )));
+        }
+        let file_schema = crate::convert::fb_to_schema(ipc_schema);
+        if file_schema != *schema {
+            return Err(ArrowError::IpcError(format!(
+                "Cannot append record batches with schema {schema:?} to IPC file with schema {file_schema:?}"
+            )));
+        }
+
+        let data_gen = IpcDataGenerator::default();
+        // assign dictionary ids in the same order as when the file was written
+        let mut dictionary_tracker = DictionaryTracker::new(true);
+        data_gen.schema_to_bytes_with_dictionary_tracker(
+            schema,
+            &mut dictionary_tracker,
+            &write_options,
+        );
+
+        let dictionary_blocks: Vec<_> = footer
+            .dictionaries()
+            .map(|blocks| blocks.iter().copied().collect())
+            .unwrap_or_default();
+        let record_blocks: Vec<_> = footer
+            .recordBatches()
+            .map(|blocks| blocks.iter().copied().collect())
+            .unwrap_or_default();
+
+        let mut decoder = FileDecoder::new(Arc::new(file_schema), footer.version())
+            .with_endianness(ipc_schema.endianness());
+        for block in &dictionary_blocks {
+            let buf = read_block(&mut file, block)?;
+            decoder.read_dictionary(block, &buf)?;
+        }
+        for (dict_id, values) in decoder.dictionaries() {
+            if !dictionary_tracker.dict_ids.contains(dict_id) {
+                return Err(ArrowError::IpcError(format!(
+                    "Cannot append to IPC file with unexpected dictionary id {dict_id}"
+                )));
+            }
+            dictionary_tracker.insert_written(*dict_id, values)?;
+        }
+
+        // the end of the last message, excluding the end-of-stream marker
+        let data_end = match dictionary_blocks
+            .iter()
+            .chain(&record_blocks)
+            .map(|block| {
+                block.offset() as u64 + block.metaDataLength() as u64 + block.bodyLength() as u64
+            })
+            .max()
+        {
+            Some(end) => end,
+            None => schema_message_end(&mut file)?,
+        };
+        if data_end > footer_offset {
+            return Err(ArrowError::ParseError(
+                "IPC file blocks extend past the start of the footer".to_string(),
+            ));
+        }
+        file.set_len(data_end)?;
+        file.seek(SeekFrom::Start(data_end))?;
+
+        Ok(Self {
+            writer: wrap(file),
+            write_options,
+            schema: Arc::new(schema.clone()),
+            block_offsets: data_end as usize,
+            dictionary_blocks,
+            record_blocks,
+            finished: false,
+            dictionary_tracker,
+            custom_metadata: crate::convert::fb_to_metadata(footer.custom_metadata()),
+            data_gen,
+            ipc_write_context: IpcWriteContext::default(),
+        })
+    }
+
     /// Adds a key-value pair to the [FileWriter]'s custom metadata
     pub fn write_metadata(&mut self, key: impl Into<String>, value: impl Into<String>) {
         self.custom_metadata.insert(key.into(), value.into());
@@ -1687,6 +1926,21 @@ impl<W: Write> FileWriter<W> {
 
     /// Write a record batch to the file
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, &Metadata::new())
+    }
+
+    /// Write a record batch to the file, attaching `custom_metadata` to its
+    /// IPC message
+    ///
+    /// Unlike [`Self::write_metadata`], which applies to the whole file, this
+    /// metadata is specific to `batch`, see [`FileReader::batch_metadata`]
+    ///
+    /// [`FileReader::batch_metadata`]: crate::reader::FileReader::batch_metadata
+    pub fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &Metadata,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IpcError(
                 "Cannot write record batch to file writer as it is closed".to_string(),
@@ -1695,6 +1949,7 @@ impl<W: Write> FileWriter<W> {
 
         let meta = self.data_gen.write(
             batch,
+            custom_metadata,
             &mut self.dictionary_tracker,
             &self.write_options,
             &mut self.ipc_write_context,
@@ -1743,6 +1998,7 @@ impl<W: Write> FileWriter<W> {
         self.dictionary_tracker.clear();
         let schema = IpcSchemaEncoder::new()
             .with_dictionary_tracker(&mut self.dictionary_tracker)
+            .with_endianness(self.write_options.endianness)
             .schema_to_fb_offset(&mut fbb, &self.schema);
         let fb_custom_metadata = (!self.custom_metadata.is_empty())
             .then(|| crate::convert::metadata_to_fb(&mut fbb, &self.custom_metadata));
@@ -1812,6 +2068,30 @@ impl<W: Write> FileWriter<W> {
     }
 }
 
+/// Returns the offset of the end of the schema message at the start of an IPC file
+fn schema_message_end(file: &mut File) -> Result<u64, ArrowError> {
+    // The schema message follows ARROW_MAGIC padded with zeros to a multiple of 8 bytes,
+    // and starts with either a continuation marker or a non-zero message length
+    let mut offset = 8;
+    let mut prefix = [0; 8];
+    loop {
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(&mut prefix)?;
+        if prefix[..4] != [0; 4] {
+            break;
+        }
+        offset += 8;
+    }
+    let (prefix_len, message_len) = match prefix[..4] == CONTINUATION_MARKER {
+        true => (8, i32::from_le_bytes(prefix[4..].try_into().unwrap())),
+        false => (4, i32::from_le_bytes(prefix[..4].try_into().unwrap())),
+    };
+    let message_len = u64::try_from(message_len).map_err(|_| {
+        ArrowError::ParseError(format!("Invalid schema message length: {message_len}"))
+    })?;
+    Ok(offset + prefix_len + message_len)
+}
+
 impl<W: Write> RecordBatchWriter for FileWriter<W> {
     fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
         self.write(batch)
@@ -1896,10 +2176,25 @@ impl StreamEncoder {
     ///
     /// Returns an error if encoding fails.
     pub fn encode(&mut self, batch: &RecordBatch) -> Result<Vec<Buffer>, ArrowError> {
+        self.encode_with_metadata(batch, &Metadata::new())
+    }
+
+    /// Encode a [`RecordBatch`] into buffers like [`Self::encode`], attaching
+    /// `custom_metadata` to the record batch message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encoding fails.
+    pub fn encode_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &Metadata,
+    ) -> Result<Vec<Buffer>, ArrowError> {
         let mut out = vec![];
         self.encode_schema(&mut out)?;
         self.data_gen.encode_to_buffers(
             batch,
+            custom_metadata,
             &mut self.dictionary_tracker,
             &self.write_options,
             &mut self.ipc_write_context,
@@ -2083,6 +2378,20 @@ impl<W: Write> StreamWriter<W> {
 
     /// Write a record batch to the stream
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, &Metadata::new())
+    }
+
+    /// Write a record batch to the stream, attaching `custom_metadata` to its
+    /// IPC message
+    ///
+    /// See [`StreamReader::batch_metadata`] for reading it back
+    ///
+    /// [`StreamReader::batch_metadata`]: crate::reader::StreamReader::batch_metadata
+    pub fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &Metadata,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IpcError(
                 "Cannot write record batch to stream writer as it is closed".to_string(),
@@ -2091,6 +2400,7 @@ impl<W: Write> StreamWriter<W> {
 
         self.data_gen.write(
             batch,
+            custom_metadata,
             &mut self.dictionary_tracker,
             &self.write_options,
             &mut self.ipc_write_context,
@@ -2410,7 +2720,9 @@ fn write_array_data(
 
     let data_type = array_data.data_type();
     if matches!(data_type, DataType::Binary | DataType::Utf8) {
-        for buffer in get_byte_array_buffers::<i32>(array_data) {
+        let [offsets, values] = get_byte_array_buffers::<i32>(array_data);
+        let offsets = to_write_endianness(offsets, &[4], write_options);
+        for buffer in [offsets, values] {
             offset = encode_sink_buffer(
                 buffer,
                 meta,
@@ -2428,7 +2740,10 @@ fn write_array_data(
         // Current implementation just serialize the raw arrays as given and not try to optimize anything.
         // If users wants to "compact" the arrays prior to sending them over IPC,
         // they should consider the gc API suggested in #5513
-        let views = get_or_truncate_buffer(array_data);
+        let mut views = get_or_truncate_buffer(array_data);
+        if !write_options.endianness.equals_to_target_endianness() {
+            views = swap_views(&views, true);
+        }
         offset = encode_sink_buffer(
             views,
             meta,
@@ -2451,7 +2766,9 @@ fn write_array_data(
             )?;
         }
     } else if matches!(data_type, DataType::LargeBinary | DataType::LargeUtf8) {
-        for buffer in get_byte_array_buffers::<i64>(array_data) {
+        let [offsets, values] = get_byte_array_buffers::<i64>(array_data);
+        let offsets = to_write_endianness(offsets, &[8], write_options);
+        for buffer in [offsets, values] {
             offset = encode_sink_buffer(
                 buffer,
                 meta,
@@ -2472,7 +2789,12 @@ fn write_array_data(
         // Truncate values
         assert_eq!(array_data.buffers().len(), 1);
 
+        let widths = match data_type {
+            DataType::Dictionary(key_type, _) => value_widths(key_type),
+            _ => value_widths(data_type),
+        };
         let buffer = get_or_truncate_buffer(array_data);
+        let buffer = to_write_endianness(buffer, widths.unwrap_or_default(), write_options);
         offset = encode_sink_buffer(
             buffer,
             meta,
@@ -2506,12 +2828,18 @@ fn write_array_data(
         assert_eq!(array_data.child_data().len(), 1);
 
         // Truncate offsets and the child data to avoid writing unnecessary data
-        let (offsets, sliced_child_data) = match data_type {
-            DataType::List(_) => get_list_array_buffers::<i32>(array_data),
-            DataType::Map(_, _) => get_list_array_buffers::<i32>(array_data),
-            DataType::LargeList(_) => get_list_array_buffers::<i64>(array_data),
+        let (offsets, sliced_child_data, offset_width) = match data_type {
+            DataType::List(_) | DataType::Map(_, _) => {
+                let (offsets, child_data) = get_list_array_buffers::<i32>(array_data);
+                (offsets, child_data, 4)
+            }
+            DataType::LargeList(_) => {
+                let (offsets, child_data) = get_list_array_buffers::<i64>(array_data);
+                (offsets, child_data, 8)
+            }
             _ => unreachable!(),
         };
+        let offsets = to_write_endianness(offsets, &[offset_width], write_options);
         offset = encode_sink_buffer(
             offsets,
             meta,
@@ -2543,6 +2871,12 @@ fn write_array_data(
             DataType::LargeListView(_) => get_list_view_array_buffers::<i64>(array_data),
             _ => unreachable!(),
         };
+        let offset_width = match data_type {
+            DataType::LargeListView(_) => 8,
+            _ => 4,
+        };
+        let offsets = to_write_endianness(offsets, &[offset_width], write_options);
+        let sizes = to_write_endianness(sizes, &[offset_width], write_options);
 
         offset = encode_sink_buffer(
             offsets,
@@ -2592,9 +2926,16 @@ fn write_array_data(
         )?;
         return Ok(offset);
     } else {
-        for buffer in array_data.buffers() {
+        for (idx, buffer) in array_data.buffers().iter().enumerate() {
+            // Dense union offsets follow the type ids
+            let buffer = match data_type {
+                DataType::Union(_, UnionMode::Dense) if idx == 1 => {
+                    to_write_endianness(buffer.clone(), &[4], write_options)
+                }
+                _ => buffer.clone(),
+            };
             offset = encode_sink_buffer(
-                buffer.clone(),
+                buffer,
                 meta,
                 sink,
                 offset,
@@ -2643,6 +2984,19 @@ fn write_array_data(
     Ok(offset)
 }
 
+/// Byte swaps the values of `buffer`, composed of fields of the given `widths`,
+/// if the endianness of `write_options` does not match the target system
+fn to_write_endianness(
+    buffer: Buffer,
+    widths: &[usize],
+    write_options: &IpcWriteOptions,
+) -> Buffer {
+    match write_options.endianness.equals_to_target_endianness() || widths.is_empty() {
+        true => buffer,
+        false => swap_values(&buffer, widths),
+    }
+}
+
 /// Encodes a single Arrow [`Buffer`] into the IPC body and records its metadata.
 ///
 /// - `buffer`: the Arrow data buffer to encode (validity bitmap, offsets, values, etc.)
@@ -5072,4 +5426,188 @@ mod tests {
         let read_batch = reader.next().unwrap().unwrap();
         assert_eq!(read_batch, batch2);
     }
+
+    fn read_file(file: &mut File) -> (Vec<RecordBatch>, HashMap<String, String>) {
+        file.rewind().unwrap();
+        let reader = FileReader::try_new(&mut *file, None).unwrap();
+        let custom_metadata = reader.custom_metadata().clone();
+        let batches = reader.collect::<Result<_, _>>().unwrap();
+        (batches, custom_metadata)
+    }
+
+    fn read_file_as_stream(file: &mut File, alignment: u64) -> Vec<RecordBatch> {
+        // the messages of an IPC file after the padded magic form an IPC stream
+        file.seek(SeekFrom::Start(alignment)).unwrap();
+        let reader = StreamReader::try_new(&mut *file, None).unwrap();
+        reader.collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn test_file_append() {
+        let batch = record_batch!(("a", Int32, [1, 2, 3]), ("b", Utf8, ["x", "y", "z"])).unwrap();
+        let schema = batch.schema();
+
+        let options = [
+            IpcWriteOptions::default(),
+            IpcWriteOptions::try_new(8, true, MetadataVersion::V4).unwrap(),
+        ];
+        for options in options {
+            let alignment = options.alignment as u64;
+            let mut file = tempfile::tempfile().unwrap();
+            let mut writer =
+                FileWriter::try_new_with_options(&mut file, &schema, options.clone()).unwrap();
+            writer.write(&batch).unwrap();
+            writer.write(&batch.slice(1, 2)).unwrap();
+            writer.write_metadata("key", "value");
+            writer.finish().unwrap();
+            drop(writer);
+
+            let mut writer = FileWriter::try_append_with_options(
+                file.try_clone().unwrap(),
+                &schema,
+                options.clone(),
+            )
+            .unwrap();
+            writer.write(&batch.slice(0, 1)).unwrap();
+            writer.finish().unwrap();
+            drop(writer);
+
+            let (batches, custom_metadata) = read_file(&mut file);
+            let expected = [batch.clone(), batch.slice(1, 2), batch.slice(0, 1)];
+            assert_eq!(batches, expected);
+            assert_eq!(custom_metadata.get("key").unwrap(), "value");
+            assert_eq!(read_file_as_stream(&mut file, alignment), expected);
+
+            // appending no batches leaves the file unchanged
+            let len = file.metadata().unwrap().len();
+            let mut writer = FileWriter::try_append_with_options(
+                file.try_clone().unwrap(),
+                &schema,
+                options.clone(),
+            )
+            .unwrap();
+            writer.finish().unwrap();
+            drop(writer);
+            assert_eq!(file.metadata().unwrap().len(), len);
+            assert_eq!(read_file(&mut file).0, expected);
+        }
+    }
+
+    #[test]
+    fn test_file_append_empty() {
+        let batch = record_batch!(("a", Int64, [1, 2, 3])).unwrap();
+        let schema = batch.schema();
+
+        for alignment in [8, 64] {
+            let options = IpcWriteOptions::try_new(alignment, false, MetadataVersion::V5).unwrap();
+            let mut file = tempfile::tempfile().unwrap();
+            let mut writer =
+                FileWriter::try_new_with_options(&mut file, &schema, options.clone()).unwrap();
+            writer.finish().unwrap();
+            drop(writer);
+
+            let mut writer = FileWriter::try_append_with_options(
+                file.try_clone().unwrap(),
+                &schema,
+                options.clone(),
+            )
+            .unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+            drop(writer);
+
+            assert_eq!(read_file(&mut file).0, vec![batch.clone()]);
+            assert_eq!(
+                read_file_as_stream(&mut file, alignment as u64),
+                vec![batch.clone()]
+            );
+        }
+    }
+
+    #[test]
+    fn test_file_append_dictionary() {
+        let schema = Arc::new(Schema::new(vec![Field::new_dictionary(
+            "d",
+            DataType::Int32,
+            DataType::Utf8,
+            true,
+        )]));
+        let dict = |values: Vec<&str>, keys: Vec<i32>| {
+            let array =
+                DictionaryArray::new(Int32Array::from(keys), Arc::new(StringArray::from(values)));
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+        };
+
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = FileWriter::try_new(&mut file, &schema).unwrap();
+        writer.write(&dict(vec!["a", "b"], vec![0, 1])).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        // the same dictionary values may be reused
+        let mut writer =
+            FileWriter::try_append_buffered(file.try_clone().unwrap(), &schema).unwrap();
+        writer.write(&dict(vec!["a", "b"], vec![1, 1])).unwrap();
+
+        // but not replaced
+        let err = writer.write(&dict(vec!["c", "d"], vec![0, 1])).unwrap_err();
+        assert!(
+            err.to_string().contains("Dictionary replacement detected"),
+            "{err}"
+        );
+        writer.finish().unwrap();
+        drop(writer);
+
+        let (batches, _) = read_file(&mut file);
+        assert_eq!(
+            batches,
+            vec![
+                dict(vec!["a", "b"], vec![0, 1]),
+                dict(vec!["a", "b"], vec![1, 1])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_file_append_invalid() {
+        let batch = record_batch!(("a", Int32, [1, 2, 3])).unwrap();
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+        let len = file.metadata().unwrap().len();
+
+        let schema = Schema::new(vec![Field::new("b", DataType::Int32, true)]);
+        let err = FileWriter::try_append(file.try_clone().unwrap(), &schema)
+            .err()
+            .unwrap();
+        assert!(
+            err.to_string()
+                .contains("Cannot append record batches with schema")
+        );
+
+        let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V4).unwrap();
+        let err = FileWriter::try_append_with_options(
+            file.try_clone().unwrap(),
+            &batch.schema(),
+            options,
+        )
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains("metadata version"), "{err}");
+
+        // the file is left untouched
+        assert_eq!(file.metadata().unwrap().len(), len);
+        assert_eq!(read_file(&mut file).0, vec![batch.clone()]);
+
+        // not an IPC file
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(b"not an arrow file").unwrap();
+        let err = FileWriter::try_append(file, &batch.schema()).err().unwrap();
+        assert!(
+            err.to_string()
+                .contains("Arrow file does not contain correct footer")
+        );
+    }
 }
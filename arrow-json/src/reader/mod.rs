@@ -141,6 +141,7 @@ use arrow_array::cast::AsArray;
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::{ArrayRef, RecordBatch, RecordBatchReader, downcast_integer};
+use arrow_cast::parse::ParseOptions;
 use arrow_schema::{ArrowError, DataType, FieldRef, Schema, SchemaRef, TimeUnit};
 use chrono::Utc;
 use serde_core::Serialize;
@@ -192,6 +193,7 @@ pub struct ReaderBuilder {
     ignore_type_conflicts: bool,
     is_field: bool,
     struct_mode: StructMode,
+    parse_options: ParseOptions,
 
     schema: SchemaRef,
 }
@@ -211,6 +213,7 @@ impl ReaderBuilder {
             coerce_primitive: false,
             strict_mode: false,
             ignore_type_conflicts: false,
+            parse_options: ParseOptions::default(),
             is_field: false,
             struct_mode: Default::default(),
             schema,
@@ -253,6 +256,7 @@ impl ReaderBuilder {
             coerce_primitive: false,
             strict_mode: false,
             ignore_type_conflicts: false,
+            parse_options: ParseOptions::default(),
             is_field: true,
             struct_mode: Default::default(),
             schema: Arc::new(Schema::new([field.into()])),
@@ -314,6 +318,17 @@ impl ReaderBuilder {
         }
     }
 
+    /// Sets the [`ParseOptions`] used to parse date, time and timestamp values
+    /// from JSON strings
+    ///
+    /// By default these are parsed with the heuristics of [`arrow_cast::parse`]
+    pub fn with_parse_options(self, parse_options: ParseOptions) -> Self {
+        Self {
+            parse_options,
+            ..self
+        }
+    }
+
     /// Create a [`Reader`] with the provided [`BufRead`]
     pub fn build<R: BufRead>(self, reader: R) -> Result<Reader<R>, ArrowError> {
         Ok(Reader {
@@ -338,6 +353,7 @@ impl ReaderBuilder {
             strict_mode: self.strict_mode,
             struct_mode: self.struct_mode,
             ignore_type_conflicts: self.ignore_type_conflicts,
+            parse_options: self.parse_options,
         };
         let decoder = ctx.make_decoder(data_type.as_ref(), nullable)?;
 
@@ -722,6 +738,8 @@ pub struct DecoderContext {
     struct_mode: StructMode,
     /// Whether to treat columns with incompatible types as missing (i.e. NULL)
     ignore_type_conflicts: bool,
+    /// Options for parsing temporal types from strings
+    parse_options: ParseOptions,
 }
 
 impl DecoderContext {
@@ -745,6 +763,11 @@ impl DecoderContext {
         self.ignore_type_conflicts
     }
 
+    /// Returns the options for parsing temporal types from strings
+    pub fn parse_options(&self) -> &ParseOptions {
+        &self.parse_options
+    }
+
     /// Create a decoder for a type.
     ///
     /// This is the standard way to create child decoders from within a decoder
@@ -770,9 +793,18 @@ fn make_decoder(
     }
     macro_rules! timestamp_decoder {
         ($t:ty, $data_type:expr, $tz:expr) => {{
-            Ok(Box::new(TimestampArrayDecoder::<$t, _>::new(
-                ctx, $data_type, $tz,
-            )))
+            // inputs without an offset are in the timezone of the parse options, if any
+            match ctx.parse_options().timezone() {
+                Some(tz) => {
+                    let tz: Tz = tz.parse()?;
+                    Ok(Box::new(TimestampArrayDecoder::<$t, _>::new(
+                        ctx, $data_type, tz,
+                    )))
+                }
+                None => Ok(Box::new(TimestampArrayDecoder::<$t, _>::new(
+                    ctx, $data_type, $tz,
+                ))),
+            }
         }};
     }
     macro_rules! decimal_decoder {
@@ -1824,6 +1856,69 @@ mod tests {
         assert_eq!(i64.values(), &[i64::MAX, i64::MIN, 900000]);
     }
 
+    #[test]
+    fn test_parse_options() {
+        let buf = r#"
+        {"ts": "01/01/1970 01:00", "tz": "01/01/1970 01:00", "date": "1970.01.02", "time": "3600"}
+        {"ts": "1970-01-01T00:00:00+02:00", "tz": "7200", "date": "86400", "time": "02h00"}
+        {"ts": 1000, "tz": null, "date": null, "time": null}
+        "#;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("ts", DataType::Timestamp(TimeUnit::Second, None), true),
+            Field::new(
+                "tz",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("+05:00".into())),
+                true,
+            ),
+            Field::new("date", DataType::Date32, true),
+            Field::new("time", DataType::Time32(TimeUnit::Second), true),
+        ]));
+        let options = ParseOptions::new()
+            .with_formats(["%d/%m/%Y %H:%M", "%Y-%m-%dT%H:%M:%S%z", "%Y.%m.%d", "%Hh%M"])
+            .with_timezone("+01:00")
+            .unwrap()
+            .with_epoch_unit(TimeUnit::Second);
+
+        let batches = ReaderBuilder::new(schema)
+            .with_parse_options(options)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+
+        let ts = batches[0].column(0).as_primitive::<TimestampSecondType>();
+        // JSON numbers are not affected by the parse options
+        assert_eq!(ts.values(), &[0, -7200, 1000]);
+        let tz = batches[0]
+            .column(1)
+            .as_primitive::<TimestampMillisecondType>();
+        assert_eq!(tz.timezone(), Some("+05:00"));
+        assert_eq!(tz.values()[..2], [0, 7_200_000]);
+        let date = batches[0].column(2).as_primitive::<Date32Type>();
+        assert_eq!(date.values()[..2], [1, 1]);
+        let time = batches[0].column(3).as_primitive::<Time32SecondType>();
+        assert_eq!(time.values()[..2], [3600, 7200]);
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "date",
+            DataType::Date32,
+            true,
+        )]));
+        let err = ReaderBuilder::new(schema)
+            .with_parse_options(ParseOptions::new().with_formats(["%Y%m%d"]))
+            .build(Cursor::new(r#"{"date": "1970-01-02"}"#.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: whilst decoding field 'date': failed to parse \"1970-01-02\" as Date32"
+        );
+    }
+
     #[test]
     fn test_strict_mode_no_missing_columns_in_schema() {
         let buf = r#"
@@ -20,7 +20,7 @@ use std::sync::Arc;
 
 use arrow_array::builder::PrimitiveBuilder;
 use arrow_array::{ArrayRef, ArrowPrimitiveType};
-use arrow_cast::parse::Parser;
+use arrow_cast::parse::{ParseOptions, Parser};
 use arrow_schema::{ArrowError, DataType};
 use half::f16;
 use num_traits::NumCast;
@@ -76,6 +76,7 @@ impl ParseJsonNumber for f64 {
 pub struct PrimitiveArrayDecoder<P: ArrowPrimitiveType> {
     data_type: DataType,
     ignore_type_conflicts: bool,
+    parse_options: ParseOptions,
     // Invariant and Send
     phantom: PhantomData<fn(P) -> P>,
 }
@@ -85,6 +86,7 @@ impl<P: ArrowPrimitiveType> PrimitiveArrayDecoder<P> {
         Self {
             data_type: data_type.clone(),
             ignore_type_conflicts: ctx.ignore_type_conflicts(),
+            parse_options: ctx.parse_options().clone(),
             phantom: Default::default(),
         }
     }
@@ -108,7 +110,7 @@ where
                 }
                 TapeElement::String(idx) => {
                     let s = tape.get_string(idx);
-                    P::parse(s).ok_or_else(|| {
+                    P::parse_with_options(s, &self.parse_options).ok_or_else(|| {
                         ArrowError::JsonError(format!("failed to parse \"{s}\" as {d}",))
                     })
                 }
@@ -21,7 +21,7 @@ use std::sync::Arc;
 use arrow_array::ArrayRef;
 use arrow_array::builder::PrimitiveBuilder;
 use arrow_array::types::ArrowTimestampType;
-use arrow_cast::parse::string_to_datetime;
+use arrow_cast::parse::ParseOptions;
 use arrow_schema::{ArrowError, DataType, TimeUnit};
 use chrono::TimeZone;
 
@@ -33,6 +33,7 @@ pub struct TimestampArrayDecoder<P: ArrowTimestampType, Tz: TimeZone> {
     data_type: DataType,
     timezone: Tz,
     ignore_type_conflicts: bool,
+    parse_options: ParseOptions,
     // Invariant and Send
     phantom: PhantomData<fn(P) -> P>,
 }
@@ -43,6 +44,7 @@ impl<P: ArrowTimestampType, Tz: TimeZone> TimestampArrayDecoder<P, Tz> {
             data_type: data_type.clone(),
             timezone,
             ignore_type_conflicts: ctx.ignore_type_conflicts(),
+            parse_options: ctx.parse_options().clone(),
             phantom: Default::default(),
         }
     }
@@ -64,7 +66,8 @@ where
                 }
                 TapeElement::String(idx) => {
                     let s = tape.get_string(idx);
-                    let date = string_to_datetime(&self.timezone, s).map_err(|e| {
+                    let date = self.parse_options.string_to_datetime(&self.timezone, s);
+                    let date = date.map_err(|e| {
                         ArrowError::JsonError(format!(
                             "failed to parse \"{s}\" as {}: {}",
                             self.data_type, e
@@ -0,0 +1,851 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels that operate on the elements of list arrays, such as [`unnest`],
+//! [`list_contains`] and [`list_sort`]
+//!
+//! Supported array types: [`ListArray`], [`LargeListArray`], [`ListViewArray`],
+//! [`LargeListViewArray`] and [`FixedSizeListArray`].
+//!
+//! Kernels returning lists return the same list type as their input, except for
+//! [`FixedSizeListArray`], which returns a [`ListArray`] when the lengths of the lists may
+//! change. Elements are compared as by [`make_comparator`], and so `NaN` equals `NaN`, and
+//! null equals null.
+
+use std::cmp::Ordering;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_cmp::make_comparator;
+use arrow_schema::{ArrowError, DataType, Field, SortOptions};
+
+use crate::concat::concat;
+use crate::take::take;
+
+/// The child values of a list array, with the range of each list within them
+struct Lists<'a> {
+    values: &'a ArrayRef,
+    ranges: Vec<Range<usize>>,
+    nulls: Option<&'a NullBuffer>,
+}
+
+impl Lists<'_> {
+    /// Returns the range of the list at `i`, or `None` if it is null
+    fn get(&self, i: usize) -> Option<Range<usize>> {
+        match self.nulls.is_some_and(|n| n.is_null(i)) {
+            true => None,
+            false => Some(self.ranges[i].clone()),
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = Option<Range<usize>>> + '_ {
+        (0..self.ranges.len()).map(|i| self.get(i))
+    }
+}
+
+fn lists<'a>(array: &'a dyn Array, name: &str) -> Result<Lists<'a>, ArrowError> {
+    fn offsets<O: OffsetSizeTrait>(a: &GenericListArray<O>) -> Vec<Range<usize>> {
+        a.value_offsets()
+            .windows(2)
+            .map(|w| w[0].as_usize()..w[1].as_usize())
+            .collect()
+    }
+    fn views<O: OffsetSizeTrait>(a: &GenericListViewArray<O>) -> Vec<Range<usize>> {
+        a.value_offsets()
+            .iter()
+            .zip(a.value_sizes())
+            .map(|(o, s)| o.as_usize()..o.as_usize() + s.as_usize())
+            .collect()
+    }
+
+    let (values, ranges) = match array.data_type() {
+        DataType::List(_) => {
+            let a = array.as_list::<i32>();
+            (a.values(), offsets(a))
+        }
+        DataType::LargeList(_) => {
+            let a = array.as_list::<i64>();
+            (a.values(), offsets(a))
+        }
+        DataType::ListView(_) => {
+            let a = array.as_list_view::<i32>();
+            (a.values(), views(a))
+        }
+        DataType::LargeListView(_) => {
+            let a = array.as_list_view::<i64>();
+            (a.values(), views(a))
+        }
+        DataType::FixedSizeList(_, size) => {
+            let a = array.as_fixed_size_list();
+            let size = *size as usize;
+            let ranges = (0..a.len())
+                .map(|i| a.value_offset(i) as usize..a.value_offset(i) as usize + size)
+                .collect();
+            (a.values(), ranges)
+        }
+        t => {
+            return Err(ArrowError::ComputeError(format!(
+                "{name} does not support type {t}"
+            )));
+        }
+    };
+    Ok(Lists {
+        values,
+        ranges,
+        nulls: array.nulls(),
+    })
+}
+
+/// Returns the offsets of lists of `lengths`, or an error if they overflow `O`
+fn offsets_from_lengths<O: OffsetSizeTrait>(
+    lengths: Vec<usize>,
+) -> Result<OffsetBuffer<O>, ArrowError> {
+    let total = lengths.iter().sum();
+    match O::from_usize(total) {
+        Some(_) => Ok(OffsetBuffer::from_lengths(lengths)),
+        None => Err(ArrowError::OffsetOverflowError(total)),
+    }
+}
+
+/// Returns a list array of the same type as `array`, or a [`ListArray`] if it is a
+/// [`FixedSizeListArray`], with lists of `lengths` formed from the `indices` of `values`
+fn list_like(
+    array: &dyn Array,
+    values: &dyn Array,
+    indices: Vec<u64>,
+    lengths: Vec<usize>,
+    nulls: Option<NullBuffer>,
+) -> Result<ArrayRef, ArrowError> {
+    fn views<O: OffsetSizeTrait>(
+        lengths: Vec<usize>,
+    ) -> Result<(ScalarBuffer<O>, ScalarBuffer<O>), ArrowError> {
+        let sizes = lengths.iter().map(|l| O::usize_as(*l)).collect();
+        let offsets = offsets_from_lengths::<O>(lengths)?;
+        let offsets = offsets.inner().slice(0, offsets.len() - 1);
+        Ok((offsets, sizes))
+    }
+
+    let values = take(values, &UInt64Array::from(indices), None)?;
+    Ok(match array.data_type() {
+        DataType::List(f) | DataType::FixedSizeList(f, _) => Arc::new(ListArray::try_new(
+            f.clone(),
+            offsets_from_lengths(lengths)?,
+            values,
+            nulls,
+        )?),
+        DataType::LargeList(f) => Arc::new(LargeListArray::try_new(
+            f.clone(),
+            offsets_from_lengths(lengths)?,
+            values,
+            nulls,
+        )?),
+        DataType::ListView(f) => {
+            let (offsets, sizes) = views(lengths)?;
+            Arc::new(ListViewArray::try_new(
+                f.clone(),
+                offsets,
+                sizes,
+                values,
+                nulls,
+            )?)
+        }
+        DataType::LargeListView(f) => {
+            let (offsets, sizes) = views(lengths)?;
+            Arc::new(LargeListViewArray::try_new(
+                f.clone(),
+                offsets,
+                sizes,
+                values,
+                nulls,
+            )?)
+        }
+        _ => unreachable!("checked by lists"),
+    })
+}
+
+/// Options for [`unnest`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnnestOptions {
+    /// Whether a null list produces a single null element, rather than no elements
+    pub preserve_nulls: bool,
+    /// Whether an empty list produces a single null element, rather than no elements
+    pub preserve_empty: bool,
+}
+
+impl UnnestOptions {
+    /// Sets whether a null list produces a single null element
+    pub fn with_preserve_nulls(mut self, preserve_nulls: bool) -> Self {
+        self.preserve_nulls = preserve_nulls;
+        self
+    }
+
+    /// Sets whether an empty list produces a single null element
+    pub fn with_preserve_empty(mut self, preserve_empty: bool) -> Self {
+        self.preserve_empty = preserve_empty;
+        self
+    }
+}
+
+/// Flattens the lists of `array` into rows, returning their elements and the index of the
+/// list each element came from
+///
+/// The returned indices can be used to [`take`] the rows of other columns of the same
+/// batch, repeating them for each element of their list.
+///
+/// ```
+/// # use arrow_array::{Int32Array, ListArray, UInt64Array};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::list::{unnest, UnnestOptions};
+/// # use arrow_select::take::take;
+/// let lists = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(1), Some(2)]),
+///     None,
+///     Some(vec![Some(3)]),
+/// ]);
+/// let ids = Int32Array::from(vec![10, 20, 30]);
+///
+/// let (values, indices) = unnest(&lists, UnnestOptions::default()).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from(vec![1, 2, 3]));
+/// assert_eq!(indices, UInt64Array::from(vec![0, 0, 2]));
+/// let ids = take(&ids, &indices, None).unwrap();
+/// assert_eq!(ids.as_ref(), &Int32Array::from(vec![10, 10, 30]));
+///
+/// let options = UnnestOptions::default().with_preserve_nulls(true);
+/// let (values, indices) = unnest(&lists, options).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from(vec![Some(1), Some(2), None, Some(3)]));
+/// assert_eq!(indices, UInt64Array::from(vec![0, 0, 1, 2]));
+/// ```
+pub fn unnest(
+    array: &dyn Array,
+    options: UnnestOptions,
+) -> Result<(ArrayRef, UInt64Array), ArrowError> {
+    let lists = lists(array, "unnest")?;
+    let mut parents = vec![];
+    let mut indices = vec![];
+    for (i, range) in lists.iter().enumerate() {
+        match range {
+            Some(range) if !range.is_empty() => {
+                parents.extend(std::iter::repeat_n(i as u64, range.len()));
+                indices.extend(range.map(|k| Some(k as u64)));
+            }
+            Some(_) if options.preserve_empty => {
+                parents.push(i as u64);
+                indices.push(None);
+            }
+            None if options.preserve_nulls => {
+                parents.push(i as u64);
+                indices.push(None);
+            }
+            _ => {}
+        }
+    }
+    let values = take(lists.values.as_ref(), &UInt64Array::from(indices), None)?;
+    Ok((values, UInt64Array::from(parents)))
+}
+
+/// Returns whether each list of `array` contains `value`
+///
+/// `value` is either a scalar, or an array with a value for each list, and must have the
+/// same type as the elements of `array`. The result is null if the list or `value` is
+/// null, or if the list does not contain `value` but contains a null element.
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Int32Array, ListArray, Scalar};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::list::list_contains;
+/// let lists = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(1), Some(2)]),
+///     Some(vec![Some(3), None]),
+///     Some(vec![Some(2), None]),
+///     None,
+/// ]);
+/// let value = Scalar::new(Int32Array::from(vec![2]));
+/// let result = list_contains(&lists, &value).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), None, Some(true), None]));
+/// ```
+pub fn list_contains(array: &dyn Array, value: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    let lists = lists(array, "list_contains")?;
+    let (value, is_scalar) = value.get();
+    if value.data_type() != lists.values.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "list_contains expected a value of type {}, got {}",
+            lists.values.data_type(),
+            value.data_type()
+        )));
+    }
+    if !is_scalar && value.len() != array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "list_contains expected {} values, got {}",
+            array.len(),
+            value.len()
+        )));
+    }
+
+    let cmp = make_comparator(lists.values.as_ref(), value, SortOptions::default())?;
+    let element_nulls = lists.values.logical_nulls();
+    let value_nulls = value.logical_nulls();
+    let mut builder = BooleanBuilder::with_capacity(array.len());
+    for (i, range) in lists.iter().enumerate() {
+        let j = if is_scalar { 0 } else { i };
+        let Some(range) = range.filter(|_| value_nulls.as_ref().is_none_or(|n| n.is_valid(j)))
+        else {
+            builder.append_null();
+            continue;
+        };
+        let mut contains_null = false;
+        let mut found = false;
+        for k in range {
+            if element_nulls.as_ref().is_some_and(|n| n.is_null(k)) {
+                contains_null = true;
+            } else if cmp(k, j).is_eq() {
+                found = true;
+                break;
+            }
+        }
+        builder.append_option((found || !contains_null).then_some(found));
+    }
+    Ok(builder.finish())
+}
+
+/// Returns the element at `index` of each list of `array`
+///
+/// Elements are indexed from 0, or from the end of the list if `index` is negative, such
+/// that `-1` is the last element. The result is null if the list is null or `index` is
+/// out of its bounds.
+///
+/// ```
+/// # use arrow_array::{Int32Array, ListArray};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::list::list_element;
+/// let lists = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(1), Some(2)]),
+///     Some(vec![Some(3)]),
+///     None,
+/// ]);
+/// let result = list_element(&lists, -2).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![Some(1), None, None]));
+/// ```
+pub fn list_element(array: &dyn Array, index: i64) -> Result<ArrayRef, ArrowError> {
+    let lists = lists(array, "list_element")?;
+    let indices: UInt64Array = lists
+        .iter()
+        .map(|range| {
+            let range = range?;
+            let offset = match index >= 0 {
+                true => index as usize,
+                false => range.len().checked_sub(index.unsigned_abs() as usize)?,
+            };
+            (offset < range.len()).then(|| (range.start + offset) as u64)
+        })
+        .collect();
+    take(lists.values.as_ref(), &indices, None)
+}
+
+/// Returns up to `length` elements of each list of `array`, starting from `start`
+///
+/// If `start` is negative, it counts from the end of the list, such that `-1` is the
+/// last element. If `length` is `None`, all elements from `start` are returned. Null
+/// lists remain null.
+///
+/// ```
+/// # use arrow_array::ListArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::list::list_slice;
+/// let lists = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(1), Some(2), Some(3)]),
+///     Some(vec![Some(4)]),
+/// ]);
+/// let result = list_slice(&lists, 1, Some(1)).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(2)]),
+///     Some(vec![]),
+/// ]);
+/// assert_eq!(result.as_list::<i32>(), &expected);
+/// ```
+pub fn list_slice(
+    array: &dyn Array,
+    start: i64,
+    length: Option<u64>,
+) -> Result<ArrayRef, ArrowError> {
+    let lists = lists(array, "list_slice")?;
+    let mut indices = vec![];
+    let mut lengths = Vec::with_capacity(array.len());
+    for range in lists.iter() {
+        let range = range.unwrap_or_default();
+        let offset = match start >= 0 {
+            true => (start as usize).min(range.len()),
+            false => range.len().saturating_sub(start.unsigned_abs() as usize),
+        };
+        let len = match length {
+            Some(length) => (range.len() - offset).min(length as usize),
+            None => range.len() - offset,
+        };
+        let begin = range.start + offset;
+        indices.extend((begin..begin + len).map(|k| k as u64));
+        lengths.push(len);
+    }
+    list_like(
+        array,
+        lists.values.as_ref(),
+        indices,
+        lengths,
+        lists.nulls.cloned(),
+    )
+}
+
+/// Returns `array` with the elements of each list sorted according to `options`
+///
+/// The sort is stable. A [`FixedSizeListArray`] is returned as a [`FixedSizeListArray`].
+///
+/// ```
+/// # use arrow_array::ListArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_schema::SortOptions;
+/// # use arrow_select::list::list_sort;
+/// let lists = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(3), None, Some(1)]),
+///     None,
+/// ]);
+/// let result = list_sort(&lists, SortOptions::default()).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![None, Some(1), Some(3)]),
+///     None,
+/// ]);
+/// assert_eq!(result.as_list::<i32>(), &expected);
+/// ```
+pub fn list_sort(array: &dyn Array, options: SortOptions) -> Result<ArrayRef, ArrowError> {
+    let lists = lists(array, "list_sort")?;
+    let cmp = make_comparator(lists.values.as_ref(), lists.values.as_ref(), options)?;
+    let fixed = matches!(array.data_type(), DataType::FixedSizeList(_, _));
+    let mut indices = vec![];
+    let mut lengths = Vec::with_capacity(array.len());
+    for range in lists.iter() {
+        // The null lists of a FixedSizeListArray still occupy their elements
+        let range = match fixed {
+            true => range.unwrap_or_else(|| lists.ranges[lengths.len()].clone()),
+            false => range.unwrap_or_default(),
+        };
+        let start = indices.len();
+        indices.extend(range.clone().map(|k| k as u64));
+        indices[start..].sort_by(|a, b| cmp(*a as usize, *b as usize));
+        lengths.push(range.len());
+    }
+
+    match array.data_type() {
+        DataType::FixedSizeList(f, size) => {
+            let values = take(lists.values.as_ref(), &UInt64Array::from(indices), None)?;
+            let nulls = lists.nulls.cloned();
+            Ok(Arc::new(FixedSizeListArray::try_new(
+                f.clone(),
+                *size,
+                values,
+                nulls,
+            )?))
+        }
+        _ => list_like(
+            array,
+            lists.values.as_ref(),
+            indices,
+            lengths,
+            lists.nulls.cloned(),
+        ),
+    }
+}
+
+/// Returns `array` with duplicate elements removed from each list, keeping the first
+/// occurrence of each element
+///
+/// ```
+/// # use arrow_array::ListArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::list::list_distinct;
+/// let lists = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(3), None, Some(1), Some(3), None]),
+/// ]);
+/// let result = list_distinct(&lists).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(3), None, Some(1)]),
+/// ]);
+/// assert_eq!(result.as_list::<i32>(), &expected);
+/// ```
+pub fn list_distinct(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    let lists = lists(array, "list_distinct")?;
+    let values = lists.values.as_ref();
+    let cmp = make_comparator(values, values, SortOptions::default())?;
+    let mut indices = vec![];
+    let mut lengths = Vec::with_capacity(array.len());
+    let mut sorted = vec![];
+    for range in lists.iter() {
+        let range = range.unwrap_or_default();
+        // A stable sort places the first occurrence of each element first among its equals
+        sorted.clear();
+        sorted.extend(range);
+        sorted.sort_by(|a, b| cmp(*a, *b));
+        let start = indices.len();
+        let mut previous = None;
+        for k in &sorted {
+            if previous.is_none_or(|p| cmp(p, *k) != Ordering::Equal) {
+                indices.push(*k as u64);
+            }
+            previous = Some(*k);
+        }
+        indices[start..].sort_unstable();
+        lengths.push(indices.len() - start);
+    }
+    list_like(array, values, indices, lengths, lists.nulls.cloned())
+}
+
+/// Returns the concatenation of the lists at each index of `arrays`
+///
+/// All `arrays` must have the same type and length. Null lists are skipped, and the
+/// result is only null if all the lists are null.
+///
+/// ```
+/// # use arrow_array::ListArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::list::list_concat;
+/// let a = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(1)]),
+///     None,
+///     None,
+/// ]);
+/// let b = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(2), Some(3)]),
+///     Some(vec![Some(4)]),
+///     None,
+/// ]);
+/// let result = list_concat(&[&a, &b]).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(1), Some(2), Some(3)]),
+///     Some(vec![Some(4)]),
+///     None,
+/// ]);
+/// assert_eq!(result.as_list::<i32>(), &expected);
+/// ```
+pub fn list_concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    let Some(first) = arrays.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "list_concat requires at least one array".to_string(),
+        ));
+    };
+    if let Some(a) = arrays.iter().find(|a| a.data_type() != first.data_type()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "list_concat requires arrays of the same type, got {} and {}",
+            first.data_type(),
+            a.data_type()
+        )));
+    }
+    if let Some(a) = arrays.iter().find(|a| a.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "list_concat requires arrays of the same length, got {} and {}",
+            first.len(),
+            a.len()
+        )));
+    }
+
+    let lists = arrays
+        .iter()
+        .map(|a| lists(*a, "list_concat"))
+        .collect::<Result<Vec<_>, _>>()?;
+    let values: Vec<_> = lists.iter().map(|l| l.values.as_ref()).collect();
+    let values = concat(&values)?;
+    let bases: Vec<_> = lists
+        .iter()
+        .scan(0, |base, l| {
+            let start = *base;
+            *base += l.values.len();
+            Some(start)
+        })
+        .collect();
+
+    let mut indices = vec![];
+    let mut lengths = Vec::with_capacity(first.len());
+    let mut valid = Vec::with_capacity(first.len());
+    for i in 0..first.len() {
+        let start = indices.len();
+        let mut is_valid = false;
+        for (l, base) in lists.iter().zip(&bases) {
+            if let Some(range) = l.get(i) {
+                indices.extend(range.map(|k| (base + k) as u64));
+                is_valid = true;
+            }
+        }
+        lengths.push(indices.len() - start);
+        valid.push(is_valid);
+    }
+    let nulls = NullBuffer::from(valid);
+    let nulls = (nulls.null_count() > 0).then_some(nulls);
+    list_like(*first, values.as_ref(), indices, lengths, nulls)
+}
+
+/// Returns a [`ListArray`] of `num_groups` lists, where the list at index `g` contains the
+/// elements of `values` whose group id is `g`, in order, as SQL's `array_agg`
+///
+/// `group_ids` contains the group of each element of `values`. Groups without any
+/// elements produce empty lists.
+///
+/// ```
+/// # use arrow_array::{Int32Array, ListArray};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::list::array_agg;
+/// let values = Int32Array::from(vec![Some(1), Some(2), None, Some(4)]);
+/// let result = array_agg(&values, &[1, 0, 1, 1], 3).unwrap();
+/// let expected = ListArray::from_iter_primitive::<Int32Type, _, _>([
+///     Some(vec![Some(2)]),
+///     Some(vec![Some(1), None, Some(4)]),
+///     Some(vec![]),
+/// ]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn array_agg(
+    values: &dyn Array,
+    group_ids: &[usize],
+    num_groups: usize,
+) -> Result<ListArray, ArrowError> {
+    if group_ids.len() != values.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "array_agg expected {} group ids, got {}",
+            values.len(),
+            group_ids.len()
+        )));
+    }
+    let mut lengths = vec![0; num_groups];
+    for g in group_ids {
+        match lengths.get_mut(*g) {
+            Some(len) => *len += 1,
+            None => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "array_agg group id {g} is out of bounds for {num_groups} groups"
+                )));
+            }
+        }
+    }
+    let offsets = offsets_from_lengths::<i32>(lengths)?;
+    let mut positions: Vec<usize> = offsets[..num_groups].iter().map(|o| *o as usize).collect();
+    let mut indices = vec![0_u64; values.len()];
+    for (i, g) in group_ids.iter().enumerate() {
+        indices[positions[*g]] = i as u64;
+        positions[*g] += 1;
+    }
+    let values = take(values, &UInt64Array::from(indices), None)?;
+    let field = Arc::new(Field::new_list_field(values.data_type().clone(), true));
+    ListArray::try_new(field, offsets, values, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    fn lists() -> ListArray {
+        ListArray::from_iter_primitive::<Int32Type, _, _>([
+            Some(vec![Some(3), Some(1), Some(3)]),
+            None,
+            Some(vec![]),
+            Some(vec![None, Some(2), None]),
+        ])
+    }
+
+    fn to_vec(array: &dyn Array) -> Vec<Option<Vec<Option<i32>>>> {
+        let lists = super::lists(array, "test").unwrap();
+        let values = lists.values.as_primitive::<Int32Type>();
+        lists
+            .iter()
+            .map(|r| {
+                r.map(|r| {
+                    r.map(|k| values.is_valid(k).then(|| values.value(k)))
+                        .collect()
+                })
+            })
+            .collect()
+    }
+
+    fn list_view(array: &ListArray) -> ListViewArray {
+        let (field, offsets, values, nulls) = array.clone().into_parts();
+        let sizes = offsets.lengths().map(|l| l as i32).collect();
+        let offsets = offsets.inner().slice(0, array.len());
+        ListViewArray::new(field, offsets, sizes, values, nulls)
+    }
+
+    fn large_list(array: &ListArray) -> LargeListArray {
+        let (field, offsets, values, nulls) = array.clone().into_parts();
+        let offsets = OffsetBuffer::from_lengths(offsets.lengths());
+        LargeListArray::new(field, offsets, values, nulls)
+    }
+
+    #[test]
+    fn test_unnest() {
+        let options = UnnestOptions::default()
+            .with_preserve_nulls(true)
+            .with_preserve_empty(true);
+        let (values, indices) = unnest(&list_view(&lists()).slice(1, 3), options).unwrap();
+        assert_eq!(
+            values.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![None, None, None, Some(2), None])
+        );
+        assert_eq!(indices, UInt64Array::from(vec![0, 1, 2, 2, 2]));
+
+        let (values, indices) = unnest(&lists(), UnnestOptions::default()).unwrap();
+        assert_eq!(values.len(), 6);
+        assert_eq!(indices, UInt64Array::from(vec![0, 0, 0, 3, 3, 3]));
+    }
+
+    #[test]
+    fn test_list_contains() {
+        let values = Int32Array::from(vec![Some(3), Some(3), Some(3), None]);
+        let result = list_contains(&lists(), &values).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), None, Some(false), None])
+        );
+
+        let err = list_contains(&lists(), &Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: list_contains expected 4 values, got 1"
+        );
+        let err = list_contains(&lists(), &Scalar::new(Int64Array::from(vec![1]))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: list_contains expected a value of type Int32, got Int64"
+        );
+    }
+
+    #[test]
+    fn test_list_element_slice() {
+        let large = large_list(&lists());
+        let result = list_element(&large, 1).unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, None, Some(2)]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let result = list_slice(&large, -2, None).unwrap();
+        assert_eq!(result.data_type(), large.data_type());
+        assert_eq!(
+            to_vec(&result),
+            vec![
+                Some(vec![Some(1), Some(3)]),
+                None,
+                Some(vec![]),
+                Some(vec![Some(2), None])
+            ]
+        );
+
+        let result = list_slice(&list_view(&lists()), 5, Some(1)).unwrap();
+        assert_eq!(
+            to_vec(&result),
+            vec![Some(vec![]), None, Some(vec![]), Some(vec![])]
+        );
+    }
+
+    #[test]
+    fn test_list_sort_distinct() {
+        let options = SortOptions::default().desc().with_nulls_first(false);
+        let result = list_sort(&lists(), options).unwrap();
+        assert_eq!(
+            to_vec(&result),
+            vec![
+                Some(vec![Some(3), Some(3), Some(1)]),
+                None,
+                Some(vec![]),
+                Some(vec![Some(2), None, None])
+            ]
+        );
+
+        let result = list_distinct(&list_view(&lists())).unwrap();
+        assert_eq!(
+            result.data_type(),
+            &DataType::ListView(Field::new_list_field(DataType::Int32, true).into())
+        );
+        assert_eq!(
+            to_vec(&result),
+            vec![
+                Some(vec![Some(3), Some(1)]),
+                None,
+                Some(vec![]),
+                Some(vec![None, Some(2)])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_list() {
+        let fixed = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            [
+                Some(vec![Some(2), Some(1)]),
+                None,
+                Some(vec![Some(4), Some(4)]),
+            ],
+            2,
+        );
+        let result = list_sort(&fixed, SortOptions::default()).unwrap();
+        assert_eq!(result.data_type(), fixed.data_type());
+        assert_eq!(
+            to_vec(&result),
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                None,
+                Some(vec![Some(4), Some(4)])
+            ]
+        );
+
+        let result = list_distinct(&fixed).unwrap();
+        assert!(matches!(result.data_type(), DataType::List(_)));
+        assert_eq!(
+            to_vec(&result),
+            vec![Some(vec![Some(2), Some(1)]), None, Some(vec![Some(4)])]
+        );
+
+        let result = list_concat(&[&fixed, &fixed]).unwrap();
+        assert_eq!(
+            to_vec(&result),
+            vec![
+                Some(vec![Some(2), Some(1), Some(2), Some(1)]),
+                None,
+                Some(vec![Some(4), Some(4), Some(4), Some(4)])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = unnest(&Int32Array::from(vec![1]), UnnestOptions::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: unnest does not support type Int32"
+        );
+
+        let err = list_concat(&[&lists(), &large_list(&lists())]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("list_concat requires arrays of the same type")
+        );
+
+        let err = array_agg(&Int32Array::from(vec![1]), &[2], 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: array_agg group id 2 is out of bounds for 2 groups"
+        );
+    }
+}
@@ -32,4 +32,6 @@ pub mod length;
 pub mod like;
 mod predicate;
 pub mod regexp;
+pub mod split;
 pub mod substring;
+pub mod transform;
@@ -19,6 +19,7 @@
 //! expression of a \[Large\]StringArray
 
 use crate::like::StringArrayType;
+use crate::transform::map_strings;
 
 use arrow_array::builder::{
     BooleanBufferBuilder, GenericStringBuilder, ListBuilder, StringViewBuilder,
@@ -517,6 +518,57 @@ pub fn regexp_match(
     }
 }
 
+/// Returns `array` with matches of the regular expression `pattern` in each value replaced
+/// by `replacement`
+///
+/// `replacement` may refer to capture groups of `pattern` by index, as `$1`, or by name, as
+/// `${name}`, with `$$` for a literal `$`. See [`Regex::replace`] for the full syntax.
+///
+/// Only the first match is replaced, unless `flags` contains `g`. The remaining `flags`
+/// are applied to `pattern`, see the documentation
+/// [here](https://docs.rs/regex/latest/regex/#grouping-and-flags).
+///
+/// Supports [`StringArray`], [`LargeStringArray`], [`StringViewArray`] and [`DictionaryArray`]
+/// with one of these as its values, returning an array of the same type.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::regexp_replace;
+/// let array = StringArray::from(vec![Some("2024-01-31"), None, Some("none")]);
+/// let result = regexp_replace(&array, r"(\d+)-(\d+)-(\d+)", "$3/$2/$1", None).unwrap();
+/// let expected = StringArray::from(vec![Some("31/01/2024"), None, Some("none")]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+///
+/// let array = StringArray::from(vec!["Foo foo"]);
+/// let result = regexp_replace(&array, "foo", "bar", Some("gi")).unwrap();
+/// assert_eq!(result.as_string::<i32>().value(0), "bar bar");
+/// ```
+pub fn regexp_replace(
+    array: &dyn Array,
+    pattern: &str,
+    replacement: &str,
+    flags: Option<&str>,
+) -> Result<ArrayRef, ArrowError> {
+    let flags = flags.unwrap_or_default();
+    let limit = match flags.contains('g') {
+        true => 0,
+        false => 1,
+    };
+    let flags = flags.replace('g', "");
+    let pattern = match flags.is_empty() {
+        true => pattern.to_string(),
+        false => format!("(?{flags}){pattern}"),
+    };
+    let re = Regex::new(pattern.as_str()).map_err(|e| {
+        ArrowError::ComputeError(format!("Regular expression did not compile: {e:?}"))
+    })?;
+    map_strings(array, "regexp_replace", |value, out| {
+        out.push_str(&re.replacen(value, limit, replacement));
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1030,4 +1082,23 @@ mod tests {
         regexp_is_match_scalar::<StringViewArray>,
         [true, true, false, false]
     );
+
+    #[test]
+    fn test_regexp_replace() {
+        let array = LargeStringArray::from(vec![Some("abc abc"), None, Some("xyz")]);
+        let result = regexp_replace(&array, "(?P<first>a)(b)", "${first}[$2]", None).unwrap();
+        let result: Vec<_> = result.as_string::<i64>().iter().collect();
+        assert_eq!(result, vec![Some("a[b]c abc"), None, Some("xyz")]);
+
+        let array = StringViewArray::from(vec![Some("abc abc"), None]);
+        let result = regexp_replace(&array, "b|c", "$$", Some("g")).unwrap();
+        let result: Vec<_> = result.as_string_view().iter().collect();
+        assert_eq!(result, vec![Some("a$$ a$$"), None]);
+
+        let err = regexp_replace(&array, "(", "", None).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Regular expression did not compile")
+        );
+    }
 }
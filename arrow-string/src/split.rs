@@ -0,0 +1,216 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to split the values of string arrays by a delimiter
+//!
+//! Supported array types: [`StringArray`], [`LargeStringArray`], [`StringViewArray`] and
+//! [`DictionaryArray`] with one of these as its values.
+
+use std::sync::Arc;
+
+use arrow_array::builder::{ArrayBuilder, GenericStringBuilder, ListBuilder, StringViewBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+
+use crate::transform::map_strings;
+
+/// Returns a [`ListArray`] of the parts of each value of `array` separated by `delimiter`
+///
+/// The parts have the same string type as `array`, or its values if it is a
+/// [`DictionaryArray`]. A value without `delimiter`, including the empty string, has a
+/// single part, as does every value if `delimiter` is empty. Null values produce null lists.
+///
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::split::split;
+/// let array = StringArray::from(vec![Some("a,b,,c"), None, Some("")]);
+/// let result = split(&array, ",").unwrap();
+/// let result = result.as_list::<i32>();
+/// assert_eq!(result.value(0).as_string::<i32>(), &StringArray::from(vec!["a", "b", "", "c"]));
+/// assert!(result.is_null(1));
+/// assert_eq!(result.value(2).as_string::<i32>(), &StringArray::from(vec![""]));
+/// ```
+pub fn split(array: &dyn Array, delimiter: &str) -> Result<ArrayRef, ArrowError> {
+    if let Some(d) = array.as_any_dictionary_opt() {
+        let values = split(d.values().as_ref(), delimiter)?;
+        return take(values.as_ref(), d.keys(), None);
+    }
+    match array.data_type() {
+        DataType::Utf8 => Ok(split_values(
+            array.as_string::<i32>().iter(),
+            delimiter,
+            GenericStringBuilder::<i32>::new(),
+        )),
+        DataType::LargeUtf8 => Ok(split_values(
+            array.as_string::<i64>().iter(),
+            delimiter,
+            GenericStringBuilder::<i64>::new(),
+        )),
+        DataType::Utf8View => Ok(split_values(
+            array.as_string_view().iter(),
+            delimiter,
+            StringViewBuilder::new(),
+        )),
+        t => Err(ArrowError::ComputeError(format!(
+            "split does not support type {t}"
+        ))),
+    }
+}
+
+fn split_values<'a, B>(
+    values: impl Iterator<Item = Option<&'a str>>,
+    delimiter: &str,
+    parts: B,
+) -> ArrayRef
+where
+    B: ArrayBuilder + Extend<Option<&'a str>>,
+{
+    // An empty delimiter limits the split to a single part
+    let n = match delimiter.is_empty() {
+        true => 1,
+        false => usize::MAX,
+    };
+    let mut builder = ListBuilder::new(parts);
+    builder.extend(values.map(|v| v.map(|v| v.splitn(n, delimiter).map(Some))));
+    Arc::new(builder.finish())
+}
+
+/// Returns the `n`th part of each value of `array` separated by `delimiter`
+///
+/// Parts are counted from 1, or from the end of the value if `n` is negative. Values
+/// with fewer parts return the empty string. If `delimiter` is empty, each value is its
+/// single part.
+///
+/// # Error
+///
+/// Errors if `n` is zero
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::split::split_part;
+/// let array = StringArray::from(vec![Some("a.b.c"), None, Some("a")]);
+/// let result = split_part(&array, ".", 2).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![Some("b"), None, Some("")]));
+///
+/// let result = split_part(&array, ".", -1).unwrap();
+/// assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![Some("c"), None, Some("a")]));
+/// ```
+pub fn split_part(array: &dyn Array, delimiter: &str, n: i64) -> Result<ArrayRef, ArrowError> {
+    if n == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "split_part position must not be zero".to_string(),
+        ));
+    }
+    let k = n.unsigned_abs() as usize;
+    map_strings(array, "split_part", |value, out| {
+        let part = match (delimiter.is_empty(), n > 0) {
+            (true, _) => (k == 1).then_some(value),
+            (false, true) => value.split(delimiter).nth(k - 1),
+            (false, false) => {
+                // Count forward to be consistent with overlapping delimiters
+                let parts = value.matches(delimiter).count() + 1;
+                parts
+                    .checked_sub(k)
+                    .and_then(|i| value.split(delimiter).nth(i))
+            }
+        };
+        out.push_str(part.unwrap_or_default());
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int8Type;
+
+    #[test]
+    fn test_split() {
+        let array = LargeStringArray::from(vec![Some("a--b"), Some("--"), None]);
+        let result = split(&array, "--").unwrap();
+        let result = result.as_list::<i32>();
+        assert_eq!(result.value_type(), DataType::LargeUtf8);
+        let parts: Vec<_> = result
+            .iter()
+            .map(|l| {
+                l.map(|l| {
+                    l.as_string::<i64>()
+                        .iter()
+                        .flatten()
+                        .map(str::to_string)
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                Some(vec!["a".to_string(), "b".to_string()]),
+                Some(vec![String::new(), String::new()]),
+                None
+            ]
+        );
+
+        let array = StringViewArray::from(vec!["abc"]);
+        let result = split(&array, "").unwrap();
+        let result = result.as_list::<i32>();
+        assert_eq!(result.value(0).as_string_view().value(0), "abc");
+        assert_eq!(result.value_length(0), 1);
+    }
+
+    #[test]
+    fn test_split_dictionary() {
+        let array: DictionaryArray<Int8Type> = vec![Some("x y"), None, Some("x y"), Some("z")]
+            .into_iter()
+            .collect();
+        let result = split(&array, " ").unwrap();
+        let result = result.as_list::<i32>();
+        assert_eq!(result.value_type(), DataType::Utf8);
+        assert_eq!(result.value_offsets(), &[0, 2, 2, 4, 5]);
+        assert!(result.is_null(1));
+        assert_eq!(
+            result.value(2).as_string::<i32>(),
+            &StringArray::from(vec!["x", "y"])
+        );
+    }
+
+    #[test]
+    fn test_split_part() {
+        let array = StringArray::from(vec![Some("aaa"), Some(""), None, Some("a,b")]);
+        let result = split_part(&array, "aa", -1).unwrap();
+        let result: Vec<_> = result.as_string::<i32>().iter().collect();
+        assert_eq!(result, vec![Some("a"), Some(""), None, Some("a,b")]);
+
+        let result = split_part(&array, ",", -3).unwrap();
+        let result: Vec<_> = result.as_string::<i32>().iter().collect();
+        assert_eq!(result, vec![Some(""), Some(""), None, Some("")]);
+
+        let result = split_part(&array, "", 1).unwrap();
+        let result: Vec<_> = result.as_string::<i32>().iter().collect();
+        assert_eq!(result, vec![Some("aaa"), Some(""), None, Some("a,b")]);
+
+        let err = split_part(&array, ",", 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: split_part position must not be zero"
+        );
+    }
+}
@@ -0,0 +1,598 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to transform the values of string arrays, such as changing their case,
+//! trimming, padding and replacing substrings
+//!
+//! Supported array types: [`StringArray`], [`LargeStringArray`], [`StringViewArray`] and
+//! [`DictionaryArray`] with one of these as its values. The output has the same type as the
+//! input, with dictionaries transforming only their values. Null values remain null.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::builder::{GenericStringBuilder, StringViewBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{Buffer, OffsetBuffer, ScalarBuffer};
+use arrow_schema::{ArrowError, DataType};
+
+/// Applies `f` to each non-null value of `array`, where `f` appends the transformed value
+/// to the provided empty `String`
+///
+/// `name` is the name of the kernel, used in error messages
+pub(crate) fn map_strings<F>(
+    array: &dyn Array,
+    name: &str,
+    mut f: F,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: FnMut(&str, &mut String) -> Result<(), ArrowError>,
+{
+    match array.as_any_dictionary_opt() {
+        Some(d) => {
+            let values = map_values(d.values().as_ref(), name, &mut f)?;
+            Ok(d.with_values(values))
+        }
+        None => map_values(array, name, &mut f),
+    }
+}
+
+fn map_values<F>(array: &dyn Array, name: &str, f: &mut F) -> Result<ArrayRef, ArrowError>
+where
+    F: FnMut(&str, &mut String) -> Result<(), ArrowError>,
+{
+    match array.data_type() {
+        DataType::Utf8 => map_offsets(array.as_string::<i32>(), name, f),
+        DataType::LargeUtf8 => map_offsets(array.as_string::<i64>(), name, f),
+        DataType::Utf8View => {
+            let array = array.as_string_view();
+            let mut builder = StringViewBuilder::with_capacity(array.len());
+            let mut buf = String::new();
+            for value in array.iter() {
+                match value {
+                    Some(value) => {
+                        buf.clear();
+                        f(value, &mut buf)?;
+                        builder.append_value(&buf);
+                    }
+                    None => builder.append_null(),
+                }
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        t => Err(ArrowError::ComputeError(format!(
+            "{name} does not support type {t}"
+        ))),
+    }
+}
+
+/// Returns the maximum length in bytes of a value of a string array of `data_type`
+fn max_value_len(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Utf8 => i32::MAX as usize,
+        DataType::LargeUtf8 => i64::MAX as usize,
+        DataType::Dictionary(_, values) => max_value_len(values),
+        _ => u32::MAX as usize,
+    }
+}
+
+fn map_offsets<O: OffsetSizeTrait, F>(
+    array: &GenericStringArray<O>,
+    name: &str,
+    f: &mut F,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: FnMut(&str, &mut String) -> Result<(), ArrowError>,
+{
+    let offsets = array.offsets();
+    let data_len = offsets[array.len()].as_usize() - offsets[0].as_usize();
+    let mut builder = GenericStringBuilder::<O>::with_capacity(array.len(), data_len);
+    let mut buf = String::new();
+    for value in array.iter() {
+        match value {
+            Some(value) => {
+                buf.clear();
+                f(value, &mut buf)?;
+                if O::from_usize(builder.values_slice().len() + buf.len()).is_none() {
+                    return Err(ArrowError::ComputeError(format!(
+                        "{name} overflowed the offsets of {}",
+                        array.data_type()
+                    )));
+                }
+                builder.append_value(&buf);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Returns `array` with each value converted to upper case
+///
+/// Case conversion follows the Unicode `Uppercase` property, as [`str::to_uppercase`], and
+/// so may change the length of a value. Arrays containing only ASCII are converted without
+/// decoding UTF-8.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::transform::upper;
+/// let array = StringArray::from(vec![Some("arrow"), None, Some("straße")]);
+/// let result = upper(&array).unwrap();
+/// let expected = StringArray::from(vec![Some("ARROW"), None, Some("STRASSE")]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+/// ```
+pub fn upper(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    change_case(array, "upper", u8::to_ascii_uppercase, str::to_uppercase)
+}
+
+/// Returns `array` with each value converted to lower case
+///
+/// Case conversion follows the Unicode `Lowercase` property, as [`str::to_lowercase`], and
+/// so may change the length of a value. Arrays containing only ASCII are converted without
+/// decoding UTF-8.
+pub fn lower(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    change_case(array, "lower", u8::to_ascii_lowercase, str::to_lowercase)
+}
+
+fn change_case(
+    array: &dyn Array,
+    name: &str,
+    ascii: fn(&u8) -> u8,
+    unicode: fn(&str) -> String,
+) -> Result<ArrayRef, ArrowError> {
+    let convert = |value: &str, out: &mut String| {
+        match value.is_ascii() {
+            true => out.extend(value.bytes().map(|b| ascii(&b) as char)),
+            false => out.push_str(&unicode(value)),
+        }
+        Ok(())
+    };
+    match array.data_type() {
+        DataType::Dictionary(_, _) => {
+            let d = array.as_any_dictionary();
+            let values = change_case(d.values().as_ref(), name, ascii, unicode)?;
+            Ok(d.with_values(values))
+        }
+        DataType::Utf8 => match ascii_case(array.as_string::<i32>(), ascii) {
+            Some(result) => Ok(result),
+            None => map_strings(array, name, convert),
+        },
+        DataType::LargeUtf8 => match ascii_case(array.as_string::<i64>(), ascii) {
+            Some(result) => Ok(result),
+            None => map_strings(array, name, convert),
+        },
+        _ => map_strings(array, name, convert),
+    }
+}
+
+/// Converts the case of `array` by mapping its value bytes with `ascii`, reusing its
+/// offsets and nulls, returning `None` if its values are not all ASCII
+fn ascii_case<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    ascii: fn(&u8) -> u8,
+) -> Option<ArrayRef> {
+    let offsets = array.offsets();
+    let first = offsets[0];
+    let values = &array.value_data()[first.as_usize()..offsets[array.len()].as_usize()];
+    if !values.is_ascii() {
+        return None;
+    }
+    let values: Buffer = values.iter().map(ascii).collect();
+    let offsets: ScalarBuffer<O> = offsets.iter().map(|o| *o - first).collect();
+    // SAFETY: the offsets are rebased to the start of the values, and mapping ASCII
+    // bytes to ASCII bytes preserves UTF-8 validity
+    let array = unsafe {
+        GenericStringArray::new_unchecked(
+            OffsetBuffer::new_unchecked(offsets),
+            values,
+            array.nulls().cloned(),
+        )
+    };
+    Some(Arc::new(array))
+}
+
+/// Returns `array` with leading and trailing `characters` removed from each value
+///
+/// If `characters` is `None`, Unicode whitespace is removed, as [`str::trim`]
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::transform::trim;
+/// let array = StringArray::from(vec![Some("  arrow "), None, Some("xxrustx")]);
+/// let result = trim(&array, None).unwrap();
+/// let expected = StringArray::from(vec![Some("arrow"), None, Some("xxrustx")]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+///
+/// let result = trim(&array, Some("x ")).unwrap();
+/// let expected = StringArray::from(vec![Some("arrow"), None, Some("rust")]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+/// ```
+pub fn trim(array: &dyn Array, characters: Option<&str>) -> Result<ArrayRef, ArrowError> {
+    trim_impl(array, "trim", characters, true, true)
+}
+
+/// Returns `array` with leading `characters` removed from each value
+///
+/// If `characters` is `None`, Unicode whitespace is removed, as [`str::trim_start`]
+pub fn ltrim(array: &dyn Array, characters: Option<&str>) -> Result<ArrayRef, ArrowError> {
+    trim_impl(array, "ltrim", characters, true, false)
+}
+
+/// Returns `array` with trailing `characters` removed from each value
+///
+/// If `characters` is `None`, Unicode whitespace is removed, as [`str::trim_end`]
+pub fn rtrim(array: &dyn Array, characters: Option<&str>) -> Result<ArrayRef, ArrowError> {
+    trim_impl(array, "rtrim", characters, false, true)
+}
+
+fn trim_impl(
+    array: &dyn Array,
+    name: &str,
+    characters: Option<&str>,
+    start: bool,
+    end: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let characters: Option<Vec<char>> = characters.map(|c| c.chars().collect());
+    let trimmed = |c: char| match &characters {
+        Some(characters) => characters.contains(&c),
+        None => c.is_whitespace(),
+    };
+    map_strings(array, name, |value, out| {
+        let value = match start {
+            true => value.trim_start_matches(trimmed),
+            false => value,
+        };
+        let value = match end {
+            true => value.trim_end_matches(trimmed),
+            false => value,
+        };
+        out.push_str(value);
+        Ok(())
+    })
+}
+
+/// Returns `array` with each value left-padded to `length` characters by repeating `fill`
+///
+/// Values longer than `length` characters are truncated to their first `length` characters.
+/// If `fill` is empty, shorter values are returned unchanged.
+///
+/// # Error
+///
+/// Errors if the result does not fit within the offsets of the array type
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::transform::lpad;
+/// let array = StringArray::from(vec![Some("7"), None, Some("123456")]);
+/// let result = lpad(&array, 4, "0").unwrap();
+/// let expected = StringArray::from(vec![Some("0007"), None, Some("1234")]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+/// ```
+pub fn lpad(array: &dyn Array, length: usize, fill: &str) -> Result<ArrayRef, ArrowError> {
+    pad(array, "lpad", length, fill, true)
+}
+
+/// Returns `array` with each value right-padded to `length` characters by repeating `fill`
+///
+/// Values longer than `length` characters are truncated to their first `length` characters.
+/// If `fill` is empty, shorter values are returned unchanged.
+///
+/// # Error
+///
+/// Errors if the result does not fit within the offsets of the array type
+pub fn rpad(array: &dyn Array, length: usize, fill: &str) -> Result<ArrayRef, ArrowError> {
+    pad(array, "rpad", length, fill, false)
+}
+
+fn pad(
+    array: &dyn Array,
+    name: &str,
+    length: usize,
+    fill: &str,
+    left: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let max = max_value_len(array.data_type());
+    map_strings(array, name, |value, out| {
+        let (value, count) = match value.char_indices().nth(length) {
+            Some((end, _)) => (&value[..end], length),
+            None => (value, value.chars().count()),
+        };
+        if count == length || fill.is_empty() {
+            out.push_str(value);
+            return Ok(());
+        }
+        // Each character of padding is at least one byte
+        if length - count > max.saturating_sub(value.len()) {
+            return Err(ArrowError::ComputeError(format!(
+                "{name} of a value of {} bytes to {length} characters overflows {}",
+                value.len(),
+                array.data_type()
+            )));
+        }
+        let padding = fill.chars().cycle().take(length - count);
+        match left {
+            true => {
+                out.extend(padding);
+                out.push_str(value);
+            }
+            false => {
+                out.push_str(value);
+                out.extend(padding);
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Returns `array` with all occurrences of `from` in each value replaced by `to`
+///
+/// If `from` is empty, values are returned unchanged
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::transform::replace;
+/// let array = StringArray::from(vec![Some("a-b-c"), None, Some("abc")]);
+/// let result = replace(&array, "-", ", ").unwrap();
+/// let expected = StringArray::from(vec![Some("a, b, c"), None, Some("abc")]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+/// ```
+pub fn replace(array: &dyn Array, from: &str, to: &str) -> Result<ArrayRef, ArrowError> {
+    map_strings(array, "replace", |value, out| {
+        if from.is_empty() {
+            out.push_str(value);
+            return Ok(());
+        }
+        let mut last = 0;
+        for (start, matched) in value.match_indices(from) {
+            out.push_str(&value[last..start]);
+            out.push_str(to);
+            last = start + matched.len();
+        }
+        out.push_str(&value[last..]);
+        Ok(())
+    })
+}
+
+/// Returns `array` with the characters of each value in reverse order
+///
+/// Values are reversed by `char`, and so combining characters are not kept with the
+/// character they modify.
+pub fn reverse(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    map_strings(array, "reverse", |value, out| {
+        out.extend(value.chars().rev());
+        Ok(())
+    })
+}
+
+/// Returns `array` with each value repeated `n` times
+///
+/// # Error
+///
+/// Errors if the result does not fit within the offsets of the array type
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::transform::repeat;
+/// let array = StringArray::from(vec![Some("ab"), None, Some("")]);
+/// let result = repeat(&array, 3).unwrap();
+/// let expected = StringArray::from(vec![Some("ababab"), None, Some("")]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+/// ```
+pub fn repeat(array: &dyn Array, n: usize) -> Result<ArrayRef, ArrowError> {
+    let max = max_value_len(array.data_type());
+    map_strings(array, "repeat", |value, out| {
+        match value.len().checked_mul(n) {
+            Some(len) if len <= max => {}
+            _ => {
+                return Err(ArrowError::ComputeError(format!(
+                    "repeat of a value of {} bytes {n} times overflows {}",
+                    value.len(),
+                    array.data_type()
+                )));
+            }
+        }
+        for _ in 0..n {
+            out.push_str(value);
+        }
+        Ok(())
+    })
+}
+
+/// Returns `array` with each character of each value found in `from` replaced by the
+/// character at the same position in `to`
+///
+/// Characters of `from` without a corresponding character in `to` are removed. If a
+/// character occurs more than once in `from`, its first occurrence is used.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::transform::translate;
+/// let array = StringArray::from(vec![Some("12345"), None]);
+/// let result = translate(&array, "143", "ax").unwrap();
+/// let expected = StringArray::from(vec![Some("a2x5"), None]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+/// ```
+pub fn translate(array: &dyn Array, from: &str, to: &str) -> Result<ArrayRef, ArrowError> {
+    let mut to = to.chars();
+    let mut mapping = HashMap::new();
+    for c in from.chars() {
+        let replacement = to.next();
+        mapping.entry(c).or_insert(replacement);
+    }
+    map_strings(array, "translate", |value, out| {
+        for c in value.chars() {
+            match mapping.get(&c) {
+                Some(Some(replacement)) => out.push(*replacement),
+                Some(None) => {}
+                None => out.push(c),
+            }
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    fn utf8(array: &ArrayRef) -> Vec<Option<&str>> {
+        array.as_string::<i32>().iter().collect()
+    }
+
+    #[test]
+    fn test_case() {
+        let array = StringArray::from(vec![Some("Arrow"), None, Some("RUST"), Some("")]);
+        let result = upper(&array.slice(1, 3)).unwrap();
+        assert_eq!(utf8(&result), vec![None, Some("RUST"), Some("")]);
+        let result = lower(&array).unwrap();
+        assert_eq!(
+            utf8(&result),
+            vec![Some("arrow"), None, Some("rust"), Some("")]
+        );
+
+        let array = LargeStringArray::from(vec![Some("ὈΔΥΣΣΕΎΣ"), None, Some("İ")]);
+        let result = lower(&array).unwrap();
+        let result: Vec<_> = result.as_string::<i64>().iter().collect();
+        assert_eq!(result, vec![Some("ὀδυσσεύς"), None, Some("i̇")]);
+
+        let array = StringViewArray::from(vec![Some("a longer string than twelve"), None]);
+        let result = upper(&array).unwrap();
+        let result: Vec<_> = result.as_string_view().iter().collect();
+        assert_eq!(result, vec![Some("A LONGER STRING THAN TWELVE"), None]);
+    }
+
+    #[test]
+    fn test_dictionary() {
+        let array: DictionaryArray<Int32Type> = vec![Some(" a"), None, Some(" a"), Some("b ")]
+            .into_iter()
+            .collect();
+        let result = trim(&array, None).unwrap();
+        let result = result.as_dictionary::<Int32Type>();
+        assert_eq!(result.keys(), array.keys());
+        let values: Vec<_> = result
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some("a"), None, Some("a"), Some("b")]);
+
+        let result = upper(&array).unwrap();
+        let values: Vec<_> = result
+            .as_dictionary::<Int32Type>()
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some(" A"), None, Some(" A"), Some("B ")]);
+    }
+
+    #[test]
+    fn test_trim() {
+        let array = StringArray::from(vec![Some("\t a b \n"), Some("xyaxy"), None]);
+        let result = ltrim(&array, None).unwrap();
+        assert_eq!(utf8(&result), vec![Some("a b \n"), Some("xyaxy"), None]);
+        let result = rtrim(&array, Some("yx")).unwrap();
+        assert_eq!(utf8(&result), vec![Some("\t a b \n"), Some("xya"), None]);
+        let result = trim(&array, Some("")).unwrap();
+        assert_eq!(utf8(&result), vec![Some("\t a b \n"), Some("xyaxy"), None]);
+    }
+
+    #[test]
+    fn test_pad() {
+        let array = StringArray::from(vec![Some("hi"), Some("ñandú"), None, Some("")]);
+        let result = rpad(&array, 4, "xy").unwrap();
+        assert_eq!(
+            utf8(&result),
+            vec![Some("hixy"), Some("ñand"), None, Some("xyxy")]
+        );
+        let result = lpad(&array, 3, "é").unwrap();
+        assert_eq!(
+            utf8(&result),
+            vec![Some("éhi"), Some("ñan"), None, Some("ééé")]
+        );
+        let result = lpad(&array, 3, "").unwrap();
+        assert_eq!(utf8(&result), vec![Some("hi"), Some("ñan"), None, Some("")]);
+
+        let err = rpad(&array, usize::MAX, "x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Compute error: rpad of a value of 2 bytes to {} characters overflows Utf8",
+                usize::MAX
+            )
+        );
+        let result = lpad(&array, usize::MAX, "").unwrap();
+        assert_eq!(
+            utf8(&result),
+            vec![Some("hi"), Some("ñandú"), None, Some("")]
+        );
+    }
+
+    #[test]
+    fn test_replace_reverse_translate() {
+        let array = StringViewArray::from(vec![Some("aaa"), Some("añb"), None]);
+        let result = replace(&array, "aa", "b").unwrap();
+        let result: Vec<_> = result.as_string_view().iter().collect();
+        assert_eq!(result, vec![Some("ba"), Some("añb"), None]);
+
+        let result = replace(&array, "", "b").unwrap();
+        let result: Vec<_> = result.as_string_view().iter().collect();
+        assert_eq!(result, vec![Some("aaa"), Some("añb"), None]);
+
+        let result = reverse(&array).unwrap();
+        let result: Vec<_> = result.as_string_view().iter().collect();
+        assert_eq!(result, vec![Some("aaa"), Some("bña"), None]);
+
+        let result = translate(&array, "aña", "xy").unwrap();
+        let result: Vec<_> = result.as_string_view().iter().collect();
+        assert_eq!(result, vec![Some("xxx"), Some("xyb"), None]);
+    }
+
+    #[test]
+    fn test_repeat() {
+        let array = StringArray::from(vec![Some("ab"), None]);
+        let result = repeat(&array, 0).unwrap();
+        assert_eq!(utf8(&result), vec![Some(""), None]);
+
+        let err = repeat(&array, usize::MAX).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Compute error: repeat of a value of 2 bytes {} times overflows Utf8",
+                usize::MAX
+            )
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let array = Int32Array::from(vec![1]);
+        let err = upper(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: upper does not support type Int32"
+        );
+    }
+}
@@ -17,14 +17,16 @@
 
 //! Computation kernels on Arrow Arrays
 
-pub use arrow_arith::{aggregate, arithmetic, arity, bitwise, boolean, numeric, temporal};
+pub use arrow_arith::{
+    aggregate, arithmetic, arity, bitwise, boolean, group_by, hash, math, numeric, temporal,
+};
 pub use arrow_cast::cast;
 pub use arrow_cast::parse as cast_utils;
 pub use arrow_ord::{cmp, partition, rank, sort};
 pub use arrow_select::{
-    coalesce, concat, filter, interleave, merge, nullif, take, union_extract, window, zip,
+    coalesce, concat, filter, interleave, list, merge, nullif, take, union_extract, window, zip,
 };
-pub use arrow_string::{concat_elements, length, regexp, substring};
+pub use arrow_string::{concat_elements, length, regexp, split, substring, transform};
 
 /// Comparison kernels for `Array`s.
 pub mod comparison {
@@ -16,13 +16,15 @@
 // under the License.
 
 use arrow_arith::numeric::{add, sub};
-use arrow_arith::temporal::{DatePart, date_part};
+use arrow_arith::temporal::{
+    DatePart, DstPolicy, TimeZoneMode, at_time_zone, date_bin, date_part, date_trunc,
+};
 use arrow_array::cast::AsArray;
 use arrow_array::temporal_conversions::as_datetime_with_timezone;
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::*;
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, NaiveDateTime, TimeZone};
 
 #[test]
 fn test_temporal_array_timestamp_hour_with_timezone_using_chrono_tz() {
@@ -188,3 +190,81 @@ fn test_timestamp_with_timezone() {
         test_timestamp_with_timezone_impl::<TimestampNanosecondType>(timezone);
     }
 }
+
+fn datetime(s: &str) -> NaiveDateTime {
+    s.parse().unwrap()
+}
+
+fn timestamps(values: &[&str], tz: Option<&str>) -> TimestampMicrosecondArray {
+    let values: Vec<_> = values
+        .iter()
+        .map(|v| TimestampMicrosecondType::from_naive_datetime(datetime(v), None).unwrap())
+        .collect();
+    TimestampMicrosecondArray::from(values).with_timezone_opt(tz)
+}
+
+#[test]
+fn test_date_bin_dst_timezone() {
+    // Bins of at least a day start at local midnight across daylight saving time
+    let tz = Some("Europe/Berlin");
+    let array = timestamps(&["2024-03-31T12:00:00"], tz);
+    let stride = IntervalMonthDayNanoType::make_value(0, 7, 0);
+    let result = date_bin(&array, stride, datetime("2024-03-25T00:00:00")).unwrap();
+    let expected = timestamps(&["2024-03-24T23:00:00"], tz);
+    assert_eq!(result.as_primitive::<TimestampMicrosecondType>(), &expected);
+    let week = date_trunc(&array, DatePart::Week).unwrap();
+    assert_eq!(week.as_primitive::<TimestampMicrosecondType>(), &expected);
+
+    // Shorter bins starting before a transition use the offset in effect at their start
+    let array = timestamps(&["2024-03-31T01:30:00"], tz);
+    let stride = IntervalMonthDayNanoType::make_value(0, 0, 6 * 3_600_000_000_000);
+    let result = date_bin(&array, stride, datetime("2024-01-01T00:00:00")).unwrap();
+    let expected = timestamps(&["2024-03-30T23:00:00"], tz);
+    assert_eq!(result.as_primitive::<TimestampMicrosecondType>(), &expected);
+}
+
+#[test]
+fn test_date_trunc_dst_timezone() {
+    let tz = Some("America/New_York");
+    // 2024-03-10T01:30:00-05:00 and 2024-11-03T01:30:00-05:00
+    let array = timestamps(&["2024-03-10T06:30:00", "2024-11-03T06:30:00"], tz);
+
+    // Midnight on both days is unambiguous
+    let result = date_trunc(&array, DatePart::Day).unwrap();
+    let expected = timestamps(&["2024-03-10T05:00:00", "2024-11-03T04:00:00"], tz);
+    assert_eq!(result.as_primitive::<TimestampMicrosecondType>(), &expected);
+
+    // 01:00 on 2024-11-03 occurs in both EDT and EST
+    let result = date_trunc(&array, DatePart::Hour).unwrap();
+    let expected = timestamps(&["2024-03-10T06:00:00", "2024-11-03T06:00:00"], tz);
+    assert_eq!(result.as_primitive::<TimestampMicrosecondType>(), &expected);
+
+    // 2024-03-10T02:30:00 does not exist, and 2024-11-03T01:30:00 is ambiguous
+    let naive = timestamps(&["2024-03-10T02:30:00", "2024-11-03T01:30:00"], None);
+    let reinterpret = |policy| at_time_zone(&naive, tz, TimeZoneMode::Reinterpret, policy);
+    let earlier = reinterpret(DstPolicy::Earlier).unwrap();
+    let expected = timestamps(&["2024-03-10T07:30:00", "2024-11-03T05:30:00"], tz);
+    assert_eq!(
+        earlier.as_primitive::<TimestampMicrosecondType>(),
+        &expected
+    );
+
+    let result = reinterpret(DstPolicy::Later).unwrap();
+    let expected = timestamps(&["2024-03-10T07:30:00", "2024-11-03T06:30:00"], tz);
+    assert_eq!(result.as_primitive::<TimestampMicrosecondType>(), &expected);
+
+    let result = reinterpret(DstPolicy::Null).unwrap();
+    assert_eq!(result.null_count(), 2);
+
+    let err = reinterpret(DstPolicy::Error).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Compute error: Local time 2024-03-10 02:30:00 is nonexistent in timezone \
+        America/New_York"
+    );
+
+    let mode = TimeZoneMode::Reinterpret;
+    let result = at_time_zone(&earlier, None, mode, DstPolicy::Error).unwrap();
+    let expected = timestamps(&["2024-03-10T03:30:00", "2024-11-03T01:30:00"], None);
+    assert_eq!(result.as_primitive::<TimestampMicrosecondType>(), &expected);
+}
@@ -849,13 +849,7 @@ mod tests {
 
     /// Builds a VariantArray from an Arrow array using the row builder.
     fn execute_row_builder_test(array: &dyn Array) -> VariantArray {
-        execute_row_builder_test_with_options(
-            array,
-            CastOptions {
-                safe: false,
-                ..Default::default()
-            },
-        )
+        execute_row_builder_test_with_options(array, CastOptions::new().with_safe(false))
     }
 
     /// Variant of `execute_row_builder_test` that allows specifying options
@@ -884,10 +878,7 @@ mod tests {
         test_row_builder_basic_with_options(
             array,
             expected_values,
-            CastOptions {
-                safe: false,
-                ..Default::default()
-            },
+            CastOptions::new().with_safe(false),
         );
     }
 
@@ -1021,10 +1012,7 @@ mod tests {
         let run_ends = Int32Array::from(vec![2, 5, 6]);
         let run_array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
 
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         let mut row_builder =
             make_arrow_to_variant_row_builder(run_array.data_type(), &run_array, &options).unwrap();
 
@@ -1050,10 +1038,7 @@ mod tests {
         let run_ends = Int32Array::from(vec![2, 4, 5]);
         let run_array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
 
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         let mut row_builder =
             make_arrow_to_variant_row_builder(run_array.data_type(), &run_array, &options).unwrap();
         let mut array_builder = VariantArrayBuilder::new(5);
@@ -1104,10 +1089,7 @@ mod tests {
         let keys = Int32Array::from(vec![Some(0), None, Some(1), None, Some(2)]);
         let dict_array = DictionaryArray::<Int32Type>::try_new(keys, Arc::new(values)).unwrap();
 
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         let mut row_builder =
             make_arrow_to_variant_row_builder(dict_array.data_type(), &dict_array, &options)
                 .unwrap();
@@ -1139,10 +1121,7 @@ mod tests {
         let keys = Int32Array::from(vec![0, 1, 2, 0, 1, 2]);
         let dict_array = DictionaryArray::<Int32Type>::try_new(keys, Arc::new(values)).unwrap();
 
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         let mut row_builder =
             make_arrow_to_variant_row_builder(dict_array.data_type(), &dict_array, &options)
                 .unwrap();
@@ -1182,10 +1161,7 @@ mod tests {
         let dict_array =
             DictionaryArray::<Int32Type>::try_new(keys, Arc::new(struct_array)).unwrap();
 
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         let mut row_builder =
             make_arrow_to_variant_row_builder(dict_array.data_type(), &dict_array, &options)
                 .unwrap();
@@ -1280,10 +1256,7 @@ mod tests {
         // Slice to get just the middle element: [[3, 4, 5]]
         let sliced_array = list_array.slice(1, 1);
 
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         let mut row_builder =
             make_arrow_to_variant_row_builder(sliced_array.data_type(), &sliced_array, &options)
                 .unwrap();
@@ -1327,10 +1300,7 @@ mod tests {
             Some(arrow::buffer::NullBuffer::from(vec![true, false])),
         );
 
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         let mut row_builder =
             make_arrow_to_variant_row_builder(outer_list.data_type(), &outer_list, &options)
                 .unwrap();
@@ -1517,10 +1487,7 @@ mod tests {
         .unwrap();
 
         // Test the row builder
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         let mut row_builder =
             make_arrow_to_variant_row_builder(union_array.data_type(), &union_array, &options)
                 .unwrap();
@@ -1572,10 +1539,7 @@ mod tests {
         .unwrap();
 
         // Test the row builder
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         let mut row_builder =
             make_arrow_to_variant_row_builder(union_array.data_type(), &union_array, &options)
                 .unwrap();
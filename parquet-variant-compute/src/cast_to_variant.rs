@@ -83,15 +83,9 @@ pub fn cast_to_variant_with_options(
 /// failures).
 ///
 /// This function provides backward compatibility. For non-strict behavior,
-/// use [`cast_to_variant_with_options`] with `CastOptions { safe: true, ..Default::default() }`.
+/// use [`cast_to_variant_with_options`] with `CastOptions::new().with_safe(true)`.
 pub fn cast_to_variant(input: &dyn Array) -> Result<VariantArray, ArrowError> {
-    cast_to_variant_with_options(
-        input,
-        &CastOptions {
-            safe: false,
-            ..Default::default()
-        },
-    )
+    cast_to_variant_with_options(input, &CastOptions::new().with_safe(false))
 }
 
 #[cfg(test)]
@@ -2280,10 +2274,7 @@ mod tests {
         values: ArrayRef,
         expected: Result<Vec<Option<Variant>>, ArrowError>,
     ) {
-        let options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let options = CastOptions::new().with_safe(false);
         match expected {
             Ok(expected) => run_test_with_options(values, expected, options),
             Err(_) => {
@@ -527,7 +527,6 @@ mod test {
     use arrow::compute::{CastOptions, cast};
     use arrow::datatypes::DataType::{Int16, Int32, Int64};
     use arrow::datatypes::i256;
-    use arrow::util::display::FormatOptions;
     use arrow_schema::ArrowError;
     use arrow_schema::DataType::{Boolean, Float32, Float64, Int8};
     use arrow_schema::{DataType, Field, FieldRef, Fields, IntervalUnit, TimeUnit};
@@ -1015,10 +1014,7 @@ mod test {
         // Extract the typed value as Int32Array
         let array = partially_shredded_int32_variant_array();
         let field = Field::new("typed_value", DataType::Int32, true);
-        let cast_options = CastOptions {
-            safe: false, // unsafe cast
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false); // unsafe cast
         let options = GetOptions::new()
             .with_as_type(Some(FieldRef::from(field)))
             .with_cast_options(cast_options);
@@ -1661,10 +1657,7 @@ mod test {
         let field = Field::new("typed_value", DataType::Null, true);
         let options = GetOptions::new()
             .with_as_type(Some(FieldRef::from(field)))
-            .with_cast_options(CastOptions {
-                safe: false,
-                format_options: FormatOptions::default(),
-            });
+            .with_cast_options(CastOptions::new().with_safe(false));
 
         let result = variant_get(&array, options);
 
@@ -1963,11 +1956,8 @@ mod test {
 
     #[test]
     fn test_shredded_list_like_index_out_of_bounds_unsafe_cast_returns_null() {
-        let options =
-            GetOptions::new_with_path(VariantPath::from(10)).with_cast_options(CastOptions {
-                safe: false,
-                ..Default::default()
-            });
+        let options = GetOptions::new_with_path(VariantPath::from(10))
+            .with_cast_options(CastOptions::new().with_safe(false));
 
         for (case, array_gen) in shredded_list_like_cases() {
             let result = variant_get(&array_gen(), options.clone()).unwrap();
@@ -2912,10 +2902,7 @@ mod test {
             let options = GetOptions {
                 path: VariantPath::try_from("nonexistent_field").unwrap(),
                 as_type: Some(Arc::new(Field::new("result", DataType::Int32, true))),
-                cast_options: CastOptions {
-                    safe,
-                    ..Default::default()
-                },
+                cast_options: CastOptions::new().with_safe(safe),
             };
 
             let result_array = variant_get(&variant_array, options).unwrap();
@@ -2938,10 +2925,7 @@ mod test {
         let options = GetOptions {
             path: VariantPath::from(0),
             as_type: Some(Arc::new(Field::new("result", DataType::Int32, true))),
-            cast_options: CastOptions {
-                safe: false,
-                ..Default::default()
-            },
+            cast_options: CastOptions::new().with_safe(false),
         };
 
         let variant_array_ref: Arc<dyn Array> = variant_array.clone();
@@ -2963,10 +2947,7 @@ mod test {
         let options = GetOptions {
             path: VariantPath::default(),
             as_type: Some(Arc::new(Field::new("result", DataType::Boolean, true))),
-            cast_options: CastOptions {
-                safe: false,
-                ..Default::default()
-            },
+            cast_options: CastOptions::new().with_safe(false),
         };
 
         let err = variant_get(&variant_array, options).unwrap_err();
@@ -2984,10 +2965,7 @@ mod test {
         let options = GetOptions {
             path: VariantPath::default(),
             as_type: Some(Arc::new(Field::new("result", DataType::Float32, true))),
-            cast_options: CastOptions {
-                safe: false,
-                ..Default::default()
-            },
+            cast_options: CastOptions::new().with_safe(false),
         };
 
         let err = variant_get(&variant_array, options).unwrap_err();
@@ -3009,10 +2987,7 @@ mod test {
                 DataType::Timestamp(TimeUnit::Nanosecond, None),
                 true,
             ))),
-            cast_options: CastOptions {
-                safe: false,
-                ..Default::default()
-            },
+            cast_options: CastOptions::new().with_safe(false),
         };
 
         let err = variant_get(&variant_array, options).unwrap_err();
@@ -3612,10 +3587,7 @@ mod test {
                 DataType::Struct(struct_fields),
                 true,
             ))),
-            cast_options: CastOptions {
-                safe: false,
-                ..Default::default()
-            },
+            cast_options: CastOptions::new().with_safe(false),
         };
 
         let err = variant_get(&variant_array_ref, options).unwrap_err();
@@ -3944,10 +3916,7 @@ mod test {
         let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
         let field = Field::new("result", DataType::Decimal32(9, 2), true);
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
         let options = GetOptions::new()
             .with_as_type(Some(FieldRef::from(field)))
             .with_cast_options(cast_options);
@@ -4094,10 +4063,7 @@ mod test {
         let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
         let field = Field::new("result", DataType::Decimal64(18, 2), true);
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
         let options = GetOptions::new()
             .with_as_type(Some(FieldRef::from(field)))
             .with_cast_options(cast_options);
@@ -4197,10 +4163,7 @@ mod test {
         let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
         let field = Field::new("result", DataType::Decimal128(38, 2), true);
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
         let options = GetOptions::new()
             .with_as_type(Some(FieldRef::from(field)))
             .with_cast_options(cast_options);
@@ -4312,10 +4275,7 @@ mod test {
         let variant_array: ArrayRef = ArrayRef::from(builder.build());
 
         let field = Field::new("result", DataType::Decimal256(76, 39), true);
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
         let options = GetOptions::new()
             .with_as_type(Some(FieldRef::from(field)))
             .with_cast_options(cast_options);
@@ -4542,10 +4502,8 @@ mod test {
         assert_eq!(result.as_ref(), &expected);
 
         // With strict casting, non-object rows are an error
-        let options = map_get_options(&data_type).with_cast_options(CastOptions {
-            safe: false,
-            format_options: FormatOptions::default(),
-        });
+        let options =
+            map_get_options(&data_type).with_cast_options(CastOptions::new().with_safe(false));
         let err = variant_get(&variant_array, options).unwrap_err();
         assert!(
             err.to_string().contains("Failed to extract object"),
@@ -4604,10 +4562,8 @@ mod test {
         let variant_array = invalid_time_variant_array();
 
         let field = Field::new("result", DataType::Time64(TimeUnit::Microsecond), true);
-        let cast_options = CastOptions {
-            safe: false, // Will error on cast failure
-            ..Default::default()
-        };
+        // Will error on cast failure
+        let cast_options = CastOptions::new().with_safe(false);
         let options = GetOptions::new()
             .with_as_type(Some(FieldRef::from(field)))
             .with_cast_options(cast_options);
@@ -4625,10 +4581,8 @@ mod test {
         let variant_array = invalid_time_variant_array();
 
         let field = Field::new("result", DataType::Time64(TimeUnit::Microsecond), true);
-        let cast_options = CastOptions {
-            safe: true, // Will return null on cast failure
-            ..Default::default()
-        };
+        // Will return null on cast failure
+        let cast_options = CastOptions::new().with_safe(true);
         let options = GetOptions::new()
             .with_as_type(Some(FieldRef::from(field)))
             .with_cast_options(cast_options);
@@ -4928,10 +4882,7 @@ mod test {
         let string_array: ArrayRef =
             Arc::new(StringArray::from(vec![r#"[1, "two", 3]"#, "[4, 5]"]));
         let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
 
         let item_field = Arc::new(Field::new("item", DataType::Int64, true));
         let request_types = vec![
@@ -4962,10 +4913,7 @@ mod test {
     fn test_variant_get_list_like_unsafe_cast_preserves_null_elements() {
         let string_array: ArrayRef = Arc::new(StringArray::from(vec!["[1, null, 3]"]));
         let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
         let options = GetOptions::new()
             .with_as_type(Some(FieldRef::from(Field::new(
                 "result",
@@ -4992,10 +4940,7 @@ mod test {
     fn test_variant_get_list_like_unsafe_cast_errors_on_non_list() {
         let string_array: ArrayRef = Arc::new(StringArray::from(vec!["[1, 2]", "\"not a list\""]));
         let variant_array = ArrayRef::from(json_to_variant(&string_array).unwrap());
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
         let item_field = Arc::new(Field::new("item", Int64, true));
         let data_types = vec![
             DataType::List(item_field.clone()),
@@ -5031,10 +4976,7 @@ mod test {
                 DataType::FixedSizeList(item_field.clone(), 2),
                 true,
             ))))
-            .with_cast_options(CastOptions {
-                safe: true,
-                ..Default::default()
-            });
+            .with_cast_options(CastOptions::new().with_safe(true));
         let result = variant_get(&variant_array, options).unwrap();
         let fixed_size_list = result
             .as_any()
@@ -5050,10 +4992,7 @@ mod test {
                 DataType::FixedSizeList(item_field.clone(), 2),
                 true,
             ))))
-            .with_cast_options(CastOptions {
-                safe: false,
-                ..Default::default()
-            });
+            .with_cast_options(CastOptions::new().with_safe(false));
         let err = variant_get(&variant_array, options).unwrap_err();
         assert!(
             err.to_string()
@@ -1569,10 +1569,7 @@ mod tests {
 
     #[test]
     fn strict_cast_allows_variant_null_for_primitive_builder() {
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
         let mut builder =
             make_primitive_variant_to_arrow_row_builder(&DataType::Int32, &cast_options, 2, false)
                 .unwrap();
@@ -1588,10 +1585,7 @@ mod tests {
 
     #[test]
     fn strict_cast_allows_variant_null_for_decimal_builder() {
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
         let mut builder = make_primitive_variant_to_arrow_row_builder(
             &DataType::Decimal32(9, 2),
             &cast_options,
@@ -1612,10 +1606,7 @@ mod tests {
 
     #[test]
     fn strict_cast_allows_variant_null_for_uuid_builder() {
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
         let mut builder = make_primitive_variant_to_arrow_row_builder(
             &DataType::FixedSizeBinary(16),
             &cast_options,
@@ -1639,10 +1630,7 @@ mod tests {
 
     #[test]
     fn strict_cast_allows_variant_null_for_list_and_struct_builders() {
-        let cast_options = CastOptions {
-            safe: false,
-            ..Default::default()
-        };
+        let cast_options = CastOptions::new().with_safe(false);
 
         let list_type = DataType::List(Arc::new(Field::new("item", DataType::Int64, true)));
         let mut list_builder =
@@ -319,12 +319,43 @@ impl<'a> ArrayReaderBuilder<'a> {
                 ))))
             }
             (None, None) => Ok(None),
-            _ => Err(general_err!(
-                "partial projection of MapArray is not supported"
-            )),
+            // Only one of the key or value is projected, read it as a list.
+            //
+            // The fields are converted from the full file schema and pruned by
+            // the projection mask here, so partially projected maps reach this
+            // reader as a `Map`, consistent with `Visitor::visit_map`
+            (Some(key_reader), None) => self.build_partial_map_reader(args, key_reader, 0),
+            (None, Some(value_reader)) => self.build_partial_map_reader(args, value_reader, 1),
         }
     }
 
+    /// Build a list array reader for a map of which only the key (`idx` 0) or
+    /// the value (`idx` 1) is projected
+    fn build_partial_map_reader(
+        &self,
+        args: ReaderArgs<'_>,
+        item_reader: Box<dyn ArrayReader>,
+        idx: usize,
+    ) -> Result<Option<Box<dyn ArrayReader>>> {
+        let field = args.field;
+        let item_field = match &field.arrow_type {
+            DataType::Map(map_field, _) => match map_field.data_type() {
+                DataType::Struct(fields) if fields.len() == 2 => fields[idx].as_ref().clone(),
+                d => return Err(general_err!("invalid map entries type {d}")),
+            },
+            d => return Err(general_err!("expected map type, got {d}")),
+        };
+        let item_field = item_field.with_data_type(item_reader.get_data_type().clone());
+        Ok(Some(Box::new(ListArrayReader::<i32>::new(
+            item_reader,
+            DataType::List(Arc::new(item_field)),
+            field.def_level,
+            field.rep_level,
+            field.nullable,
+            args.padding_threshold,
+        ))))
+    }
+
     /// Build array reader for list type.
     fn build_list_reader(&self, args: ReaderArgs<'_>) -> Result<Option<Box<dyn ArrayReader>>> {
         let field = args.field;
@@ -694,4 +725,48 @@ mod tests {
 
         assert_eq!(array_reader.get_data_type(), &arrow_type);
     }
+
+    #[test]
+    fn test_create_partial_map_array_reader() {
+        use crate::arrow::ArrowWriter;
+        use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+        use arrow_array::{ArrayRef, RecordBatch};
+
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        map.keys().append_value("a");
+        map.values().append_value(1);
+        map.append(true).unwrap();
+        let map = Arc::new(map.finish()) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("map", map)]).unwrap();
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let file_reader: Arc<dyn FileReader> =
+            Arc::new(SerializedFileReader::new(bytes::Bytes::from(buffer)).unwrap());
+        let file_metadata = file_reader.metadata().file_metadata();
+        // The fields describe the whole map, the mask only projects its values
+        let (_, fields) = parquet_to_arrow_schema_and_fields(
+            file_metadata.schema_descr(),
+            ProjectionMask::all(),
+            file_metadata.key_value_metadata(),
+            &[],
+        )
+        .unwrap();
+        let mask = ProjectionMask::leaves(file_metadata.schema_descr(), [1]);
+
+        let metrics = ArrowReaderMetrics::disabled();
+        let array_reader = ArrayReaderBuilder::new(&file_reader, &metrics)
+            .with_batch_size(DEFAULT_BATCH_SIZE)
+            .build_array_reader(fields.as_ref(), &mask)
+            .unwrap();
+
+        let arrow_type = DataType::Struct(Fields::from(vec![Field::new_list(
+            "map",
+            Field::new("value", DataType::Int32, true),
+            false,
+        )]));
+        assert_eq!(array_reader.get_data_type(), &arrow_type);
+    }
 }
@@ -133,10 +133,15 @@ mod tests {
     use super::*;
     use crate::arrow::ArrowWriter;
     use crate::arrow::arrow_reader::ParquetRecordBatchReader;
+    use crate::arrow::arrow_reader::{
+        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowFilter,
+    };
+    use crate::arrow::{ProjectionMask, parquet_to_arrow_schema_by_columns};
     use arrow::datatypes::{Field, Int32Type, Schema};
     use arrow_array::RecordBatch;
     use arrow_array::builder::{MapBuilder, PrimitiveBuilder, StringBuilder};
     use arrow_array::cast::*;
+    use arrow_array::{BooleanArray, Int32Array, ListArray, StringArray};
     use arrow_schema::Fields;
     use bytes::Bytes;
 
@@ -220,4 +225,107 @@ mod tests {
             assert_eq!(key_col.value(4), "seven");
         }
     }
+
+    /// Writes a file with a single map column containing
+    /// `[null, {}, {a -> 1, b -> null}, {c -> 3}]`
+    fn write_map_column() -> Bytes {
+        let mut map_builder = MapBuilder::new(
+            None,
+            StringBuilder::new(),
+            PrimitiveBuilder::<Int32Type>::new(),
+        );
+        map_builder.append(false).unwrap();
+        map_builder.append(true).unwrap();
+        map_builder.keys().append_value("a");
+        map_builder.values().append_value(1);
+        map_builder.keys().append_value("b");
+        map_builder.values().append_null();
+        map_builder.append(true).unwrap();
+        map_builder.keys().append_value("c");
+        map_builder.values().append_value(3);
+        map_builder.append(true).unwrap();
+
+        let batch =
+            RecordBatch::try_from_iter([("map", Arc::new(map_builder.finish()) as ArrayRef)])
+                .unwrap();
+        let mut buffer = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buffer)
+    }
+
+    fn read_map_leaf(data: Bytes, leaf: usize) -> RecordBatch {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+        let parquet_schema = builder.parquet_schema();
+        let mask = ProjectionMask::leaves(parquet_schema, [leaf]);
+        let schema =
+            parquet_to_arrow_schema_by_columns(parquet_schema, mask.clone(), None).unwrap();
+
+        let mut reader = builder.with_projection(mask).build().unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert!(reader.next().is_none());
+        assert_eq!(batch.schema().fields(), schema.fields());
+        batch
+    }
+
+    #[test]
+    fn read_map_keys_only() {
+        let batch = read_map_leaf(write_map_column(), 0);
+        let ArrowType::List(field) = batch.schema().field(0).data_type().clone() else {
+            panic!("expected list")
+        };
+        assert_eq!(field.name(), Field::MAP_KEY_FIELD_DEFAULT_NAME);
+        assert!(!field.is_nullable());
+
+        let list = batch.column(0).as_list::<i32>();
+        assert!(list.is_null(0));
+        assert!(list.is_valid(1));
+        assert_eq!(list.value_offsets(), &[0, 0, 0, 2, 3]);
+        let keys = list.values().as_string::<i32>();
+        assert_eq!(keys, &StringArray::from(vec!["a", "b", "c"]));
+    }
+
+    #[test]
+    fn read_map_values_only() {
+        let batch = read_map_leaf(write_map_column(), 1);
+        let ArrowType::List(field) = batch.schema().field(0).data_type().clone() else {
+            panic!("expected list")
+        };
+        assert_eq!(field.name(), Field::MAP_VALUE_FIELD_DEFAULT_NAME);
+        assert!(field.is_nullable());
+
+        let list = batch.column(0).as_list::<i32>();
+        assert!(list.is_null(0));
+        assert_eq!(list.value_offsets(), &[0, 0, 0, 2, 3]);
+        let values = list.values().as_primitive::<Int32Type>();
+        assert_eq!(values, &Int32Array::from(vec![Some(1), None, Some(3)]));
+    }
+
+    #[test]
+    fn filter_on_map_keys() {
+        let data = write_map_column();
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+        let parquet_schema = builder.parquet_schema();
+        let keys = ProjectionMask::leaves(parquet_schema, [0]);
+        let has_key_c = ArrowPredicateFn::new(keys, |batch| {
+            let list: &ListArray = batch.column(0).as_list();
+            Ok(list
+                .iter()
+                .map(|keys| {
+                    Some(keys.is_some_and(|k| k.as_string::<i32>().iter().any(|k| k == Some("c"))))
+                })
+                .collect::<BooleanArray>())
+        });
+
+        let mut reader = builder
+            .with_row_filter(RowFilter::new(vec![Box::new(has_key_c)]))
+            .build()
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        let map = batch.column(0).as_map();
+        assert_eq!(map.keys().as_string::<i32>().value(0), "c");
+        assert_eq!(map.values().as_primitive::<Int32Type>().value(0), 3);
+    }
 }
@@ -23,10 +23,13 @@ use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
 use arrow_schema::{ArrowError, DataType as ArrowType, FieldRef, Schema, SchemaRef};
 use arrow_select::filter::filter_record_batch;
 pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
+use predicate::PredicatePruner;
+pub use predicate::{CompareOp, Predicate};
 use selection::MaskCursor;
 pub use selection::{
     MaskRunIter, RowSelection, RowSelectionCursor, RowSelectionPolicy, RowSelector,
 };
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
@@ -57,6 +60,7 @@ pub use read_plan::{PredicateOptions, ReadPlan, ReadPlanBuilder};
 
 mod filter;
 pub mod metrics;
+mod predicate;
 mod read_plan;
 pub(crate) mod selection;
 pub mod statistics;
@@ -80,6 +84,7 @@ pub const DEFAULT_BATCH_SIZE: usize = 1024;
 /// * Row group filtering: [`Self::with_row_groups`]
 /// * Range filtering: [`Self::with_row_selection`]
 /// * Row level filtering: [`Self::with_row_filter`]
+/// * Predicate pushdown: [`Self::with_predicate`]
 ///
 /// # Implementing Predicate Pushdown
 ///
@@ -95,6 +100,9 @@ pub const DEFAULT_BATCH_SIZE: usize = 1024;
 /// * [`Self::with_row_selection`] for data page pruning
 /// * [`StatisticsConverter`] to convert Parquet statistics to Arrow arrays
 ///
+/// For simple predicates, [`Self::with_predicate`] combines these steps
+/// using a [`Predicate`] expression.
+///
 /// The rationale for this design is that implementing predicate pushdown is a
 /// complex topic and varies significantly from system to system. For example
 ///
@@ -136,6 +144,8 @@ pub struct ArrowReaderBuilder<T> {
 
     pub(crate) filter: Option<RowFilter>,
 
+    pub(crate) predicate: Option<Predicate>,
+
     pub(crate) selection: Option<RowSelection>,
 
     pub(crate) row_selection_policy: RowSelectionPolicy,
@@ -160,6 +170,7 @@ impl<T: Debug> Debug for ArrowReaderBuilder<T> {
             .field("row_groups", &self.row_groups)
             .field("projection", &self.projection)
             .field("filter", &self.filter)
+            .field("predicate", &self.predicate)
             .field("selection", &self.selection)
             .field("row_selection_policy", &self.row_selection_policy)
             .field("limit", &self.limit)
@@ -180,6 +191,7 @@ impl<T> ArrowReaderBuilder<T> {
             row_groups: None,
             projection: ProjectionMask::all(),
             filter: None,
+            predicate: None,
             selection: None,
             row_selection_policy: RowSelectionPolicy::default(),
             limit: None,
@@ -189,6 +201,82 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Resolve the [predicate](Self::with_predicate), if any
+    pub(crate) fn predicate_pruner(&self) -> Result<Option<PredicatePruner>> {
+        let schema = self.metadata.file_metadata().schema_descr_ptr();
+        self.predicate
+            .as_ref()
+            .map(|p| PredicatePruner::try_new(p, Arc::clone(&self.schema), schema))
+            .transpose()
+    }
+
+    /// Returns the row groups to read
+    pub(crate) fn selected_row_groups(&self) -> Vec<usize> {
+        match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..self.metadata.num_row_groups()).collect(),
+        }
+    }
+
+    /// Retain only the row groups to read where `keep` is true, removing the
+    /// rows of the other row groups from the row selection, if any
+    pub(crate) fn retain_row_groups(&mut self, keep: &[bool]) {
+        if keep.iter().all(|keep| *keep) {
+            return;
+        }
+        let row_groups = self.selected_row_groups();
+        if let Some(mut selection) = self.selection.take() {
+            let mut selectors = vec![];
+            for (row_group, keep) in row_groups.iter().zip(keep) {
+                let num_rows = self.metadata.row_group(*row_group).num_rows() as usize;
+                let row_group_selection = selection.split_off(num_rows);
+                if *keep {
+                    selectors.extend(row_group_selection.iter().cloned());
+                }
+            }
+            self.selection = Some(selectors.into());
+        }
+        let row_groups = row_groups.into_iter().zip(keep);
+        self.row_groups = Some(row_groups.filter(|(_, k)| **k).map(|(rg, _)| rg).collect());
+    }
+
+    /// Skip the row groups whose statistics show they do not match `pruner`
+    pub(crate) fn prune_row_groups_by_statistics(
+        &mut self,
+        pruner: &PredicatePruner,
+    ) -> Result<()> {
+        let keep = pruner.prune_row_groups(&self.metadata, &self.selected_row_groups())?;
+        self.retain_row_groups(&keep);
+        Ok(())
+    }
+
+    /// Apply the [predicate](Self::with_predicate), if any, to the row groups,
+    /// row selection and row filter
+    ///
+    /// Bloom filters are not read, as this performs no IO
+    pub(crate) fn apply_predicate(&mut self) -> Result<()> {
+        let Some(pruner) = self.predicate_pruner()? else {
+            return Ok(());
+        };
+        self.predicate = None;
+        self.prune_row_groups_by_statistics(&pruner)?;
+
+        let row_groups = self.selected_row_groups();
+        if let Some(pages) = pruner.page_selection(&self.metadata, &row_groups)? {
+            self.selection = Some(match self.selection.take() {
+                Some(selection) => selection.intersection(&pages),
+                None => pages,
+            });
+        }
+
+        let mut predicates = vec![pruner.row_filter()];
+        if let Some(filter) = self.filter.take() {
+            predicates.extend(filter.predicates);
+        }
+        self.filter = Some(RowFilter::new(predicates));
+        Ok(())
+    }
+
     /// Returns a reference to the [`ParquetMetaData`] for this parquet file
     pub fn metadata(&self) -> &Arc<ParquetMetaData> {
         &self.metadata
@@ -356,6 +444,65 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Provide a [`Predicate`] to skip row groups, data pages and rows that
+    /// do not match it
+    ///
+    /// When the reader is built, the predicate is used to:
+    ///
+    /// 1. Skip row groups using their column statistics
+    /// 2. Skip row groups using bloom filters, if present, see below
+    /// 3. Skip data pages using the page index, if loaded, see [`ArrowReaderOptions::with_page_index_policy`]
+    /// 4. Filter the remaining rows, as the first predicate of the [`RowFilter`]
+    ///
+    /// This is applied in addition to [`Self::with_row_groups`],
+    /// [`Self::with_row_selection`] and [`Self::with_row_filter`].
+    ///
+    /// Bloom filters are read by [`ParquetRecordBatchReaderBuilder::build`].
+    /// As building an async reader performs no IO, they are instead read by
+    /// [`ParquetRecordBatchStreamBuilder::prune_with_bloom_filters`].
+    ///
+    /// An error is returned when the reader is built if the predicate refers
+    /// to columns that are not top-level primitive columns, or values that do
+    /// not match the type of the column.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ParquetRecordBatchReaderBuilder, Predicate};
+    /// # use parquet::file::properties::WriterProperties;
+    /// # let col = Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef;
+    /// # let batch = RecordBatch::try_from_iter([("id", col)]).unwrap();
+    /// # let props = WriterProperties::builder().set_max_row_group_row_count(Some(10)).build();
+    /// # let mut file = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// // id >= 25 AND id < 32
+    /// let predicate = Predicate::gt_eq("id", &Int32Array::from(vec![25]))
+    ///     .and(Predicate::lt("id", &Int32Array::from(vec![32])));
+    ///
+    /// let reader = ParquetRecordBatchReaderBuilder::try_new(file)?
+    ///     .with_predicate(predicate)
+    ///     .build()?;
+    ///
+    /// // Only row groups 2 and 3 are read
+    /// let num_rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+    /// assert_eq!(num_rows, 7);
+    /// # Ok::<(), parquet::errors::ParquetError>(())
+    /// ```
+    ///
+    /// [`ParquetRecordBatchStreamBuilder::prune_with_bloom_filters`]: crate::arrow::async_reader::ParquetRecordBatchStreamBuilder::prune_with_bloom_filters
+    pub fn with_predicate(self, predicate: Predicate) -> Self {
+        Self {
+            predicate: Some(predicate),
+            ..self
+        }
+    }
+
     /// Provide a limit to the number of rows to be read
     ///
     /// The limit will be applied after any [`Self::with_row_selection`] and [`Self::with_row_filter`]
@@ -1189,10 +1336,39 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
         Ok(Some(Sbbf::new(&bitset)))
     }
 
+    /// Skip the row groups whose statistics or bloom filters show they do not
+    /// match the [predicate](Self::with_predicate), if any
+    fn prune_with_bloom_filters(&mut self) -> Result<()> {
+        let Some(pruner) = self.predicate_pruner()? else {
+            return Ok(());
+        };
+        self.prune_row_groups_by_statistics(&pruner)?;
+
+        let columns = pruner.bloom_filter_columns();
+        if columns.is_empty() {
+            return Ok(());
+        }
+        let mut keep = vec![];
+        for row_group in self.selected_row_groups() {
+            let mut bloom_filters = HashMap::new();
+            for column in &columns {
+                if let Some(sbbf) = self.get_row_group_column_bloom_filter(row_group, *column)? {
+                    bloom_filters.insert(*column, sbbf);
+                }
+            }
+            keep.push(pruner.bloom_filters_may_match(&bloom_filters));
+        }
+        self.retain_row_groups(&keep);
+        Ok(())
+    }
+
     /// Build a [`ParquetRecordBatchReader`]
     ///
     /// Note: this will eagerly evaluate any `RowFilter` before returning
-    pub fn build(self) -> Result<ParquetRecordBatchReader> {
+    pub fn build(mut self) -> Result<ParquetRecordBatchReader> {
+        self.prune_with_bloom_filters()?;
+        self.apply_predicate()?;
+
         let Self {
             input,
             metadata,
@@ -1202,6 +1378,8 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             row_groups,
             projection,
             mut filter,
+            // Applied above
+            predicate: _,
             selection,
             row_selection_policy,
             limit,
@@ -0,0 +1,1171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A predicate expression tree used to prune and filter data, see [`Predicate`]
+
+use std::collections::HashMap;
+use std::ops::Not;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::{
+    Array, ArrayAccessor, ArrayRef, BooleanArray, Datum, RecordBatch, Scalar, UInt64Array,
+    make_array,
+};
+use arrow_buffer::BooleanBuffer;
+use arrow_ord::cmp;
+use arrow_schema::{ArrowError, DataType, Schema, SchemaRef};
+use arrow_select::take::take;
+
+use crate::arrow::ProjectionMask;
+use crate::arrow::arrow_reader::statistics::StatisticsConverter;
+use crate::arrow::arrow_reader::{ArrowPredicate, ArrowPredicateFn, RowSelection, RowSelector};
+use crate::arrow::parquet_column;
+use crate::basic::Type as PhysicalType;
+use crate::bloom_filter::Sbbf;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ParquetMetaData;
+use crate::file::page_index::column_index::ColumnIndexMetaData;
+use crate::schema::types::{SchemaDescPtr, SchemaDescriptor};
+
+/// The comparison performed by [`Predicate::Compare`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    /// `column = value`
+    Eq,
+    /// `column != value`
+    NotEq,
+    /// `column < value`
+    Lt,
+    /// `column <= value`
+    LtEq,
+    /// `column > value`
+    Gt,
+    /// `column >= value`
+    GtEq,
+}
+
+impl CompareOp {
+    fn negate(self) -> Self {
+        match self {
+            Self::Eq => Self::NotEq,
+            Self::NotEq => Self::Eq,
+            Self::Lt => Self::GtEq,
+            Self::LtEq => Self::Gt,
+            Self::Gt => Self::LtEq,
+            Self::GtEq => Self::Lt,
+        }
+    }
+}
+
+/// A boolean expression over the columns of a parquet file
+///
+/// A [`Predicate`] can be provided to [`ArrowReaderBuilder::with_predicate`] to
+/// skip row groups and data pages that cannot contain matching rows, and to
+/// filter the remaining rows while decoding.
+///
+/// Columns are referenced by the name of a top-level, non-nested field of the
+/// arrow schema. Values must have the same type as the column, or the value
+/// type for dictionary columns. Comparisons follow SQL semantics, in
+/// particular rows where the column is null never match a comparison.
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int32Array, StringArray};
+/// # use parquet::arrow::arrow_reader::Predicate;
+/// // a >= 10 AND (b LIKE 'foo%' OR b IS NULL)
+/// let predicate = Predicate::gt_eq("a", &Int32Array::from(vec![10])).and(
+///     Predicate::starts_with("b", "foo").or(Predicate::is_null("b")),
+/// );
+///
+/// // a NOT IN (1, 2, 3)
+/// let predicate = !Predicate::in_list("a", &Int32Array::from(vec![1, 2, 3]));
+/// ```
+///
+/// [`ArrowReaderBuilder::with_predicate`]: super::ArrowReaderBuilder::with_predicate
+#[derive(Debug, Clone)]
+pub enum Predicate {
+    /// `column <op> value`, where `value` contains a single element
+    Compare {
+        /// The name of the column
+        column: String,
+        /// The comparison to perform
+        op: CompareOp,
+        /// The value to compare against
+        value: ArrayRef,
+    },
+    /// `column [NOT] IN (values)`
+    InList {
+        /// The name of the column
+        column: String,
+        /// The values to compare against
+        values: ArrayRef,
+        /// If true, the predicate is `NOT IN`
+        negated: bool,
+    },
+    /// `column IS [NOT] NULL`
+    IsNull {
+        /// The name of the column
+        column: String,
+        /// If true, the predicate is `IS NOT NULL`
+        negated: bool,
+    },
+    /// `column [NOT] LIKE 'prefix%'`
+    StartsWith {
+        /// The name of the column, which must be a string column
+        column: String,
+        /// The prefix to match
+        prefix: String,
+        /// If true, the predicate is `NOT LIKE`
+        negated: bool,
+    },
+    /// Both predicates are true
+    And(Box<Predicate>, Box<Predicate>),
+    /// Either predicate is true
+    Or(Box<Predicate>, Box<Predicate>),
+    /// The predicate is false
+    Not(Box<Predicate>),
+}
+
+impl Predicate {
+    /// `column <op> value`, where `value` contains a single element
+    pub fn compare(column: impl Into<String>, op: CompareOp, value: &dyn Datum) -> Self {
+        let (value, _) = value.get();
+        Self::Compare {
+            column: column.into(),
+            op,
+            value: make_array(value.to_data()),
+        }
+    }
+
+    /// `column = value`
+    pub fn eq(column: impl Into<String>, value: &dyn Datum) -> Self {
+        Self::compare(column, CompareOp::Eq, value)
+    }
+
+    /// `column != value`
+    pub fn not_eq(column: impl Into<String>, value: &dyn Datum) -> Self {
+        Self::compare(column, CompareOp::NotEq, value)
+    }
+
+    /// `column < value`
+    pub fn lt(column: impl Into<String>, value: &dyn Datum) -> Self {
+        Self::compare(column, CompareOp::Lt, value)
+    }
+
+    /// `column <= value`
+    pub fn lt_eq(column: impl Into<String>, value: &dyn Datum) -> Self {
+        Self::compare(column, CompareOp::LtEq, value)
+    }
+
+    /// `column > value`
+    pub fn gt(column: impl Into<String>, value: &dyn Datum) -> Self {
+        Self::compare(column, CompareOp::Gt, value)
+    }
+
+    /// `column >= value`
+    pub fn gt_eq(column: impl Into<String>, value: &dyn Datum) -> Self {
+        Self::compare(column, CompareOp::GtEq, value)
+    }
+
+    /// `column IN (values)`
+    pub fn in_list(column: impl Into<String>, values: &dyn Array) -> Self {
+        Self::InList {
+            column: column.into(),
+            values: make_array(values.to_data()),
+            negated: false,
+        }
+    }
+
+    /// `column IS NULL`
+    pub fn is_null(column: impl Into<String>) -> Self {
+        Self::IsNull {
+            column: column.into(),
+            negated: false,
+        }
+    }
+
+    /// `column IS NOT NULL`
+    pub fn is_not_null(column: impl Into<String>) -> Self {
+        Self::IsNull {
+            column: column.into(),
+            negated: true,
+        }
+    }
+
+    /// `column LIKE 'prefix%'`
+    pub fn starts_with(column: impl Into<String>, prefix: impl Into<String>) -> Self {
+        Self::StartsWith {
+            column: column.into(),
+            prefix: prefix.into(),
+            negated: false,
+        }
+    }
+
+    /// `self AND other`
+    pub fn and(self, other: Predicate) -> Self {
+        Self::And(Box::new(self), Box::new(other))
+    }
+
+    /// `self OR other`
+    pub fn or(self, other: Predicate) -> Self {
+        Self::Or(Box::new(self), Box::new(other))
+    }
+}
+
+impl Not for Predicate {
+    type Output = Predicate;
+
+    fn not(self) -> Self::Output {
+        Self::Not(Box::new(self))
+    }
+}
+
+/// A leaf of a resolved [`Predicate`]
+#[derive(Debug, Clone)]
+enum LeafKind {
+    Compare(CompareOp, ArrayRef),
+    InList(ArrayRef, bool),
+    IsNull(bool),
+    StartsWith(String, bool),
+}
+
+/// A predicate on a single column
+#[derive(Debug, Clone)]
+struct Leaf {
+    /// The name of the arrow field
+    column: String,
+    /// The index of the parquet leaf column
+    parquet_idx: usize,
+    /// The physical type of the parquet leaf column
+    physical_type: PhysicalType,
+    /// If the column is a floating point column, for which the statistics
+    /// do not account for NaN
+    float: bool,
+    kind: LeafKind,
+}
+
+/// A [`Predicate`] in negation normal form with resolved columns
+#[derive(Debug, Clone)]
+enum Expr {
+    Leaf(Leaf),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+/// Statistics for a set of containers, either row groups or data pages
+struct Containers {
+    mins: ArrayRef,
+    /// `None` if the maximum values cannot be used for pruning
+    maxes: Option<ArrayRef>,
+    null_counts: UInt64Array,
+    row_counts: Option<UInt64Array>,
+}
+
+impl Containers {
+    fn len(&self) -> usize {
+        self.mins.len()
+    }
+
+    /// Returns a mask of the containers that contain at least one non-null value
+    fn not_all_null(&self) -> BooleanBuffer {
+        let Some(row_counts) = &self.row_counts else {
+            return BooleanBuffer::new_set(self.len());
+        };
+        BooleanBuffer::collect_bool(self.len(), |i| {
+            self.null_counts.is_null(i)
+                || row_counts.is_null(i)
+                || self.null_counts.value(i) < row_counts.value(i)
+        })
+    }
+}
+
+/// Evaluates a [`Predicate`] against the metadata and data of a parquet file
+#[derive(Debug)]
+pub(crate) struct PredicatePruner {
+    expr: Expr,
+    arrow_schema: SchemaRef,
+    parquet_schema: SchemaDescPtr,
+}
+
+impl PredicatePruner {
+    /// Resolve the columns of `predicate`, returning an error if they are not
+    /// top-level primitive columns, or the values have the wrong type
+    pub(crate) fn try_new(
+        predicate: &Predicate,
+        arrow_schema: SchemaRef,
+        parquet_schema: SchemaDescPtr,
+    ) -> Result<Self> {
+        let expr = resolve(predicate, false, &arrow_schema, &parquet_schema)?;
+        Ok(Self {
+            expr,
+            arrow_schema,
+            parquet_schema,
+        })
+    }
+
+    fn converter(&self, leaf: &Leaf) -> Result<StatisticsConverter<'_>> {
+        StatisticsConverter::try_new(&leaf.column, &self.arrow_schema, &self.parquet_schema)
+    }
+
+    /// Returns a mask of the `row_groups` that may contain rows matching the
+    /// predicate, based on their column statistics
+    pub(crate) fn prune_row_groups(
+        &self,
+        metadata: &ParquetMetaData,
+        row_groups: &[usize],
+    ) -> Result<Vec<bool>> {
+        let row_group_metadata: Vec<_> = row_groups
+            .iter()
+            .map(|idx| metadata.row_group(*idx))
+            .collect();
+        let mask = self.evaluate(&self.expr, &mut |leaf| {
+            let converter = self.converter(leaf)?;
+            let iter = || row_group_metadata.iter().copied();
+            let containers = Containers {
+                mins: converter.row_group_mins(iter())?,
+                maxes: (!leaf.float)
+                    .then(|| converter.row_group_maxes(iter()))
+                    .transpose()?,
+                null_counts: converter.row_group_null_counts(iter())?,
+                row_counts: converter.row_group_row_counts(iter())?,
+            };
+            leaf_may_match(leaf, &containers)
+        })?;
+        Ok(mask.iter().collect())
+    }
+
+    /// Returns a [`RowSelection`] of the data pages of `row_groups` that may
+    /// contain rows matching the predicate, based on the page index
+    ///
+    /// Returns `None` if the page index is not available
+    pub(crate) fn page_selection(
+        &self,
+        metadata: &ParquetMetaData,
+        row_groups: &[usize],
+    ) -> Result<Option<RowSelection>> {
+        let (Some(column_index), Some(offset_index)) =
+            (metadata.column_index(), metadata.offset_index())
+        else {
+            return Ok(None);
+        };
+        self.evaluate_pages(&self.expr, &mut |leaf| {
+            let has_index = row_groups.iter().all(|rg| {
+                !matches!(
+                    column_index[*rg].get(leaf.parquet_idx),
+                    None | Some(ColumnIndexMetaData::NONE)
+                ) && offset_index[*rg].get(leaf.parquet_idx).is_some()
+            });
+            if !has_index {
+                return Ok(None);
+            }
+
+            let converter = self.converter(leaf)?;
+            let Some(row_counts) =
+                converter.data_page_row_counts(offset_index, metadata.row_groups(), row_groups)?
+            else {
+                return Ok(None);
+            };
+            let containers = Containers {
+                mins: converter.data_page_mins(column_index, offset_index, row_groups)?,
+                maxes: (!leaf.float)
+                    .then(|| converter.data_page_maxes(column_index, offset_index, row_groups))
+                    .transpose()?,
+                null_counts: converter.data_page_null_counts(
+                    column_index,
+                    offset_index,
+                    row_groups,
+                )?,
+                row_counts: Some(row_counts.clone()),
+            };
+            let mask = leaf_may_match(leaf, &containers)?;
+            let selection = mask
+                .iter()
+                .zip(row_counts.values())
+                .map(|(select, rows)| match select {
+                    true => RowSelector::select(*rows as usize),
+                    false => RowSelector::skip(*rows as usize),
+                })
+                .collect();
+            Ok(Some(selection))
+        })
+    }
+
+    /// Returns the parquet leaf columns with bloom filters that may be used by
+    /// [`Self::bloom_filters_may_match`]
+    pub(crate) fn bloom_filter_columns(&self) -> Vec<usize> {
+        let mut columns = vec![];
+        visit_leaves(&self.expr, &mut |leaf| {
+            let values = match &leaf.kind {
+                LeafKind::Compare(CompareOp::Eq, value) => value,
+                LeafKind::InList(values, false) => values,
+                _ => return,
+            };
+            if bloom_filter_supported(values.data_type(), leaf.physical_type)
+                && !columns.contains(&leaf.parquet_idx)
+            {
+                columns.push(leaf.parquet_idx);
+            }
+        });
+        columns
+    }
+
+    /// Returns false if the bloom filters of a row group, keyed by parquet leaf
+    /// column, show it contains no rows matching the predicate
+    pub(crate) fn bloom_filters_may_match(&self, bloom_filters: &HashMap<usize, Sbbf>) -> bool {
+        fn eval(expr: &Expr, bloom_filters: &HashMap<usize, Sbbf>) -> bool {
+            match expr {
+                Expr::And(l, r) => eval(l, bloom_filters) && eval(r, bloom_filters),
+                Expr::Or(l, r) => eval(l, bloom_filters) || eval(r, bloom_filters),
+                Expr::Leaf(leaf) => {
+                    let Some(filter) = bloom_filters.get(&leaf.parquet_idx) else {
+                        return true;
+                    };
+                    let values = match &leaf.kind {
+                        LeafKind::Compare(CompareOp::Eq, value) => value,
+                        LeafKind::InList(values, false) => values,
+                        _ => return true,
+                    };
+                    (0..values.len()).any(|idx| {
+                        bloom_filter_check(filter, values.as_ref(), idx, leaf.physical_type)
+                    })
+                }
+            }
+        }
+        eval(&self.expr, bloom_filters)
+    }
+
+    /// Returns an [`ArrowPredicate`] that evaluates the predicate
+    pub(crate) fn row_filter(&self) -> Box<dyn ArrowPredicate> {
+        let mut leaves = vec![];
+        visit_leaves(&self.expr, &mut |leaf| leaves.push(leaf.parquet_idx));
+        let projection = ProjectionMask::leaves(&self.parquet_schema, leaves);
+
+        let expr = self.expr.clone();
+        Box::new(ArrowPredicateFn::new(projection, move |batch| {
+            Ok(BooleanArray::new(evaluate_batch(&expr, &batch)?, None))
+        }))
+    }
+
+    /// Evaluate `expr` for a set of containers, returning a mask of those that
+    /// may contain matching rows
+    fn evaluate(
+        &self,
+        expr: &Expr,
+        leaf: &mut dyn FnMut(&Leaf) -> Result<BooleanBuffer>,
+    ) -> Result<BooleanBuffer> {
+        Ok(match expr {
+            Expr::Leaf(l) => leaf(l)?,
+            Expr::And(l, r) => &self.evaluate(l, leaf)? & &self.evaluate(r, leaf)?,
+            Expr::Or(l, r) => &self.evaluate(l, leaf)? | &self.evaluate(r, leaf)?,
+        })
+    }
+
+    /// Evaluate `expr` for data pages, where each column may have different
+    /// page boundaries, by combining the selections of each leaf
+    fn evaluate_pages(
+        &self,
+        expr: &Expr,
+        leaf: &mut dyn FnMut(&Leaf) -> Result<Option<RowSelection>>,
+    ) -> Result<Option<RowSelection>> {
+        Ok(match expr {
+            Expr::Leaf(l) => leaf(l)?,
+            Expr::And(l, r) => {
+                match (self.evaluate_pages(l, leaf)?, self.evaluate_pages(r, leaf)?) {
+                    (Some(l), Some(r)) => Some(l.intersection(&r)),
+                    (l, r) => l.or(r),
+                }
+            }
+            Expr::Or(l, r) => {
+                match (self.evaluate_pages(l, leaf)?, self.evaluate_pages(r, leaf)?) {
+                    (Some(l), Some(r)) => Some(l.union(&r)),
+                    _ => None,
+                }
+            }
+        })
+    }
+}
+
+/// Resolve the columns of `predicate`, converting it to negation normal form
+fn resolve(
+    predicate: &Predicate,
+    negated: bool,
+    arrow_schema: &Schema,
+    parquet_schema: &SchemaDescriptor,
+) -> Result<Expr> {
+    let (column, kind) = match predicate {
+        Predicate::And(l, r) | Predicate::Or(l, r) => {
+            let l = Box::new(resolve(l, negated, arrow_schema, parquet_schema)?);
+            let r = Box::new(resolve(r, negated, arrow_schema, parquet_schema)?);
+            // De Morgan's laws
+            let is_and = matches!(predicate, Predicate::And(_, _)) != negated;
+            return Ok(match is_and {
+                true => Expr::And(l, r),
+                false => Expr::Or(l, r),
+            });
+        }
+        Predicate::Not(p) => return resolve(p, !negated, arrow_schema, parquet_schema),
+        Predicate::Compare { column, op, value } => {
+            let op = if negated { op.negate() } else { *op };
+            (column, LeafKind::Compare(op, value.clone()))
+        }
+        Predicate::InList {
+            column,
+            values,
+            negated: n,
+        } => (column, LeafKind::InList(values.clone(), *n != negated)),
+        Predicate::IsNull { column, negated: n } => (column, LeafKind::IsNull(*n != negated)),
+        Predicate::StartsWith {
+            column,
+            prefix,
+            negated: n,
+        } => (column, LeafKind::StartsWith(prefix.clone(), *n != negated)),
+    };
+
+    let (parquet_idx, field) =
+        parquet_column(parquet_schema, arrow_schema, column).ok_or_else(|| {
+            general_err!("Predicate column '{column}' is not a top-level primitive column")
+        })?;
+    let value_type = match field.data_type() {
+        DataType::Dictionary(_, value_type) => value_type.as_ref(),
+        data_type => data_type,
+    };
+
+    let check_type = |values: &ArrayRef| match values.data_type() == value_type {
+        true => Ok(()),
+        false => Err(general_err!(
+            "Predicate value for column '{column}' has type {}, expected {value_type}",
+            values.data_type()
+        )),
+    };
+    match &kind {
+        LeafKind::Compare(_, value) => {
+            check_type(value)?;
+            if value.len() != 1 {
+                return Err(general_err!(
+                    "Predicate value for column '{column}' must contain a single element"
+                ));
+            }
+        }
+        LeafKind::InList(values, _) => check_type(values)?,
+        LeafKind::IsNull(_) => {}
+        LeafKind::StartsWith(_, _) => {
+            if !matches!(
+                value_type,
+                DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+            ) {
+                return Err(general_err!(
+                    "Prefix predicate requires a string column, '{column}' has type {value_type}"
+                ));
+            }
+        }
+    }
+
+    Ok(Expr::Leaf(Leaf {
+        column: column.clone(),
+        parquet_idx,
+        physical_type: parquet_schema.column(parquet_idx).physical_type(),
+        float: value_type.is_floating(),
+        kind,
+    }))
+}
+
+fn visit_leaves<'a>(expr: &'a Expr, f: &mut dyn FnMut(&'a Leaf)) {
+    match expr {
+        Expr::Leaf(leaf) => f(leaf),
+        Expr::And(l, r) | Expr::Or(l, r) => {
+            visit_leaves(l, f);
+            visit_leaves(r, f);
+        }
+    }
+}
+
+/// Returns the positions that are true, treating null as true
+fn maybe(array: &BooleanArray) -> BooleanBuffer {
+    match array.nulls() {
+        Some(nulls) => array.values() | &!nulls.inner(),
+        None => array.values().clone(),
+    }
+}
+
+/// Returns the positions that are true, treating null as false
+fn definitely(array: &BooleanArray) -> BooleanBuffer {
+    match array.nulls() {
+        Some(nulls) => array.values() & nulls.inner(),
+        None => array.values().clone(),
+    }
+}
+
+/// Returns a mask of the containers that may contain rows matching `leaf`
+fn leaf_may_match(leaf: &Leaf, containers: &Containers) -> Result<BooleanBuffer> {
+    let len = containers.len();
+    let all = || BooleanBuffer::new_set(len);
+    // Statistics of byte array columns may be truncated, and so are only bounds
+    let exact = !leaf.float
+        && !matches!(
+            leaf.physical_type,
+            PhysicalType::BYTE_ARRAY | PhysicalType::FIXED_LEN_BYTE_ARRAY
+        );
+
+    let compare = |op: CompareOp, value: &ArrayRef| -> Result<BooleanBuffer> {
+        let mins = &containers.mins;
+        let value = Scalar::new(value);
+        let max_cmp = |f: fn(&dyn Datum, &dyn Datum) -> Result<BooleanArray, ArrowError>| {
+            Ok::<_, ParquetError>(match &containers.maxes {
+                Some(maxes) => maybe(&f(maxes, &value)?),
+                None => all(),
+            })
+        };
+        Ok(match op {
+            CompareOp::Eq => &maybe(&cmp::lt_eq(mins, &value)?) & &max_cmp(cmp::gt_eq)?,
+            CompareOp::NotEq => match (&containers.maxes, exact) {
+                (Some(maxes), true) => {
+                    let min_eq = definitely(&cmp::eq(mins, &value)?);
+                    let max_eq = definitely(&cmp::eq(maxes, &value)?);
+                    !&(&min_eq & &max_eq)
+                }
+                _ => all(),
+            },
+            CompareOp::Lt => maybe(&cmp::lt(mins, &value)?),
+            CompareOp::LtEq => maybe(&cmp::lt_eq(mins, &value)?),
+            CompareOp::Gt => max_cmp(cmp::gt)?,
+            CompareOp::GtEq => max_cmp(cmp::gt_eq)?,
+        })
+    };
+
+    let mask = match &leaf.kind {
+        LeafKind::Compare(op, value) => compare(*op, value)?,
+        LeafKind::InList(values, negated) => {
+            let (op, mut mask) = match negated {
+                false => (CompareOp::Eq, BooleanBuffer::new_unset(len)),
+                true => (CompareOp::NotEq, all()),
+            };
+            for idx in (0..values.len()).filter(|idx| values.is_valid(*idx)) {
+                let value_mask = compare(op, &values.slice(idx, 1))?;
+                mask = match negated {
+                    false => &mask | &value_mask,
+                    true => &mask & &value_mask,
+                };
+            }
+            mask
+        }
+        LeafKind::IsNull(false) => {
+            let null_counts = &containers.null_counts;
+            BooleanBuffer::collect_bool(len, |i| null_counts.is_null(i) || null_counts.value(i) > 0)
+        }
+        LeafKind::IsNull(true) => all(),
+        LeafKind::StartsWith(prefix, negated) => {
+            let prefix = prefix.as_bytes();
+            let mins = string_values(&containers.mins);
+            let maxes = match &containers.maxes {
+                Some(maxes) => string_values(maxes),
+                None => vec![None; len],
+            };
+            BooleanBuffer::collect_bool(len, |i| match negated {
+                // All values are in the range of strings starting with prefix
+                true => {
+                    !(mins[i].is_some_and(|min| min.starts_with(prefix))
+                        && maxes[i].is_some_and(|max| max.starts_with(prefix)))
+                }
+                false => {
+                    let below = maxes[i].is_some_and(|max| max < prefix);
+                    let above =
+                        mins[i].is_some_and(|min| &min[..min.len().min(prefix.len())] > prefix);
+                    !below && !above
+                }
+            })
+        }
+    };
+
+    // Only IS NULL matches rows that are null
+    Ok(match leaf.kind {
+        LeafKind::IsNull(false) => mask,
+        _ => &mask & &containers.not_all_null(),
+    })
+}
+
+/// Returns the values of a string array as bytes
+fn string_values(array: &ArrayRef) -> Vec<Option<&[u8]>> {
+    match array.data_type() {
+        DataType::Utf8 => array
+            .as_string::<i32>()
+            .iter()
+            .map(|v| v.map(str::as_bytes))
+            .collect(),
+        DataType::LargeUtf8 => array
+            .as_string::<i64>()
+            .iter()
+            .map(|v| v.map(str::as_bytes))
+            .collect(),
+        DataType::Utf8View => array
+            .as_string_view()
+            .iter()
+            .map(|v| v.map(str::as_bytes))
+            .collect(),
+        _ => vec![None; array.len()],
+    }
+}
+
+/// Returns true if values of `data_type` can be checked against the bloom filter of
+/// a column with `physical_type`, see [`bloom_filter_check`]
+fn bloom_filter_supported(data_type: &DataType, physical_type: PhysicalType) -> bool {
+    matches!(
+        (data_type, physical_type),
+        (
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::Date32,
+            PhysicalType::INT32
+        ) | (DataType::Int64 | DataType::UInt64, PhysicalType::INT64)
+            | (DataType::Float32, PhysicalType::FLOAT)
+            | (DataType::Float64, PhysicalType::DOUBLE)
+            | (
+                DataType::Utf8
+                    | DataType::LargeUtf8
+                    | DataType::Utf8View
+                    | DataType::Binary
+                    | DataType::LargeBinary
+                    | DataType::BinaryView,
+                PhysicalType::BYTE_ARRAY
+            )
+    )
+}
+
+/// Returns false if `filter` shows that `array[idx]` is not present in the column
+///
+/// Values are hashed using their parquet physical representation
+fn bloom_filter_check(
+    filter: &Sbbf,
+    array: &dyn Array,
+    idx: usize,
+    physical_type: PhysicalType,
+) -> bool {
+    if array.is_null(idx) {
+        return false;
+    }
+    if !bloom_filter_supported(array.data_type(), physical_type) {
+        return true;
+    }
+    match array.data_type() {
+        DataType::Int8 => filter.check(&(array.as_primitive::<Int8Type>().value(idx) as i32)),
+        DataType::Int16 => filter.check(&(array.as_primitive::<Int16Type>().value(idx) as i32)),
+        DataType::Int32 => filter.check(&array.as_primitive::<Int32Type>().value(idx)),
+        DataType::UInt8 => filter.check(&(array.as_primitive::<UInt8Type>().value(idx) as i32)),
+        DataType::UInt16 => filter.check(&(array.as_primitive::<UInt16Type>().value(idx) as i32)),
+        DataType::UInt32 => filter.check(&(array.as_primitive::<UInt32Type>().value(idx) as i32)),
+        DataType::Date32 => filter.check(&array.as_primitive::<Date32Type>().value(idx)),
+        DataType::Int64 => filter.check(&array.as_primitive::<Int64Type>().value(idx)),
+        DataType::UInt64 => filter.check(&(array.as_primitive::<UInt64Type>().value(idx) as i64)),
+        DataType::Float32 => filter.check(&array.as_primitive::<Float32Type>().value(idx)),
+        DataType::Float64 => filter.check(&array.as_primitive::<Float64Type>().value(idx)),
+        DataType::Utf8 => filter.check(array.as_string::<i32>().value(idx)),
+        DataType::LargeUtf8 => filter.check(array.as_string::<i64>().value(idx)),
+        DataType::Utf8View => filter.check(array.as_string_view().value(idx)),
+        DataType::Binary => filter.check(array.as_binary::<i32>().value(idx)),
+        DataType::LargeBinary => filter.check(array.as_binary::<i64>().value(idx)),
+        DataType::BinaryView => filter.check(array.as_binary_view().value(idx)),
+        _ => true,
+    }
+}
+
+/// Evaluate `expr` against `batch`, returning the rows for which it is true
+fn evaluate_batch(expr: &Expr, batch: &RecordBatch) -> Result<BooleanBuffer, ArrowError> {
+    let leaf = match expr {
+        Expr::And(l, r) => return Ok(&evaluate_batch(l, batch)? & &evaluate_batch(r, batch)?),
+        Expr::Or(l, r) => return Ok(&evaluate_batch(l, batch)? | &evaluate_batch(r, batch)?),
+        Expr::Leaf(leaf) => leaf,
+    };
+    let array = batch.column_by_name(&leaf.column).ok_or_else(|| {
+        ArrowError::SchemaError(format!("Predicate column '{}' not found", leaf.column))
+    })?;
+    let valid = || match array.logical_nulls() {
+        Some(nulls) => nulls.into_inner(),
+        None => BooleanBuffer::new_set(array.len()),
+    };
+
+    let compare = |op: CompareOp, value: &ArrayRef| {
+        let value = Scalar::new(value);
+        let result = match op {
+            CompareOp::Eq => cmp::eq(array, &value),
+            CompareOp::NotEq => cmp::neq(array, &value),
+            CompareOp::Lt => cmp::lt(array, &value),
+            CompareOp::LtEq => cmp::lt_eq(array, &value),
+            CompareOp::Gt => cmp::gt(array, &value),
+            CompareOp::GtEq => cmp::gt_eq(array, &value),
+        };
+        result.map(|r| definitely(&r))
+    };
+
+    match &leaf.kind {
+        LeafKind::Compare(op, value) => compare(*op, value),
+        LeafKind::InList(values, false) => (0..values.len())
+            .try_fold(BooleanBuffer::new_unset(array.len()), |mask, idx| {
+                Ok(&mask | &compare(CompareOp::Eq, &values.slice(idx, 1))?)
+            }),
+        LeafKind::InList(values, true) => (0..values.len()).try_fold(valid(), |mask, idx| {
+            Ok(&mask & &compare(CompareOp::NotEq, &values.slice(idx, 1))?)
+        }),
+        LeafKind::IsNull(false) => Ok(!&valid()),
+        LeafKind::IsNull(true) => Ok(valid()),
+        LeafKind::StartsWith(prefix, negated) => starts_with(array.as_ref(), prefix, *negated),
+    }
+}
+
+/// Returns the non-null rows of `array` that start with `prefix`, or do not if `negated`
+fn starts_with(
+    array: &dyn Array,
+    prefix: &str,
+    negated: bool,
+) -> Result<BooleanBuffer, ArrowError> {
+    fn collect<'a>(
+        array: impl ArrayAccessor<Item = &'a str>,
+        prefix: &str,
+        negated: bool,
+    ) -> BooleanBuffer {
+        BooleanBuffer::collect_bool(array.len(), |i| {
+            array.is_valid(i) && array.value(i).starts_with(prefix) != negated
+        })
+    }
+    Ok(match array.data_type() {
+        DataType::Utf8 => collect(array.as_string::<i32>(), prefix, negated),
+        DataType::LargeUtf8 => collect(array.as_string::<i64>(), prefix, negated),
+        DataType::Utf8View => collect(array.as_string_view(), prefix, negated),
+        DataType::Dictionary(_, _) => {
+            let dictionary = array.as_any_dictionary();
+            let values = starts_with(dictionary.values().as_ref(), prefix, negated)?;
+            let mask = take(&BooleanArray::new(values, None), dictionary.keys(), None)?;
+            definitely(mask.as_boolean())
+        }
+        data_type => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Prefix predicate requires a string column, got {data_type}"
+            )));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::ArrowWriter;
+    use crate::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    use crate::file::metadata::PageIndexPolicy;
+    use crate::file::properties::WriterProperties;
+    use arrow_array::{DictionaryArray, Int32Array, StringArray};
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    /// Writes `id` 0..100 and `name` = `"name_{id % 10}"`, with null `name`
+    /// for multiples of 7, using 10 rows per row group and 5 rows per page
+    fn test_file(props: WriterProperties) -> Bytes {
+        let ids = Int32Array::from_iter_values(0..100);
+        let names: StringArray = (0..100)
+            .map(|i| (i % 7 != 0).then(|| format!("name_{}", i % 10)))
+            .collect();
+        let batch = RecordBatch::try_from_iter([
+            ("id", Arc::new(ids) as ArrayRef),
+            ("name", Arc::new(names) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = props
+            .into_builder()
+            .set_max_row_group_row_count(Some(10))
+            .set_data_page_row_count_limit(5)
+            .set_write_batch_size(5)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buf)
+    }
+
+    fn builder(data: Bytes) -> ParquetRecordBatchReaderBuilder<Bytes> {
+        let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+        ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap()
+    }
+
+    fn read_ids(builder: ParquetRecordBatchReaderBuilder<Bytes>) -> Vec<i32> {
+        builder
+            .build()
+            .unwrap()
+            .flat_map(|batch| {
+                let batch = batch.unwrap();
+                let ids = batch.column(0).as_primitive::<Int32Type>().clone();
+                ids.values().to_vec()
+            })
+            .collect()
+    }
+
+    fn int(v: i32) -> Int32Array {
+        Int32Array::from(vec![v])
+    }
+
+    fn pruner(
+        builder: &ParquetRecordBatchReaderBuilder<Bytes>,
+        predicate: Predicate,
+    ) -> PredicatePruner {
+        let schema = builder.metadata().file_metadata().schema_descr_ptr();
+        PredicatePruner::try_new(&predicate, builder.schema().clone(), schema).unwrap()
+    }
+
+    #[test]
+    fn test_predicate_results() {
+        let data = test_file(WriterProperties::default());
+        let name = |s: &str| StringArray::from(vec![s]);
+
+        let cases = [
+            (Predicate::eq("id", &int(42)), vec![42]),
+            (Predicate::lt("id", &int(3)), vec![0, 1, 2]),
+            (
+                Predicate::gt("id", &int(95)).or(Predicate::lt_eq("id", &int(1))),
+                vec![0, 1, 96, 97, 98, 99],
+            ),
+            (
+                (!Predicate::gt_eq("id", &int(10))).and(Predicate::not_eq("id", &int(1))),
+                vec![0, 2, 3, 4, 5, 6, 7, 8, 9],
+            ),
+            (
+                Predicate::in_list("id", &Int32Array::from(vec![Some(5), None, Some(50)])),
+                vec![5, 50],
+            ),
+            (
+                !Predicate::in_list("id", &Int32Array::from_iter_values(2..100)),
+                vec![0, 1],
+            ),
+            (
+                Predicate::is_null("name").and(Predicate::lt("id", &int(30))),
+                vec![0, 7, 14, 21, 28],
+            ),
+            (
+                Predicate::eq("name", &name("name_3")).and(Predicate::lt("id", &int(40))),
+                vec![3, 13, 23, 33],
+            ),
+            (
+                Predicate::starts_with("name", "name_9").and(Predicate::lt("id", &int(50))),
+                vec![9, 19, 29, 39],
+            ),
+            (
+                Predicate::is_not_null("name")
+                    .and(!Predicate::starts_with("name", "name_"))
+                    .or(Predicate::eq("id", &int(99))),
+                vec![99],
+            ),
+            // Nulls never match a comparison, even when negated
+            (
+                (!Predicate::eq("name", &name("name_0"))).and(Predicate::lt("id", &int(15))),
+                vec![1, 2, 3, 4, 5, 6, 8, 9, 11, 12, 13],
+            ),
+        ];
+
+        for (predicate, expected) in cases {
+            let builder = builder(data.clone()).with_predicate(predicate.clone());
+            assert_eq!(read_ids(builder), expected, "{predicate:?}");
+        }
+    }
+
+    #[test]
+    fn test_prune_row_groups() {
+        let data = test_file(WriterProperties::default());
+        let builder = builder(data);
+        let row_groups: Vec<_> = (0..10).collect();
+        let prune = |predicate| {
+            let keep = pruner(&builder, predicate)
+                .prune_row_groups(builder.metadata(), &row_groups)
+                .unwrap();
+            let kept: Vec<_> = row_groups.iter().copied().filter(|rg| keep[*rg]).collect();
+            kept
+        };
+
+        assert_eq!(prune(Predicate::eq("id", &int(42))), vec![4]);
+        assert_eq!(prune(Predicate::gt_eq("id", &int(85))), vec![8, 9]);
+        assert_eq!(prune(Predicate::lt("id", &int(0))), Vec::<usize>::new());
+        assert_eq!(
+            prune(Predicate::lt("id", &int(5)).or(Predicate::eq("id", &int(61)))),
+            vec![0, 6]
+        );
+        assert_eq!(prune(!Predicate::gt("id", &int(15))), vec![0, 1]);
+        // Every row group contains nulls and non-null values
+        assert_eq!(prune(Predicate::is_null("name")).len(), 10);
+        assert_eq!(prune(Predicate::is_not_null("name")).len(), 10);
+        assert_eq!(
+            prune(Predicate::starts_with("name", "z")),
+            Vec::<usize>::new()
+        );
+        assert_eq!(prune(Predicate::starts_with("name", "name_")).len(), 10);
+        // NOT LIKE can only be pruned when all values match the prefix
+        assert_eq!(prune(!Predicate::starts_with("name", "name_")).len(), 0);
+        assert_eq!(prune(!Predicate::starts_with("name", "name_3")).len(), 10);
+    }
+
+    #[test]
+    fn test_page_selection() {
+        let data = test_file(WriterProperties::default());
+        let builder = builder(data);
+        let row_groups = [2, 3];
+        let select = |predicate| {
+            pruner(&builder, predicate)
+                .page_selection(builder.metadata(), &row_groups)
+                .unwrap()
+                .unwrap()
+        };
+
+        let selection = select(Predicate::eq("id", &int(27)));
+        let expected: RowSelection = vec![
+            RowSelector::skip(5),
+            RowSelector::select(5),
+            RowSelector::skip(10),
+        ]
+        .into();
+        assert_eq!(selection, expected);
+
+        let selection = select(Predicate::lt("id", &int(22)).or(Predicate::gt_eq("id", &int(37))));
+        let expected: RowSelection = vec![
+            RowSelector::select(5),
+            RowSelector::skip(10),
+            RowSelector::select(5),
+        ]
+        .into();
+        assert_eq!(selection, expected);
+
+        // The page index for name cannot prune, so only id is used
+        let selection =
+            select(Predicate::is_not_null("name").and(Predicate::gt_eq("id", &int(35))));
+        let expected: RowSelection = vec![RowSelector::skip(15), RowSelector::select(5)].into();
+        assert_eq!(selection, expected);
+
+        // Without a page index there is no selection
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            test_file(WriterProperties::default()),
+            ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Skip),
+        )
+        .unwrap();
+        let selection = pruner(&builder, Predicate::eq("id", &int(27)))
+            .page_selection(builder.metadata(), &row_groups)
+            .unwrap();
+        assert!(selection.is_none());
+    }
+
+    #[test]
+    fn test_predicate_with_row_selection() {
+        let data = test_file(WriterProperties::default());
+        // Select the last 5 rows of row groups 0 and 5
+        let selection: RowSelection = vec![
+            RowSelector::skip(5),
+            RowSelector::select(5),
+            RowSelector::skip(15),
+            RowSelector::select(5),
+            RowSelector::skip(10),
+        ]
+        .into();
+        let builder = builder(data)
+            .with_row_groups(vec![0, 1, 5, 6])
+            .with_row_selection(selection)
+            .with_predicate(Predicate::gt("id", &int(20)));
+        // Row group 0 is pruned, the selection is adjusted accordingly
+        assert_eq!(read_ids(builder), vec![55, 56, 57, 58, 59]);
+    }
+
+    #[test]
+    fn test_bloom_filter() {
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .build();
+        let data = test_file(props);
+
+        // Statistics cannot rule out names that do not occur
+        let names = StringArray::from(vec!["name_35", "name_4"]);
+        let predicate = Predicate::in_list("name", &names).and(Predicate::lt("id", &int(50)));
+        let mut reader = builder(data.clone()).with_predicate(predicate);
+        reader.prune_with_bloom_filters().unwrap();
+        // Row group 1 only contains a null name for id 14
+        assert_eq!(reader.row_groups, Some(vec![0, 2, 3, 4]));
+        assert_eq!(read_ids(reader), vec![4, 24, 34, 44]);
+
+        let name = StringArray::from(vec!["name_35"]);
+        let mut reader = builder(data).with_predicate(Predicate::eq("name", &name));
+        reader.prune_with_bloom_filters().unwrap();
+        assert_eq!(reader.row_groups, Some(vec![]));
+        assert_eq!(read_ids(reader), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_dictionary_column() {
+        let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(0), Some(1)]);
+        let values = StringArray::from(vec!["apple", "banana"]);
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+        let ids = Int32Array::from_iter_values(0..5);
+        let batch = RecordBatch::try_from_iter([
+            ("id", Arc::new(ids) as ArrayRef),
+            ("fruit", Arc::new(dict) as ArrayRef),
+        ])
+        .unwrap();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let cases = [
+            (Predicate::starts_with("fruit", "ban"), vec![1, 4]),
+            (!Predicate::starts_with("fruit", "ban"), vec![0, 3]),
+            (
+                Predicate::eq("fruit", &StringArray::from(vec!["apple"])),
+                vec![0, 3],
+            ),
+            (Predicate::is_null("fruit"), vec![2]),
+        ];
+        for (predicate, expected) in cases {
+            let builder = builder(data.clone()).with_predicate(predicate.clone());
+            assert_eq!(read_ids(builder), expected, "{predicate:?}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_predicate() {
+        let data = test_file(WriterProperties::default());
+        let err = |predicate| {
+            builder(data.clone())
+                .with_predicate(predicate)
+                .build()
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            err(Predicate::eq("missing", &int(1))),
+            "Parquet error: Predicate column 'missing' is not a top-level primitive column"
+        );
+        assert_eq!(
+            err(Predicate::eq("name", &int(1))),
+            "Parquet error: Predicate value for column 'name' has type Int32, expected Utf8"
+        );
+        assert_eq!(
+            err(Predicate::eq("id", &Int32Array::from(vec![1, 2]))),
+            "Parquet error: Predicate value for column 'id' must contain a single element"
+        );
+        assert_eq!(
+            err(Predicate::starts_with("id", "1")),
+            "Parquet error: Prefix predicate requires a string column, 'id' has type Int32"
+        );
+    }
+}
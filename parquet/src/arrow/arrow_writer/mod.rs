@@ -56,6 +56,11 @@ use levels::{ArrayLevels, calculate_array_levels};
 
 mod byte_array;
 mod levels;
+mod sort;
+
+use sort::SortValidator;
+pub(crate) use sort::{SortKey, first_unsorted};
+pub use sort::{SortValidation, SortingArrowWriter};
 
 #[doc(inline)]
 pub use crate::column::page_store::{
@@ -202,6 +207,9 @@ pub struct ArrowWriter<W: Write> {
 
     /// CDC chunkers persisted across row groups (one per leaf column).
     cdc_chunkers: Option<Vec<ContentDefinedChunker>>,
+
+    /// Verifies the sort order of written data, if enabled
+    sort_validator: Option<SortValidator>,
 }
 
 impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
@@ -287,6 +295,21 @@ impl<W: Write + Send> ArrowWriter<W> {
             })
             .transpose()?;
 
+        let sort_validator = options
+            .sort_validation
+            .map(|mode| {
+                let sorting_columns = props_ptr.sorting_columns().ok_or_else(|| {
+                    general_err!("Sort validation requires sorting columns to be set")
+                })?;
+                let key = SortKey::try_new(&schema, &arrow_schema, sorting_columns)?;
+                Ok::<_, ParquetError>(SortValidator {
+                    key,
+                    mode,
+                    last: None,
+                })
+            })
+            .transpose()?;
+
         Ok(Self {
             writer: file_writer,
             in_progress: None,
@@ -295,6 +318,7 @@ impl<W: Write + Send> ArrowWriter<W> {
             max_row_group_row_count,
             max_row_group_bytes,
             cdc_chunkers,
+            sort_validator,
         })
     }
 
@@ -355,7 +379,47 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// If both limits are set, the lower bound (whichever triggers first) is respected.
     ///
     /// This will fail if the `batch`'s schema does not match the writer's schema.
+    ///
+    /// If [sort validation](ArrowWriterOptions::with_sort_validation) is enabled,
+    /// this will also verify that `batch` is sorted.
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        let Some(validator) = self.sort_validator.as_mut() else {
+            return self.write_batch(batch);
+        };
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let rows = validator.key.convert(batch)?;
+        let mut previous = validator.last.as_ref().map(|r| r.row());
+        if validator.mode == SortValidation::Error
+            && let Some(idx) = first_unsorted(&rows, 0, previous)
+        {
+            return Err(general_err!(
+                "Row {idx} of batch is not sorted by the sorting columns"
+            ));
+        }
+
+        let mut start = 0;
+        while let Some(idx) = first_unsorted(&rows, start, previous) {
+            // Rows before idx belong to the in progress row group
+            if idx > start {
+                self.write_batch(&batch.slice(start, idx - start))?;
+            }
+            self.flush()?;
+            previous = None;
+            start = idx;
+        }
+        self.write_batch(&batch.slice(start, batch.num_rows() - start))?;
+
+        let last = (self.in_progress_rows() > 0).then(|| rows.row(rows.num_rows() - 1).owned());
+        if let Some(validator) = self.sort_validator.as_mut() {
+            validator.last = last;
+        }
+        Ok(())
+    }
+
+    fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
         if batch.num_rows() == 0 {
             return Ok(());
         }
@@ -374,8 +438,8 @@ impl<W: Write + Send> ArrowWriter<W> {
             let to_write = max_rows - in_progress.buffered_rows;
             let a = batch.slice(0, to_write);
             let b = batch.slice(to_write, batch.num_rows() - to_write);
-            self.write(&a)?;
-            return self.write(&b);
+            self.write_batch(&a)?;
+            return self.write_batch(&b);
         }
 
         // Check byte limit: if we have buffered data, use measured average row size
@@ -387,7 +451,7 @@ impl<W: Write + Send> ArrowWriter<W> {
 
             if current_bytes >= max_bytes {
                 self.flush()?;
-                return self.write(batch);
+                return self.write_batch(batch);
             }
 
             if let Some(avg_row_bytes) = current_bytes
@@ -402,11 +466,11 @@ impl<W: Write + Send> ArrowWriter<W> {
                     if rows_that_fit > 0 {
                         let a = batch.slice(0, rows_that_fit);
                         let b = batch.slice(rows_that_fit, batch.num_rows() - rows_that_fit);
-                        self.write(&a)?;
-                        return self.write(&b);
+                        self.write_batch(&a)?;
+                        return self.write_batch(&b);
                     } else {
                         self.flush()?;
-                        return self.write(batch);
+                        return self.write_batch(batch);
                     }
                 }
             }
@@ -448,6 +512,9 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// Note the underlying writer is not flushed with this call.
     /// If this is a desired behavior, please call [`ArrowWriter::sync`].
     pub fn flush(&mut self) -> Result<()> {
+        if let Some(validator) = self.sort_validator.as_mut() {
+            validator.last = None;
+        }
         let in_progress = match self.in_progress.take() {
             Some(in_progress) => in_progress,
             None => return Ok(()),
@@ -541,6 +608,7 @@ pub struct ArrowWriterOptions {
     schema_root: Option<String>,
     schema_descr: Option<SchemaDescriptor>,
     page_store_factory: Option<Arc<dyn PageStoreFactory>>,
+    sort_validation: Option<SortValidation>,
 }
 
 impl ArrowWriterOptions {
@@ -678,6 +746,48 @@ impl ArrowWriterOptions {
             ..self
         }
     }
+
+    /// Verify that written data is sorted by [`WriterProperties::sorting_columns`]
+    /// (defaults to `None`)
+    ///
+    /// The [`ArrowWriter`] records the sorting columns in the metadata of every
+    /// row group, but does not otherwise check them. When set, each batch is
+    /// checked against the sort order, and any violation handled as described
+    /// by [`SortValidation`]. The sorting columns must be set and refer to
+    /// top-level primitive columns.
+    ///
+    /// To sort unsorted data before writing it, see [`SortingArrowWriter`].
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_writer::{ArrowWriterOptions, SortValidation};
+    /// # use parquet::file::metadata::SortingColumn;
+    /// # use parquet::file::properties::WriterProperties;
+    /// let props = WriterProperties::builder()
+    ///     .set_sorting_columns(Some(vec![SortingColumn {
+    ///         column_idx: 0,
+    ///         descending: false,
+    ///         nulls_first: false,
+    ///     }]))
+    ///     .build();
+    /// let options = ArrowWriterOptions::new()
+    ///     .with_properties(props)
+    ///     .with_sort_validation(SortValidation::Error);
+    ///
+    /// let col = Arc::new(Int32Array::from(vec![1, 3, 2])) as ArrayRef;
+    /// let batch = RecordBatch::try_from_iter([("a", col)]).unwrap();
+    ///
+    /// let mut writer = ArrowWriter::try_new_with_options(Vec::new(), batch.schema(), options).unwrap();
+    /// assert!(writer.write(&batch).is_err());
+    /// ```
+    pub fn with_sort_validation(self, sort_validation: SortValidation) -> Self {
+        Self {
+            sort_validation: Some(sort_validation),
+            ..self
+        }
+    }
 }
 
 /// A single column chunk produced by [`ArrowColumnWriter`].
@@ -6125,4 +6235,172 @@ mod tests {
         );
         assert_eq!(parquet_schema.column(1).path().string(), "row.b");
     }
+
+    fn sorted_props(max_rows: usize) -> WriterProperties {
+        WriterProperties::builder()
+            .set_max_row_group_row_count(Some(max_rows))
+            .set_sorting_columns(Some(vec![crate::file::metadata::SortingColumn {
+                column_idx: 0,
+                descending: false,
+                nulls_first: true,
+            }]))
+            .build()
+    }
+
+    fn int_batch(values: Vec<Option<i32>>) -> RecordBatch {
+        let col = Arc::new(Int32Array::from(values)) as ArrayRef;
+        RecordBatch::try_from_iter([("a", col)]).unwrap()
+    }
+
+    #[test]
+    fn test_sort_validation_error() {
+        let options = ArrowWriterOptions::new()
+            .with_properties(sorted_props(100))
+            .with_sort_validation(SortValidation::Error);
+        let batch = int_batch(vec![None, Some(1), Some(3)]);
+        let mut writer =
+            ArrowWriter::try_new_with_options(Vec::new(), batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+
+        // Unsorted within the batch
+        let err = writer
+            .write(&int_batch(vec![Some(4), Some(2)]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Row 1 of batch is not sorted by the sorting columns"
+        );
+        // Sorts before the last row of the in progress row group
+        let err = writer
+            .write(&int_batch(vec![Some(2), Some(5)]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Row 0 of batch is not sorted by the sorting columns"
+        );
+        assert_eq!(writer.in_progress_rows(), 3);
+
+        writer.write(&int_batch(vec![Some(3), Some(5)])).unwrap();
+        // A new row group may start anywhere
+        writer.flush().unwrap();
+        writer.write(&int_batch(vec![Some(0)])).unwrap();
+
+        let metadata = writer.close().unwrap();
+        assert_eq!(metadata.num_row_groups(), 2);
+        assert_eq!(metadata.row_group(0).num_rows(), 5);
+        assert_eq!(metadata.row_group(1).num_rows(), 1);
+    }
+
+    #[test]
+    fn test_sort_validation_split_row_group() {
+        let options = ArrowWriterOptions::new()
+            .with_properties(sorted_props(4))
+            .with_sort_validation(SortValidation::SplitRowGroup);
+        let batch = int_batch(vec![Some(1), Some(2), Some(3)]);
+        let mut buf = Vec::new();
+        let mut writer =
+            ArrowWriter::try_new_with_options(&mut buf, batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        writer
+            .write(&int_batch(vec![Some(2), Some(4), None, Some(1)]))
+            .unwrap();
+        writer
+            .write(&int_batch(vec![Some(5), Some(6), Some(7), Some(8)]))
+            .unwrap();
+        writer.close().unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let row_groups: Vec<_> = reader
+            .metadata()
+            .row_groups()
+            .iter()
+            .map(|rg| rg.num_rows())
+            .collect();
+        // [1, 2, 3], [2, 4], [null, 1, 5, 6], [7, 8]
+        assert_eq!(row_groups, vec![3, 2, 4, 2]);
+    }
+
+    #[test]
+    fn test_sort_validation_requires_sorting_columns() {
+        let batch = int_batch(vec![Some(1)]);
+        let options = ArrowWriterOptions::new().with_sort_validation(SortValidation::Error);
+        let err =
+            ArrowWriter::try_new_with_options(Vec::new(), batch.schema(), options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Sort validation requires sorting columns to be set"
+        );
+    }
+
+    #[test]
+    fn test_sorting_arrow_writer() {
+        let batch = int_batch(vec![Some(5), None, Some(3), Some(9), Some(1)]);
+        let mut buf = Vec::new();
+        let mut writer =
+            SortingArrowWriter::try_new(&mut buf, batch.schema(), sorted_props(4)).unwrap();
+        writer.write(&batch).unwrap();
+        assert_eq!(writer.buffered_rows(), 1);
+        writer.write(&int_batch(vec![Some(2), Some(0)])).unwrap();
+        assert_eq!(writer.flushed_row_groups().len(), 1);
+        let metadata = writer.close().unwrap();
+        assert_eq!(metadata.num_row_groups(), 2);
+        for rg in metadata.row_groups() {
+            assert!(rg.sorting_columns().is_some());
+        }
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf))
+            .unwrap()
+            .with_batch_size(1024)
+            .build()
+            .unwrap();
+        let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| {
+                b.column(0)
+                    .as_primitive::<Int32Type>()
+                    .iter()
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(
+            values,
+            vec![None, Some(3), Some(5), Some(9), Some(0), Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    fn test_sorting_arrow_writer_max_bytes() {
+        // Slices of a large batch share its buffers, only the sliced
+        // rows should count towards the byte budget
+        let batch = int_batch((0..1000).rev().map(Some).collect());
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(1000))
+            .set_max_row_group_bytes(Some(1000))
+            .set_sorting_columns(sorted_props(1000).sorting_columns().cloned())
+            .build();
+        let mut writer = SortingArrowWriter::try_new(Vec::new(), batch.schema(), props).unwrap();
+        for offset in (0..1000).step_by(100) {
+            writer.write(&batch.slice(offset, 100)).unwrap();
+        }
+        let metadata = writer.close().unwrap();
+        let row_groups: Vec<_> = metadata
+            .row_groups()
+            .iter()
+            .map(|rg| rg.num_rows())
+            .collect();
+        // Each slice holds 400 bytes of values
+        assert_eq!(row_groups, vec![300, 300, 300, 100]);
+    }
+
+    #[test]
+    fn test_sorting_arrow_writer_requires_sorting_columns() {
+        let batch = int_batch(vec![Some(1)]);
+        let props = WriterProperties::builder().build();
+        let err = SortingArrowWriter::try_new(Vec::new(), batch.schema(), props).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: SortingArrowWriter requires sorting columns"
+        );
+    }
 }
@@ -0,0 +1,332 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for writing data sorted by [`SortingColumn`]s
+//!
+//! The parquet format records the sort order of each row group in
+//! [`RowGroupMetaData::sorting_columns`], as configured by
+//! [`WriterProperties::sorting_columns`]. The [`ArrowWriter`] does not sort
+//! data itself, this module provides:
+//!
+//! * [`SortValidation`] to verify that the written data is sorted, see
+//!   [`ArrowWriterOptions::with_sort_validation`]
+//! * [`SortingArrowWriter`] to sort data before it is written
+//!
+//! [`RowGroupMetaData::sorting_columns`]: crate::file::metadata::RowGroupMetaData::sorting_columns
+//! [`ArrowWriterOptions::with_sort_validation`]: super::ArrowWriterOptions::with_sort_validation
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_row::{OwnedRow, Row, RowConverter, Rows, SortField};
+use arrow_schema::{Schema, SchemaRef, SortOptions};
+use arrow_select::interleave::interleave_record_batch;
+
+use super::{ArrowWriter, ArrowWriterOptions};
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{ParquetMetaData, RowGroupMetaData, SortingColumn};
+use crate::file::properties::{DEFAULT_MAX_ROW_GROUP_ROW_COUNT, WriterProperties};
+use crate::schema::types::SchemaDescriptor;
+
+/// How an [`ArrowWriter`] handles data that is not sorted by the
+/// [`WriterProperties::sorting_columns`]
+///
+/// Sort order is only required within a row group, matching the semantics of
+/// [`RowGroupMetaData::sorting_columns`]. Each batch is compared against the
+/// rows already buffered in the in progress row group.
+///
+/// See [`ArrowWriterOptions::with_sort_validation`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortValidation {
+    /// Return an error, without writing any of the batch, when a batch
+    /// contains a row that sorts before its predecessor
+    Error,
+    /// Flush the in progress row group before a row that sorts before its
+    /// predecessor, so that every row group is sorted
+    SplitRowGroup,
+}
+
+/// The row format sort key described by a list of [`SortingColumn`]
+#[derive(Debug)]
+pub(crate) struct SortKey {
+    converter: RowConverter,
+    /// The root columns that make up the sort key
+    roots: Vec<usize>,
+}
+
+impl SortKey {
+    /// Create a [`SortKey`] for `sorting_columns`, which must refer to top-level
+    /// primitive columns of `schema`
+    pub(crate) fn try_new(
+        schema: &SchemaDescriptor,
+        arrow_schema: &Schema,
+        sorting_columns: &[SortingColumn],
+    ) -> Result<Self> {
+        if sorting_columns.is_empty() {
+            return Err(general_err!(
+                "Sort key requires at least one sorting column"
+            ));
+        }
+
+        let mut roots = Vec::with_capacity(sorting_columns.len());
+        let mut fields = Vec::with_capacity(sorting_columns.len());
+        for sorting_column in sorting_columns {
+            let col = sorting_column.column_idx as usize;
+            if col >= schema.num_columns() || schema.column(col).path().parts().len() != 1 {
+                return Err(general_err!(
+                    "Sorting column {col} must refer to a top-level primitive column"
+                ));
+            }
+            let root = schema.get_column_root_idx(col);
+            let options = SortOptions {
+                descending: sorting_column.descending,
+                nulls_first: sorting_column.nulls_first,
+            };
+            let data_type = arrow_schema.field(root).data_type().clone();
+            roots.push(root);
+            fields.push(SortField::new_with_options(data_type, options));
+        }
+        let converter = RowConverter::new(fields)?;
+        Ok(Self { converter, roots })
+    }
+
+    fn columns(&self, batch: &RecordBatch) -> Vec<ArrayRef> {
+        self.roots
+            .iter()
+            .map(|root| Arc::clone(batch.column(*root)))
+            .collect()
+    }
+
+    /// Convert the sort key columns of `batch` to [`Rows`]
+    pub(crate) fn convert(&self, batch: &RecordBatch) -> Result<Rows> {
+        Ok(self.converter.convert_columns(&self.columns(batch))?)
+    }
+
+    /// Append the sort key columns of `batch` to `rows`
+    fn append(&self, rows: &mut Rows, batch: &RecordBatch) -> Result<()> {
+        Ok(self.converter.append(rows, &self.columns(batch))?)
+    }
+}
+
+/// Returns the index of the first row at or after `start` that sorts before its
+/// predecessor, where the predecessor of `start` is `previous`
+pub(crate) fn first_unsorted(
+    rows: &Rows,
+    start: usize,
+    previous: Option<Row<'_>>,
+) -> Option<usize> {
+    if start >= rows.num_rows() {
+        return None;
+    }
+    if previous.is_some_and(|previous| previous > rows.row(start)) {
+        return Some(start);
+    }
+    (start + 1..rows.num_rows()).find(|i| rows.row(i - 1) > rows.row(*i))
+}
+
+/// Verifies the sort order of the batches written by an [`ArrowWriter`]
+#[derive(Debug)]
+pub(super) struct SortValidator {
+    pub(super) key: SortKey,
+    pub(super) mode: SortValidation,
+    /// The last row of the in progress row group, if any
+    pub(super) last: Option<OwnedRow>,
+}
+
+/// Buffers and sorts data by [`WriterProperties::sorting_columns`] before
+/// writing it with an [`ArrowWriter`]
+///
+/// Batches are buffered until the row group budget is reached, at which point
+/// the buffered rows are sorted and written as a row group. The budget is
+/// [`WriterProperties::max_row_group_row_count`] rows and, if set,
+/// [`WriterProperties::max_row_group_bytes`] bytes of buffered arrow data.
+///
+/// Each row group of the resulting file is sorted, the file as a whole is not.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use parquet::arrow::arrow_writer::SortingArrowWriter;
+/// # use parquet::file::metadata::SortingColumn;
+/// # use parquet::file::properties::WriterProperties;
+/// let col = Arc::new(Int32Array::from(vec![3, 1, 2])) as ArrayRef;
+/// let batch = RecordBatch::try_from_iter([("a", col)]).unwrap();
+///
+/// let props = WriterProperties::builder()
+///     .set_sorting_columns(Some(vec![SortingColumn {
+///         column_idx: 0,
+///         descending: false,
+///         nulls_first: false,
+///     }]))
+///     .build();
+///
+/// let mut buffer = Vec::new();
+/// let mut writer = SortingArrowWriter::try_new(&mut buffer, batch.schema(), props).unwrap();
+/// writer.write(&batch).unwrap();
+/// let metadata = writer.close().unwrap();
+/// assert!(metadata.row_group(0).sorting_columns().is_some());
+/// ```
+pub struct SortingArrowWriter<W: Write> {
+    writer: ArrowWriter<W>,
+    key: SortKey,
+    buffered: Vec<RecordBatch>,
+    buffered_rows: usize,
+    buffered_bytes: usize,
+    max_rows: usize,
+    max_bytes: Option<usize>,
+}
+
+impl<W: Write + Send> std::fmt::Debug for SortingArrowWriter<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SortingArrowWriter")
+            .field("writer", &self.writer)
+            .field("buffered_rows", &self.buffered_rows)
+            .field("buffered_bytes", &self.buffered_bytes)
+            .field("max_rows", &self.max_rows)
+            .field("max_bytes", &self.max_bytes)
+            .finish()
+    }
+}
+
+impl<W: Write + Send> SortingArrowWriter<W> {
+    /// Try to create a new [`SortingArrowWriter`]
+    ///
+    /// Returns an error if [`WriterProperties::sorting_columns`] is not set, or
+    /// refers to columns other than top-level primitive columns
+    pub fn try_new(writer: W, arrow_schema: SchemaRef, props: WriterProperties) -> Result<Self> {
+        let options = ArrowWriterOptions::new().with_properties(props);
+        Self::try_new_with_options(writer, arrow_schema, options)
+    }
+
+    /// Try to create a new [`SortingArrowWriter`] with [`ArrowWriterOptions`]
+    ///
+    /// See [`Self::try_new`]
+    pub fn try_new_with_options(
+        writer: W,
+        arrow_schema: SchemaRef,
+        options: ArrowWriterOptions,
+    ) -> Result<Self> {
+        let props = &options.properties;
+        let sorting_columns = props
+            .sorting_columns()
+            .filter(|columns| !columns.is_empty())
+            .cloned()
+            .ok_or_else(|| general_err!("SortingArrowWriter requires sorting columns"))?;
+        let max_rows = props
+            .max_row_group_row_count()
+            .unwrap_or(DEFAULT_MAX_ROW_GROUP_ROW_COUNT);
+        let max_bytes = props.max_row_group_bytes();
+
+        let writer = ArrowWriter::try_new_with_options(writer, arrow_schema.clone(), options)?;
+        let key = SortKey::try_new(
+            writer.writer.schema_descr(),
+            &arrow_schema,
+            &sorting_columns,
+        )?;
+        Ok(Self {
+            writer,
+            key,
+            buffered: vec![],
+            buffered_rows: 0,
+            buffered_bytes: 0,
+            max_rows,
+            max_bytes,
+        })
+    }
+
+    /// Returns the number of rows buffered and not yet written
+    pub fn buffered_rows(&self) -> usize {
+        self.buffered_rows
+    }
+
+    /// Returns the metadata for any flushed row groups
+    pub fn flushed_row_groups(&self) -> &[RowGroupMetaData] {
+        self.writer.flushed_row_groups()
+    }
+
+    /// Buffers the provided [`RecordBatch`], writing a sorted row group
+    /// whenever the row group budget is reached
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            let len = (batch.num_rows() - offset).min(self.max_rows - self.buffered_rows);
+            let slice = batch.slice(offset, len);
+            offset += len;
+
+            self.buffered_rows += len;
+            // Only count the memory referenced by the slice, as the buffers of
+            // `batch` are shared by all its slices
+            for column in slice.columns() {
+                self.buffered_bytes += column.to_data().get_slice_memory_size()?;
+            }
+            self.buffered.push(slice);
+
+            let full = self.buffered_rows >= self.max_rows
+                || self.max_bytes.is_some_and(|max| self.buffered_bytes >= max);
+            if full {
+                self.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sorts the buffered rows and writes them to a new row group
+    pub fn flush(&mut self) -> Result<()> {
+        if self.buffered_rows == 0 {
+            return Ok(());
+        }
+        let batches = std::mem::take(&mut self.buffered);
+        self.buffered_rows = 0;
+        self.buffered_bytes = 0;
+
+        let num_rows = batches.iter().map(|b| b.num_rows()).sum();
+        let mut rows = self.key.converter.empty_rows(num_rows, 0);
+        let mut positions = Vec::with_capacity(num_rows);
+        for (idx, batch) in batches.iter().enumerate() {
+            self.key.append(&mut rows, batch)?;
+            positions.extend((0..batch.num_rows()).map(|row| (idx, row)));
+        }
+
+        let mut order: Vec<usize> = (0..num_rows).collect();
+        order.sort_by(|a, b| rows.row(*a).cmp(&rows.row(*b)));
+        let indices: Vec<_> = order.into_iter().map(|i| positions[i]).collect();
+
+        let batches: Vec<_> = batches.iter().collect();
+        let sorted = interleave_record_batch(&batches, &indices)?;
+        self.writer.write(&sorted)?;
+        self.writer.flush()
+    }
+
+    /// Returns a reference to the underlying [`ArrowWriter`]
+    pub fn inner(&self) -> &ArrowWriter<W> {
+        &self.writer
+    }
+
+    /// Flushes any buffered rows and returns the underlying [`ArrowWriter`]
+    pub fn into_inner(mut self) -> Result<ArrowWriter<W>> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Flushes any buffered rows, then closes and finalizes the underlying
+    /// Parquet writer
+    pub fn close(mut self) -> Result<ParquetMetaData> {
+        self.flush()?;
+        self.writer.close()
+    }
+}
@@ -21,6 +21,7 @@
 //!
 //! See example on [`ParquetRecordBatchStreamBuilder::new`]
 
+use std::collections::HashMap;
 use std::fmt::Formatter;
 use std::io::SeekFrom;
 use std::ops::Range;
@@ -644,6 +645,41 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         Ok(Some(Sbbf::new(&bitset)))
     }
 
+    /// Skip the row groups whose statistics or bloom filters show they do not
+    /// match the [predicate](ArrowReaderBuilder::with_predicate), if any
+    ///
+    /// Unlike [`ParquetRecordBatchReaderBuilder::build`], [`Self::build`]
+    /// performs no IO and so does not read bloom filters. Call this method
+    /// before [`Self::build`] to also skip row groups using bloom filters.
+    ///
+    /// [`ParquetRecordBatchReaderBuilder::build`]: crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::build
+    pub async fn prune_with_bloom_filters(mut self) -> Result<Self> {
+        let Some(pruner) = self.predicate_pruner()? else {
+            return Ok(self);
+        };
+        self.prune_row_groups_by_statistics(&pruner)?;
+
+        let columns = pruner.bloom_filter_columns();
+        if columns.is_empty() {
+            return Ok(self);
+        }
+        let mut keep = vec![];
+        for row_group in self.selected_row_groups() {
+            let mut bloom_filters = HashMap::new();
+            for column in &columns {
+                if let Some(sbbf) = self
+                    .get_row_group_column_bloom_filter(row_group, *column)
+                    .await?
+                {
+                    bloom_filters.insert(*column, sbbf);
+                }
+            }
+            keep.push(pruner.bloom_filters_may_match(&bloom_filters));
+        }
+        self.retain_row_groups(&keep);
+        Ok(self)
+    }
+
     /// Build a new [`ParquetRecordBatchStream`]
     ///
     /// See examples on [`ParquetRecordBatchStreamBuilder::new`]
@@ -657,6 +693,7 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             row_groups,
             projection,
             filter,
+            predicate,
             selection,
             row_selection_policy: selection_strategy,
             limit,
@@ -680,6 +717,7 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             fields,
             projection,
             filter,
+            predicate,
             selection,
             row_selection_policy: selection_strategy,
             batch_size,
@@ -938,7 +976,8 @@ mod tests {
     use super::*;
     use crate::arrow::arrow_reader::tests::test_row_numbers_with_multiple_row_groups_helper;
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelector,
+        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, Predicate, RowFilter, RowSelection,
+        RowSelector,
     };
     use crate::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
     use crate::arrow::schema::virtual_type::RowNumber;
@@ -1053,6 +1092,54 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_async_reader_with_predicate() {
+        let ids = Int32Array::from_iter_values(0..100);
+        let names: StringArray = (0..100).map(|i| Some(format!("name_{}", i % 10))).collect();
+        let batch = RecordBatch::try_from_iter([
+            ("id", Arc::new(ids) as ArrayRef),
+            ("name", Arc::new(names) as ArrayRef),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(10))
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let predicate = Predicate::eq("name", &StringArray::from(vec!["name_3"]))
+            .and(Predicate::lt("id", &Int32Array::from(vec![40])));
+
+        // Statistics alone skip row groups 4 to 9
+        let builder = ParquetRecordBatchStreamBuilder::new(TestReader::new(data.clone()))
+            .await
+            .unwrap()
+            .with_predicate(predicate.clone());
+        let stream = builder.build().unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let ids: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect();
+        assert_eq!(ids, vec![3, 13, 23, 33]);
+
+        // A name that does not occur is ruled out by the bloom filters
+        let builder = ParquetRecordBatchStreamBuilder::new(TestReader::new(data))
+            .await
+            .unwrap()
+            .with_predicate(Predicate::eq("name", &StringArray::from(vec!["name_33"])))
+            .prune_with_bloom_filters()
+            .await
+            .unwrap();
+        assert_eq!(builder.row_groups, Some(vec![]));
+        let batches: Vec<_> = builder.build().unwrap().try_collect().await.unwrap();
+        assert!(batches.is_empty());
+    }
+
     #[tokio::test]
     async fn test_async_reader_with_next_row_group() {
         let testdata = arrow::util::test_util::parquet_test_data();
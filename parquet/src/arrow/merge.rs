@@ -0,0 +1,1198 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Merging (compacting) many Parquet files into one, see [`ParquetMerger`]
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::Write;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_row::{OwnedRow, Rows};
+use arrow_schema::SchemaRef;
+use arrow_select::interleave::interleave_record_batch;
+use bytes::Bytes;
+
+use crate::arrow::ProjectionMask;
+use crate::arrow::arrow_reader::{
+    ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
+    ParquetRecordBatchReaderBuilder,
+};
+use crate::arrow::arrow_writer::{
+    ArrowColumnWriter, ArrowRowGroupWriterFactory, ArrowWriter, ArrowWriterOptions, SortKey,
+    compute_leaves, first_unsorted,
+};
+use crate::basic::Encoding;
+use crate::bloom_filter::Sbbf;
+use crate::column::writer::{ColumnCloseResult, merge_statistics};
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{
+    ColumnChunkMetaData, ColumnIndexBuilder, LevelHistogram, OffsetIndexBuilder, PageIndexPolicy,
+    ParquetMetaData, ParquetMetaDataReader, RowGroupMetaData, SortingColumn,
+};
+use crate::file::page_index::column_index::ColumnIndexMetaData;
+use crate::file::page_index::offset_index::OffsetIndexMetaData;
+use crate::file::properties::WriterProperties;
+use crate::file::reader::{ChunkReader, Length};
+use crate::file::writer::SerializedFileWriter;
+use crate::schema::types::{ColumnDescriptor, SchemaDescPtr};
+
+/// Merges many Parquet files with identical schemas into a single file with
+/// right-sized row groups.
+///
+/// Input row groups are packed, in order, into output row groups limited by
+/// [`WriterProperties::max_row_group_row_count`] and
+/// [`WriterProperties::max_row_group_bytes`] (compressed size). Input row
+/// groups are never split, so a row group that alone exceeds these limits is
+/// written on its own.
+///
+/// Each column chunk of an output row group is produced in the cheapest way
+/// possible:
+///
+/// 1. If the output row group consists of a single input row group, the
+///    column chunk is copied verbatim, along with its page index and bloom
+///    filter.
+/// 2. Otherwise, if none of the input chunks are dictionary encoded, and all
+///    use the same compression codec, their compressed pages are copied
+///    verbatim into a single chunk. Statistics, the page index and bloom filters
+///    are computed from those of the input chunks.
+/// 3. Otherwise the column is decoded and re-encoded using the configured
+///    [`WriterProperties`].
+///
+/// Chunks are also re-encoded if [`WriterProperties`] enables a bloom filter
+/// for a column which cannot be derived from the input files. Note that the
+/// compression codec of copied pages is preserved, the codec configured in the
+/// [`WriterProperties`] only applies to re-encoded columns.
+///
+/// If [sorting columns](Self::with_sorting_columns) are provided, the rows of
+/// the (individually sorted) inputs are instead merged into a single sorted
+/// output, which requires re-encoding all data.
+///
+/// Encrypted input files are not supported.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use bytes::Bytes;
+/// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::arrow::merge::ParquetMerger;
+/// # use parquet::file::properties::WriterProperties;
+/// let write = |values: Vec<i64>| {
+///     let col = Arc::new(Int64Array::from(values)) as ArrayRef;
+///     let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+///     let mut buffer = Vec::new();
+///     let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+///     writer.write(&batch).unwrap();
+///     writer.close().unwrap();
+///     Bytes::from(buffer)
+/// };
+/// let inputs = vec![write(vec![1, 2]), write(vec![3]), write(vec![4, 5, 6])];
+///
+/// let props = WriterProperties::builder()
+///     .set_max_row_group_row_count(Some(4))
+///     .build();
+/// let mut output = Vec::new();
+/// let metadata = ParquetMerger::new()
+///     .with_properties(props)
+///     .merge(inputs, &mut output)
+///     .unwrap();
+///
+/// assert_eq!(metadata.num_row_groups(), 2);
+/// assert_eq!(metadata.row_group(0).num_rows(), 3);
+/// assert_eq!(metadata.row_group(1).num_rows(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParquetMerger {
+    properties: WriterProperties,
+    sorting_columns: Option<Vec<SortingColumn>>,
+    batch_size: usize,
+}
+
+impl Default for ParquetMerger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParquetMerger {
+    /// Create a new [`ParquetMerger`] with default [`WriterProperties`]
+    pub fn new() -> Self {
+        Self {
+            properties: WriterProperties::default(),
+            sorting_columns: None,
+            batch_size: 1024,
+        }
+    }
+
+    /// Set the [`WriterProperties`] used to size output row groups and to
+    /// encode any re-encoded data
+    pub fn with_properties(self, properties: WriterProperties) -> Self {
+        Self { properties, ..self }
+    }
+
+    /// Merge the rows of the inputs by the given [`SortingColumn`]s
+    ///
+    /// Each input must already be sorted by these columns, which must refer to
+    /// top-level primitive columns. The output is sorted and records the sort
+    /// order in its row group metadata.
+    pub fn with_sorting_columns(self, sorting_columns: Vec<SortingColumn>) -> Self {
+        Self {
+            sorting_columns: Some(sorting_columns),
+            ..self
+        }
+    }
+
+    /// Set the number of rows decoded at a time when re-encoding data
+    /// (defaults to 1024)
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Merge `inputs`, in order, writing the result to `output`
+    ///
+    /// All inputs must have the same Parquet schema. The key-value metadata of
+    /// the first input is carried over to the output.
+    pub fn merge<R, W>(&self, inputs: Vec<R>, output: W) -> Result<ParquetMetaData>
+    where
+        R: ChunkReader + 'static,
+        W: Write + Send,
+    {
+        if inputs.is_empty() {
+            return Err(general_err!("Must provide at least one input file"));
+        }
+        if self.batch_size == 0 {
+            return Err(general_err!("Batch size must be greater than 0"));
+        }
+
+        let mut loaded: Vec<MergeInput<R>> = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            let reader = SharedReader(Arc::new(input));
+            let metadata = ParquetMetaDataReader::new()
+                .with_page_index_policy(PageIndexPolicy::Optional)
+                .parse_and_finish(&reader)?;
+
+            // Decode all inputs with the arrow schema of the first
+            let options = match loaded.first() {
+                Some(first) => ArrowReaderOptions::new().with_schema(first.arrow_schema()),
+                None => ArrowReaderOptions::new(),
+            };
+            if let Some(first) = loaded.first() {
+                let expected = first.metadata().file_metadata().schema();
+                let actual = metadata.file_metadata().schema();
+                if expected != actual {
+                    return Err(general_err!(
+                        "inputs must have the same schema, {expected:#?} vs {actual:#?}"
+                    ));
+                }
+            }
+            let metadata = ArrowReaderMetadata::try_new(Arc::new(metadata), options)?;
+            loaded.push(MergeInput { reader, metadata });
+        }
+
+        match &self.sorting_columns {
+            Some(sorting_columns) if !sorting_columns.is_empty() => {
+                self.merge_sorted(&loaded, sorting_columns, output)
+            }
+            _ => self.merge_row_groups(&loaded, output),
+        }
+    }
+
+    /// Merge by copying whole row groups, see [`ParquetMerger`]
+    fn merge_row_groups<R: ChunkReader + 'static, W: Write + Send>(
+        &self,
+        inputs: &[MergeInput<R>],
+        output: W,
+    ) -> Result<ParquetMetaData> {
+        let first = inputs[0].metadata();
+        let schema = first.file_metadata().schema_descr_ptr();
+        let props = Arc::new(self.properties.clone());
+        let mut writer = SerializedFileWriter::new(output, schema.root_schema_ptr(), props)?;
+        for kv in first
+            .file_metadata()
+            .key_value_metadata()
+            .into_iter()
+            .flatten()
+        {
+            writer.append_key_value_metadata(kv.clone());
+        }
+
+        let factory = ArrowRowGroupWriterFactory::new(&writer, inputs[0].arrow_schema());
+        let context = MergeContext::new(inputs, schema);
+        for sources in self.plan_row_groups(inputs) {
+            let row_group_index = writer.flushed_row_groups().len();
+            let plans = (0..context.schema.num_columns())
+                .map(|col| self.plan_column(&context, &sources, col))
+                .collect::<Result<Vec<_>>>()?;
+
+            // Re-encoded columns must be fully encoded before any column is
+            // appended, as the row group must be written in column order
+            let mut encoded =
+                self.reencode(&context, &factory, row_group_index, &sources, &plans)?;
+
+            let mut row_group = writer.next_row_group()?;
+            for (col, plan) in plans.into_iter().enumerate() {
+                match plan {
+                    ColumnPlan::Copy { input, close } => {
+                        row_group.append_column(&inputs[input].reader, *close)?
+                    }
+                    ColumnPlan::Splice { chunks, close } => {
+                        let pages = chunks
+                            .into_iter()
+                            .map(|(input, range)| inputs[input].read_range(range));
+                        row_group.append_column_from_pages(pages, *close)?
+                    }
+                    ColumnPlan::Reencode => {
+                        let chunk = encoded[col].take().expect("column re-encoded");
+                        chunk.close()?.append_to_row_group(&mut row_group)?
+                    }
+                }
+            }
+            row_group.close()?;
+        }
+        writer.close()
+    }
+
+    /// Group the input row groups into output row groups
+    fn plan_row_groups<R: ChunkReader>(&self, inputs: &[MergeInput<R>]) -> Vec<Vec<Source>> {
+        let max_rows = self.properties.max_row_group_row_count();
+        let max_bytes = self.properties.max_row_group_bytes();
+
+        let mut groups = vec![];
+        let mut current = vec![];
+        let (mut rows, mut bytes) = (0, 0);
+        for (input, input_meta) in inputs.iter().enumerate() {
+            for (row_group, meta) in input_meta.metadata().row_groups().iter().enumerate() {
+                let (rg_rows, rg_bytes) =
+                    (meta.num_rows() as usize, meta.compressed_size() as usize);
+                if rg_rows == 0 {
+                    continue;
+                }
+
+                let fits = max_rows.is_none_or(|max| rows + rg_rows <= max)
+                    && max_bytes.is_none_or(|max| bytes + rg_bytes <= max);
+                if !fits && !current.is_empty() {
+                    groups.push(std::mem::take(&mut current));
+                    (rows, bytes) = (0, 0);
+                }
+                current.push(Source { input, row_group });
+                rows += rg_rows;
+                bytes += rg_bytes;
+            }
+        }
+        if !current.is_empty() {
+            groups.push(current);
+        }
+        groups
+    }
+
+    /// Determine how to produce leaf column `col` of the output row group
+    /// made up of `sources`
+    fn plan_column<R: ChunkReader>(
+        &self,
+        context: &MergeContext<'_, R>,
+        sources: &[Source],
+        col: usize,
+    ) -> Result<ColumnPlan> {
+        let descr = context.schema.column(col);
+        let bloom_filter_required = self
+            .properties
+            .bloom_filter_properties(descr.path())
+            .is_some();
+
+        let chunks: Vec<_> = sources
+            .iter()
+            .map(|source| context.chunk(source, col))
+            .collect();
+
+        if let [chunk] = chunks.as_slice() {
+            let bloom_filter = chunk.bloom_filter()?;
+            if bloom_filter_required && bloom_filter.is_none() {
+                return Ok(ColumnPlan::Reencode);
+            }
+            let close = ColumnCloseResult {
+                bytes_written: chunk.metadata.compressed_size() as _,
+                rows_written: chunk.row_group.num_rows() as _,
+                metadata: chunk.metadata.clone(),
+                bloom_filter,
+                column_index: chunk.column_index.cloned(),
+                offset_index: chunk.offset_index.cloned(),
+            };
+            return Ok(ColumnPlan::Copy {
+                input: chunk.input,
+                close: Box::new(close),
+            });
+        }
+
+        let codec = chunks[0].metadata.compression_codec();
+        let spliceable = chunks.iter().all(|chunk| {
+            chunk.metadata.compression_codec() == codec
+                && chunk.metadata.dictionary_page_offset().is_none()
+                && !chunk
+                    .metadata
+                    .encodings()
+                    .any(|e| matches!(e, Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY))
+                && !chunk.is_encrypted()
+        });
+        if !spliceable {
+            return Ok(ColumnPlan::Reencode);
+        }
+
+        let bloom_filter = merge_bloom_filters(&chunks)?;
+        if bloom_filter_required && bloom_filter.is_none() {
+            return Ok(ColumnPlan::Reencode);
+        }
+
+        let encodings = chunks.iter().flat_map(|c| c.metadata.encodings()).collect();
+        let mut builder = ColumnChunkMetaData::builder(Arc::clone(&descr))
+            .set_compression_codec(codec)
+            .set_encodings(encodings);
+
+        let sum = |f: &dyn Fn(&ColumnChunkMetaData) -> i64| -> i64 {
+            chunks.iter().map(|c| f(c.metadata)).sum()
+        };
+        let total_compressed = sum(&|c| c.compressed_size());
+        builder = builder
+            .set_total_compressed_size(total_compressed)
+            .set_total_uncompressed_size(sum(&|c| c.uncompressed_size()))
+            .set_num_values(sum(&|c| c.num_values()))
+            // Offsets are relative to the start of the spliced chunk
+            .set_data_page_offset(0)
+            .set_unencoded_byte_array_data_bytes(
+                chunks
+                    .iter()
+                    .map(|c| c.metadata.unencoded_byte_array_data_bytes())
+                    .sum(),
+            )
+            .set_repetition_level_histogram(merge_histograms(
+                chunks
+                    .iter()
+                    .map(|c| c.metadata.repetition_level_histogram()),
+            ))
+            .set_definition_level_histogram(merge_histograms(
+                chunks
+                    .iter()
+                    .map(|c| c.metadata.definition_level_histogram()),
+            ));
+        if let Some(statistics) = merge_chunk_statistics(&descr, &chunks) {
+            builder = builder.set_statistics(statistics);
+        }
+
+        let rows_written = chunks.iter().map(|c| c.row_group.num_rows() as u64).sum();
+        let close = ColumnCloseResult {
+            bytes_written: total_compressed as _,
+            rows_written,
+            metadata: builder.build()?,
+            bloom_filter,
+            column_index: concat_column_indexes(&descr, &chunks),
+            offset_index: concat_offset_indexes(&chunks),
+        };
+        Ok(ColumnPlan::Splice {
+            chunks: chunks.iter().map(|c| (c.input, c.byte_range())).collect(),
+            close: Box::new(close),
+        })
+    }
+
+    /// Decode and re-encode all columns planned as [`ColumnPlan::Reencode`],
+    /// returning the encoded column writers indexed by leaf column
+    fn reencode<R: ChunkReader + 'static>(
+        &self,
+        context: &MergeContext<'_, R>,
+        factory: &ArrowRowGroupWriterFactory,
+        row_group_index: usize,
+        sources: &[Source],
+        plans: &[ColumnPlan],
+    ) -> Result<Vec<Option<ArrowColumnWriter>>> {
+        let mut writers: Vec<_> = factory
+            .create_column_writers(row_group_index)?
+            .into_iter()
+            .zip(plans)
+            .map(|(writer, plan)| matches!(plan, ColumnPlan::Reencode).then_some(writer))
+            .collect();
+
+        let roots: Vec<usize> = (0..context.root_leaves.len())
+            .filter(|root| {
+                context.root_leaves[*root]
+                    .clone()
+                    .any(|c| writers[c].is_some())
+            })
+            .collect();
+        if roots.is_empty() {
+            return Ok(writers);
+        }
+
+        let arrow_schema = context.inputs[0].arrow_schema();
+        let mask = ProjectionMask::roots(&context.schema, roots.iter().copied());
+        for source in sources {
+            let input = &context.inputs[source.input];
+            let reader = ParquetRecordBatchReaderBuilder::new_with_metadata(
+                input.reader.clone(),
+                input.metadata.clone(),
+            )
+            .with_batch_size(self.batch_size)
+            .with_row_groups(vec![source.row_group])
+            .with_projection(mask.clone())
+            .build()?;
+
+            for batch in reader {
+                let batch = batch?;
+                for (column, root) in batch.columns().iter().zip(&roots) {
+                    let leaves = compute_leaves(arrow_schema.field(*root), column)?;
+                    for (leaf, col) in leaves.iter().zip(context.root_leaves[*root].clone()) {
+                        if let Some(writer) = writers[col].as_mut() {
+                            writer.write(leaf)?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(writers)
+    }
+
+    /// Merge the rows of the sorted inputs, see [`Self::with_sorting_columns`]
+    fn merge_sorted<R: ChunkReader + 'static, W: Write + Send>(
+        &self,
+        inputs: &[MergeInput<R>],
+        sorting_columns: &[SortingColumn],
+        output: W,
+    ) -> Result<ParquetMetaData> {
+        let first = inputs[0].metadata();
+        let schema = first.file_metadata().schema_descr_ptr();
+        let arrow_schema = inputs[0].arrow_schema();
+
+        let key = SortKey::try_new(&schema, &arrow_schema, sorting_columns)?;
+
+        let props = self
+            .properties
+            .clone()
+            .into_builder()
+            .set_sorting_columns(Some(sorting_columns.to_vec()))
+            .build();
+        let options = ArrowWriterOptions::new()
+            .with_properties(props)
+            .with_parquet_schema(schema.as_ref().clone())
+            .with_skip_arrow_metadata(true);
+        let mut writer = ArrowWriter::try_new_with_options(output, arrow_schema, options)?;
+        for kv in first
+            .file_metadata()
+            .key_value_metadata()
+            .into_iter()
+            .flatten()
+        {
+            writer.append_key_value_metadata(kv.clone());
+        }
+
+        let mut merge = SortedMerge {
+            key,
+            batches: vec![],
+            cursors: Vec::with_capacity(inputs.len()),
+            heap: BinaryHeap::with_capacity(inputs.len()),
+        };
+        for input in inputs {
+            let reader = ParquetRecordBatchReaderBuilder::new_with_metadata(
+                input.reader.clone(),
+                input.metadata.clone(),
+            )
+            .with_batch_size(self.batch_size)
+            .build()?;
+            merge.add_input(reader)?;
+        }
+
+        let mut indices = Vec::with_capacity(self.batch_size);
+        while let Some(next) = merge.next_row()? {
+            indices.push(next);
+            if indices.len() == self.batch_size {
+                writer.write(&merge.take(&indices)?)?;
+                indices.clear();
+            }
+        }
+        if !indices.is_empty() {
+            writer.write(&merge.take(&indices)?)?;
+        }
+        writer.close()
+    }
+}
+
+/// A [`ChunkReader`] shared by all the readers created for an input
+struct SharedReader<R>(Arc<R>);
+
+impl<R> Clone for SharedReader<R> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<R: ChunkReader> Length for SharedReader<R> {
+    fn len(&self) -> u64 {
+        self.0.len()
+    }
+}
+
+impl<R: ChunkReader> ChunkReader for SharedReader<R> {
+    type T = R::T;
+
+    fn get_read(&self, start: u64) -> Result<Self::T> {
+        self.0.get_read(start)
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Bytes> {
+        self.0.get_bytes(start, length)
+    }
+}
+
+/// An input file of a [`ParquetMerger`]
+struct MergeInput<R> {
+    reader: SharedReader<R>,
+    metadata: ArrowReaderMetadata,
+}
+
+impl<R: ChunkReader> MergeInput<R> {
+    fn metadata(&self) -> &ParquetMetaData {
+        self.metadata.metadata()
+    }
+
+    fn arrow_schema(&self) -> SchemaRef {
+        Arc::clone(self.metadata.schema())
+    }
+
+    fn read_range(&self, range: Range<u64>) -> Result<Bytes> {
+        self.reader
+            .get_bytes(range.start, (range.end - range.start) as usize)
+    }
+}
+
+/// A row group of an input
+struct Source {
+    input: usize,
+    row_group: usize,
+}
+
+/// How a column chunk of an output row group is produced
+enum ColumnPlan {
+    /// Copy a single input column chunk
+    Copy {
+        input: usize,
+        close: Box<ColumnCloseResult>,
+    },
+    /// Concatenate the pages of the given byte ranges of the inputs
+    Splice {
+        chunks: Vec<(usize, Range<u64>)>,
+        close: Box<ColumnCloseResult>,
+    },
+    /// Decode and re-encode the column
+    Reencode,
+}
+
+/// State shared by the planning of all row groups
+struct MergeContext<'a, R> {
+    inputs: &'a [MergeInput<R>],
+    schema: SchemaDescPtr,
+    /// The leaf columns of each root column
+    root_leaves: Vec<Range<usize>>,
+}
+
+impl<'a, R: ChunkReader> MergeContext<'a, R> {
+    fn new(inputs: &'a [MergeInput<R>], schema: SchemaDescPtr) -> Self {
+        let mut root_leaves: Vec<Range<usize>> =
+            vec![0..0; schema.root_schema().get_fields().len()];
+        for col in 0..schema.num_columns() {
+            let leaves = &mut root_leaves[schema.get_column_root_idx(col)];
+            if leaves.end == 0 {
+                leaves.start = col;
+            }
+            leaves.end = col + 1;
+        }
+        Self {
+            inputs,
+            schema,
+            root_leaves,
+        }
+    }
+
+    fn chunk(&self, source: &Source, col: usize) -> InputChunk<'a, R> {
+        let input = &self.inputs[source.input];
+        let metadata = input.metadata.metadata();
+        let row_group = metadata.row_group(source.row_group);
+        InputChunk {
+            input: source.input,
+            reader: &input.reader,
+            row_group,
+            metadata: row_group.column(col),
+            column_index: metadata
+                .column_index()
+                .and_then(|i| i.get(source.row_group))
+                .and_then(|i| i.get(col))
+                .filter(|i| !matches!(i, ColumnIndexMetaData::NONE)),
+            offset_index: metadata
+                .offset_index()
+                .and_then(|i| i.get(source.row_group))
+                .and_then(|i| i.get(col)),
+        }
+    }
+}
+
+/// A column chunk of an input row group
+struct InputChunk<'a, R> {
+    input: usize,
+    reader: &'a SharedReader<R>,
+    row_group: &'a RowGroupMetaData,
+    metadata: &'a ColumnChunkMetaData,
+    column_index: Option<&'a ColumnIndexMetaData>,
+    offset_index: Option<&'a OffsetIndexMetaData>,
+}
+
+impl<R: ChunkReader> InputChunk<'_, R> {
+    fn bloom_filter(&self) -> Result<Option<Sbbf>> {
+        Sbbf::read_from_column_chunk(self.metadata, self.reader)
+    }
+
+    fn byte_range(&self) -> Range<u64> {
+        let (start, length) = self.metadata.byte_range();
+        start..start + length
+    }
+
+    #[cfg(feature = "encryption")]
+    fn is_encrypted(&self) -> bool {
+        self.metadata.crypto_metadata().is_some()
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    fn is_encrypted(&self) -> bool {
+        false
+    }
+}
+
+/// Combine the bloom filters of `chunks`, returning `None` if any chunk has
+/// no bloom filter or they cannot be combined
+fn merge_bloom_filters<R: ChunkReader>(chunks: &[InputChunk<'_, R>]) -> Result<Option<Sbbf>> {
+    let mut merged: Option<Sbbf> = None;
+    for chunk in chunks {
+        let Some(bloom_filter) = chunk.bloom_filter()? else {
+            return Ok(None);
+        };
+        match merged.as_mut() {
+            None => merged = Some(bloom_filter),
+            Some(merged) => {
+                if merged.union(&bloom_filter).is_err() {
+                    return Ok(None);
+                }
+            }
+        }
+    }
+    Ok(merged)
+}
+
+/// Combine the chunk statistics of `chunks`, returning `None` if any chunk has
+/// no statistics
+fn merge_chunk_statistics<R>(
+    descr: &ColumnDescriptor,
+    chunks: &[InputChunk<'_, R>],
+) -> Option<crate::file::statistics::Statistics> {
+    let (first, rest) = chunks.split_first()?;
+    let mut merged = first.metadata.statistics()?.clone();
+    let mut num_values = first.metadata.num_values() as u64;
+    for chunk in rest {
+        let statistics = chunk.metadata.statistics()?;
+        let chunk_values = chunk.metadata.num_values() as u64;
+        merged = merge_statistics(descr, &merged, num_values, statistics, chunk_values)?;
+        num_values += chunk_values;
+    }
+    Some(merged)
+}
+
+/// Sum the level histograms, returning `None` if any is missing
+fn merge_histograms<'a>(
+    mut histograms: impl Iterator<Item = Option<&'a LevelHistogram>>,
+) -> Option<LevelHistogram> {
+    let mut merged = histograms.next()??.clone();
+    for histogram in histograms {
+        merged.add(histogram?);
+    }
+    Some(merged)
+}
+
+/// Concatenate the offset indexes of `chunks` for the spliced chunk, whose
+/// offsets start at 0
+fn concat_offset_indexes<R>(chunks: &[InputChunk<'_, R>]) -> Option<OffsetIndexMetaData> {
+    let mut builder = OffsetIndexBuilder::new();
+    let mut chunk_offset = 0;
+    for chunk in chunks {
+        let index = chunk.offset_index?;
+        let (start, length) = chunk.metadata.byte_range();
+        let num_rows = chunk.row_group.num_rows();
+        let locations = index.page_locations();
+        for (page, location) in locations.iter().enumerate() {
+            let end = match locations.get(page + 1) {
+                Some(next) => next.first_row_index,
+                None => num_rows,
+            };
+            builder.append_row_count(end - location.first_row_index);
+            builder.append_offset_and_size(
+                location.offset - start as i64 + chunk_offset,
+                location.compressed_page_size,
+            );
+        }
+        match index.unencoded_byte_array_data_bytes() {
+            Some(bytes) => bytes
+                .iter()
+                .for_each(|b| builder.append_unencoded_byte_array_data_bytes(Some(*b))),
+            None => builder.append_unencoded_byte_array_data_bytes(None),
+        }
+        chunk_offset += length as i64;
+    }
+
+    let mut index = builder.build();
+    let num_pages = index.page_locations.len();
+    // Only retain the unencoded sizes if known for every page
+    if index
+        .unencoded_byte_array_data_bytes
+        .as_ref()
+        .is_some_and(|b| b.len() != num_pages)
+    {
+        index.unencoded_byte_array_data_bytes = None;
+    }
+    Some(index)
+}
+
+/// Concatenate the column indexes of `chunks`, returning `None` if any chunk
+/// has no column index
+fn concat_column_indexes<R>(
+    descr: &ColumnDescriptor,
+    chunks: &[InputChunk<'_, R>],
+) -> Option<ColumnIndexMetaData> {
+    let indexes = chunks
+        .iter()
+        .map(|c| c.column_index)
+        .collect::<Option<Vec<_>>>()?;
+
+    let has_nan_counts = indexes[0].nan_counts().is_some();
+    let has_rep_histograms = indexes[0].repetition_level_histogram(0).is_some();
+    let has_def_histograms = indexes[0].definition_level_histogram(0).is_some();
+    let consistent = indexes.iter().all(|index| {
+        index.null_counts().is_some()
+            && index.nan_counts().is_some() == has_nan_counts
+            && index.repetition_level_histogram(0).is_some() == has_rep_histograms
+            && index.definition_level_histogram(0).is_some() == has_def_histograms
+    });
+    if !consistent {
+        return None;
+    }
+
+    let mut builder = ColumnIndexBuilder::new(descr.physical_type());
+    for index in indexes {
+        for page in 0..index.num_pages() as usize {
+            let null_page = index.is_null_page(page);
+            let (min, max) = match null_page {
+                true => (vec![], vec![]),
+                false => page_bounds(index, page)?,
+            };
+            builder.append(
+                null_page,
+                min,
+                max,
+                index.null_count(page)?,
+                index.nan_count(page),
+            );
+            let histogram = |h: Option<&[i64]>| h.map(|h| LevelHistogram::from(h.to_vec()));
+            builder.append_histograms(
+                &histogram(index.repetition_level_histogram(page)),
+                &histogram(index.definition_level_histogram(page)),
+            );
+        }
+    }
+    builder.build().ok()
+}
+
+/// Returns the encoded min and max values of `page`
+fn page_bounds(index: &ColumnIndexMetaData, page: usize) -> Option<(Vec<u8>, Vec<u8>)> {
+    use crate::data_type::AsBytes;
+
+    fn bounds<T: AsBytes + ?Sized>(min: Option<&T>, max: Option<&T>) -> Option<(Vec<u8>, Vec<u8>)> {
+        Some((min?.as_bytes().to_vec(), max?.as_bytes().to_vec()))
+    }
+
+    match index {
+        ColumnIndexMetaData::NONE => None,
+        ColumnIndexMetaData::BOOLEAN(i) => bounds(i.min_value(page), i.max_value(page)),
+        ColumnIndexMetaData::INT32(i) => bounds(i.min_value(page), i.max_value(page)),
+        ColumnIndexMetaData::INT64(i) => bounds(i.min_value(page), i.max_value(page)),
+        ColumnIndexMetaData::INT96(i) => bounds(i.min_value(page), i.max_value(page)),
+        ColumnIndexMetaData::FLOAT(i) => bounds(i.min_value(page), i.max_value(page)),
+        ColumnIndexMetaData::DOUBLE(i) => bounds(i.min_value(page), i.max_value(page)),
+        ColumnIndexMetaData::BYTE_ARRAY(i) | ColumnIndexMetaData::FIXED_LEN_BYTE_ARRAY(i) => {
+            bounds(i.min_value(page), i.max_value(page))
+        }
+    }
+}
+
+/// The position of an input within a [`SortedMerge`]
+struct MergeCursor {
+    reader: ParquetRecordBatchReader,
+    /// Index of the current batch in [`SortedMerge::batches`]
+    batch: usize,
+    rows: Rows,
+    offset: usize,
+}
+
+/// A k-way merge of the rows of sorted inputs
+struct SortedMerge {
+    key: SortKey,
+    /// The batches referenced by the cursors or pending output
+    batches: Vec<RecordBatch>,
+    /// The cursor of each input, `None` once exhausted
+    cursors: Vec<Option<MergeCursor>>,
+    /// The current row of each non-exhausted input
+    heap: BinaryHeap<Reverse<(OwnedRow, usize)>>,
+}
+
+impl SortedMerge {
+    fn add_input(&mut self, reader: ParquetRecordBatchReader) -> Result<()> {
+        let idx = self.cursors.len();
+        let cursor = self.next_batch(idx, reader, None)?;
+        if let Some(cursor) = &cursor {
+            self.heap.push(Reverse((cursor.rows.row(0).owned(), idx)));
+        }
+        self.cursors.push(cursor);
+        Ok(())
+    }
+
+    /// Read the next non-empty batch from `reader`, verifying it is sorted
+    /// and follows on from the `previous` row of input `idx`
+    fn next_batch(
+        &mut self,
+        idx: usize,
+        mut reader: ParquetRecordBatchReader,
+        previous: Option<OwnedRow>,
+    ) -> Result<Option<MergeCursor>> {
+        let batch = loop {
+            match reader.next().transpose()? {
+                Some(batch) if batch.num_rows() == 0 => continue,
+                Some(batch) => break batch,
+                None => return Ok(None),
+            }
+        };
+
+        let rows = self.key.convert(&batch)?;
+        if first_unsorted(&rows, 0, previous.as_ref().map(|p| p.row())).is_some() {
+            return Err(general_err!(
+                "Input {idx} is not sorted by the sorting columns"
+            ));
+        }
+
+        self.batches.push(batch);
+        Ok(Some(MergeCursor {
+            reader,
+            batch: self.batches.len() - 1,
+            rows,
+            offset: 0,
+        }))
+    }
+
+    /// Returns the `(batch, row)` of the next row in sort order
+    fn next_row(&mut self) -> Result<Option<(usize, usize)>> {
+        let Some(Reverse((_, idx))) = self.heap.pop() else {
+            return Ok(None);
+        };
+
+        let cursor = self.cursors[idx].as_mut().expect("cursor in heap");
+        let next = (cursor.batch, cursor.offset);
+        cursor.offset += 1;
+
+        if cursor.offset < cursor.rows.num_rows() {
+            let row = cursor.rows.row(cursor.offset).owned();
+            self.heap.push(Reverse((row, idx)));
+        } else {
+            let cursor = self.cursors[idx].take().expect("cursor in heap");
+            let previous = cursor.rows.row(cursor.offset - 1).owned();
+            let cursor = self.next_batch(idx, cursor.reader, Some(previous))?;
+            if let Some(cursor) = &cursor {
+                self.heap.push(Reverse((cursor.rows.row(0).owned(), idx)));
+            }
+            self.cursors[idx] = cursor;
+        }
+        Ok(Some(next))
+    }
+
+    /// Build the batch of rows identified by `indices`, releasing any batches
+    /// no longer referenced by a cursor
+    fn take(&mut self, indices: &[(usize, usize)]) -> Result<RecordBatch> {
+        let batches: Vec<_> = self.batches.iter().collect();
+        let batch = interleave_record_batch(&batches, indices)?;
+
+        let mut retained = Vec::with_capacity(self.cursors.len());
+        for cursor in self.cursors.iter_mut().flatten() {
+            retained.push(self.batches[cursor.batch].clone());
+            cursor.batch = retained.len() - 1;
+        }
+        self.batches = retained;
+        Ok(batch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::Compression;
+    use crate::file::properties::EnabledStatistics;
+    use crate::file::statistics::Statistics;
+    use arrow::compute::concat_batches;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+
+    fn write(batch: &RecordBatch, props: WriterProperties) -> Bytes {
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buffer)
+    }
+
+    fn batch(ids: impl IntoIterator<Item = i32>) -> RecordBatch {
+        let ids: Vec<_> = ids.into_iter().collect();
+        let names: Vec<_> = ids.iter().map(|id| format!("name_{id}")).collect();
+        RecordBatch::try_from_iter([
+            ("id", Arc::new(Int32Array::from(ids)) as ArrayRef),
+            ("name", Arc::new(StringArray::from(names)) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    fn read(data: Vec<u8>) -> (RecordBatch, Arc<ParquetMetaData>) {
+        let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(data), options)
+                .unwrap();
+        let metadata = Arc::clone(builder.metadata());
+        let schema = Arc::clone(builder.schema());
+        let batches: Vec<_> = builder.build().unwrap().map(|b| b.unwrap()).collect();
+        (concat_batches(&schema, &batches).unwrap(), metadata)
+    }
+
+    fn plain_props() -> WriterProperties {
+        WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_compression(Compression::SNAPPY)
+            .set_statistics_enabled(EnabledStatistics::Page)
+            .build()
+    }
+
+    #[test]
+    fn test_merge_copies_row_groups() {
+        let inputs = vec![
+            write(&batch(0..10), plain_props()),
+            write(&batch(10..30), WriterProperties::default()),
+        ];
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(15))
+            .build();
+
+        let mut output = Vec::new();
+        let metadata = ParquetMerger::new()
+            .with_properties(props)
+            .merge(inputs.clone(), &mut output)
+            .unwrap();
+        assert_eq!(metadata.num_row_groups(), 2);
+
+        let (actual, metadata) = read(output);
+        assert_eq!(actual, batch(0..30));
+        assert_eq!(metadata.row_group(0).num_rows(), 10);
+        assert_eq!(metadata.row_group(1).num_rows(), 20);
+
+        // Both row groups are copied verbatim, including the compression
+        let column = metadata.row_group(0).column(0);
+        assert_eq!(column.compression(), Compression::SNAPPY);
+        assert!(metadata.offset_index().is_some());
+    }
+
+    #[test]
+    fn test_merge_splices_pages() {
+        let inputs = vec![
+            write(&batch(0..10), plain_props()),
+            write(&batch(10..20), plain_props()),
+            write(&batch(20..25), plain_props()),
+        ];
+
+        let mut output = Vec::new();
+        ParquetMerger::new()
+            .merge(inputs.clone(), &mut output)
+            .unwrap();
+
+        let (actual, metadata) = read(output);
+        assert_eq!(actual, batch(0..25));
+        assert_eq!(metadata.num_row_groups(), 1);
+
+        let row_group = metadata.row_group(0);
+        let id = row_group.column(0);
+        // The pages are copied so the codec and size are unchanged
+        assert_eq!(id.compression(), Compression::SNAPPY);
+        let input_size: i64 = inputs
+            .iter()
+            .map(|i| {
+                let metadata = ParquetMetaDataReader::new().parse_and_finish(i).unwrap();
+                metadata.row_group(0).column(0).compressed_size()
+            })
+            .sum();
+        assert_eq!(id.compressed_size(), input_size);
+
+        match id.statistics().unwrap() {
+            Statistics::Int32(s) => {
+                assert_eq!(s.min_opt(), Some(&0));
+                assert_eq!(s.max_opt(), Some(&24));
+                assert_eq!(s.null_count_opt(), Some(0));
+            }
+            s => panic!("unexpected statistics {s:?}"),
+        }
+
+        let offset_index = &metadata.offset_index().unwrap()[0][0];
+        let first_rows: Vec<_> = offset_index
+            .page_locations()
+            .iter()
+            .map(|l| l.first_row_index)
+            .collect();
+        assert_eq!(first_rows, vec![0, 10, 20]);
+
+        let column_index = &metadata.column_index().unwrap()[0][0];
+        assert_eq!(column_index.num_pages(), 3);
+    }
+
+    #[test]
+    fn test_merge_reencodes_dictionaries() {
+        let inputs = vec![
+            write(&batch(0..10), WriterProperties::default()),
+            write(&batch(10..20), WriterProperties::default()),
+        ];
+        let props = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+
+        let mut output = Vec::new();
+        ParquetMerger::new()
+            .with_properties(props)
+            .merge(inputs, &mut output)
+            .unwrap();
+
+        let (actual, metadata) = read(output);
+        assert_eq!(actual, batch(0..20));
+        assert_eq!(metadata.num_row_groups(), 1);
+        // Re-encoded columns use the configured codec
+        let column = metadata.row_group(0).column(1);
+        assert_eq!(column.compression(), Compression::SNAPPY);
+        assert!(column.dictionary_page_offset().is_some());
+    }
+
+    #[test]
+    fn test_merge_bloom_filters() {
+        let bloom_props = || {
+            WriterProperties::builder()
+                .set_dictionary_enabled(false)
+                .set_bloom_filter_enabled(true)
+                .build()
+        };
+        let inputs = vec![
+            write(&batch(0..10), bloom_props()),
+            write(&batch(10..20), bloom_props()),
+            write(&batch(20..30), plain_props()),
+        ];
+
+        for (inputs, props) in [
+            // Bloom filters are merged from the inputs
+            (inputs[..2].to_vec(), WriterProperties::default()),
+            // The last input has no bloom filters so they must be recomputed
+            (inputs.clone(), bloom_props()),
+        ] {
+            let mut output = Vec::new();
+            ParquetMerger::new()
+                .with_properties(props)
+                .merge(inputs, &mut output)
+                .unwrap();
+
+            let output = Bytes::from(output);
+            let metadata = ParquetMetaDataReader::new()
+                .parse_and_finish(&output)
+                .unwrap();
+            let row_group = metadata.row_group(0);
+            let bloom_filter = Sbbf::read_from_column_chunk(row_group.column(0), &output)
+                .unwrap()
+                .unwrap();
+            for i in 0..row_group.num_rows() as i32 {
+                assert!(bloom_filter.check(&i));
+            }
+        }
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let inputs = vec![
+            write(&batch([1, 4, 7, 10]), WriterProperties::default()),
+            write(&batch([2, 5, 8]), plain_props()),
+            write(&batch([3, 6, 9, 11, 12]), WriterProperties::default()),
+        ];
+        let sorting_columns = vec![SortingColumn {
+            column_idx: 0,
+            descending: false,
+            nulls_first: false,
+        }];
+
+        let mut output = Vec::new();
+        ParquetMerger::new()
+            .with_batch_size(2)
+            .with_sorting_columns(sorting_columns.clone())
+            .merge(inputs, &mut output)
+            .unwrap();
+
+        let (actual, metadata) = read(output);
+        assert_eq!(actual, batch(1..13));
+        assert_eq!(
+            metadata.row_group(0).sorting_columns(),
+            Some(&sorting_columns)
+        );
+    }
+
+    #[test]
+    fn test_merge_sorted_rejects_unsorted_input() {
+        let inputs = vec![
+            write(&batch([1, 2]), WriterProperties::default()),
+            write(&batch([5, 3]), WriterProperties::default()),
+        ];
+        let err = ParquetMerger::new()
+            .with_sorting_columns(vec![SortingColumn {
+                column_idx: 0,
+                descending: false,
+                nulls_first: false,
+            }])
+            .merge(inputs, Vec::new())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Input 1 is not sorted by the sorting columns"
+        );
+    }
+
+    #[test]
+    fn test_merge_schema_mismatch() {
+        let other =
+            RecordBatch::try_from_iter([("id", Arc::new(Int32Array::from(vec![1])) as ArrayRef)])
+                .unwrap();
+        let inputs = vec![
+            write(&batch(0..2), WriterProperties::default()),
+            write(&other, WriterProperties::default()),
+        ];
+        let err = ParquetMerger::new().merge(inputs, Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("inputs must have the same schema"));
+    }
+}
@@ -250,7 +250,9 @@ impl ParquetPushDecoderBuilder {
     }
 
     /// Create a [`ParquetPushDecoder`] with the configured options
-    pub fn build(self) -> Result<ParquetPushDecoder, ParquetError> {
+    pub fn build(mut self) -> Result<ParquetPushDecoder, ParquetError> {
+        self.apply_predicate()?;
+
         let Self {
             input: PushDecoderInput { buffers },
             metadata: parquet_metadata,
@@ -260,6 +262,8 @@ impl ParquetPushDecoderBuilder {
             row_groups,
             projection,
             filter,
+            // Applied above
+            predicate: _,
             selection,
             limit,
             offset,
@@ -346,6 +350,8 @@ fn builder_from_remaining(parts: RemainingRowGroupsParts) -> ParquetPushDecoderB
         row_groups: Some(row_groups),
         projection,
         filter,
+        // Any predicate was applied to the filter and selection when first built
+        predicate: None,
         selection,
         row_selection_policy,
         limit,
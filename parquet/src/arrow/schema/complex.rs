@@ -486,7 +486,17 @@ impl Visitor {
             self.dispatch(map_value, context)?
         };
 
-        // Need both columns to be projected
+        // If only one of the key or value is projected, it is read as a list
+        let partial = |child: ParquetField, arrow_field: Field| ParquetField {
+            rep_level,
+            def_level,
+            nullable,
+            arrow_type: DataType::List(Arc::new(arrow_field)),
+            field_type: ParquetFieldType::Group {
+                children: vec![child],
+            },
+        };
+
         match (maybe_key, maybe_value) {
             (Some(key), Some(value)) => {
                 let key_field = Arc::new(
@@ -516,7 +526,15 @@ impl Visitor {
                     },
                 }))
             }
-            _ => Ok(None),
+            (Some(key), None) => {
+                let key_field = convert_field(map_key, &key, arrow_key, true)?.with_nullable(false);
+                Ok(Some(partial(key, key_field)))
+            }
+            (None, Some(value)) => {
+                let value_field = convert_field(map_value, &value, arrow_value, true)?;
+                Ok(Some(partial(value, value_field)))
+            }
+            (None, None) => Ok(None),
         }
     }
 
@@ -32,19 +32,28 @@
 //! ```
 //! cargo run --features=cli --bin parquet-rewrite -- -i XYZ.parquet -o XYZ2.parquet
 //! ```
+//!
+//! Bloom filters can be added to an existing file without re-encoding its data
+//! pages, and exported to (or imported from) a standalone sidecar file:
+//! ```
+//! parquet-rewrite -i XYZ.parquet -o XYZ2.parquet --add-bloom-filter a,b.c --export-bloom-filters XYZ2.bf
+//! ```
 
 use std::fs::File;
+use std::io::BufWriter;
 
 use arrow_array::RecordBatchReader;
 use clap::{Parser, ValueEnum, builder::PossibleValue};
 use parquet::{
     arrow::{ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder},
     basic::{BrotliLevel, Compression, Encoding, GzipLevel, ZstdLevel},
+    bloom_filter::{BloomFilterRewriter, BloomFilterSidecar},
     file::{
         properties::{BloomFilterPosition, EnabledStatistics, WriterProperties, WriterVersion},
         reader::FileReader,
         serialized_reader::SerializedFileReader,
     },
+    schema::types::ColumnPath,
 };
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
@@ -217,6 +226,28 @@ impl From<BloomFilterPositionArgs> for BloomFilterPosition {
     }
 }
 
+/// Options that only apply when re-encoding the input, and so conflict with
+/// adding bloom filters without re-encoding
+const REWRITE_ARGS: [&str; 17] = [
+    "compression",
+    "compression_level",
+    "encoding",
+    "dictionary_enabled",
+    "dictionary_page_size_limit",
+    "max_row_group_size",
+    "data_page_row_count_limit",
+    "data_page_size_limit",
+    "statistics_truncate_length",
+    "column_index_truncate_length",
+    "write_page_header_statistics",
+    "write_path_in_schema",
+    "bloom_filter_enabled",
+    "statistics_enabled",
+    "writer_version",
+    "write_batch_size",
+    "coerce_types",
+];
+
 #[derive(Debug, Parser)]
 #[clap(author, version, about("Read and write parquet file with potentially different settings"), long_about = None)]
 struct Args {
@@ -314,11 +345,96 @@ struct Args {
     /// Sets whether to coerce Arrow types to match Parquet specification
     #[clap(long)]
     coerce_types: Option<bool>,
+
+    /// Add bloom filters to the given columns, identified by their dot
+    /// separated paths, without re-encoding any data pages.
+    ///
+    /// In this mode only the bloom filter fpp, ndv and position options apply,
+    /// other options are rejected.
+    #[clap(long, value_delimiter = ',', conflicts_with_all = REWRITE_ARGS)]
+    add_bloom_filter: Vec<String>,
+
+    /// Path to a bloom filter sidecar file whose bloom filters are added to
+    /// the output without re-encoding any data pages.
+    ///
+    /// As with `--add-bloom-filter`, only the bloom filter fpp, ndv and
+    /// position options apply.
+    #[clap(long, conflicts_with_all = REWRITE_ARGS)]
+    import_bloom_filters: Option<String>,
+
+    /// Path to write the bloom filters of the output file to, as a sidecar file.
+    #[clap(long)]
+    export_bloom_filters: Option<String>,
+}
+
+/// Add bloom filters to the input file without re-encoding any data pages
+fn add_bloom_filters(args: &Args) {
+    let mut writer_properties_builder = WriterProperties::builder();
+    for column in &args.add_bloom_filter {
+        let path = ColumnPath::new(column.split('.').map(String::from).collect());
+        writer_properties_builder =
+            writer_properties_builder.set_column_bloom_filter_enabled(path.clone(), true);
+        if let Some(value) = args.bloom_filter_fpp {
+            writer_properties_builder =
+                writer_properties_builder.set_column_bloom_filter_fpp(path.clone(), value);
+        }
+        if let Some(value) = args.bloom_filter_ndv {
+            writer_properties_builder =
+                writer_properties_builder.set_column_bloom_filter_max_ndv(path, value);
+        }
+    }
+    if let Some(value) = args.bloom_filter_position {
+        writer_properties_builder =
+            writer_properties_builder.set_bloom_filter_position(value.into());
+    }
+
+    let mut rewriter =
+        BloomFilterRewriter::new().with_properties(writer_properties_builder.build());
+    if let Some(path) = &args.import_bloom_filters {
+        let bytes = std::fs::read(path).expect("Unable to read bloom filter sidecar");
+        let sidecar =
+            BloomFilterSidecar::from_bytes(&bytes).expect("Failed to parse bloom filter sidecar");
+        rewriter = rewriter.with_bloom_filters(sidecar);
+    }
+
+    rewriter
+        .rewrite(
+            File::open(&args.input).expect("Unable to open input file"),
+            File::create(&args.output).expect("Unable to open output file"),
+        )
+        .expect("adding bloom filters");
+}
+
+/// Write the bloom filters of the output file to a sidecar file
+fn export_bloom_filters(output: &str, path: &str) {
+    let sidecar = BloomFilterSidecar::read_from_file(
+        &File::open(output).expect("Unable to open output file"),
+    )
+    .expect("reading bloom filters");
+    let mut file = BufWriter::new(File::create(path).expect("Unable to open sidecar file"));
+    sidecar.write(&mut file).expect("writing bloom filters");
+    file.into_inner()
+        .expect("writing bloom filters")
+        .sync_all()
+        .expect("writing bloom filters");
 }
 
 fn main() {
     let args = Args::parse();
 
+    if !args.add_bloom_filter.is_empty() || args.import_bloom_filters.is_some() {
+        add_bloom_filters(&args);
+    } else {
+        rewrite(&args);
+    }
+
+    if let Some(path) = &args.export_bloom_filters {
+        export_bloom_filters(&args.output, path);
+    }
+}
+
+/// Read the input file and write it with the configured writer properties
+fn rewrite(args: &Args) {
     // read key-value metadata
     let parquet_reader =
         SerializedFileReader::new(File::open(&args.input).expect("Unable to open input file"))
@@ -331,7 +447,7 @@ fn main() {
 
     // create actual parquet reader
     let parquet_reader = ParquetRecordBatchReaderBuilder::try_new(
-        File::open(args.input).expect("Unable to open input file"),
+        File::open(&args.input).expect("Unable to open input file"),
     )
     .expect("parquet open")
     .build()
@@ -126,6 +126,12 @@ use bytes::Bytes;
 use std::io::Write;
 use twox_hash::XxHash64;
 
+mod rewrite;
+mod sidecar;
+
+pub use rewrite::{BloomFilterRewriter, compute_bloom_filter};
+pub use sidecar::BloomFilterSidecar;
+
 /// Salt as defined in the [spec](https://github.com/apache/parquet-format/blob/master/BloomFilter.md#technical-approach).
 const SALT: [u32; 8] = [
     0x47b6137b_u32,
@@ -170,7 +176,7 @@ pub struct BloomFilterHeader {
 /// When a value is inserted, [`Block::mask`] picks one bit in each word
 /// (8 bits total), and those bits are OR'd in. When checking, we verify
 /// all 8 bits are set.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[repr(transparent)]
 struct Block([u32; 8]);
 impl Block {
@@ -317,7 +323,7 @@ impl Block {
 ///
 /// The creation of this structure is based on the [`crate::file::properties::BloomFilterProperties`]
 /// struct set via [`crate::file::properties::WriterProperties`] and is thus hidden by default.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Sbbf(Vec<Block>);
 
 pub(crate) const SBBF_HEADER_SIZE_ESTIMATE: usize = 20;
@@ -704,6 +710,37 @@ impl Sbbf {
         self.0.truncate(new_len);
     }
 
+    /// Merge the values inserted into `other` into this filter, so that
+    /// [`Self::check`] returns `true` for any value inserted into either.
+    ///
+    /// Filters of the same size are combined with a blockwise OR. If the sizes
+    /// differ, the larger filter is first [folded](Self::fold_to_target_fpp) down
+    /// to the size of the smaller one, which is only possible when both contain
+    /// a power of two number of blocks. The false positive probability of the
+    /// result is therefore at least that of the smaller input.
+    ///
+    /// Returns an error, leaving `self` unchanged, if the filters cannot be merged.
+    pub fn union(&mut self, other: &Sbbf) -> Result<(), ParquetError> {
+        let (len, other_len) = (self.0.len(), other.0.len());
+        if len != other_len && !(len.is_power_of_two() && other_len.is_power_of_two()) {
+            return Err(ParquetError::General(format!(
+                "Cannot merge bloom filters with {len} and {other_len} blocks"
+            )));
+        }
+
+        let mut other = other.clone();
+        if len > other_len {
+            self.fold_n((len / other_len).trailing_zeros());
+        } else if other_len > len {
+            other.fold_n((other_len / len).trailing_zeros());
+        }
+
+        for (block, other) in self.0.iter_mut().zip(other.0) {
+            *block |= other;
+        }
+        Ok(())
+    }
+
     /// Reads a Sbff from Thrift encoded bytes
     ///
     /// # Examples
@@ -956,6 +993,41 @@ mod tests {
         sbbf.fold_n(1);
     }
 
+    #[test]
+    fn test_union_different_sizes() {
+        let mut small = Sbbf::new_with_num_of_bytes(1024); // 32 blocks
+        let mut large = Sbbf::new_with_num_of_bytes(8 * 1024); // 256 blocks
+        for i in 0..100 {
+            small.insert(&i);
+        }
+        for i in 100..200 {
+            large.insert(&i);
+        }
+
+        let mut merged = large.clone();
+        merged.union(&small).unwrap();
+        assert_eq!(merged.num_blocks(), 32);
+        small.union(&large).unwrap();
+        assert_eq!(small.num_blocks(), 32);
+
+        for i in 0..200 {
+            assert!(merged.check(&i), "value {i} missing after union");
+            assert!(small.check(&i), "value {i} missing after union");
+        }
+    }
+
+    #[test]
+    fn test_union_incompatible_sizes() {
+        let mut a = Sbbf::new(&[0; 3 * 32]);
+        let b = Sbbf::new(&[0; 2 * 32]);
+        let err = a.union(&b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot merge bloom filters with 3 and 2 blocks"
+        );
+        assert_eq!(a.num_blocks(), 3);
+    }
+
     #[test]
     fn test_sbbf_write_round_trip() {
         // Create a bloom filter with a 32-byte bitset (minimum size)
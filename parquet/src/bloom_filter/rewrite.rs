@@ -0,0 +1,452 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Adding bloom filters to already written Parquet files, see [`BloomFilterRewriter`]
+
+use std::io::Write;
+use std::sync::Arc;
+
+use super::{BloomFilterSidecar, Sbbf};
+use crate::column::reader::{ColumnReader, ColumnReaderImpl, get_column_reader};
+use crate::column::writer::ColumnCloseResult;
+use crate::data_type::DataType;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{PageIndexPolicy, ParquetMetaData, ParquetMetaDataReader};
+use crate::file::page_index::column_index::ColumnIndexMetaData;
+use crate::file::properties::{BloomFilterProperties, WriterProperties};
+use crate::file::reader::ChunkReader;
+use crate::file::serialized_reader::SerializedPageReader;
+use crate::file::writer::SerializedFileWriter;
+
+/// The number of records decoded at a time when computing a bloom filter
+const BATCH_SIZE: usize = 1024;
+
+/// Computes the bloom filter of column chunk `column` of row group `row_group`
+/// by decoding its values
+///
+/// The filter is sized using the NDV and FPP of `props`, and folded to the
+/// target FPP once all values have been inserted, as is done when writing.
+/// Values are inserted using their Parquet physical representation, so the
+/// result is identical to a bloom filter computed by the writer.
+pub fn compute_bloom_filter<R: ChunkReader + 'static>(
+    reader: &Arc<R>,
+    metadata: &ParquetMetaData,
+    row_group: usize,
+    column: usize,
+    props: &BloomFilterProperties,
+) -> Result<Sbbf> {
+    let row_group_meta = metadata.row_group(row_group);
+    let chunk = row_group_meta.column(column);
+    let page_locations = metadata
+        .offset_index()
+        .and_then(|i| i.get(row_group))
+        .and_then(|i| i.get(column))
+        .map(|i| i.page_locations.clone());
+    let pages = SerializedPageReader::new(
+        Arc::clone(reader),
+        chunk,
+        usize::try_from(row_group_meta.num_rows())?,
+        page_locations,
+    )?;
+
+    let mut sbbf = Sbbf::new_with_ndv_fpp(props.ndv(), props.fpp())?;
+    match get_column_reader(chunk.column_descr_ptr(), Box::new(pages)) {
+        ColumnReader::BoolColumnReader(r) => insert_values(r, &mut sbbf)?,
+        ColumnReader::Int32ColumnReader(r) => insert_values(r, &mut sbbf)?,
+        ColumnReader::Int64ColumnReader(r) => insert_values(r, &mut sbbf)?,
+        ColumnReader::Int96ColumnReader(r) => insert_values(r, &mut sbbf)?,
+        ColumnReader::FloatColumnReader(r) => insert_values(r, &mut sbbf)?,
+        ColumnReader::DoubleColumnReader(r) => insert_values(r, &mut sbbf)?,
+        ColumnReader::ByteArrayColumnReader(r) => insert_values(r, &mut sbbf)?,
+        ColumnReader::FixedLenByteArrayColumnReader(r) => insert_values(r, &mut sbbf)?,
+    }
+    sbbf.fold_to_target_fpp(props.fpp());
+    Ok(sbbf)
+}
+
+/// Insert all non-null values read by `reader` into `sbbf`
+fn insert_values<T: DataType>(mut reader: ColumnReaderImpl<T>, sbbf: &mut Sbbf) -> Result<()> {
+    let mut values = Vec::with_capacity(BATCH_SIZE);
+    let mut def_levels = Vec::with_capacity(BATCH_SIZE);
+    let mut rep_levels = Vec::with_capacity(BATCH_SIZE);
+    loop {
+        values.clear();
+        def_levels.clear();
+        rep_levels.clear();
+        let (records, _, _) = reader.read_records(
+            BATCH_SIZE,
+            Some(&mut def_levels),
+            Some(&mut rep_levels),
+            &mut values,
+        )?;
+        if records == 0 {
+            return Ok(());
+        }
+        for value in &values {
+            sbbf.insert(value);
+        }
+    }
+}
+
+/// Rewrites a Parquet file with bloom filters added, without re-encoding any
+/// data pages.
+///
+/// Every column chunk of the input is copied verbatim, along with its
+/// statistics and page index. The bloom filter written for each chunk is,
+/// in order of preference:
+///
+/// 1. the bloom filter for the chunk in the [sidecar](Self::with_bloom_filters),
+///    if any
+/// 2. the bloom filter already present in the input file, if any
+/// 3. a bloom filter computed by decoding the chunk, if
+///    [`WriterProperties`] enables bloom filters for the column (see
+///    [`WriterPropertiesBuilder::set_column_bloom_filter_enabled`])
+///
+/// The [`WriterProperties`] also determine the [`BloomFilterPosition`] of the
+/// output, while the schema, row groups and key-value metadata are those of the
+/// input.
+///
+/// Encrypted input files are not supported.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use bytes::Bytes;
+/// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::bloom_filter::{BloomFilterRewriter, Sbbf};
+/// # use parquet::file::properties::WriterProperties;
+/// let col = Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef;
+/// let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+/// let mut input = Vec::new();
+/// let mut writer = ArrowWriter::try_new(&mut input, batch.schema(), None).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.close().unwrap();
+///
+/// let props = WriterProperties::builder()
+///     .set_column_bloom_filter_enabled("col".into(), true)
+///     .build();
+/// let mut output = Vec::new();
+/// let metadata = BloomFilterRewriter::new()
+///     .with_properties(props)
+///     .rewrite(Bytes::from(input), &mut output)
+///     .unwrap();
+///
+/// let output = Bytes::from(output);
+/// let sbbf = Sbbf::read_from_column_chunk(metadata.row_group(0).column(0), &output)
+///     .unwrap()
+///     .unwrap();
+/// assert!(sbbf.check(&2_i64));
+/// ```
+///
+/// [`WriterPropertiesBuilder::set_column_bloom_filter_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_column_bloom_filter_enabled
+/// [`BloomFilterPosition`]: crate::file::properties::BloomFilterPosition
+#[derive(Debug, Clone, Default)]
+pub struct BloomFilterRewriter {
+    properties: WriterProperties,
+    bloom_filters: BloomFilterSidecar,
+}
+
+impl BloomFilterRewriter {
+    /// Create a new [`BloomFilterRewriter`] with default [`WriterProperties`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the [`WriterProperties`] used to select the columns for which bloom
+    /// filters are computed, and their NDV and FPP
+    pub fn with_properties(self, properties: WriterProperties) -> Self {
+        Self { properties, ..self }
+    }
+
+    /// Use the bloom filters of `bloom_filters` in place of those of the input
+    /// file, or of computing them
+    pub fn with_bloom_filters(self, bloom_filters: BloomFilterSidecar) -> Self {
+        Self {
+            bloom_filters,
+            ..self
+        }
+    }
+
+    /// Rewrite `input` to `output`, returning the metadata of the output
+    ///
+    /// Returns an error if the [sidecar](Self::with_bloom_filters) contains a
+    /// bloom filter for a column chunk not present in `input`
+    pub fn rewrite<R, W>(&self, input: R, output: W) -> Result<ParquetMetaData>
+    where
+        R: ChunkReader + 'static,
+        W: Write + Send,
+    {
+        let input = Arc::new(input);
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Optional)
+            .parse_and_finish(input.as_ref())?;
+        let schema = metadata.file_metadata().schema_descr_ptr();
+        for ((row_group, column), _) in self.bloom_filters.iter() {
+            if row_group >= metadata.num_row_groups() || column >= schema.num_columns() {
+                return Err(general_err!(
+                    "Bloom filter sidecar contains row group {row_group} column {column}, \
+                    which is not present in a file with {} row groups and {} columns",
+                    metadata.num_row_groups(),
+                    schema.num_columns()
+                ));
+            }
+        }
+
+        let props = Arc::new(self.properties.clone());
+        let mut writer = SerializedFileWriter::new(output, schema.root_schema_ptr(), props)?;
+        for kv in metadata
+            .file_metadata()
+            .key_value_metadata()
+            .into_iter()
+            .flatten()
+        {
+            writer.append_key_value_metadata(kv.clone());
+        }
+
+        for (rg_idx, row_group) in metadata.row_groups().iter().enumerate() {
+            let mut row_group_writer = writer.next_row_group()?;
+            for (col_idx, chunk) in row_group.columns().iter().enumerate() {
+                let bloom_filter = match self.bloom_filters.get(rg_idx, col_idx) {
+                    Some(sbbf) => Some(sbbf.clone()),
+                    None => match Sbbf::read_from_column_chunk(chunk, input.as_ref())? {
+                        Some(sbbf) => Some(sbbf),
+                        None => self
+                            .properties
+                            .bloom_filter_properties(chunk.column_path())
+                            .map(|p| compute_bloom_filter(&input, &metadata, rg_idx, col_idx, p))
+                            .transpose()?,
+                    },
+                };
+
+                let close = ColumnCloseResult {
+                    bytes_written: chunk.compressed_size() as _,
+                    rows_written: row_group.num_rows() as _,
+                    metadata: chunk.clone(),
+                    bloom_filter,
+                    column_index: metadata
+                        .column_index()
+                        .and_then(|i| i.get(rg_idx))
+                        .and_then(|i| i.get(col_idx))
+                        .filter(|i| !matches!(i, ColumnIndexMetaData::NONE))
+                        .cloned(),
+                    offset_index: metadata
+                        .offset_index()
+                        .and_then(|i| i.get(rg_idx))
+                        .and_then(|i| i.get(col_idx))
+                        .cloned(),
+                };
+                row_group_writer.append_column(input.as_ref(), close)?;
+            }
+            row_group_writer.close()?;
+        }
+        writer.close()
+    }
+}
+
+#[cfg(all(test, feature = "arrow"))]
+mod tests {
+    use super::*;
+    use crate::arrow::ArrowWriter;
+    use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use crate::file::properties::EnabledStatistics;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    use arrow_select::concat::concat_batches;
+    use bytes::Bytes;
+
+    fn batch(range: std::ops::Range<i32>) -> RecordBatch {
+        let ids = Int32Array::from_iter_values(range.clone());
+        let names =
+            StringArray::from_iter(range.map(|i| (i % 5 != 0).then(|| format!("name_{i}"))));
+        RecordBatch::try_from_iter([
+            ("id", Arc::new(ids) as ArrayRef),
+            ("name", Arc::new(names) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    fn write(batches: &[RecordBatch], props: WriterProperties) -> Bytes {
+        let mut buffer = Vec::new();
+        let mut writer =
+            ArrowWriter::try_new(&mut buffer, batches[0].schema(), Some(props)).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+            writer.flush().unwrap();
+        }
+        writer.close().unwrap();
+        Bytes::from(buffer)
+    }
+
+    fn read(data: Bytes) -> RecordBatch {
+        let reader = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        concat_batches(&batches[0].schema(), &batches).unwrap()
+    }
+
+    fn bloom_props() -> WriterProperties {
+        WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_bloom_filter_fpp(0.01)
+            .set_bloom_filter_max_ndv(100)
+            .build()
+    }
+
+    #[test]
+    fn test_rewrite_matches_writer() {
+        let batches = [batch(0..50), batch(50..100)];
+        let props = || {
+            WriterProperties::builder()
+                .set_statistics_enabled(EnabledStatistics::Page)
+                .set_data_page_row_count_limit(10)
+                .set_write_batch_size(10)
+        };
+        let input = write(&batches, props().build());
+        let expected = write(
+            &batches,
+            props()
+                .set_bloom_filter_enabled(true)
+                .set_bloom_filter_fpp(0.01)
+                .set_bloom_filter_max_ndv(100)
+                .build(),
+        );
+
+        let mut output = Vec::new();
+        let metadata = BloomFilterRewriter::new()
+            .with_properties(bloom_props())
+            .rewrite(input.clone(), &mut output)
+            .unwrap();
+        let output = Bytes::from(output);
+
+        assert_eq!(read(output.clone()), read(input.clone()));
+        let expected_meta = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&expected)
+            .unwrap();
+        let output_meta = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&output)
+            .unwrap();
+        assert_eq!(output_meta.column_index(), expected_meta.column_index());
+        assert_eq!(metadata.num_row_groups(), 2);
+
+        for (rg, expected_rg) in metadata.row_groups().iter().zip(expected_meta.row_groups()) {
+            for (chunk, expected_chunk) in rg.columns().iter().zip(expected_rg.columns()) {
+                assert_eq!(chunk.statistics(), expected_chunk.statistics());
+                let actual = Sbbf::read_from_column_chunk(chunk, &output)
+                    .unwrap()
+                    .unwrap();
+                let expected = Sbbf::read_from_column_chunk(expected_chunk, &expected)
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rewrite_selected_columns() {
+        let input = write(&[batch(0..20)], WriterProperties::default());
+        let props = WriterProperties::builder()
+            .set_column_bloom_filter_enabled("name".into(), true)
+            .build();
+
+        let mut output = Vec::new();
+        let metadata = BloomFilterRewriter::new()
+            .with_properties(props.clone())
+            .rewrite(input.clone(), &mut output)
+            .unwrap();
+        let output = Bytes::from(output);
+
+        let computed = BloomFilterSidecar::compute(input, &props).unwrap();
+        assert_eq!(computed.len(), 1);
+        assert_eq!(
+            computed,
+            BloomFilterSidecar::read_from_file(&output).unwrap()
+        );
+
+        let row_group = metadata.row_group(0);
+        assert!(
+            Sbbf::read_from_column_chunk(row_group.column(0), &output)
+                .unwrap()
+                .is_none()
+        );
+        let sbbf = Sbbf::read_from_column_chunk(row_group.column(1), &output)
+            .unwrap()
+            .unwrap();
+        assert!(sbbf.check("name_1"));
+        assert!(sbbf.check("name_19"));
+    }
+
+    #[test]
+    fn test_rewrite_keeps_existing_bloom_filters() {
+        let input = write(&[batch(0..20)], bloom_props());
+        let mut output = Vec::new();
+        let metadata = BloomFilterRewriter::new()
+            .rewrite(input.clone(), &mut output)
+            .unwrap();
+        let output = Bytes::from(output);
+
+        let exported = BloomFilterSidecar::read_from_file(&input).unwrap();
+        let rewritten = BloomFilterSidecar::read_from_file(&output).unwrap();
+        assert_eq!(exported.len(), 2);
+        assert_eq!(exported, rewritten);
+        assert_eq!(metadata.num_row_groups(), 1);
+    }
+
+    #[test]
+    fn test_rewrite_with_sidecar() {
+        let input = write(&[batch(0..20)], WriterProperties::default());
+        let mut sidecar = BloomFilterSidecar::new();
+        let mut sbbf = Sbbf::new_with_num_of_bytes(32);
+        sbbf.insert(&1000_i32);
+        sidecar.insert(0, 0, sbbf);
+
+        let mut output = Vec::new();
+        let metadata = BloomFilterRewriter::new()
+            .with_bloom_filters(sidecar.clone())
+            .rewrite(input, &mut output)
+            .unwrap();
+        let output = Bytes::from(output);
+
+        let rewritten = BloomFilterSidecar::read_from_file(&output).unwrap();
+        assert_eq!(rewritten, sidecar);
+        let sbbf = Sbbf::read_from_column_chunk(metadata.row_group(0).column(0), &output)
+            .unwrap()
+            .unwrap();
+        assert!(sbbf.check(&1000_i32));
+    }
+
+    #[test]
+    fn test_rewrite_with_sidecar_out_of_bounds() {
+        let input = write(&[batch(0..20)], WriterProperties::default());
+        let mut sidecar = BloomFilterSidecar::new();
+        sidecar.insert(1, 0, Sbbf::new_with_num_of_bytes(32));
+
+        let err = BloomFilterRewriter::new()
+            .with_bloom_filters(sidecar)
+            .rewrite(input, Vec::new())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Bloom filter sidecar contains row group 1 column 0, \
+            which is not present in a file with 1 row groups and 2 columns"
+        );
+    }
+}
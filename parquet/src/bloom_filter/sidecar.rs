@@ -0,0 +1,270 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Bloom filters stored outside of a Parquet file, see [`BloomFilterSidecar`]
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::sync::Arc;
+
+use super::{Sbbf, compute_bloom_filter};
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{PageIndexPolicy, ParquetMetaDataReader};
+use crate::file::properties::WriterProperties;
+use crate::file::reader::ChunkReader;
+
+/// Magic bytes at the start of a serialized [`BloomFilterSidecar`]
+const SIDECAR_MAGIC: &[u8; 4] = b"PBF1";
+
+/// A set of bloom filters keyed by row group and leaf column index, that can be
+/// stored separately from the Parquet file they describe, e.g. in a catalog.
+///
+/// A sidecar can be exported from the bloom filters of a file with
+/// [`Self::read_from_file`], or computed from its data with [`Self::compute`].
+/// Bloom filters of a sidecar can be embedded into a file with
+/// [`BloomFilterRewriter::with_bloom_filters`].
+///
+/// # Serialized format
+///
+/// [`Self::write`] produces the 4 magic bytes `PBF1`, followed by the number of
+/// bloom filters as a little-endian `u32`. Each bloom filter is then written
+/// as its row group index, leaf column index and length in bytes, each a
+/// little-endian `u32`, followed by the bloom filter in the form written by
+/// [`Sbbf::write`]. Bloom filters are ordered by row group and then column.
+///
+/// # Example
+/// ```
+/// # use parquet::bloom_filter::{BloomFilterSidecar, Sbbf};
+/// let mut sbbf = Sbbf::new_with_num_of_bytes(32);
+/// sbbf.insert("hello");
+///
+/// let mut sidecar = BloomFilterSidecar::new();
+/// sidecar.insert(0, 1, sbbf);
+///
+/// let mut buffer = Vec::new();
+/// sidecar.write(&mut buffer).unwrap();
+///
+/// let decoded = BloomFilterSidecar::from_bytes(&buffer).unwrap();
+/// assert!(decoded.get(0, 1).unwrap().check("hello"));
+/// assert!(decoded.get(0, 0).is_none());
+/// ```
+///
+/// [`BloomFilterRewriter::with_bloom_filters`]: super::BloomFilterRewriter::with_bloom_filters
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BloomFilterSidecar {
+    bloom_filters: BTreeMap<(usize, usize), Sbbf>,
+}
+
+impl BloomFilterSidecar {
+    /// Create an empty [`BloomFilterSidecar`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the bloom filters present in the Parquet file `reader`
+    pub fn read_from_file<R: ChunkReader>(reader: &R) -> Result<Self> {
+        let metadata = ParquetMetaDataReader::new().parse_and_finish(reader)?;
+        let mut sidecar = Self::new();
+        for (rg_idx, row_group) in metadata.row_groups().iter().enumerate() {
+            for (col_idx, chunk) in row_group.columns().iter().enumerate() {
+                if let Some(sbbf) = Sbbf::read_from_column_chunk(chunk, reader)? {
+                    sidecar.insert(rg_idx, col_idx, sbbf);
+                }
+            }
+        }
+        Ok(sidecar)
+    }
+
+    /// Compute bloom filters for the Parquet file `reader` by decoding its data
+    ///
+    /// A bloom filter is computed for every column chunk of the columns for
+    /// which `properties` enables bloom filters, see
+    /// [`compute_bloom_filter`]. Bloom filters already present in the file
+    /// are ignored.
+    pub fn compute<R: ChunkReader + 'static>(
+        reader: R,
+        properties: &WriterProperties,
+    ) -> Result<Self> {
+        let reader = Arc::new(reader);
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Optional)
+            .parse_and_finish(reader.as_ref())?;
+        let mut sidecar = Self::new();
+        for (rg_idx, row_group) in metadata.row_groups().iter().enumerate() {
+            for (col_idx, chunk) in row_group.columns().iter().enumerate() {
+                if let Some(props) = properties.bloom_filter_properties(chunk.column_path()) {
+                    let sbbf = compute_bloom_filter(&reader, &metadata, rg_idx, col_idx, props)?;
+                    sidecar.insert(rg_idx, col_idx, sbbf);
+                }
+            }
+        }
+        Ok(sidecar)
+    }
+
+    /// Set the bloom filter of leaf column `column` of row group `row_group`,
+    /// returning the bloom filter it replaces, if any
+    pub fn insert(&mut self, row_group: usize, column: usize, sbbf: Sbbf) -> Option<Sbbf> {
+        self.bloom_filters.insert((row_group, column), sbbf)
+    }
+
+    /// Returns the bloom filter of leaf column `column` of row group
+    /// `row_group`, if any
+    pub fn get(&self, row_group: usize, column: usize) -> Option<&Sbbf> {
+        self.bloom_filters.get(&(row_group, column))
+    }
+
+    /// Returns an iterator over the `(row_group, column)` keys and bloom
+    /// filters, ordered by row group and then column
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &Sbbf)> + '_ {
+        self.bloom_filters.iter().map(|(k, v)| (*k, v))
+    }
+
+    /// Returns the number of bloom filters
+    pub fn len(&self) -> usize {
+        self.bloom_filters.len()
+    }
+
+    /// Returns true if there are no bloom filters
+    pub fn is_empty(&self) -> bool {
+        self.bloom_filters.is_empty()
+    }
+
+    /// Serialize the bloom filters to `writer`, see [`BloomFilterSidecar`]
+    /// for the format
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+        writer.write_all(SIDECAR_MAGIC)?;
+        writer.write_all(&to_u32(self.bloom_filters.len())?.to_le_bytes())?;
+        let mut buffer = Vec::new();
+        for ((row_group, column), sbbf) in &self.bloom_filters {
+            buffer.clear();
+            sbbf.write(&mut buffer)?;
+            writer.write_all(&to_u32(*row_group)?.to_le_bytes())?;
+            writer.write_all(&to_u32(*column)?.to_le_bytes())?;
+            writer.write_all(&to_u32(buffer.len())?.to_le_bytes())?;
+            writer.write_all(&buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Deserialize bloom filters written by [`Self::write`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut remaining = bytes
+            .strip_prefix(SIDECAR_MAGIC.as_slice())
+            .ok_or_else(|| general_err!("Invalid bloom filter sidecar: missing magic bytes"))?;
+        let count = read_u32(&mut remaining)?;
+
+        let mut sidecar = Self::new();
+        for _ in 0..count {
+            let row_group = read_u32(&mut remaining)? as usize;
+            let column = read_u32(&mut remaining)? as usize;
+            let len = read_u32(&mut remaining)? as usize;
+            if remaining.len() < len {
+                return Err(eof_err!(
+                    "Invalid bloom filter sidecar: truncated bloom filter"
+                ));
+            }
+            let (data, rest) = remaining.split_at(len);
+            sidecar.insert(row_group, column, Sbbf::from_bytes(data)?);
+            remaining = rest;
+        }
+        if !remaining.is_empty() {
+            return Err(general_err!(
+                "Invalid bloom filter sidecar: {} trailing bytes",
+                remaining.len()
+            ));
+        }
+        Ok(sidecar)
+    }
+}
+
+fn to_u32(value: usize) -> Result<u32> {
+    u32::try_from(value)
+        .map_err(|_| general_err!("Bloom filter sidecar value {value} exceeds u32::MAX"))
+}
+
+fn read_u32(bytes: &mut &[u8]) -> Result<u32> {
+    let (value, rest) = bytes
+        .split_first_chunk::<4>()
+        .ok_or_else(|| eof_err!("Invalid bloom filter sidecar: unexpected end of data"))?;
+    *bytes = rest;
+    Ok(u32::from_le_bytes(*value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sidecar() -> BloomFilterSidecar {
+        let mut sidecar = BloomFilterSidecar::new();
+        for (row_group, column) in [(1, 0), (0, 2), (0, 0)] {
+            let mut sbbf = Sbbf::new_with_num_of_bytes(64);
+            sbbf.insert(&(row_group as i64 * 10 + column as i64));
+            sidecar.insert(row_group, column, sbbf);
+        }
+        sidecar
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let sidecar = sidecar();
+        let mut buffer = Vec::new();
+        sidecar.write(&mut buffer).unwrap();
+
+        let decoded = BloomFilterSidecar::from_bytes(&buffer).unwrap();
+        assert_eq!(decoded, sidecar);
+        let keys: Vec<_> = decoded.iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, vec![(0, 0), (0, 2), (1, 0)]);
+        assert!(decoded.get(0, 2).unwrap().check(&2_i64));
+        assert!(decoded.get(1, 0).unwrap().check(&10_i64));
+        assert!(decoded.get(1, 1).is_none());
+
+        let mut empty = Vec::new();
+        BloomFilterSidecar::new().write(&mut empty).unwrap();
+        assert!(BloomFilterSidecar::from_bytes(&empty).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut buffer = Vec::new();
+        sidecar().write(&mut buffer).unwrap();
+
+        let err = BloomFilterSidecar::from_bytes(&buffer[1..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid bloom filter sidecar: missing magic bytes"
+        );
+
+        let err = BloomFilterSidecar::from_bytes(&buffer[..buffer.len() - 1]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "EOF: Invalid bloom filter sidecar: truncated bloom filter"
+        );
+
+        let err = BloomFilterSidecar::from_bytes(&buffer[..6]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "EOF: Invalid bloom filter sidecar: unexpected end of data"
+        );
+
+        buffer.push(0);
+        let err = BloomFilterSidecar::from_bytes(&buffer).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid bloom filter sidecar: 1 trailing bytes"
+        );
+    }
+}
@@ -1677,6 +1677,108 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
     }
 }
 
+/// Combine the chunk-level [`Statistics`] of two column chunks of `descr`,
+/// containing `left_values` and `right_values` values respectively, into
+/// statistics describing a single chunk containing the values of both.
+///
+/// A chunk without min/max is only ignored if it is known to contain nothing
+/// but nulls, otherwise the combined min/max is unknown. Returns `None` if the
+/// statistics cannot be combined, e.g. because either uses the deprecated
+/// min/max fields.
+#[cfg(feature = "arrow")]
+pub(crate) fn merge_statistics(
+    descr: &ColumnDescriptor,
+    left: &Statistics,
+    left_values: u64,
+    right: &Statistics,
+    right_values: u64,
+) -> Option<Statistics> {
+    fn merge<T: ParquetValueType>(
+        descr: &ColumnDescriptor,
+        left: &ValueStatistics<T>,
+        left_values: u64,
+        right: &ValueStatistics<T>,
+        right_values: u64,
+    ) -> ValueStatistics<T> {
+        let all_null = |s: &ValueStatistics<T>, values| {
+            s.min_opt().is_none() && s.max_opt().is_none() && s.null_count_opt() == Some(values)
+        };
+        let (left_null, right_null) = (all_null(left, left_values), all_null(right, right_values));
+
+        let (min, max) = match (left_null, right_null) {
+            (true, _) => (right.min_opt().cloned(), right.max_opt().cloned()),
+            (_, true) => (left.min_opt().cloned(), left.max_opt().cloned()),
+            _ => match (
+                left.min_opt(),
+                left.max_opt(),
+                right.min_opt(),
+                right.max_opt(),
+            ) {
+                (Some(l_min), Some(l_max), Some(r_min), Some(r_max)) => {
+                    let mut min = Some(l_min.clone());
+                    let mut max = Some(l_max.clone());
+                    update_min(descr, r_min, &mut min);
+                    update_max(descr, r_max, &mut max);
+                    (min, max)
+                }
+                _ => (None, None),
+            },
+        };
+
+        let min_is_exact =
+            (left_null || left.min_is_exact()) && (right_null || right.min_is_exact());
+        let max_is_exact =
+            (left_null || left.max_is_exact()) && (right_null || right.max_is_exact());
+        let sum = |l: Option<u64>, r: Option<u64>| l.zip(r).map(|(l, r)| l + r);
+
+        ValueStatistics::new(
+            min,
+            max,
+            None,
+            sum(left.null_count_opt(), right.null_count_opt()),
+            false,
+        )
+        .with_min_is_exact(min_is_exact)
+        .with_max_is_exact(max_is_exact)
+        .with_nan_count(sum(left.nan_count_opt(), right.nan_count_opt()))
+        .with_backwards_compatible_min_max(
+            left.is_min_max_backwards_compatible() && right.is_min_max_backwards_compatible(),
+        )
+    }
+
+    if left.is_min_max_deprecated() || right.is_min_max_deprecated() {
+        return None;
+    }
+
+    Some(match (left, right) {
+        (Statistics::Boolean(l), Statistics::Boolean(r)) => {
+            merge(descr, l, left_values, r, right_values).into()
+        }
+        (Statistics::Int32(l), Statistics::Int32(r)) => {
+            merge(descr, l, left_values, r, right_values).into()
+        }
+        (Statistics::Int64(l), Statistics::Int64(r)) => {
+            merge(descr, l, left_values, r, right_values).into()
+        }
+        (Statistics::Int96(l), Statistics::Int96(r)) => {
+            merge(descr, l, left_values, r, right_values).into()
+        }
+        (Statistics::Float(l), Statistics::Float(r)) => {
+            merge(descr, l, left_values, r, right_values).into()
+        }
+        (Statistics::Double(l), Statistics::Double(r)) => {
+            merge(descr, l, left_values, r, right_values).into()
+        }
+        (Statistics::ByteArray(l), Statistics::ByteArray(r)) => {
+            merge(descr, l, left_values, r, right_values).into()
+        }
+        (Statistics::FixedLenByteArray(l), Statistics::FixedLenByteArray(r)) => {
+            merge(descr, l, left_values, r, right_values).into()
+        }
+        _ => return None,
+    })
+}
+
 fn update_min<T: ParquetValueType>(descr: &ColumnDescriptor, val: &T, min: &mut Option<T>) {
     match min {
         None => *min = Some(val.clone()),
@@ -0,0 +1,113 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for the `parquet-rewrite` binary
+
+use std::fs::File;
+use std::path::Path;
+use std::process::{Command, Output};
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+use parquet::arrow::ArrowWriter;
+use parquet::bloom_filter::{BloomFilterSidecar, Sbbf};
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use tempfile::TempDir;
+
+fn write_input(path: &Path) {
+    let col = Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef;
+    let batch = RecordBatch::try_from_iter([("a", col)]).unwrap();
+    let mut writer =
+        ArrowWriter::try_new(File::create(path).unwrap(), batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+}
+
+fn rewrite(dir: &TempDir, args: &[&str]) -> Output {
+    let input = dir.path().join("input.parquet");
+    let output = dir.path().join("output.parquet");
+    Command::new(env!("CARGO_BIN_EXE_parquet-rewrite"))
+        .arg("-i")
+        .arg(input)
+        .arg("-o")
+        .arg(output)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_add_and_export_bloom_filters() {
+    let dir = TempDir::new().unwrap();
+    write_input(&dir.path().join("input.parquet"));
+    let sidecar_path = dir.path().join("output.bf");
+
+    let output = rewrite(
+        &dir,
+        &[
+            "--add-bloom-filter",
+            "a",
+            "--export-bloom-filters",
+            sidecar_path.to_str().unwrap(),
+        ],
+    );
+    assert!(output.status.success(), "{output:?}");
+
+    let file = File::open(dir.path().join("output.parquet")).unwrap();
+    let reader = SerializedFileReader::new(file.try_clone().unwrap()).unwrap();
+    let chunk = reader.metadata().row_group(0).column(0);
+    let sbbf = Sbbf::read_from_column_chunk(chunk, &file).unwrap().unwrap();
+    assert!(sbbf.check(&42_i32));
+
+    let sidecar = BloomFilterSidecar::from_bytes(&std::fs::read(sidecar_path).unwrap()).unwrap();
+    assert_eq!(sidecar.len(), 1);
+    assert_eq!(sidecar.get(0, 0), Some(&sbbf));
+}
+
+#[test]
+fn test_add_bloom_filter_conflicts() {
+    let dir = TempDir::new().unwrap();
+    write_input(&dir.path().join("input.parquet"));
+
+    let output = rewrite(
+        &dir,
+        &["--add-bloom-filter", "a", "--compression", "snappy"],
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("cannot be used with"), "{stderr}");
+    assert!(!dir.path().join("output.parquet").exists());
+}
+
+#[test]
+fn test_import_bloom_filters_out_of_bounds() {
+    let dir = TempDir::new().unwrap();
+    write_input(&dir.path().join("input.parquet"));
+
+    let mut sidecar = BloomFilterSidecar::new();
+    sidecar.insert(0, 3, Sbbf::new_with_num_of_bytes(32));
+    let sidecar_path = dir.path().join("input.bf");
+    sidecar.write(File::create(&sidecar_path).unwrap()).unwrap();
+
+    let output = rewrite(
+        &dir,
+        &["--import-bloom-filters", sidecar_path.to_str().unwrap()],
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("row group 0 column 3"), "{stderr}");
+}